@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! A small abstraction over file-system access,
+//! so helpers can be written once,
+//! and used both blocking (with [`StdFs`])
+//! and async (with `TokioFs`, requiring the `async` feature).
+
+use std::fs::Metadata;
+use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+/// The file-system operations the higher layers need.
+///
+/// All operations return futures;
+/// those of [`StdFs`] are already complete when returned,
+/// and can be run without an async runtime with [`StdFs::run`].
+pub trait FsProvider: Send + Sync {
+    /// See [`std::path::Path::try_exists`].
+    fn try_exists(&self, path: &Path) -> impl Future<Output = io::Result<bool>> + Send;
+
+    /// See [`std::fs::metadata`].
+    fn metadata(&self, path: &Path) -> impl Future<Output = io::Result<Metadata>> + Send;
+
+    /// See [`std::fs::create_dir_all`].
+    fn create_dir_all(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send;
+
+    /// See [`std::fs::read`].
+    fn read(&self, path: &Path) -> impl Future<Output = io::Result<Vec<u8>>> + Send;
+
+    /// See [`std::fs::write`].
+    fn write(&self, path: &Path, contents: &[u8]) -> impl Future<Output = io::Result<()>> + Send;
+
+    /// See [`std::fs::rename`].
+    fn rename(&self, from: &Path, to: &Path) -> impl Future<Output = io::Result<()>> + Send;
+
+    /// See [`std::fs::remove_file`].
+    fn remove_file(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send;
+
+    /// See [`std::fs::remove_dir_all`].
+    fn remove_dir_all(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send;
+}
+
+/// Blocking file-system access through [`std::fs`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdFs;
+
+impl StdFs {
+    /// Runs a future that only awaits operations of [`StdFs`] to completion,
+    /// without an async runtime.
+    ///
+    /// # Panics
+    ///
+    /// If the future awaits anything that is not immediately ready,
+    /// e.g. an operation of `TokioFs`.
+    pub fn run<F: Future>(future: F) -> F::Output {
+        let mut context = Context::from_waker(Waker::noop());
+        match pin!(future).poll(&mut context) {
+            Poll::Ready(output) => output,
+            Poll::Pending => {
+                panic!("StdFs::run was given a future that awaits non-StdFs operations")
+            }
+        }
+    }
+}
+
+impl FsProvider for StdFs {
+    fn try_exists(&self, path: &Path) -> impl Future<Output = io::Result<bool>> + Send {
+        std::future::ready(path.try_exists())
+    }
+
+    fn metadata(&self, path: &Path) -> impl Future<Output = io::Result<Metadata>> + Send {
+        std::future::ready(std::fs::metadata(path))
+    }
+
+    fn create_dir_all(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send {
+        std::future::ready(std::fs::create_dir_all(path))
+    }
+
+    fn read(&self, path: &Path) -> impl Future<Output = io::Result<Vec<u8>>> + Send {
+        std::future::ready(std::fs::read(path))
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> impl Future<Output = io::Result<()>> + Send {
+        std::future::ready(std::fs::write(path, contents))
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> impl Future<Output = io::Result<()>> + Send {
+        std::future::ready(std::fs::rename(from, to))
+    }
+
+    fn remove_file(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send {
+        std::future::ready(std::fs::remove_file(path))
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send {
+        std::future::ready(std::fs::remove_dir_all(path))
+    }
+}
+
+/// Async file-system access through [`tokio::fs`].
+#[cfg(feature = "async")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioFs;
+
+#[cfg(feature = "async")]
+impl FsProvider for TokioFs {
+    fn try_exists(&self, path: &Path) -> impl Future<Output = io::Result<bool>> + Send {
+        tokio::fs::try_exists(path)
+    }
+
+    fn metadata(&self, path: &Path) -> impl Future<Output = io::Result<Metadata>> + Send {
+        tokio::fs::metadata(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send {
+        tokio::fs::create_dir_all(path)
+    }
+
+    fn read(&self, path: &Path) -> impl Future<Output = io::Result<Vec<u8>>> + Send {
+        tokio::fs::read(path)
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> impl Future<Output = io::Result<()>> + Send {
+        tokio::fs::write(path, contents.to_vec())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> impl Future<Output = io::Result<()>> + Send {
+        tokio::fs::rename(from, to)
+    }
+
+    fn remove_file(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send {
+        tokio::fs::remove_file(path)
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> impl Future<Output = io::Result<()>> + Send {
+        tokio::fs::remove_dir_all(path)
+    }
+}
+
+/// The provider to use by default:
+/// [`TokioFs`] with the `async` feature, [`StdFs`] otherwise.
+#[cfg(feature = "async")]
+pub type DefaultFs = TokioFs;
+
+/// The provider to use by default:
+/// `TokioFs` with the `async` feature, [`StdFs`] otherwise.
+#[cfg(not(feature = "async"))]
+pub type DefaultFs = StdFs;
@@ -13,3 +13,17 @@ where
     obj.hash(&mut hasher);
     hasher.finish()
 }
+
+/// Hashes bytes with 64 bit FNV-1a.
+///
+/// In contrast to [`hash_num`], the result is stable
+/// across Rust versions and platforms,
+/// so it may be persisted, e.g. as part of a file name.
+#[must_use]
+pub fn stable_hash_num(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
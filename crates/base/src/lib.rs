@@ -2,11 +2,20 @@
 //
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
+pub mod fs;
 pub mod hasher;
+#[cfg(feature = "manifest")]
+pub mod manifest;
 pub mod util;
+#[cfg(feature = "warc")]
+pub mod warc;
 
 use git_version::git_version;
 
+// Only used by the integration tests.
+#[cfg(test)]
+use {tempfile as _, tokio as _, url as _};
+
 // This tests rust code in the README with doc-tests.
 // Though, It will not appear in the generated documentation.
 #[doc = include_str!("../README.md")]
@@ -0,0 +1,350 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Records size, modification time and SHA-256 checksum
+//! of all files in a directory tree,
+//! so the tree can later be verified to be unchanged.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ring::digest;
+
+use crate::util;
+
+/// The default name of the sidecar file a manifest is stored in,
+/// within the directory it describes.
+/// Being hidden, it is not part of the manifest itself.
+pub const MANIFEST_FILE_NAME: &str = ".manifest";
+
+/// What is recorded about a single file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub size: u64,
+    pub modified: SystemTime,
+    pub sha256: [u8; 32],
+}
+
+/// A difference between a manifest and the directory it describes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mismatch {
+    /// The file is in the manifest, but not in the directory.
+    Missing(String),
+    /// The size or content of the file differs from the manifest.
+    Modified(String),
+    /// The file is in the directory, but not in the manifest.
+    Untracked(String),
+}
+
+/// Size, modification time and SHA-256 checksum of all files
+/// in a directory tree, by their path relative to its root
+/// (with `/` as separator).
+///
+/// Hidden files and directories (with a name starting with `.`),
+/// like lock- and temporary files and the manifest sidecar file itself,
+/// are skipped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    entries: BTreeMap<String, ManifestEntry>,
+}
+
+/// Calculates the SHA-256 checksum of a file's content.
+///
+/// # Errors
+///
+/// If the file cannot be read.
+pub fn sha256_file(file: &Path) -> io::Result<[u8; 32]> {
+    let mut reader = fs::File::open(file)?;
+    let mut context = digest::Context::new(&digest::SHA256);
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        let Some(chunk) = buffer.get(..read).filter(|chunk| !chunk.is_empty()) else {
+            break;
+        };
+        context.update(chunk);
+    }
+    let mut sha256 = [0; 32];
+    sha256.copy_from_slice(context.finish().as_ref());
+    Ok(sha256)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+fn from_hex(hex: &str) -> Option<[u8; 32]> {
+    let mut bytes = [0; 32];
+    if hex.len() != 2 * bytes.len() || !hex.is_ascii() {
+        return None;
+    }
+    for (idx, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(hex.get(2 * idx..2 * idx + 2)?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Converts a path relative to the manifest root into its recorded form.
+fn rel_path_str(rel_path: &Path) -> io::Result<String> {
+    let parts = rel_path
+        .iter()
+        .map(|part| {
+            part.to_str()
+                .filter(|name| !name.contains('\n'))
+                .ok_or_else(|| {
+                    invalid_data(format!(
+                        "Unsupported file name for a manifest: '{}'",
+                        rel_path.display()
+                    ))
+                })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    Ok(parts.join("/"))
+}
+
+/// Lists all non-hidden files below `dir`, relative to `root`.
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<String>) -> io::Result<()> {
+    for entry_res in fs::read_dir(dir)? {
+        let entry = entry_res?;
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            collect_files(root, &path, files)?;
+        } else {
+            files.push(rel_path_str(
+                path.strip_prefix(root).map_err(io::Error::other)?,
+            )?);
+        }
+    }
+    Ok(())
+}
+
+impl ManifestEntry {
+    /// Records the current state of a file.
+    ///
+    /// # Errors
+    ///
+    /// If the file or its metadata cannot be read.
+    pub fn of_file(file: &Path) -> io::Result<Self> {
+        let metadata = fs::metadata(file)?;
+        Ok(Self {
+            size: metadata.len(),
+            modified: metadata.modified()?,
+            sha256: sha256_file(file)?,
+        })
+    }
+
+    /// Whether the file still has the recorded size and content.
+    /// The modification time is not considered,
+    /// as it changes on copying without the content changing.
+    ///
+    /// # Errors
+    ///
+    /// If the file or its metadata cannot be read.
+    pub fn matches(&self, file: &Path) -> io::Result<bool> {
+        Ok(fs::metadata(file)?.len() == self.size && sha256_file(file)? == self.sha256)
+    }
+}
+
+impl Manifest {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[must_use]
+    pub fn get(&self, rel_path: &str) -> Option<&ManifestEntry> {
+        self.entries.get(rel_path)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ManifestEntry)> {
+        self.entries
+            .iter()
+            .map(|(rel_path, entry)| (rel_path.as_str(), entry))
+    }
+
+    /// Records the current state of the file at `rel_path` within `root`,
+    /// replacing a previous record of it.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read, or its name is not supported
+    /// (non UTF-8 or containing a newline).
+    pub fn add_file(&mut self, root: &Path, rel_path: &Path) -> io::Result<()> {
+        let entry = ManifestEntry::of_file(&root.join(rel_path))?;
+        self.entries.insert(rel_path_str(rel_path)?, entry);
+        Ok(())
+    }
+
+    /// Records the current state of all (non-hidden) files below `root`.
+    ///
+    /// # Errors
+    ///
+    /// If a directory or file cannot be read,
+    /// or a file name is not supported.
+    pub fn from_dir(root: &Path) -> io::Result<Self> {
+        let mut files = Vec::new();
+        collect_files(root, root, &mut files)?;
+        let mut manifest = Self::new();
+        for rel_path in files {
+            let entry = ManifestEntry::of_file(&root.join(&rel_path))?;
+            manifest.entries.insert(rel_path, entry);
+        }
+        Ok(manifest)
+    }
+
+    /// Records the current state of all (non-hidden) files below `root`.
+    ///
+    /// # Errors
+    ///
+    /// If a directory or file cannot be read,
+    /// or a file name is not supported.
+    #[cfg(feature = "async")]
+    pub async fn from_dir_async(root: &Path) -> io::Result<Self> {
+        let root_buf = root.to_path_buf();
+        util::spawn_blocking_io(move || Self::from_dir(&root_buf)).await
+    }
+
+    /// Compares the files below `root` with this manifest.
+    /// Returns all differences, which is empty if the tree is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// If a directory or file cannot be read.
+    pub fn verify(&self, root: &Path) -> io::Result<Vec<Mismatch>> {
+        let mut files = Vec::new();
+        collect_files(root, root, &mut files)?;
+        files.sort();
+        let mut mismatches = Vec::new();
+        for (rel_path, entry) in &self.entries {
+            if files.binary_search(rel_path).is_err() {
+                mismatches.push(Mismatch::Missing(rel_path.clone()));
+            } else if !entry.matches(&root.join(rel_path))? {
+                mismatches.push(Mismatch::Modified(rel_path.clone()));
+            }
+        }
+        mismatches.extend(
+            files
+                .into_iter()
+                .filter(|rel_path| !self.entries.contains_key(rel_path))
+                .map(Mismatch::Untracked),
+        );
+        Ok(mismatches)
+    }
+
+    /// Compares the files below `root` with this manifest.
+    /// Returns all differences, which is empty if the tree is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// If a directory or file cannot be read.
+    #[cfg(feature = "async")]
+    pub async fn verify_async(&self, root: &Path) -> io::Result<Vec<Mismatch>> {
+        let manifest = self.clone();
+        let root_buf = root.to_path_buf();
+        util::spawn_blocking_io(move || manifest.verify(&root_buf)).await
+    }
+
+    /// Serializes this manifest into its sidecar file format:
+    /// one line per file, with checksum, size,
+    /// modification time (seconds since the UNIX epoch) and path.
+    #[must_use]
+    pub fn to_sidecar(&self) -> String {
+        let mut content = String::from("# sha256 size modified path\n");
+        for (rel_path, entry) in &self.entries {
+            let modified = entry
+                .modified
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            let _ = writeln!(
+                content,
+                "{} {} {}.{:09} {rel_path}",
+                to_hex(&entry.sha256),
+                entry.size,
+                modified.as_secs(),
+                modified.subsec_nanos()
+            );
+        }
+        content
+    }
+
+    /// Parses a manifest from its sidecar file format
+    /// (see [`Self::to_sidecar`]).
+    ///
+    /// # Errors
+    ///
+    /// If a line is not in the expected format.
+    pub fn from_sidecar(content: &str) -> io::Result<Self> {
+        let mut manifest = Self::new();
+        for line in content.lines().filter(|line| !line.starts_with('#')) {
+            let parse_line = || {
+                let mut parts = line.splitn(4, ' ');
+                let sha256 = from_hex(parts.next()?)?;
+                let size = parts.next()?.parse().ok()?;
+                let (secs, subsec) = parts.next()?.split_once('.')?;
+                let nanos = subsec
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|&parsed| parsed < 1_000_000_000)?;
+                let modified = UNIX_EPOCH.checked_add(Duration::new(secs.parse().ok()?, nanos))?;
+                let rel_path = parts.next()?.to_owned();
+                Some((
+                    rel_path,
+                    ManifestEntry {
+                        size,
+                        modified,
+                        sha256,
+                    },
+                ))
+            };
+            let (rel_path, entry) = parse_line()
+                .ok_or_else(|| invalid_data(format!("Invalid manifest line: '{line}'")))?;
+            manifest.entries.insert(rel_path, entry);
+        }
+        Ok(manifest)
+    }
+
+    /// Reads a manifest from a sidecar file.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is not in the expected format.
+    pub fn read(sidecar: &Path) -> io::Result<Self> {
+        Self::from_sidecar(&fs::read_to_string(sidecar)?)
+    }
+
+    /// Writes this manifest into a sidecar file, atomically.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be written.
+    pub fn write(&self, sidecar: &Path) -> io::Result<()> {
+        util::write_atomic(sidecar, self.to_sidecar())
+    }
+}
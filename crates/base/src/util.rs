@@ -5,24 +5,103 @@
 use std::ffi::OsStr;
 // #[cfg(not(feature = "async"))]
 // use std::fs;
+#[cfg(feature = "async")]
+use crate::fs::TokioFs;
+use crate::fs::{FsProvider, StdFs};
+use crate::hasher;
 use std::io;
-use std::path::Path as StdPath;
+use std::path::{Path as StdPath, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 #[cfg(feature = "async")]
 use tokio::fs;
 #[cfg(feature = "url")]
-use {once_cell::sync::Lazy, regex::Regex, url::Url};
+use {
+    percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC},
+    regex::Regex,
+    std::sync::LazyLock,
+    url::Url,
+};
 
 #[cfg(feature = "url")]
-pub static NON_BASIC_CHARS: Lazy<Regex> = Lazy::new(|| Regex::new(r"[^a-zA-Z0-9]").unwrap());
+pub static NON_BASIC_CHARS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[^a-zA-Z0-9]").unwrap());
+#[cfg(feature = "url")]
+pub static MULTI_UNDERSCORES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"__+").unwrap());
+
+/// The maximum length in bytes of a file name
+/// on most common file-systems.
+pub const MAX_FNAME_LEN: usize = 255;
+
+/// The characters of a URL that are percent-encoded in file names:
+/// everything except ASCII alphanumerics, `-`, `_` and `.`.
 #[cfg(feature = "url")]
-pub static MULTI_UNDERSCORES: Lazy<Regex> = Lazy::new(|| Regex::new(r"__+").unwrap());
+const FNAME_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'_').remove(b'.');
+
+/// Separates a file name cut to [`MAX_FNAME_LEN`]
+/// from the hash of the full name.
+const CAPPED_FNAME_SEPARATOR: char = '~';
+
+/// Cuts a file name down to at most [`MAX_FNAME_LEN`] bytes,
+/// if it is longer.
+/// The end is then replaced by a (stable) hash of the full name,
+/// which keeps different long names distinct.
+#[must_use]
+pub fn cap_fname_len(fname: String) -> String {
+    if fname.len() <= MAX_FNAME_LEN {
+        return fname;
+    }
+    let suffix = format!(
+        "{CAPPED_FNAME_SEPARATOR}{:016x}",
+        hasher::stable_hash_num(fname.as_bytes())
+    );
+    let mut cut_at = MAX_FNAME_LEN - suffix.len();
+    while !fname.is_char_boundary(cut_at) {
+        cut_at -= 1;
+    }
+    let mut capped = fname.get(..cut_at).unwrap_or_default().to_owned();
+    capped.push_str(&suffix);
+    capped
+}
 
+/// Converts a URL into a string usable as a file name,
+/// by replacing all non-alphanumeric characters with `_`.
+///
+/// NOTE: This is lossy, so different URLs may end up with the same name;
+///       see [`url2fname_reversible`] for an alternative.
 #[cfg(feature = "url")]
 pub fn url2fname(url: &Url) -> String {
     let url_str = url.as_str();
     let url_cleaned = NON_BASIC_CHARS.replace_all(url_str, "_");
     let url_nameified = MULTI_UNDERSCORES.replace_all(&url_cleaned, "_");
-    url_nameified.into()
+    cap_fname_len(url_nameified.into())
+}
+
+/// Converts a URL into a string usable as a file name, reversibly.
+///
+/// All characters except ASCII alphanumerics, `-`, `_` and `.`
+/// get percent-encoded.
+/// Names longer than [`MAX_FNAME_LEN`] are capped (see [`cap_fname_len`]).
+///
+/// Unless capped, the URL can be restored with [`fname2url`].
+#[cfg(feature = "url")]
+#[must_use]
+pub fn url2fname_reversible(url: &Url) -> String {
+    cap_fname_len(utf8_percent_encode(url.as_str(), FNAME_ENCODE_SET).to_string())
+}
+
+/// Restores the URL from a file name created with [`url2fname_reversible`].
+///
+/// Returns `None` if the name was capped in length,
+/// or is not a (percent-encoded) URL.
+#[cfg(feature = "url")]
+#[must_use]
+pub fn fname2url(fname: &str) -> Option<Url> {
+    if fname.contains(CAPPED_FNAME_SEPARATOR) {
+        return None;
+    }
+    let url_str = percent_decode_str(fname).decode_utf8().ok()?;
+    Url::parse(&url_str).ok()
 }
 
 fn handle_create_dir_res<P: AsRef<StdPath> + Send + ?Sized>(
@@ -60,8 +139,15 @@ pub async fn create_dir_async<P: AsRef<StdPath> + Send>(dir: P) {
     handle_create_dir_res(dir.as_ref(), create_dir_res_async(dir.as_ref()).await);
 }
 
-fn remap_create_dir_res(create_dir_res: io::Result<()>) -> io::Result<()> {
-    create_dir_res.or_else(|err| {
+/// Create a directory if it does not yet exist,
+/// using the given file-system provider.
+/// There is no error if the directory already exists.
+///
+/// # Errors
+///
+/// If the directory cannot be created due to an IO- or permission-error.
+pub async fn create_dir_res_with<F: FsProvider>(fs: &F, dir: &StdPath) -> io::Result<()> {
+    fs.create_dir_all(dir).await.or_else(|err| {
         if err.kind() == io::ErrorKind::AlreadyExists {
             Ok(())
         } else {
@@ -76,8 +162,9 @@ fn remap_create_dir_res(create_dir_res: io::Result<()>) -> io::Result<()> {
 /// # Errors
 ///
 /// If the directory cannot be created due to an IO- or permission-error.
+#[allow(clippy::needless_pass_by_value)]
 pub fn create_dir_res<P: AsRef<StdPath> + Send>(dir: P) -> io::Result<()> {
-    remap_create_dir_res(std::fs::create_dir_all(dir))
+    StdFs::run(create_dir_res_with(&StdFs, dir.as_ref()))
 }
 
 /// Create a directory if it does not yet exist.
@@ -88,20 +175,18 @@ pub fn create_dir_res<P: AsRef<StdPath> + Send>(dir: P) -> io::Result<()> {
 /// If the directory cannot be created due to an IO- or permission-error.
 #[cfg(feature = "async")]
 pub async fn create_dir_res_async<P: AsRef<StdPath> + Send>(dir: P) -> io::Result<()> {
-    remap_create_dir_res(fs::create_dir_all(dir).await)
+    create_dir_res_with(&TokioFs, dir.as_ref()).await
 }
 
 fn report_err_if_not_a_file(file_path: &StdPath) -> io::Result<bool> {
-    Err(io::Error::new(
-        io::ErrorKind::Other,
-        format!(
-            "Should be a file, but is not: '{}' - possible solution: delete it",
-            file_path.display()
-        ),
-    ))
+    Err(io::Error::other(format!(
+        "Should be a file, but is not: '{}' - possible solution: delete it",
+        file_path.display()
+    )))
 }
 
-/// Checks whether the given path exists and is a file.
+/// Checks whether the given path exists and is a file,
+/// using the given file-system provider.
 ///
 /// # Errors
 ///
@@ -109,14 +194,26 @@ fn report_err_if_not_a_file(file_path: &StdPath) -> io::Result<bool> {
 /// - If the path is not a file.
 /// - If there is a permission problem.
 /// - If there is an IO error.
-pub fn look_for_file(file_path: &StdPath) -> io::Result<bool> {
-    let path_exists = StdPath::try_exists(file_path)?;
-    if path_exists && !std::fs::metadata(file_path)?.is_file() {
+pub async fn look_for_file_with<F: FsProvider>(fs: &F, file_path: &StdPath) -> io::Result<bool> {
+    let path_exists = fs.try_exists(file_path).await?;
+    if path_exists && !fs.metadata(file_path).await?.is_file() {
         return report_err_if_not_a_file(file_path);
     }
     Ok(path_exists)
 }
 
+/// Checks whether the given path exists and is a file.
+///
+/// # Errors
+///
+/// - If the path does not exist.
+/// - If the path is not a file.
+/// - If there is a permission problem.
+/// - If there is an IO error.
+pub fn look_for_file(file_path: &StdPath) -> io::Result<bool> {
+    StdFs::run(look_for_file_with(&StdFs, file_path))
+}
+
 /// Checks whether the given path exists and is a file.
 ///
 /// # Errors
@@ -127,35 +224,41 @@ pub fn look_for_file(file_path: &StdPath) -> io::Result<bool> {
 /// - If there is an IO error.
 #[cfg(feature = "async")]
 pub async fn look_for_file_async(file_path: &StdPath) -> io::Result<bool> {
-    let path_exists = fs::try_exists(&file_path).await?;
-    if path_exists && !fs::metadata(&file_path).await?.is_file() {
-        return report_err_if_not_a_file(file_path);
-    }
-    Ok(path_exists)
+    look_for_file_with(&TokioFs, file_path).await
 }
 
-/// Ensures the provided dir exists.
+/// Ensures the provided dir exists,
+/// using the given file-system provider.
 /// Returns whether it was created.
 ///
 /// # Errors
 ///
 /// - if Checking if the directory exists fails.
 /// - if Creating the directory fails.
-pub fn ensure_dir_exists(dir_path: &StdPath) -> io::Result<bool> {
-    let dir_path_exists = std::path::Path::try_exists(dir_path)?;
+pub async fn ensure_dir_exists_with<F: FsProvider>(fs: &F, dir_path: &StdPath) -> io::Result<bool> {
+    let dir_path_exists = fs.try_exists(dir_path).await?;
     if dir_path_exists {
-        if !std::fs::metadata(dir_path)?.is_dir() {
-            return Err(io::Error::new(
-                // io::ErrorKind::NotADirectory,
-                io::ErrorKind::Other,
+        if !fs.metadata(dir_path).await?.is_dir() {
+            return Err(io::Error::other(
                 format!("Should be an ontology cache directory, but is not a directory: '{}' - possible solution: delete it", dir_path.display())));
         }
     } else {
-        std::fs::create_dir_all(dir_path)?;
+        fs.create_dir_all(dir_path).await?;
     }
     Ok(!dir_path_exists)
 }
 
+/// Ensures the provided dir exists.
+/// Returns whether it was created.
+///
+/// # Errors
+///
+/// - if Checking if the directory exists fails.
+/// - if Creating the directory fails.
+pub fn ensure_dir_exists(dir_path: &StdPath) -> io::Result<bool> {
+    StdFs::run(ensure_dir_exists_with(&StdFs, dir_path))
+}
+
 /// Ensures the provided dir exists.
 /// Returns whether it was created.
 ///
@@ -165,20 +268,448 @@ pub fn ensure_dir_exists(dir_path: &StdPath) -> io::Result<bool> {
 /// - if Creating the directory fails.
 #[cfg(feature = "async")]
 pub async fn ensure_dir_exists_async(dir_path: &StdPath) -> io::Result<bool> {
-    let dir_path_exists = fs::try_exists(&dir_path).await?;
-    if dir_path_exists {
-        if !fs::metadata(&dir_path).await?.is_dir() {
-            return Err(io::Error::new(
-                // io::ErrorKind::NotADirectory,
-                io::ErrorKind::Other,
-                format!("Should be an ontology cache directory, but is not a directory: '{}' - possible solution: delete it", dir_path.display())));
+    ensure_dir_exists_with(&TokioFs, dir_path).await
+}
+
+pub fn extract_file_ext(file: &StdPath) -> Option<&str> {
+    file.extension().and_then(OsStr::to_str)
+}
+
+/// Counts the temporary files created by this process,
+/// to make their names unique.
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns a not yet used path for a temporary file
+/// in the same directory as `path`.
+/// The file name of `path` is kept as the suffix of the returned path,
+/// so its extension stays the same.
+///
+/// Being in the same directory (and thus on the same file-system)
+/// ensures that the temporary file can be atomically renamed to `path`.
+#[must_use]
+pub fn sibling_temp_path(path: &StdPath) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map_or_else(|| "file".into(), OsStr::to_string_lossy);
+    let counter = TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    path.with_file_name(format!(".tmp-{}-{counter}.{file_name}", std::process::id()))
+}
+
+/// The directory containing `path`,
+/// which is `.` for a bare file name.
+fn parent_dir(path: &StdPath) -> &StdPath {
+    path.parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or_else(|| StdPath::new("."))
+}
+
+/// Makes sure the rename of a file within the directory `dir`
+/// survives a crash.
+///
+/// # Errors
+///
+/// If the directory cannot be opened or synced.
+#[cfg(unix)]
+fn sync_dir(dir: &StdPath) -> io::Result<()> {
+    std::fs::File::open(dir)?.sync_all()
+}
+
+/// Directories can not be synced on this platform.
+#[cfg(not(unix))]
+#[allow(clippy::unnecessary_wraps)]
+const fn sync_dir(_dir: &StdPath) -> io::Result<()> {
+    Ok(())
+}
+
+/// Moves the fully written file at `tmp_path` to `path`.
+///
+/// Afterwards, `path` either keeps its old content
+/// or has the complete new one, even after a crash.
+/// `tmp_path` has to be on the same file-system as `path`,
+/// see [`sibling_temp_path`].
+///
+/// # Errors
+///
+/// If syncing or renaming the file fails.
+pub fn persist_atomic(tmp_path: &StdPath, path: &StdPath) -> io::Result<()> {
+    std::fs::OpenOptions::new()
+        .write(true)
+        .open(tmp_path)?
+        .sync_all()?;
+    std::fs::rename(tmp_path, path)?;
+    sync_dir(parent_dir(path))
+}
+
+/// Moves the fully written file at `tmp_path` to `path`.
+///
+/// Afterwards, `path` either keeps its old content
+/// or has the complete new one, even after a crash.
+/// `tmp_path` has to be on the same file-system as `path`,
+/// see [`sibling_temp_path`].
+///
+/// # Errors
+///
+/// If syncing or renaming the file fails.
+#[cfg(feature = "async")]
+pub async fn persist_atomic_async(tmp_path: &StdPath, path: &StdPath) -> io::Result<()> {
+    fs::OpenOptions::new()
+        .write(true)
+        .open(tmp_path)
+        .await?
+        .sync_all()
+        .await?;
+    fs::rename(tmp_path, path).await?;
+    #[cfg(unix)]
+    fs::File::open(parent_dir(path)).await?.sync_all().await?;
+    Ok(())
+}
+
+/// Removes a temporary file after a failed write, if it exists.
+/// Errors are ignored, as the error of the write is the relevant one.
+pub fn discard_temp(tmp_path: &StdPath) {
+    let _ = std::fs::remove_file(tmp_path);
+}
+
+/// Writes `contents` to the file at `path`,
+/// replacing it atomically if it already exists.
+/// In contrast to [`std::fs::write`],
+/// this never leaves a partially written file behind,
+/// not even after a crash.
+///
+/// # Errors
+///
+/// If writing, syncing or renaming the (temporary) file fails.
+pub fn write_atomic<P: AsRef<StdPath>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
+    let tmp_path = sibling_temp_path(path.as_ref());
+    let res =
+        std::fs::write(&tmp_path, contents).and_then(|()| persist_atomic(&tmp_path, path.as_ref()));
+    if res.is_err() {
+        discard_temp(&tmp_path);
+    }
+    res
+}
+
+/// Writes `contents` to the file at `path`,
+/// replacing it atomically if it already exists.
+/// In contrast to [`tokio::fs::write`],
+/// this never leaves a partially written file behind,
+/// not even after a crash.
+///
+/// # Errors
+///
+/// If writing, syncing or renaming the (temporary) file fails.
+#[cfg(feature = "async")]
+pub async fn write_atomic_async<P: AsRef<StdPath> + Send, C: AsRef<[u8]> + Send>(
+    path: P,
+    contents: C,
+) -> io::Result<()> {
+    let tmp_path = sibling_temp_path(path.as_ref());
+    let mut res = fs::write(&tmp_path, contents).await;
+    if res.is_ok() {
+        res = persist_atomic_async(&tmp_path, path.as_ref()).await;
+    }
+    if res.is_err() {
+        discard_temp(&tmp_path);
+    }
+    res
+}
+
+/// Opens (creating if necessary) the file used for locking.
+fn open_lock_file(lock_file: &StdPath) -> io::Result<std::fs::File> {
+    std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(lock_file)
+}
+
+/// Runs `action` while holding an exclusive, advisory lock on `lock_file`,
+/// which is created if it does not yet exist.
+/// This blocks until the lock is available.
+///
+/// The lock only synchronizes with other processes (or threads)
+/// that also use this function (or [`with_file_lock_async`])
+/// with the same lock file; it is released when `action` returns.
+/// The lock file itself is left in place,
+/// as removing it would race with other processes waiting for it.
+///
+/// # Errors
+///
+/// If the lock file cannot be created, opened or locked.
+pub fn with_file_lock<P, F, T>(lock_file: P, action: F) -> io::Result<T>
+where
+    P: AsRef<StdPath>,
+    F: FnOnce() -> T,
+{
+    let file = open_lock_file(lock_file.as_ref())?;
+    file.lock()?;
+    let res = action();
+    drop(file);
+    Ok(res)
+}
+
+/// Runs `action` while holding an exclusive, advisory lock on `lock_file`,
+/// which is created if it does not yet exist.
+/// This waits (without blocking the executor) until the lock is available.
+///
+/// See [`with_file_lock`] for details.
+///
+/// # Errors
+///
+/// If the lock file cannot be created, opened or locked.
+#[cfg(feature = "async")]
+pub async fn with_file_lock_async<P, F, Fut, T>(lock_file: P, action: F) -> io::Result<T>
+where
+    P: AsRef<StdPath> + Send,
+    F: FnOnce() -> Fut + Send,
+    Fut: std::future::Future<Output = T> + Send,
+{
+    let unlocked = open_lock_file(lock_file.as_ref())?;
+    let file = tokio::task::spawn_blocking(move || unlocked.lock().map(|()| unlocked))
+        .await
+        .map_err(io::Error::other)??;
+    let res = action().await;
+    drop(file);
+    Ok(res)
+}
+
+/// Runs a blocking file-system operation on tokio's blocking thread pool.
+#[cfg(feature = "async")]
+pub(crate) async fn spawn_blocking_io<F, T>(operation: F) -> io::Result<T>
+where
+    F: FnOnce() -> io::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(operation)
+        .await
+        .map_err(io::Error::other)?
+}
+
+/// Returns the total size in bytes of all files within `dir`, recursively.
+/// Symbolic links are not followed.
+///
+/// # Errors
+///
+/// If `dir` or one of its sub-directories cannot be read.
+pub fn dir_size<P: AsRef<StdPath>>(dir: P) -> io::Result<u64> {
+    let mut size = 0;
+    for entry in std::fs::read_dir(dir)? {
+        size += entry_size(&entry?.path())?;
+    }
+    Ok(size)
+}
+
+/// Returns the total size in bytes of all files within `dir`, recursively.
+/// Symbolic links are not followed.
+///
+/// # Errors
+///
+/// If `dir` or one of its sub-directories cannot be read.
+#[cfg(feature = "async")]
+pub async fn dir_size_async<P: AsRef<StdPath> + Send>(dir: P) -> io::Result<u64> {
+    let dir_buf = dir.as_ref().to_path_buf();
+    spawn_blocking_io(move || dir_size(dir_buf)).await
+}
+
+/// The size of a file, or of all files within a directory.
+fn entry_size(path: &StdPath) -> io::Result<u64> {
+    let metadata = std::fs::symlink_metadata(path)?;
+    if metadata.is_dir() {
+        dir_size(path)
+    } else {
+        Ok(metadata.len())
+    }
+}
+
+/// A direct entry (file or directory) of a directory,
+/// as considered for retention.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirEntryInfo {
+    pub path: PathBuf,
+    /// The last modification time of the entry itself.
+    pub modified: SystemTime,
+    /// The size of the file,
+    /// or the total size of all files within the directory.
+    pub size: u64,
+}
+
+/// Returns the direct entries of `dir`,
+/// the least recently modified one first.
+///
+/// Hidden entries (with a name starting with `.`),
+/// like lock- and temporary files, are skipped.
+///
+/// # Errors
+///
+/// If `dir` or one of its entries cannot be read.
+pub fn oldest_entries<P: AsRef<StdPath>>(dir: P) -> io::Result<Vec<DirEntryInfo>> {
+    let mut entries = Vec::new();
+    for entry_res in std::fs::read_dir(dir)? {
+        let entry = entry_res?;
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
         }
+        let path = entry.path();
+        entries.push(DirEntryInfo {
+            modified: std::fs::symlink_metadata(&path)?.modified()?,
+            size: entry_size(&path)?,
+            path,
+        });
+    }
+    entries.sort_by(|a, b| {
+        a.modified
+            .cmp(&b.modified)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    Ok(entries)
+}
+
+/// Returns the direct entries of `dir`,
+/// the least recently modified one first.
+///
+/// See [`oldest_entries`] for details.
+///
+/// # Errors
+///
+/// If `dir` or one of its entries cannot be read.
+#[cfg(feature = "async")]
+pub async fn oldest_entries_async<P: AsRef<StdPath> + Send>(
+    dir: P,
+) -> io::Result<Vec<DirEntryInfo>> {
+    let dir_buf = dir.as_ref().to_path_buf();
+    spawn_blocking_io(move || oldest_entries(dir_buf)).await
+}
+
+/// Which entries of a directory to keep;
+/// see [`prune_by_policy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Entries not modified for longer than this get removed.
+    pub max_age: Option<Duration>,
+    /// The least recently modified entries get removed,
+    /// until the total size of the remaining ones is at most this.
+    pub max_size: Option<u64>,
+}
+
+impl RetentionPolicy {
+    /// Whether this policy would never remove anything.
+    #[must_use]
+    pub const fn keeps_all(&self) -> bool {
+        self.max_age.is_none() && self.max_size.is_none()
+    }
+
+    /// Selects the entries to remove under this policy,
+    /// given all entries sorted the least recently modified first
+    /// (as returned by [`oldest_entries`]).
+    #[must_use]
+    pub fn select_for_removal(
+        &self,
+        entries: Vec<DirEntryInfo>,
+        now: SystemTime,
+    ) -> Vec<DirEntryInfo> {
+        let mut total_size = entries.iter().map(|entry| entry.size).sum::<u64>();
+        let mut removed = Vec::new();
+        for entry in entries {
+            let too_old = self.max_age.is_some_and(|max_age| {
+                now.duration_since(entry.modified)
+                    .is_ok_and(|age| age > max_age)
+            });
+            let too_big = self.max_size.is_some_and(|max_size| total_size > max_size);
+            if too_old || too_big {
+                total_size -= entry.size;
+                removed.push(entry);
+            }
+        }
+        removed
+    }
+}
+
+/// Removes a file or a directory with all its content.
+fn remove_entry(path: &StdPath) -> io::Result<()> {
+    if std::fs::symlink_metadata(path)?.is_dir() {
+        std::fs::remove_dir_all(path)
     } else {
-        fs::create_dir_all(&dir_path).await?;
+        std::fs::remove_file(path)
     }
-    Ok(!dir_path_exists)
 }
 
-pub fn extract_file_ext(file: &StdPath) -> Option<&str> {
-    file.extension().and_then(OsStr::to_str)
+/// Removes the direct entries of `dir` that are not to be kept
+/// according to `policy`, and returns them.
+/// Hidden entries are never removed (see [`oldest_entries`]).
+///
+/// # Errors
+///
+/// If `dir` cannot be read, or an entry cannot be removed.
+pub fn prune_by_policy<P: AsRef<StdPath>>(
+    dir: P,
+    policy: &RetentionPolicy,
+) -> io::Result<Vec<DirEntryInfo>> {
+    if policy.keeps_all() {
+        return Ok(Vec::new());
+    }
+    let removed = policy.select_for_removal(oldest_entries(dir)?, SystemTime::now());
+    for entry in &removed {
+        remove_entry(&entry.path)?;
+    }
+    Ok(removed)
+}
+
+/// Removes the direct entries of `dir` that are not to be kept
+/// according to `policy`, and returns them.
+///
+/// See [`prune_by_policy`] for details.
+///
+/// # Errors
+///
+/// If `dir` cannot be read, or an entry cannot be removed.
+#[cfg(feature = "async")]
+pub async fn prune_by_policy_async<P: AsRef<StdPath> + Send>(
+    dir: P,
+    policy: &RetentionPolicy,
+) -> io::Result<Vec<DirEntryInfo>> {
+    let dir_buf = dir.as_ref().to_path_buf();
+    let policy_copy = *policy;
+    spawn_blocking_io(move || prune_by_policy(dir_buf, &policy_copy)).await
+}
+
+/// Converts days since the UNIX epoch into a (proleptic Gregorian) date,
+/// see <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let shifted = days + 719_468;
+    let era = shifted.div_euclid(146_097);
+    let day_of_era = shifted.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_shifted = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_shifted + 2) / 5 + 1;
+    let month = if month_shifted < 10 {
+        month_shifted + 3
+    } else {
+        month_shifted - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Formats a point in time in UTC, with second precision,
+/// e.g. `2024-05-01T12:00:00Z`.
+///
+/// This is valid as ISO 8601, `xsd:dateTime` and `WARC-Date`.
+/// Times before the UNIX epoch are formatted as the epoch.
+#[must_use]
+pub fn format_utc(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let days = i64::try_from(secs / 86_400).unwrap_or(i64::MAX / 2);
+    let secs_of_day = secs % 86_400;
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60
+    )
 }
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! A minimal writer for web archive files (WARC, version 1.1),
+//! to keep an archival record of HTTP exchanges with upstream servers.
+//!
+//! See <https://iipc.github.io/warc-specifications/specifications/warc-format/warc-1.1/>.
+
+use std::io::{self, Write};
+use std::time::SystemTime;
+
+use uuid::Uuid;
+
+use crate::util;
+
+/// The file extension of WARC files.
+pub const FILE_EXT: &str = "warc";
+
+/// A new, unique record ID.
+fn new_record_id() -> String {
+    format!("<urn:uuid:{}>", Uuid::new_v4())
+}
+
+/// Writes a single record, with `block` as its content.
+fn write_record<W: Write>(
+    writer: &mut W,
+    headers: &[(&str, &str)],
+    content_type: &str,
+    block: &[u8],
+) -> io::Result<()> {
+    let mut head = String::from("WARC/1.1\r\n");
+    for (name, value) in headers {
+        head.push_str(name);
+        head.push_str(": ");
+        head.push_str(value);
+        head.push_str("\r\n");
+    }
+    head.push_str("Content-Type: ");
+    head.push_str(content_type);
+    head.push_str("\r\nContent-Length: ");
+    head.push_str(&block.len().to_string());
+    head.push_str("\r\n\r\n");
+    writer.write_all(head.as_bytes())?;
+    writer.write_all(block)?;
+    writer.write_all(b"\r\n\r\n")
+}
+
+/// Writes a `warcinfo` record, describing the software that wrote the file.
+/// It should be the first record of a WARC file.
+///
+/// # Errors
+///
+/// If writing fails.
+pub fn write_warcinfo<W: Write>(
+    writer: &mut W,
+    software: &str,
+    date: SystemTime,
+) -> io::Result<()> {
+    let date_str = util::format_utc(date);
+    let record_id = new_record_id();
+    let fields = format!("software: {software}\r\nformat: WARC File Format 1.1\r\n");
+    write_record(
+        writer,
+        &[
+            ("WARC-Type", "warcinfo"),
+            ("WARC-Record-ID", &record_id),
+            ("WARC-Date", &date_str),
+        ],
+        "application/warc-fields",
+        fields.as_bytes(),
+    )
+}
+
+/// Writes a `request` and a `response` record for a single HTTP exchange.
+///
+/// `request` and `response` are the full HTTP messages,
+/// as sent and received on the wire (status-/request-line, headers and body).
+///
+/// # Errors
+///
+/// If writing fails.
+pub fn write_exchange<W: Write>(
+    writer: &mut W,
+    target_uri: &str,
+    date: SystemTime,
+    request: &[u8],
+    response: &[u8],
+) -> io::Result<()> {
+    let date_str = util::format_utc(date);
+    let request_id = new_record_id();
+    let response_id = new_record_id();
+    write_record(
+        writer,
+        &[
+            ("WARC-Type", "response"),
+            ("WARC-Record-ID", &response_id),
+            ("WARC-Date", &date_str),
+            ("WARC-Target-URI", target_uri),
+        ],
+        "application/http;msgtype=response",
+        response,
+    )?;
+    write_record(
+        writer,
+        &[
+            ("WARC-Type", "request"),
+            ("WARC-Record-ID", &request_id),
+            ("WARC-Date", &date_str),
+            ("WARC-Target-URI", target_uri),
+            ("WARC-Concurrent-To", &response_id),
+        ],
+        "application/http;msgtype=request",
+        request,
+    )
+}
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use std::io;
+use std::path::Path;
+
+use rdfoothills_base::fs::{FsProvider, StdFs};
+use rdfoothills_base::util;
+
+/// A helper written once against the provider.
+async fn copy_file<F: FsProvider>(fs: &F, from: &Path, to: &Path) -> io::Result<()> {
+    let content = fs.read(from).await?;
+    fs.write(to, &content).await
+}
+
+#[test]
+fn test_std_fs() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = dir.path().join("from.txt");
+    let to = dir.path().join("to.txt");
+    std::fs::write(&from, "content").unwrap();
+    StdFs::run(copy_file(&StdFs, &from, &to)).unwrap();
+    assert_eq!(std::fs::read_to_string(&to).unwrap(), "content");
+    assert!(StdFs::run(StdFs.try_exists(&to)).unwrap());
+    StdFs::run(StdFs.remove_file(&to)).unwrap();
+    assert!(!StdFs::run(StdFs.try_exists(&to)).unwrap());
+}
+
+#[test]
+fn test_ensure_dir_exists() {
+    let dir = tempfile::tempdir().unwrap();
+    let sub_dir = dir.path().join("a").join("b");
+    assert!(util::ensure_dir_exists(&sub_dir).unwrap());
+    assert!(!util::ensure_dir_exists(&sub_dir).unwrap());
+    assert!(!util::look_for_file(&sub_dir.join("file")).unwrap());
+    assert!(util::look_for_file(&sub_dir).is_err());
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_tokio_fs() {
+    use rdfoothills_base::fs::TokioFs;
+
+    let dir = tempfile::tempdir().unwrap();
+    let from = dir.path().join("from.txt");
+    let to = dir.path().join("to.txt");
+    std::fs::write(&from, "content").unwrap();
+    copy_file(&TokioFs, &from, &to).await.unwrap();
+    assert_eq!(std::fs::read_to_string(&to).unwrap(), "content");
+    let sub_dir = dir.path().join("sub");
+    assert!(util::ensure_dir_exists_async(&sub_dir).await.unwrap());
+    assert!(util::look_for_file_async(&to).await.unwrap());
+}
@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "manifest")]
+
+use std::fs;
+use std::path::Path;
+
+use rdfoothills_base::manifest::{self, Manifest, Mismatch, MANIFEST_FILE_NAME};
+
+fn create_tree() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("ont.ttl"), "<a> <b> <c> .\n").unwrap();
+    fs::create_dir(dir.path().join("sub")).unwrap();
+    fs::write(dir.path().join("sub").join("ont.html"), "<html/>").unwrap();
+    fs::write(dir.path().join(".lock"), "").unwrap();
+    dir
+}
+
+#[test]
+fn test_sha256_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("abc");
+    fs::write(&file, "abc").unwrap();
+    let expected = [
+        0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22,
+        0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00,
+        0x15, 0xad,
+    ];
+    assert_eq!(manifest::sha256_file(&file).unwrap(), expected);
+}
+
+#[test]
+fn test_from_dir() {
+    let dir = create_tree();
+    let manifest = Manifest::from_dir(dir.path()).unwrap();
+    assert_eq!(
+        manifest.iter().map(|(path, _)| path).collect::<Vec<_>>(),
+        ["ont.ttl", "sub/ont.html"]
+    );
+    assert_eq!(manifest.get("ont.ttl").unwrap().size, 14);
+    assert!(manifest.verify(dir.path()).unwrap().is_empty());
+}
+
+#[test]
+fn test_verify() {
+    let dir = create_tree();
+    let manifest = Manifest::from_dir(dir.path()).unwrap();
+    fs::write(dir.path().join("ont.ttl"), "<a> <b> <d> .\n").unwrap();
+    fs::remove_file(dir.path().join("sub").join("ont.html")).unwrap();
+    fs::write(dir.path().join("new.ttl"), "").unwrap();
+    assert_eq!(
+        manifest.verify(dir.path()).unwrap(),
+        [
+            Mismatch::Modified("ont.ttl".to_owned()),
+            Mismatch::Missing("sub/ont.html".to_owned()),
+            Mismatch::Untracked("new.ttl".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn test_sidecar_round_trip() {
+    let dir = create_tree();
+    let manifest = Manifest::from_dir(dir.path()).unwrap();
+    let sidecar = dir.path().join(MANIFEST_FILE_NAME);
+    manifest.write(&sidecar).unwrap();
+    assert_eq!(Manifest::read(&sidecar).unwrap(), manifest);
+    // The sidecar itself is hidden, and thus not tracked.
+    assert!(manifest.verify(dir.path()).unwrap().is_empty());
+    assert!(Manifest::from_sidecar("not a manifest line").is_err());
+}
+
+#[test]
+fn test_sidecar_invalid_modification_time() {
+    let sha256 = "0".repeat(64);
+    for modified in ["1.1000000000", "18446744073709551615.999999999"] {
+        let res = Manifest::from_sidecar(&format!("{sha256} 1 {modified} a"));
+        assert_eq!(
+            res.unwrap_err().kind(),
+            std::io::ErrorKind::InvalidData,
+            "{modified}"
+        );
+    }
+    assert!(Manifest::from_sidecar(&format!("{sha256} 1 1.999999999 a")).is_ok());
+}
+
+#[test]
+fn test_add_file() {
+    let dir = create_tree();
+    let mut manifest = Manifest::new();
+    manifest
+        .add_file(dir.path(), &Path::new("sub").join("ont.html"))
+        .unwrap();
+    assert_eq!(manifest.len(), 1);
+    assert!(manifest.get("sub/ont.html").is_some());
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_async() {
+    let dir = create_tree();
+    let manifest = Manifest::from_dir_async(dir.path()).await.unwrap();
+    assert_eq!(manifest.len(), 2);
+    assert!(manifest.verify_async(dir.path()).await.unwrap().is_empty());
+}
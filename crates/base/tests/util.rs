@@ -0,0 +1,284 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use rdfoothills_base::util::{self, RetentionPolicy};
+
+/// Creates a file of `size` bytes, last modified `age_secs` ago.
+fn create_aged_file(path: &Path, size: usize, age_secs: u64) {
+    fs::write(path, vec![b'x'; size]).unwrap();
+    let modified = SystemTime::now() - Duration::from_secs(age_secs);
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(modified)
+        .unwrap();
+}
+
+/// Creates a cache like directory with entries of different size and age.
+fn create_cache_dir() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    create_aged_file(&dir.path().join("new"), 10, 0);
+    create_aged_file(&dir.path().join("mid"), 20, 100);
+    fs::create_dir(dir.path().join("old")).unwrap();
+    create_aged_file(&dir.path().join("old").join("a"), 30, 0);
+    create_aged_file(&dir.path().join("old").join("b"), 40, 0);
+    fs::File::open(dir.path().join("old"))
+        .unwrap()
+        .set_modified(SystemTime::now() - Duration::from_secs(1000))
+        .unwrap();
+    create_aged_file(&dir.path().join(".lock"), 5, 10_000);
+    dir
+}
+
+fn names(entries: &[util::DirEntryInfo]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|entry| {
+            entry
+                .path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect()
+}
+
+fn dir_entries(dir: &Path) -> Vec<String> {
+    let mut entries = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries
+}
+
+#[test]
+fn test_sibling_temp_path() {
+    let path = Path::new("/some/dir/ont.ttl");
+    let tmp_a = util::sibling_temp_path(path);
+    let tmp_b = util::sibling_temp_path(path);
+    assert_eq!(tmp_a.parent(), path.parent());
+    assert_eq!(tmp_a.extension(), path.extension());
+    assert!(tmp_a
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .starts_with('.'));
+    assert_ne!(tmp_a, tmp_b);
+}
+
+#[test]
+fn test_write_atomic() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("out.txt");
+    util::write_atomic(&file, "first").unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "first");
+    util::write_atomic(&file, b"second").unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "second");
+    assert_eq!(dir_entries(dir.path()), ["out.txt"]);
+}
+
+#[test]
+fn test_write_atomic_failure_keeps_old_content() {
+    let dir = tempfile::tempdir().unwrap();
+    let target = dir.path().join("target");
+    fs::create_dir(&target).unwrap();
+    fs::write(target.join("inner"), "x").unwrap();
+    // Renaming a file onto a non-empty directory fails.
+    assert!(util::write_atomic(&target, "content").is_err());
+    assert!(target.is_dir());
+    assert_eq!(dir_entries(dir.path()), ["target"]);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_write_atomic_async() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("out.txt");
+    util::write_atomic_async(&file, "first").await.unwrap();
+    util::write_atomic_async(&file, "second").await.unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "second");
+    assert_eq!(dir_entries(dir.path()), ["out.txt"]);
+}
+
+#[test]
+fn test_with_file_lock() {
+    let dir = tempfile::tempdir().unwrap();
+    let lock_file = dir.path().join("dir.lock");
+    let inside = AtomicBool::new(false);
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                util::with_file_lock(&lock_file, || {
+                    assert!(!inside.swap(true, Ordering::SeqCst));
+                    thread::sleep(Duration::from_millis(10));
+                    inside.store(false, Ordering::SeqCst);
+                })
+                .unwrap();
+            });
+        }
+    });
+    assert!(lock_file.is_file());
+    assert_eq!(util::with_file_lock(&lock_file, || 42).unwrap(), 42);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_with_file_lock_async() {
+    let dir = tempfile::tempdir().unwrap();
+    let lock_file = dir.path().join("dir.lock");
+    let res = util::with_file_lock_async(&lock_file, || async { 42 }).await;
+    assert_eq!(res.unwrap(), 42);
+    // The lock is released again.
+    assert_eq!(util::with_file_lock(&lock_file, || 43).unwrap(), 43);
+}
+
+#[test]
+fn test_dir_size() {
+    let dir = create_cache_dir();
+    assert_eq!(util::dir_size(dir.path()).unwrap(), 105);
+    assert_eq!(util::dir_size(dir.path().join("old")).unwrap(), 70);
+    assert!(util::dir_size(dir.path().join("missing")).is_err());
+}
+
+#[test]
+fn test_oldest_entries() {
+    let dir = create_cache_dir();
+    let entries = util::oldest_entries(dir.path()).unwrap();
+    assert_eq!(names(&entries), ["old", "mid", "new"]);
+    assert_eq!(
+        entries.iter().map(|entry| entry.size).collect::<Vec<_>>(),
+        [70, 20, 10]
+    );
+}
+
+#[test]
+fn test_prune_by_policy_keeps_all() {
+    let dir = create_cache_dir();
+    let removed = util::prune_by_policy(dir.path(), &RetentionPolicy::default()).unwrap();
+    assert!(removed.is_empty());
+    assert_eq!(dir_entries(dir.path()), [".lock", "mid", "new", "old"]);
+}
+
+#[test]
+fn test_prune_by_policy_max_age() {
+    let dir = create_cache_dir();
+    let policy = RetentionPolicy {
+        max_age: Some(Duration::from_secs(50)),
+        max_size: None,
+    };
+    let removed = util::prune_by_policy(dir.path(), &policy).unwrap();
+    assert_eq!(names(&removed), ["old", "mid"]);
+    assert_eq!(dir_entries(dir.path()), [".lock", "new"]);
+}
+
+#[test]
+fn test_prune_by_policy_max_size() {
+    let dir = create_cache_dir();
+    let policy = RetentionPolicy {
+        max_age: None,
+        max_size: Some(30),
+    };
+    let removed = util::prune_by_policy(dir.path(), &policy).unwrap();
+    assert_eq!(names(&removed), ["old"]);
+    assert_eq!(dir_entries(dir.path()), [".lock", "mid", "new"]);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_retention_async() {
+    let dir = create_cache_dir();
+    assert_eq!(util::dir_size_async(dir.path()).await.unwrap(), 105);
+    let entries = util::oldest_entries_async(dir.path()).await.unwrap();
+    assert_eq!(names(&entries), ["old", "mid", "new"]);
+    let policy = RetentionPolicy {
+        max_age: None,
+        max_size: Some(0),
+    };
+    let removed = util::prune_by_policy_async(dir.path(), &policy)
+        .await
+        .unwrap();
+    assert_eq!(names(&removed), ["old", "mid", "new"]);
+    assert_eq!(dir_entries(dir.path()), [".lock"]);
+}
+
+#[test]
+fn test_cap_fname_len() {
+    assert_eq!(util::cap_fname_len("short".to_owned()), "short");
+    let long_a = "a".repeat(300);
+    let long_b = format!("{}b", "a".repeat(299));
+    let capped_a = util::cap_fname_len(long_a.clone());
+    let capped_b = util::cap_fname_len(long_b);
+    assert_eq!(capped_a.len(), util::MAX_FNAME_LEN);
+    assert_eq!(capped_b.len(), util::MAX_FNAME_LEN);
+    assert_ne!(capped_a, capped_b);
+    assert_eq!(util::cap_fname_len(long_a), capped_a);
+    // Multi-byte characters are not cut in half.
+    let capped_multi = util::cap_fname_len("ä".repeat(200));
+    assert!(capped_multi.len() <= util::MAX_FNAME_LEN);
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn test_url2fname_reversible() {
+    use url::Url;
+
+    for url_str in [
+        "https://www.w3.org/2002/07/owl#",
+        "http://xmlns.com/foaf/0.1/",
+        "https://example.org/a_b/c-d.ttl?x=1&y=%20z",
+        "http://example.org/ä",
+    ] {
+        let url = Url::parse(url_str).unwrap();
+        let fname = util::url2fname_reversible(&url);
+        assert!(fname
+            .chars()
+            .all(|chr| chr.is_ascii_alphanumeric() || "-_.%".contains(chr)));
+        assert_eq!(util::fname2url(&fname), Some(url));
+    }
+    // The lossy variant maps these two to the same name.
+    let url_a = Url::parse("http://a.org/b").unwrap();
+    let url_b = Url::parse("http://a_org/b").unwrap();
+    assert_eq!(util::url2fname(&url_a), util::url2fname(&url_b));
+    assert_ne!(
+        util::url2fname_reversible(&url_a),
+        util::url2fname_reversible(&url_b)
+    );
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn test_url2fname_reversible_capped() {
+    let url = url::Url::parse(&format!("https://example.org/{}", "x".repeat(300))).unwrap();
+    let fname = util::url2fname_reversible(&url);
+    assert_eq!(fname.len(), util::MAX_FNAME_LEN);
+    assert_eq!(util::fname2url(&fname), None);
+    assert_eq!(util::fname2url("not a url"), None);
+}
+
+#[test]
+fn test_format_utc() {
+    assert_eq!(
+        util::format_utc(SystemTime::UNIX_EPOCH),
+        "1970-01-01T00:00:00Z"
+    );
+    assert_eq!(
+        util::format_utc(SystemTime::UNIX_EPOCH + Duration::from_secs(951_827_696)),
+        "2000-02-29T12:34:56Z"
+    );
+    assert_eq!(
+        util::format_utc(SystemTime::UNIX_EPOCH + Duration::from_secs(1_735_689_599)),
+        "2024-12-31T23:59:59Z"
+    );
+}
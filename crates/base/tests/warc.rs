@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "warc")]
+
+use std::time::UNIX_EPOCH;
+
+use rdfoothills_base::warc;
+
+#[test]
+fn test_write_exchange() {
+    let request = b"GET /ont HTTP/1.1\r\nHost: example.org\r\n\r\n";
+    let response = b"HTTP/1.1 200 OK\r\nContent-Type: text/turtle\r\n\r\n<a> <b> <c> .\n";
+    let mut out = Vec::new();
+    warc::write_warcinfo(&mut out, "test 1.0", UNIX_EPOCH).unwrap();
+    warc::write_exchange(
+        &mut out,
+        "http://example.org/ont",
+        UNIX_EPOCH,
+        request,
+        response,
+    )
+    .unwrap();
+    let warc = String::from_utf8(out).unwrap();
+    let records = warc
+        .split("WARC/1.1\r\n")
+        .filter(|record| !record.is_empty())
+        .collect::<Vec<_>>();
+    let [info, response_record, request_record] = records.as_slice() else {
+        panic!("Expected 3 records, got {}", records.len());
+    };
+    assert!(info.starts_with("WARC-Type: warcinfo\r\n"));
+    assert!(response_record.starts_with("WARC-Type: response\r\n"));
+    assert!(response_record.contains("WARC-Target-URI: http://example.org/ont\r\n"));
+    assert!(response_record.contains(&format!(
+        "Content-Length: {}\r\n\r\n{}\r\n\r\n",
+        response.len(),
+        String::from_utf8_lossy(response)
+    )));
+    assert!(request_record.starts_with("WARC-Type: request\r\n"));
+    assert!(request_record.contains("Content-Type: application/http;msgtype=request\r\n"));
+    // The request refers to the response.
+    let response_id = response_record
+        .lines()
+        .find_map(|line| line.strip_prefix("WARC-Record-ID: "))
+        .unwrap();
+    assert!(request_record.contains(&format!("WARC-Concurrent-To: {response_id}\r\n")));
+}
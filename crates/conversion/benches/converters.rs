@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Compares how fast the available converters (native and CLI)
+//! convert synthetic ontologies of different sizes
+//! (see [`doctor::synthetic_ontology`]) from Turtle to N-Triples.
+//!
+//! Run with `cargo bench --package rdfoothills-conversion`.
+
+#![allow(unused_crate_dependencies)]
+
+use std::fs;
+use std::path::Path;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rdfoothills_conversion::{self as conversion, doctor, Converter, OntFile};
+use rdfoothills_mime as mime;
+
+/// The sizes of the ontologies, in number of classes.
+const SIZES: &[(&str, usize)] = &[("small", 10), ("medium", 1_000), ("large", 10_000)];
+
+const TARGET: mime::Type = mime::Type::NTriples;
+
+fn ont_file(dir: &Path, name: &str, mime_type: mime::Type) -> OntFile {
+    OntFile {
+        file: dir.join(format!("{name}.{}", mime_type.file_ext())),
+        mime_type,
+    }
+}
+
+/// The available converters that support the conversion,
+/// and manage to convert `from` once.
+fn usable_converters(from: &OntFile, to: &OntFile) -> Vec<&'static dyn Converter> {
+    conversion::converters()
+        .filter(|converter| {
+            converter.is_available()
+                && converter.supports(from.mime_type, to.mime_type)
+                && converter.convert(from, to).is_ok()
+        })
+        .collect()
+}
+
+fn bench_converters(crit: &mut Criterion) {
+    let dir = tempfile::tempdir().unwrap();
+    let mut group = crit.benchmark_group(format!("turtle-to-{}", TARGET.name()));
+    // The CLI converters take too long for the default of 100.
+    group.sample_size(10);
+    for &(size, classes) in SIZES {
+        let from = ont_file(dir.path(), size, mime::Type::Turtle);
+        let to = ont_file(dir.path(), size, TARGET);
+        fs::write(&from.file, doctor::synthetic_ontology(classes)).unwrap();
+        group.throughput(Throughput::Elements(classes as u64));
+        for converter in usable_converters(&from, &to) {
+            group.bench_with_input(
+                BenchmarkId::new(converter.info().name, size),
+                &from,
+                |bench, input| bench.iter(|| converter.convert(input, &to).unwrap()),
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_converters);
+criterion_main!(benches);
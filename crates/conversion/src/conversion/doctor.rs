@@ -0,0 +1,331 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Diagnoses the registered converters:
+//! whether they are available, which version of the tool is installed,
+//! and whether they actually manage to convert a tiny sample.
+//!
+//! It can also measure how fast they convert ontologies of different sizes.
+
+use std::fmt;
+use std::fmt::Write as _;
+use std::fs;
+use std::time::{Duration, Instant};
+
+use rdfoothills_mime as mime;
+
+use super::version::Version;
+use super::work_dir::WorkDir;
+use super::{Converter, Info, OntFile, Type};
+
+/// A tiny ontology the self-tests convert.
+const SAMPLE_TURTLE: &str = r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+<http://example.org/ont#> a owl:Ontology ;
+    rdfs:label "Sample ontology"@en .
+
+<http://example.org/ont#Thing> a owl:Class ;
+    rdfs:label "Thing"@en ;
+    rdfs:comment "A sample class."@en .
+"#;
+
+/// The formats tried as targets of the self-test, in order;
+/// the first one the converter supports (from Turtle) is used.
+const SELF_TEST_TARGETS: &[mime::Type] = &[
+    mime::Type::NTriples,
+    mime::Type::RdfXml,
+    mime::Type::JsonLd,
+    mime::Type::Html,
+];
+
+/// The outcome of converting the sample with one converter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelfTest {
+    /// The converter supports none of the self-test conversions.
+    Skipped,
+    /// The sample was converted to the given format,
+    /// and back to Turtle, if supported.
+    Passed {
+        target: mime::Type,
+        round_trip: bool,
+    },
+    /// The conversion failed or produced no output.
+    Failed { target: mime::Type, error: String },
+}
+
+/// The diagnosis of a single converter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    pub info: Info,
+    pub available: bool,
+    pub version: Option<String>,
+    /// Whether the installed version of the tool is supported,
+    /// see [`super::check_version`].
+    pub version_supported: bool,
+    /// `None` if the converter is not available.
+    pub self_test: Option<SelfTest>,
+}
+
+impl Diagnosis {
+    /// Whether the converter is available in a supported version,
+    /// and converted the sample.
+    #[must_use]
+    pub const fn is_healthy(&self) -> bool {
+        self.available
+            && self.version_supported
+            && matches!(self.self_test, Some(SelfTest::Passed { .. }))
+    }
+}
+
+impl fmt::Display for SelfTest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Skipped => write!(f, "skipped (no supported sample conversion)"),
+            Self::Passed {
+                target,
+                round_trip: true,
+            } => write!(f, "passed (turtle -> {} -> turtle)", target.name()),
+            Self::Passed {
+                target,
+                round_trip: false,
+            } => write!(f, "passed (turtle -> {})", target.name()),
+            Self::Failed { target, error } => {
+                write!(f, "FAILED (turtle -> {}): {error}", target.name())
+            }
+        }
+    }
+}
+
+impl fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let typ = match self.info.typ {
+            Type::Native => "native",
+            Type::Cli => "CLI",
+            Type::NetworkService => "network service",
+        };
+        write!(f, "{} ({typ}): ", self.info.name)?;
+        if !self.available {
+            return write!(f, "not available");
+        }
+        write!(
+            f,
+            "available, version: {}",
+            self.version.as_deref().unwrap_or("unknown")
+        )?;
+        if !self.version_supported {
+            write!(f, " (unsupported)")?;
+        }
+        if let Some(self_test) = &self.self_test {
+            write!(f, ", self-test: {self_test}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts `from` into a new file of format `to_type` next to it,
+/// and checks that the result is not empty.
+fn convert_checked(
+    converter: &dyn Converter,
+    from: &OntFile,
+    to_type: mime::Type,
+    file_stem: &str,
+) -> Result<OntFile, String> {
+    let to = OntFile {
+        file: from
+            .file
+            .with_file_name(format!("{file_stem}.{}", to_type.file_ext())),
+        mime_type: to_type,
+    };
+    converter
+        .convert(from, &to)
+        .map_err(|err| err.to_string())?;
+    let size = fs::metadata(&to.file).map_err(|err| err.to_string())?.len();
+    if size == 0 {
+        return Err("The output is empty".to_owned());
+    }
+    Ok(to)
+}
+
+/// Converts the sample with the given converter.
+fn self_test(converter: &dyn Converter) -> SelfTest {
+    let Some(&target) = SELF_TEST_TARGETS
+        .iter()
+        .find(|&&target| converter.supports(mime::Type::Turtle, target))
+    else {
+        return SelfTest::Skipped;
+    };
+    let failed = |error: String| SelfTest::Failed { target, error };
+    let dir = match WorkDir::new("self-test") {
+        Ok(dir) => dir,
+        Err(err) => return failed(err.to_string()),
+    };
+    let sample = OntFile {
+        file: dir.path().join("sample.ttl"),
+        mime_type: mime::Type::Turtle,
+    };
+    if let Err(err) = fs::write(&sample.file, SAMPLE_TURTLE) {
+        return failed(err.to_string());
+    }
+    let intermediate = match convert_checked(converter, &sample, target, "converted") {
+        Ok(intermediate) => intermediate,
+        Err(error) => return failed(error),
+    };
+    let round_trip = target.is_machine_readable() && converter.supports(target, mime::Type::Turtle);
+    if round_trip {
+        if let Err(error) = convert_checked(converter, &intermediate, mime::Type::Turtle, "back") {
+            return failed(error);
+        }
+    }
+    dir.finish();
+    SelfTest::Passed { target, round_trip }
+}
+
+/// Diagnoses a single converter.
+/// The self-test is only run if the converter is available.
+#[must_use]
+pub fn diagnose_converter(converter: &dyn Converter) -> Diagnosis {
+    let available = converter.is_available();
+    let version = available.then(|| converter.version()).flatten();
+    let version_supported = version
+        .as_deref()
+        .and_then(Version::extract)
+        .is_none_or(|found| converter.supported_versions().contains(&found));
+    Diagnosis {
+        info: converter.info(),
+        available,
+        version,
+        version_supported,
+        self_test: available.then(|| self_test(converter)),
+    }
+}
+
+/// Diagnoses all registered converters (see [`super::converters`]).
+#[must_use]
+pub fn diagnose() -> Vec<Diagnosis> {
+    super::converters().map(diagnose_converter).collect()
+}
+
+/// Generates an ontology with the given number of classes,
+/// each with a label, a comment and a super-class,
+/// which makes for about four triples per class.
+#[must_use]
+pub fn synthetic_ontology(classes: usize) -> String {
+    (0..classes).fold(
+        String::from(
+            "@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix ex: <http://example.org/bench#> .
+
+ex: a owl:Ontology ;
+    rdfs:label \"Benchmark ontology\"@en .
+",
+        ),
+        |mut turtle, idx| {
+            let _ = write!(
+                turtle,
+                "
+ex:Class{idx} a owl:Class ;
+    rdfs:subClassOf ex:Class{} ;
+    rdfs:label \"Class {idx}\"@en ;
+    rdfs:comment \"The class number {idx}.\"@en .
+",
+                idx / 2
+            );
+            turtle
+        },
+    )
+}
+
+/// How long a converter takes to convert a synthetic ontology
+/// (see [`synthetic_ontology`]) from Turtle into an other format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Benchmark {
+    pub info: Info,
+    /// The number of classes in the ontology.
+    pub classes: usize,
+    pub target: mime::Type,
+    /// The average duration of a conversion,
+    /// or why it failed.
+    pub result: Result<Duration, String>,
+}
+
+impl fmt::Display for Benchmark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: turtle -> {}, {} classes: ",
+            self.info.name,
+            self.target.name(),
+            self.classes
+        )?;
+        match &self.result {
+            Ok(duration) => write!(f, "{:.1} ms", duration.as_secs_f64() * 1000.0),
+            Err(error) => write!(f, "FAILED: {error}"),
+        }
+    }
+}
+
+/// Converts the ontology `iterations` times (at least once),
+/// returning the average duration.
+fn measure(
+    converter: &dyn Converter,
+    from: &OntFile,
+    target: mime::Type,
+    iterations: u32,
+) -> Result<Duration, String> {
+    let mut total = Duration::ZERO;
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        convert_checked(converter, from, target, "converted")?;
+        total += start.elapsed();
+    }
+    Ok(total / iterations.max(1))
+}
+
+/// Measures how long the converter takes
+/// to convert an ontology with the given number of classes,
+/// into the first of the self-test formats it supports.
+/// Returns `None` if it supports none of them.
+#[must_use]
+pub fn benchmark_converter(
+    converter: &dyn Converter,
+    classes: usize,
+    iterations: u32,
+) -> Option<Benchmark> {
+    let &target = SELF_TEST_TARGETS
+        .iter()
+        .find(|&&target| converter.supports(mime::Type::Turtle, target))?;
+    let result = WorkDir::new("benchmark").and_then(|dir| {
+        let from = OntFile {
+            file: dir.path().join("bench.ttl"),
+            mime_type: mime::Type::Turtle,
+        };
+        fs::write(&from.file, synthetic_ontology(classes))?;
+        let result = measure(converter, &from, target, iterations);
+        dir.finish();
+        Ok(result)
+    });
+    Some(Benchmark {
+        info: converter.info(),
+        classes,
+        target,
+        result: result.unwrap_or_else(|err| Err(err.to_string())),
+    })
+}
+
+/// Benchmarks all available converters (see [`benchmark_converter`]),
+/// with ontologies of each of the given numbers of classes,
+/// e.g. small, medium and large ones.
+#[must_use]
+pub fn benchmark(classes: &[usize], iterations: u32) -> Vec<Benchmark> {
+    super::converters()
+        .filter(|converter| converter.is_available())
+        .flat_map(|converter| {
+            classes
+                .iter()
+                .filter_map(move |&num| benchmark_converter(converter, num, iterations))
+        })
+        .collect()
+}
@@ -0,0 +1,57 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Reading and writing whole in-memory graphs with `OxRDF` I/O,
+//! for the native operations that need more than streaming conversion.
+
+use oxrdf::dataset::CanonicalizationAlgorithm;
+use oxrdf::Graph;
+use oxrdfio::{RdfParser, RdfSerializer};
+
+use rdfoothills_mime as mime;
+
+/// Parses an RDF document into a graph,
+/// merging all named graphs into it.
+///
+/// # Errors
+///
+/// Returns `Error::NoNativeSupport` if the format is not supported by `OxRDF` I/O.
+/// Returns `Error::Syntax` if the content is not valid.
+pub fn parse(content: &[u8], mime_type: mime::Type) -> Result<Graph, super::Error> {
+    let format = mime_type
+        .to_rdf_format()
+        .ok_or(super::Error::NoNativeSupport(mime_type))?;
+    let mut graph = Graph::new();
+    for quad_res in RdfParser::from_format(format).for_reader(content) {
+        let quad = quad_res.map_err(super::oxrdfio::map_rdf_parse_error)?;
+        graph.insert(quad.as_ref());
+    }
+    Ok(graph)
+}
+
+/// Serializes a graph into an RDF document.
+///
+/// # Errors
+///
+/// Returns `Error::NoNativeSupport` if the format is not supported by `OxRDF` I/O.
+pub fn serialize(graph: &Graph, mime_type: mime::Type) -> Result<Vec<u8>, super::Error> {
+    let format = mime_type
+        .to_rdf_format()
+        .ok_or(super::Error::NoNativeSupport(mime_type))?;
+    let mut writer = RdfSerializer::from_format(format).for_writer(Vec::new());
+    for triple in graph {
+        writer.serialize_triple(triple)?;
+    }
+    Ok(writer.finish()?)
+}
+
+/// Whether the two graphs are the same, up to the naming of blank nodes.
+pub fn isomorphic(mut one: Graph, mut other: Graph) -> bool {
+    if one.len() != other.len() {
+        return false;
+    }
+    one.canonicalize(CanonicalizationAlgorithm::Unstable);
+    other.canonicalize(CanonicalizationAlgorithm::Unstable);
+    one == other
+}
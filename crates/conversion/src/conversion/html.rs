@@ -0,0 +1,230 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! A minimal, native HTML documentation renderer,
+//! used as a fallback when no external HTML generator is available.
+
+use std::fmt::Write as _;
+
+#[cfg(feature = "async")]
+use async_trait::async_trait;
+use oxrdf::vocab::{rdf, rdfs};
+use oxrdf::{Graph, NamedNodeRef, SubjectRef, TermRef};
+use rdfoothills_vocab::{dcterms, owl, skos};
+
+use super::OntFile;
+use rdfoothills_mime as mime;
+
+#[derive(Debug, Default)]
+pub struct Converter;
+
+/// The predicates to take a term's label from, in order of preference.
+const LABEL_PREDICATES: &[NamedNodeRef<'static>] = &[rdfs::LABEL, skos::PREF_LABEL, dcterms::TITLE];
+
+/// The predicates to take a term's description from, in order of preference.
+const DESCRIPTION_PREDICATES: &[NamedNodeRef<'static>] =
+    &[rdfs::COMMENT, skos::DEFINITION, dcterms::DESCRIPTION];
+
+/// The types of the terms listed as classes.
+const CLASS_TYPES: &[NamedNodeRef<'static>] = &[owl::CLASS, rdfs::CLASS];
+
+/// The types of the terms listed as properties,
+/// with how they are called in the output.
+const PROPERTY_TYPES: &[(NamedNodeRef<'static>, &str)] = &[
+    (owl::OBJECT_PROPERTY, "Object property"),
+    (owl::DATATYPE_PROPERTY, "Datatype property"),
+    (owl::ANNOTATION_PROPERTY, "Annotation property"),
+    (rdf::PROPERTY, "Property"),
+];
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em auto; max-width: 60em; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: left; vertical-align: top; }
+th { background: #eee; }
+code { word-break: break-all; }";
+
+impl Converter {
+    const fn supports_format(fmt: mime::Type) -> bool {
+        fmt.to_rdf_format().is_some()
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for chr in text.chars() {
+        match chr {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(chr),
+        }
+    }
+    escaped
+}
+
+/// Picks the literal value of the first of the `predicates`
+/// that `subject` has one for,
+/// preferring English, then no language, then any other language.
+fn pick_literal(
+    graph: &Graph,
+    subject: NamedNodeRef<'_>,
+    predicates: &[NamedNodeRef<'_>],
+) -> Option<String> {
+    predicates.iter().find_map(|&predicate| {
+        let mut literals = graph
+            .objects_for_subject_predicate(subject, predicate)
+            .filter_map(|object| match object {
+                TermRef::Literal(literal) => Some(literal),
+                TermRef::NamedNode(_) | TermRef::BlankNode(_) | TermRef::Triple(_) => None,
+            })
+            .map(|literal| {
+                let rank = match literal.language() {
+                    Some(lang) if lang.eq_ignore_ascii_case("en") || lang.starts_with("en-") => 0,
+                    None => 1,
+                    Some(_) => 2,
+                };
+                (rank, literal.value().to_owned())
+            })
+            .collect::<Vec<_>>();
+        literals.sort();
+        literals.into_iter().next().map(|(_, value)| value)
+    })
+}
+
+/// All named subjects of any of the given `types`, sorted by IRI.
+fn subjects_of_types<'a>(graph: &'a Graph, types: &[NamedNodeRef<'_>]) -> Vec<NamedNodeRef<'a>> {
+    let mut subjects = types
+        .iter()
+        .flat_map(|&typ| graph.subjects_for_predicate_object(rdf::TYPE, typ))
+        .filter_map(|subject| match subject {
+            SubjectRef::NamedNode(node) => Some(node),
+            SubjectRef::BlankNode(_) | SubjectRef::Triple(_) => None,
+        })
+        .collect::<Vec<_>>();
+    subjects.sort_by_key(|node| node.as_str());
+    subjects.dedup();
+    subjects
+}
+
+/// The part of a term's IRI after the last `#` or `/`,
+/// used as its anchor and as fallback label.
+fn local_name(iri: &str) -> &str {
+    iri.rsplit(['#', '/'])
+        .find(|part| !part.is_empty())
+        .unwrap_or(iri)
+}
+
+/// Renders a table of terms,
+/// with an additional "Type" column if any of them has a type given.
+fn render_terms(
+    html: &mut String,
+    graph: &Graph,
+    heading: &str,
+    terms: &[(NamedNodeRef<'_>, Option<&str>)],
+) {
+    if terms.is_empty() {
+        return;
+    }
+    let rows = terms.iter().fold(String::new(), |mut rows, &(term, typ)| {
+        let label = pick_literal(graph, term, LABEL_PREDICATES)
+            .unwrap_or_else(|| local_name(term.as_str()).to_owned());
+        let description = pick_literal(graph, term, DESCRIPTION_PREDICATES).unwrap_or_default();
+        let typ_cell = typ.map_or_else(String::new, |name| format!("<td>{}</td>", escape(name)));
+        let _ = writeln!(
+            rows,
+            "<tr id=\"{}\"><td><code>{}</code></td>{typ_cell}<td>{}</td><td>{}</td></tr>",
+            escape(local_name(term.as_str())),
+            escape(term.as_str()),
+            escape(&label),
+            escape(&description),
+        );
+        rows
+    });
+    let type_header = if terms.iter().any(|(_, typ)| typ.is_some()) {
+        "<th>Type</th>"
+    } else {
+        ""
+    };
+    let _ = write!(
+        html,
+        "<h2>{heading}</h2>\n<table>\n<thead><tr><th>IRI</th>{type_header}<th>Label</th><th>Description</th></tr></thead>\n<tbody>\n{rows}</tbody>\n</table>\n"
+    );
+}
+
+/// Renders simple HTML documentation of the ontology in `graph`.
+fn render(graph: &Graph) -> String {
+    let ontology = subjects_of_types(graph, &[owl::ONTOLOGY])
+        .into_iter()
+        .next();
+    let title = ontology
+        .and_then(|ont| pick_literal(graph, ont, LABEL_PREDICATES))
+        .unwrap_or_else(|| "Ontology".to_owned());
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n<h1>{title}</h1>\n",
+        title = escape(&title)
+    );
+    if let Some(ont) = ontology {
+        let _ = writeln!(html, "<p><code>{}</code></p>", escape(ont.as_str()));
+        if let Some(description) = pick_literal(graph, ont, DESCRIPTION_PREDICATES) {
+            let _ = writeln!(html, "<p>{}</p>", escape(&description));
+        }
+    }
+    let classes = subjects_of_types(graph, CLASS_TYPES)
+        .into_iter()
+        .map(|class| (class, None))
+        .collect::<Vec<_>>();
+    render_terms(&mut html, graph, "Classes", &classes);
+    let mut properties = PROPERTY_TYPES
+        .iter()
+        .flat_map(|&(typ, typ_name)| {
+            subjects_of_types(graph, &[typ])
+                .into_iter()
+                .map(move |property| (property, Some(typ_name)))
+        })
+        .collect::<Vec<_>>();
+    // Only list each property once,
+    // with the first of its types in `PROPERTY_TYPES`.
+    properties.sort_by_key(|(property, _)| property.as_str());
+    properties.dedup_by_key(|(property, _)| *property);
+    render_terms(&mut html, graph, "Properties", &properties);
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+#[cfg_attr(feature = "async", async_trait)]
+impl super::Converter for Converter {
+    fn info(&self) -> super::Info {
+        super::Info {
+            quality: super::Quality::Data,
+            priority: super::Priority::Fallback,
+            typ: super::Type::Native,
+            name: "native HTML",
+        }
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn supports(&self, from: mime::Type, to: mime::Type) -> bool {
+        to == mime::Type::Html && Self::supports_format(from)
+    }
+
+    fn convert(&self, from: &OntFile, to: &OntFile) -> Result<(), super::Error> {
+        let content = std::fs::read(&from.file)?;
+        let html = render(&super::graph::parse(&content, from.mime_type)?);
+        std::fs::write(&to.file, html)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    async fn convert_async(&self, from: &OntFile, to: &OntFile) -> Result<(), super::Error> {
+        let content = tokio::fs::read(&from.file).await?;
+        let html = render(&super::graph::parse(&content, from.mime_type)?);
+        tokio::fs::write(&to.file, html).await?;
+        Ok(())
+    }
+}
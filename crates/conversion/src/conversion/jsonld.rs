@@ -0,0 +1,783 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Brings the JSON-LD written by the converters
+//! into the form asked for with [`Options`].
+//!
+//! The converters all write expanded JSON-LD, as generated from RDF,
+//! so only what is needed on top of that is implemented,
+//! which is a subset of the JSON-LD 1.1 algorithms:
+//!
+//! - Only simple term definitions (`"term": "iri"` or `{"@id": "iri"}`)
+//!   and `@vocab` of the context are used to compact IRIs;
+//!   terms with type coercion, containers and the like are never used.
+//! - Framing matches nodes by `@id`, by `@type`,
+//!   or else by having all the properties of the frame,
+//!   and embeds each referenced node once (`"@embed": "@once"`);
+//!   all other framing keywords are ignored.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use super::Error;
+
+/// A context to compact the JSON-LD output with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Context {
+    /// A context as JSON, either the context itself,
+    /// or a document with a `@context` entry.
+    Inline(String),
+    /// A remote context, which is referenced by its URL in the output.
+    /// It is not fetched;
+    /// its content (like `Inline`) is only used to compact IRIs with,
+    /// if given as `document`.
+    Url {
+        url: String,
+        document: Option<String>,
+    },
+}
+
+/// The form of the JSON-LD output.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Form {
+    /// As written by the converters;
+    /// the context is ignored.
+    #[default]
+    Expanded,
+    Compacted,
+    /// All nodes at the top level, in a `@graph`;
+    /// compacted only if there is a context.
+    Flattened,
+    /// Framed with this frame document (JSON), and compacted.
+    /// If no context is given, the one of the frame is used.
+    Framed(String),
+}
+
+/// How to write JSON-LD.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    pub context: Option<Context>,
+    pub form: Form,
+}
+
+impl Options {
+    /// Whether the JSON-LD is to be left as written by the converters.
+    #[must_use]
+    pub const fn is_as_written(&self) -> bool {
+        matches!(self.form, Form::Expanded)
+    }
+}
+
+fn invalid(what: &str, reason: impl std::fmt::Display) -> Error {
+    Error::JsonLd(format!("{what}: {reason}"))
+}
+
+fn parse(json: &str, what: &str) -> Result<Value, Error> {
+    serde_json::from_str(json).map_err(|err| invalid(what, err))
+}
+
+/// Wraps a value in an array, if it is not one already.
+fn into_array(value: Value) -> Vec<Value> {
+    if let Value::Array(values) = value {
+        values
+    } else {
+        vec![value]
+    }
+}
+
+/// Whether a term definition may be used as a prefix
+/// in compact IRIs, if it has no explicit `@prefix`.
+fn ends_with_gen_delim(iri: &str) -> bool {
+    iri.ends_with([':', '/', '?', '#', '[', ']', '@'])
+}
+
+/// The IRI of a term definition, whether the definition is simple,
+/// and its explicit prefix flag;
+/// or `None` if it does not map the term to an IRI.
+fn term_definition(definition: &Value) -> Option<(String, bool, Option<bool>)> {
+    if let Some(iri) = definition.as_str() {
+        return (!iri.starts_with('@')).then(|| (iri.to_owned(), true, None));
+    }
+    let def = definition.as_object()?;
+    let iri = def
+        .get("@id")
+        .and_then(Value::as_str)
+        .filter(|iri| !iri.starts_with('@'))?;
+    let simple = def
+        .keys()
+        .all(|key| matches!(key.as_str(), "@id" | "@prefix" | "@protected"));
+    let prefix = def.get("@prefix").and_then(Value::as_bool).unwrap_or(false);
+    Some((iri.to_owned(), simple, Some(prefix)))
+}
+
+/// The (usable parts of the) definitions of a context.
+#[derive(Debug, Default)]
+struct Definitions {
+    /// Simple terms, by their IRI.
+    terms: BTreeMap<String, String>,
+    /// All defined terms, including those that are not usable,
+    /// which must not be produced by accident.
+    defined: BTreeSet<String>,
+    /// The IRIs of all terms that may be used as prefix, by prefix.
+    prefixes: BTreeMap<String, String>,
+    /// The IRIs of all terms, by term.
+    iris: BTreeMap<String, String>,
+    vocab: Option<String>,
+    /// Whether plain strings could get a language or direction,
+    /// so they have to remain value objects.
+    plain_strings_unsafe: bool,
+}
+
+impl Definitions {
+    /// Definitions of a context that is not known,
+    /// which can therefore not be used for anything.
+    fn unknown() -> Self {
+        Self {
+            plain_strings_unsafe: true,
+            ..Self::default()
+        }
+    }
+
+    fn from_context(context: &Value) -> Result<Self, Error> {
+        let mut defs = Self::default();
+        // term -> (raw IRI, usable as a simple term, prefix flag)
+        let mut raw: BTreeMap<String, (String, bool, Option<bool>)> = BTreeMap::new();
+        for local in into_array(context.clone()) {
+            let Value::Object(entries) = local else {
+                return Err(invalid(
+                    "context",
+                    "only inline context objects are supported, not references to remote contexts",
+                ));
+            };
+            for (key, definition) in entries {
+                match key.as_str() {
+                    "@vocab" => defs.vocab = definition.as_str().map(ToOwned::to_owned),
+                    "@language" | "@direction" => {
+                        defs.plain_strings_unsafe |= !definition.is_null();
+                    }
+                    _ if key.starts_with('@') => {}
+                    _ => {
+                        defs.defined.insert(key.clone());
+                        if let Some(mapping) = term_definition(&definition) {
+                            raw.insert(key, mapping);
+                        }
+                    }
+                }
+            }
+        }
+        for (term, (raw_iri, simple, prefix)) in &raw {
+            let iri = Self::expand_definition(raw_iri, &raw, defs.vocab.as_deref());
+            // The shortest (then least) term wins.
+            let better = defs
+                .terms
+                .get(&iri)
+                .is_none_or(|other| (term.len(), term.as_str()) < (other.len(), other.as_str()));
+            if *simple && better {
+                defs.terms.insert(iri.clone(), term.clone());
+            }
+            if prefix.unwrap_or_else(|| ends_with_gen_delim(&iri)) && !term.contains(':') {
+                defs.prefixes.insert(term.clone(), iri.clone());
+            }
+            defs.iris.insert(term.clone(), iri);
+        }
+        Ok(defs)
+    }
+
+    /// Expands the IRI of a term definition,
+    /// which may be a compact IRI using another term of the same context,
+    /// or relative to the vocabulary.
+    fn expand_definition(
+        raw_iri: &str,
+        raw: &BTreeMap<String, (String, bool, Option<bool>)>,
+        vocab: Option<&str>,
+    ) -> String {
+        if let Some((prefix, suffix)) = raw_iri.split_once(':') {
+            if !suffix.starts_with("//") {
+                if let Some((prefix_iri, _, _)) = raw.get(prefix) {
+                    return format!("{prefix_iri}{suffix}");
+                }
+            }
+            return raw_iri.to_owned();
+        }
+        vocab.map_or_else(
+            || raw_iri.to_owned(),
+            |vocab_iri| format!("{vocab_iri}{raw_iri}"),
+        )
+    }
+
+    /// Expands a term, compact IRI or (with `vocab`) vocabulary relative IRI,
+    /// as used in a frame.
+    fn expand(&self, value: &str, vocab: bool) -> String {
+        if value.starts_with('@') {
+            return value.to_owned();
+        }
+        if vocab {
+            if let Some(iri) = self.iris.get(value) {
+                return iri.clone();
+            }
+        }
+        if let Some((prefix, suffix)) = value.split_once(':') {
+            if !suffix.starts_with("//") {
+                if let Some(prefix_iri) = self.prefixes.get(prefix) {
+                    return format!("{prefix_iri}{suffix}");
+                }
+            }
+            return value.to_owned();
+        }
+        match &self.vocab {
+            Some(vocab_iri) if vocab => format!("{vocab_iri}{value}"),
+            _ => value.to_owned(),
+        }
+    }
+
+    /// Compacts an IRI with a term, vocabulary relative (both only with `vocab`)
+    /// or as a compact IRI, whichever is possible first,
+    /// choosing the shortest one of each.
+    fn compact(&self, iri: &str, vocab: bool) -> String {
+        if vocab {
+            if let Some(term) = self.terms.get(iri) {
+                return term.clone();
+            }
+            if let Some(suffix) = self
+                .vocab
+                .as_deref()
+                .and_then(|vocab_iri| iri.strip_prefix(vocab_iri))
+            {
+                if !suffix.is_empty() && !suffix.contains(':') && !self.defined.contains(suffix) {
+                    return suffix.to_owned();
+                }
+            }
+        }
+        self.prefixes
+            .iter()
+            .filter_map(|(prefix, prefix_iri)| {
+                let suffix = iri.strip_prefix(prefix_iri.as_str())?;
+                let compact = format!("{prefix}:{suffix}");
+                (!suffix.is_empty()
+                    && !suffix.starts_with("//")
+                    && !self.defined.contains(&compact))
+                .then_some(compact)
+            })
+            .min_by(|one, other| one.len().cmp(&other.len()).then_with(|| one.cmp(other)))
+            .unwrap_or_else(|| iri.to_owned())
+    }
+}
+
+/// The context to put into the output, and its definitions.
+fn context(
+    context: Option<&Context>,
+    frame: Option<&Map<String, Value>>,
+) -> Result<Option<(Value, Definitions)>, Error> {
+    let inline = |json: &str, what: &str| -> Result<Value, Error> {
+        let mut value = parse(json, what)?;
+        Ok(value
+            .as_object_mut()
+            .and_then(|document| document.remove("@context"))
+            .unwrap_or(value))
+    };
+    match context {
+        Some(Context::Inline(json)) => {
+            let value = inline(json, "context")?;
+            let defs = Definitions::from_context(&value)?;
+            Ok(Some((value, defs)))
+        }
+        Some(Context::Url { url, document }) => {
+            let defs = match document {
+                Some(json) => Definitions::from_context(&inline(json, "context document")?)?,
+                None => Definitions::unknown(),
+            };
+            Ok(Some((Value::String(url.clone()), defs)))
+        }
+        None => match frame.and_then(|frame_doc| frame_doc.get("@context")) {
+            Some(value) => Ok(Some((value.clone(), Definitions::from_context(value)?))),
+            None => Ok(None),
+        },
+    }
+}
+
+/// Collects all nodes of expanded JSON-LD into node maps,
+/// one per graph, replacing embedded nodes by references.
+#[derive(Default)]
+struct Flattener {
+    /// The nodes by their `@id`, by graph name;
+    /// the default graph is named `@default`.
+    graphs: BTreeMap<String, BTreeMap<String, Map<String, Value>>>,
+    /// The new labels of blank nodes, by their original one.
+    blank_nodes: HashMap<String, String>,
+    blank_node_count: usize,
+}
+
+const DEFAULT_GRAPH: &str = "@default";
+
+impl Flattener {
+    /// Relabels blank nodes, to prevent clashes with generated labels.
+    fn blank_node(&mut self, label: Option<&str>) -> String {
+        if let Some(existing) = label.and_then(|old| self.blank_nodes.get(old)) {
+            return existing.clone();
+        }
+        let new_label = format!("_:b{}", self.blank_node_count);
+        self.blank_node_count += 1;
+        if let Some(old) = label {
+            self.blank_nodes.insert(old.to_owned(), new_label.clone());
+        }
+        new_label
+    }
+
+    fn id(&mut self, id: Option<&str>) -> String {
+        match id {
+            Some(iri) if !iri.starts_with("_:") => iri.to_owned(),
+            _ => self.blank_node(id),
+        }
+    }
+
+    fn node(&mut self, node: Map<String, Value>, graph: &str) -> Result<String, Error> {
+        let id = self.id(node.get("@id").and_then(Value::as_str));
+        let mut entries = Vec::new();
+        for (key, value) in node {
+            match key.as_str() {
+                "@id" => {}
+                "@graph" => {
+                    self.graphs.entry(id.clone()).or_default();
+                    for graph_entry in into_array(value) {
+                        let Value::Object(graph_node) = graph_entry else {
+                            return Err(invalid("document", "a graph entry is not a node object"));
+                        };
+                        self.node(graph_node, &id)?;
+                    }
+                }
+                "@type" => {
+                    let types = into_array(value)
+                        .into_iter()
+                        .map(|typ| match typ.as_str() {
+                            Some(label) if label.starts_with("_:") => {
+                                Value::String(self.blank_node(Some(label)))
+                            }
+                            _ => typ,
+                        })
+                        .collect();
+                    entries.push((key, types));
+                }
+                _ if key.starts_with('@') => entries.push((key, into_array(value))),
+                _ => {
+                    let values = into_array(value)
+                        .into_iter()
+                        .map(|item| self.value(item, graph))
+                        .collect::<Result<_, _>>()?;
+                    entries.push((key, values));
+                }
+            }
+        }
+        let merged = self
+            .graphs
+            .entry(graph.to_owned())
+            .or_default()
+            .entry(id.clone())
+            .or_insert_with(|| Map::from_iter([("@id".to_owned(), Value::String(id.clone()))]));
+        for (key, values) in entries {
+            let Value::Array(existing) = merged
+                .entry(key)
+                .or_insert_with(|| Value::Array(Vec::new()))
+            else {
+                continue;
+            };
+            for item in values {
+                if !existing.contains(&item) {
+                    existing.push(item);
+                }
+            }
+        }
+        Ok(id)
+    }
+
+    fn value(&mut self, value: Value, graph: &str) -> Result<Value, Error> {
+        let Value::Object(mut object) = value else {
+            return Err(invalid("document", "it is not in expanded form"));
+        };
+        if object.contains_key("@value") {
+            return Ok(Value::Object(object));
+        }
+        if let Some(list) = object.remove("@list") {
+            let items = into_array(list)
+                .into_iter()
+                .map(|item| self.value(item, graph))
+                .collect::<Result<_, _>>()?;
+            return Ok(Value::Object(Map::from_iter([(
+                "@list".to_owned(),
+                Value::Array(items),
+            )])));
+        }
+        let id = self.node(object, graph)?;
+        Ok(Value::Object(Map::from_iter([(
+            "@id".to_owned(),
+            Value::String(id),
+        )])))
+    }
+
+    fn add(&mut self, nodes: Vec<Value>) -> Result<(), Error> {
+        self.graphs.entry(DEFAULT_GRAPH.to_owned()).or_default();
+        for entry in nodes {
+            let Value::Object(node) = entry else {
+                return Err(invalid("document", "it is not in expanded form"));
+            };
+            self.node(node, DEFAULT_GRAPH)?;
+        }
+        Ok(())
+    }
+
+    /// The nodes of a graph, sorted by `@id`,
+    /// leaving out those that are only referenced.
+    fn sorted(nodes: BTreeMap<String, Map<String, Value>>) -> Vec<Map<String, Value>> {
+        nodes.into_values().filter(|node| node.len() > 1).collect()
+    }
+
+    /// The flattened nodes, with the named graphs
+    /// as `@graph` of the node with the same `@id`.
+    fn flattened(mut self) -> Vec<Value> {
+        let mut default = self.graphs.remove(DEFAULT_GRAPH).unwrap_or_default();
+        for (name, nodes) in self.graphs {
+            let graph = Self::sorted(nodes).into_iter().map(Value::Object).collect();
+            default
+                .entry(name.clone())
+                .or_insert_with(|| Map::from_iter([("@id".to_owned(), Value::String(name))]))
+                .insert("@graph".to_owned(), Value::Array(graph));
+        }
+        Self::sorted(default)
+            .into_iter()
+            .map(Value::Object)
+            .collect()
+    }
+
+    /// The nodes of all graphs, merged into one node map.
+    fn merged(self) -> BTreeMap<String, Map<String, Value>> {
+        let mut merged: BTreeMap<String, Map<String, Value>> = BTreeMap::new();
+        for nodes in self.graphs.into_values() {
+            for (id, node) in nodes {
+                let target = merged.entry(id).or_default();
+                for (key, value) in node {
+                    match target.get_mut(&key) {
+                        Some(Value::Array(existing)) => {
+                            for item in into_array(value) {
+                                if !existing.contains(&item) {
+                                    existing.push(item);
+                                }
+                            }
+                        }
+                        Some(_) => {}
+                        None => {
+                            target.insert(key, value);
+                        }
+                    }
+                }
+            }
+        }
+        merged
+    }
+}
+
+/// Frames the nodes (see the module docs for what is supported).
+struct Framer<'a> {
+    nodes: &'a BTreeMap<String, Map<String, Value>>,
+    defs: &'a Definitions,
+    embedded: HashSet<String>,
+}
+
+impl Framer<'_> {
+    fn strings(value: &Value) -> HashSet<&str> {
+        value.as_array().map_or_else(
+            || value.as_str().into_iter().collect(),
+            |items| items.iter().filter_map(Value::as_str).collect(),
+        )
+    }
+
+    /// Expands the strings of a frame entry.
+    fn expanded(&self, value: &Value, vocab: bool) -> HashSet<String> {
+        Self::strings(value)
+            .into_iter()
+            .map(|item| self.defs.expand(item, vocab))
+            .collect()
+    }
+
+    fn matches(&self, node: &Map<String, Value>, frame: &Map<String, Value>) -> bool {
+        if let Some(frame_ids) = frame.get("@id") {
+            let ids = self.expanded(frame_ids, false);
+            return node
+                .get("@id")
+                .and_then(Value::as_str)
+                .is_some_and(|id| ids.contains(id));
+        }
+        if let Some(types) = frame.get("@type") {
+            let node_types = node.get("@type").map(Self::strings).unwrap_or_default();
+            // `{}` matches any type
+            if types.as_object().is_some_and(Map::is_empty) {
+                return !node_types.is_empty();
+            }
+            return self
+                .expanded(types, true)
+                .iter()
+                .any(|typ| node_types.contains(typ.as_str()));
+        }
+        frame
+            .keys()
+            .filter(|key| !key.starts_with('@'))
+            .all(|key| node.contains_key(&self.defs.expand(key, true)))
+    }
+
+    /// Replaces references to nodes that were not embedded yet by the nodes.
+    fn embed(&mut self, value: Value) -> Value {
+        let Value::Object(mut object) = value else {
+            return value;
+        };
+        if object.len() == 1 {
+            if let Some(Value::String(id)) = object.get("@id") {
+                if let Some(node) = self.nodes.get(id) {
+                    if self.embedded.insert(id.clone()) {
+                        return self.embed_node(node.clone());
+                    }
+                }
+                return Value::Object(object);
+            }
+        }
+        if let Some(Value::Array(list)) = object.remove("@list") {
+            let items = list.into_iter().map(|item| self.embed(item)).collect();
+            object.insert("@list".to_owned(), Value::Array(items));
+        }
+        Value::Object(object)
+    }
+
+    fn embed_node(&mut self, node: Map<String, Value>) -> Value {
+        let mut framed = Map::new();
+        for (key, value) in node {
+            let embedded = if key.starts_with('@') {
+                value
+            } else {
+                let items = into_array(value)
+                    .into_iter()
+                    .map(|item| self.embed(item))
+                    .collect();
+                Value::Array(items)
+            };
+            framed.insert(key, embedded);
+        }
+        Value::Object(framed)
+    }
+
+    fn frame(mut self, frame: &Map<String, Value>) -> Vec<Value> {
+        let matched = self
+            .nodes
+            .values()
+            .filter(|node| self.matches(node, frame))
+            .cloned()
+            .collect::<Vec<_>>();
+        for node in &matched {
+            if let Some(Value::String(id)) = node.get("@id") {
+                self.embedded.insert(id.clone());
+            }
+        }
+        matched
+            .into_iter()
+            .map(|node| self.embed_node(node))
+            .collect()
+    }
+}
+
+/// Compacts expanded node objects with the definitions of a context.
+struct Compactor<'a> {
+    defs: &'a Definitions,
+}
+
+impl Compactor<'_> {
+    /// Unwraps single values (`compactArrays`).
+    fn unwrap_single(mut values: Vec<Value>) -> Value {
+        if values.len() == 1 {
+            values.remove(0)
+        } else {
+            Value::Array(values)
+        }
+    }
+
+    fn node(&self, node: Map<String, Value>) -> Map<String, Value> {
+        let mut compacted = Map::new();
+        for (key, value) in node {
+            match key.as_str() {
+                "@id" => {
+                    let id = value.as_str().map_or_else(
+                        || value.clone(),
+                        |iri| Value::String(self.defs.compact(iri, false)),
+                    );
+                    compacted.insert(key, id);
+                }
+                "@type" => {
+                    let types = into_array(value)
+                        .into_iter()
+                        .map(|typ| {
+                            typ.as_str().map_or_else(
+                                || typ.clone(),
+                                |iri| Value::String(self.defs.compact(iri, true)),
+                            )
+                        })
+                        .collect();
+                    compacted.insert(key, Self::unwrap_single(types));
+                }
+                "@graph" => {
+                    let nodes = into_array(value)
+                        .into_iter()
+                        .map(|item| self.value(item))
+                        .collect();
+                    compacted.insert(key, Value::Array(nodes));
+                }
+                _ if key.starts_with('@') => {
+                    compacted.insert(key, value);
+                }
+                _ => {
+                    let values = into_array(value)
+                        .into_iter()
+                        .map(|item| self.value(item))
+                        .collect();
+                    compacted.insert(self.defs.compact(&key, true), Self::unwrap_single(values));
+                }
+            }
+        }
+        compacted
+    }
+
+    fn value(&self, value: Value) -> Value {
+        let Value::Object(mut object) = value else {
+            return value;
+        };
+        if object.contains_key("@value") {
+            let plain = object.len() == 1;
+            if let Some(Value::String(datatype)) = object.get("@type") {
+                let compacted = self.defs.compact(datatype, true);
+                object.insert("@type".to_owned(), Value::String(compacted));
+            }
+            return match object.remove("@value") {
+                Some(Value::String(string)) if plain && !self.defs.plain_strings_unsafe => {
+                    Value::String(string)
+                }
+                Some(scalar @ (Value::Bool(_) | Value::Number(_))) if plain => scalar,
+                Some(other) => {
+                    object.insert("@value".to_owned(), other);
+                    Value::Object(object)
+                }
+                None => Value::Object(object),
+            };
+        }
+        if let Some(list) = object.remove("@list") {
+            let items = into_array(list)
+                .into_iter()
+                .map(|item| self.value(item))
+                .collect();
+            object.insert("@list".to_owned(), Value::Array(items));
+            return Value::Object(object);
+        }
+        Value::Object(self.node(object))
+    }
+}
+
+/// Puts the nodes into a document,
+/// with the context and a `@graph` if required.
+fn document(context: Option<Value>, mut nodes: Vec<Value>, always_graph: bool) -> Value {
+    let mut document = Map::new();
+    if let Some(context_value) = context {
+        document.insert("@context".to_owned(), context_value);
+    }
+    if nodes.len() == 1 && !always_graph {
+        if let Some(Value::Object(node)) = nodes.pop() {
+            document.extend(node);
+        }
+    } else if !nodes.is_empty() || always_graph {
+        document.insert("@graph".to_owned(), Value::Array(nodes));
+    }
+    Value::Object(document)
+}
+
+/// Brings expanded JSON-LD into the form given in the options.
+///
+/// # Errors
+///
+/// Returns `Error::JsonLd` if the document, context or frame is not valid JSON,
+/// or the document is not in expanded form,
+/// or the context references remote contexts.
+pub fn apply(expanded: &str, options: &Options) -> Result<String, Error> {
+    if options.is_as_written() {
+        return Ok(expanded.to_owned());
+    }
+    let input = into_array(parse(expanded, "document")?);
+    if input.iter().any(|entry| {
+        entry
+            .as_object()
+            .is_some_and(|node| node.contains_key("@context"))
+    }) {
+        return Err(invalid("document", "it is not in expanded form"));
+    }
+    let frame = match &options.form {
+        Form::Framed(json) => {
+            let Value::Object(frame_doc) = parse(json, "frame")? else {
+                return Err(invalid("frame", "it is not a JSON object"));
+            };
+            Some(frame_doc)
+        }
+        Form::Expanded | Form::Compacted | Form::Flattened => None,
+    };
+    let (output_context, defs) = match context(options.context.as_ref(), frame.as_ref())? {
+        Some((value, definitions)) => (Some(value), definitions),
+        None => (None, Definitions::default()),
+    };
+    let mut flattener = Flattener::default();
+    let (nodes, always_graph) = match (&options.form, &frame) {
+        (Form::Flattened, _) => {
+            flattener.add(input)?;
+            (flattener.flattened(), true)
+        }
+        (_, Some(frame_doc)) => {
+            flattener.add(input)?;
+            let merged = flattener.merged();
+            let framer = Framer {
+                nodes: &merged,
+                defs: &defs,
+                embedded: HashSet::new(),
+            };
+            (framer.frame(frame_doc), false)
+        }
+        _ => (input, false),
+    };
+    let output = if matches!(options.form, Form::Flattened) && output_context.is_none() {
+        Value::Array(nodes)
+    } else {
+        let compactor = Compactor { defs: &defs };
+        let compacted = nodes
+            .into_iter()
+            .map(|node| compactor.value(node))
+            .collect();
+        document(output_context, compacted, always_graph)
+    };
+    serde_json::to_string_pretty(&output).map_err(|err| invalid("output", err))
+}
+
+/// Like [`apply`], but on a file, in place.
+///
+/// # Errors
+///
+/// Returns `Error::Io` if the file can not be read or written,
+/// and any error of [`apply`].
+pub fn apply_to_file(file: &Path, options: &Options) -> Result<(), Error> {
+    let expanded = std::fs::read_to_string(file)?;
+    std::fs::write(file, apply(&expanded, options)?)?;
+    Ok(())
+}
+
+/// Like [`apply`], but on a file, in place.
+///
+/// # Errors
+///
+/// Returns `Error::Io` if the file can not be read or written,
+/// and any error of [`apply`].
+#[cfg(feature = "async")]
+pub async fn apply_to_file_async(file: &Path, options: &Options) -> Result<(), Error> {
+    let expanded = tokio::fs::read_to_string(file).await?;
+    tokio::fs::write(file, apply(&expanded, options)?).await?;
+    Ok(())
+}
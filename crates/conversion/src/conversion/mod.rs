@@ -2,39 +2,124 @@
 //
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
+pub mod doctor;
+#[cfg(feature = "oxrdfio")]
+mod graph;
+#[cfg(feature = "oxrdfio")]
+mod html;
+pub mod jsonld;
+#[cfg(feature = "oxrdfio")]
+mod n3;
 #[cfg(feature = "oxrdfio")]
 mod oxrdfio;
+pub mod process_limit;
+#[cfg(feature = "oxrdfio")]
+pub mod provenance;
 mod pylode;
 mod rdfconvert;
 mod rdfx;
+#[cfg(feature = "oxrdfio")]
+pub mod reasoning;
+#[cfg(feature = "oxrdfio")]
+pub mod shapes;
+pub mod stderr;
+pub mod version;
+mod widoco;
+pub mod work_dir;
 
 #[cfg(feature = "async")]
 use async_trait::async_trait;
-use once_cell::sync::Lazy;
-#[cfg(not(feature = "async"))]
-use std::process;
+use std::sync::{LazyLock, Mutex, PoisonError};
 #[cfg(feature = "async")]
 use tokio::process;
 
+use rdfoothills_base::util;
 use rdfoothills_mime as mime;
 
+use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use version::{Version, VersionRange};
 
 pub struct OntFile {
     pub file: PathBuf,
     pub mime_type: mime::Type,
 }
 
-static CONVERTERS: Lazy<Vec<Box<dyn Converter>>> = Lazy::new(|| {
+/// Options for selecting a converter and converting.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConversionOptions {
+    /// Whether to allow converting from an RDF-star format
+    /// to a machine-readable format without RDF-star support,
+    /// which drops or breaks all quoted triples.
+    pub allow_star_downgrade: bool,
+    /// The minimal quality the conversion should have,
+    /// e.g. `Quality::Prefixes` if prefixes have to be preserved.
+    /// If no available converter can provide it,
+    /// the best one that can do the conversion at all is used,
+    /// with a warning.
+    pub min_quality: Option<Quality>,
+    /// What to do with N3 formulae and rules
+    /// when converting from N3 to any other format.
+    pub n3_formulae: N3FormulaPolicy,
+    /// Which base IRI to declare in the target,
+    /// to write IRIs relative to it.
+    pub base_iri: BaseIri,
+    /// The context and form of JSON-LD output.
+    pub json_ld: jsonld::Options,
+}
+
+/// The base IRI to declare in the target of a conversion,
+/// relative to which IRIs are written where possible,
+/// instead of all of them absolute.
+///
+/// Only the native converter (with the `oxrdfio` feature) supports this,
+/// and only for Turtle, `TriG` and N3 targets;
+/// all others ignore it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum BaseIri {
+    /// Write all IRIs absolute.
+    #[default]
+    Absolute,
+    /// Use the base IRI declared in the source (e.g. `@base`),
+    /// if there is one.
+    Source,
+    /// Use this (absolute) base IRI.
+    Explicit(String),
+}
+
+/// How to handle N3 formulae (`{ ... }`), variables and rules
+/// (e.g. `{ ?x a :A } => { ?x a :B } .`)
+/// when converting from N3 to any other format,
+/// none of which can represent them.
+///
+/// When converting from N3 to N3, they are always preserved.
+/// Without the `oxrdfio` feature, they can not be detected,
+/// and it is up to the converter what happens to them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum N3FormulaPolicy {
+    /// Fail with `Error::N3Formulae`.
+    #[default]
+    Refuse,
+    /// Drop all statements that are or use formulae or variables,
+    /// with a warning, and convert the plain RDF rest.
+    Strip,
+}
+
+static CONVERTERS: LazyLock<Vec<Box<dyn Converter>>> = LazyLock::new(|| {
     let mut converters: Vec<Box<dyn Converter>> = vec![
         Box::new(rdfx::Converter),
         Box::new(rdfconvert::Converter),
         Box::new(pylode::Converter),
+        Box::new(widoco::Converter),
     ];
     #[cfg(feature = "oxrdfio")]
     converters.push(Box::new(oxrdfio::Converter));
+    #[cfg(feature = "oxrdfio")]
+    converters.push(Box::new(html::Converter));
     converters.sort();
     converters
 });
@@ -67,14 +152,54 @@ pub enum Error {
     )]
     NoConversionRequired,
 
-    #[error("The input file was not syntactically valid:\n{0}")]
-    Syntax(String),
+    #[error("No source files were given to convert from. ")]
+    NoSources,
+
+    #[error("The input file was not syntactically valid{}:\n{message}", line.map(|num| format!(" (line {num})")).unwrap_or_default())]
+    Syntax { message: String, line: Option<u64> },
+
+    #[error("The conversion is not supported: {0}")]
+    Unsupported(String),
+
+    #[error(transparent)]
+    Saturated(#[from] process_limit::Saturated),
+
+    #[error("Converting from {from} (supporting RDF-star) to {to} (not supporting RDF-star) would drop all quoted triples; this has to be explicitly allowed. ")]
+    StarDowngrade { from: mime::Type, to: mime::Type },
+
+    #[error("The source contains {statements} N3 statements with formulae or variables (e.g. rules), which can not be represented in {to}; they may be explicitly allowed to be stripped. ")]
+    N3Formulae { statements: usize, to: mime::Type },
+
+    #[error("Failed to bring the JSON-LD into the requested form; invalid {0}")]
+    JsonLd(String),
+
+    #[error("The base IRI <{0}> is not a valid, absolute IRI. ")]
+    InvalidBaseIri(String),
+
+    #[error("The installed version of {tool} ({found}) is not supported; supported versions: {supported}. ")]
+    UnsupportedToolVersion {
+        tool: &'static str,
+        found: String,
+        supported: VersionRange,
+    },
+
+    #[error("The format {0} can not be read or written natively (with OxRDF I/O). ")]
+    NoNativeSupport(mime::Type),
+
+    #[error("Failed to detect the format of '{}': {source}", file.display())]
+    UnknownFormat {
+        file: PathBuf,
+        source: mime::ParseError,
+    },
 
     /// Represents all cases of `std::io::Error`.
     #[error(transparent)]
     Io(#[from] std::io::Error),
 }
 
+/// How faithfully a converter retains the input,
+/// from the best to the worst;
+/// each level also preserves everything the following ones do.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Quality {
     PreservesComments,
@@ -90,6 +215,8 @@ pub enum Priority {
     High,
     Mid,
     Low,
+    /// Only to be used if nothing else is available.
+    Fallback,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -111,8 +238,25 @@ pub struct Info {
 pub trait Converter: Send + Sync {
     fn info(&self) -> Info;
     fn is_available(&self) -> bool;
+
+    /// The version of the underlying tool, if it can be detected.
+    fn version(&self) -> Option<String> {
+        None
+    }
+
+    /// The versions of the underlying tool this converter works with.
+    fn supported_versions(&self) -> VersionRange {
+        VersionRange::ANY
+    }
+
     fn supports(&self, from: mime::Type, to: mime::Type) -> bool;
 
+    /// Whether quoted triples (RDF-star) are retained
+    /// when converting between formats supporting them.
+    fn supports_star(&self) -> bool {
+        false
+    }
+
     /// Converts from one RDF format to another - non-async version.
     ///
     /// # Errors
@@ -129,6 +273,39 @@ pub trait Converter: Send + Sync {
     /// - if the conversion fails
     #[cfg(feature = "async")]
     async fn convert_async(&self, from: &OntFile, to: &OntFile) -> Result<(), Error>;
+
+    /// Like [`Self::convert`], but also applying those `options`
+    /// that concern the conversion itself (e.g. `base_iri`),
+    /// if supported; by default, they are ignored.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::convert`].
+    fn convert_with_options(
+        &self,
+        from: &OntFile,
+        to: &OntFile,
+        _options: &ConversionOptions,
+    ) -> Result<(), Error> {
+        self.convert(from, to)
+    }
+
+    /// Like [`Self::convert_async`], but also applying those `options`
+    /// that concern the conversion itself (e.g. `base_iri`),
+    /// if supported; by default, they are ignored.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::convert_async`].
+    #[cfg(feature = "async")]
+    async fn convert_with_options_async(
+        &self,
+        from: &OntFile,
+        to: &OntFile,
+        _options: &ConversionOptions,
+    ) -> Result<(), Error> {
+        self.convert_async(from, to).await
+    }
 }
 
 impl PartialEq for dyn Converter {
@@ -151,45 +328,95 @@ impl Ord for dyn Converter {
     }
 }
 
-#[must_use]
-pub const fn to_rdflib_format(mime_type: mime::Type) -> Option<&'static str> {
-    match mime_type {
-        mime::Type::BinaryRdf
-        | mime::Type::Csvw
-        | mime::Type::Hdt
-        | mime::Type::Html
-        | mime::Type::Microdata
-        | mime::Type::NdJsonLd
-        | mime::Type::NQuadsStar
-        | mime::Type::NTriplesStar
-        | mime::Type::RdfA
-        | mime::Type::RdfJson
-        | mime::Type::TriGStar
-        | mime::Type::OwlFunctional
-        | mime::Type::OwlXml
-        | mime::Type::Tsvw
-        | mime::Type::TurtleStar
-        | mime::Type::YamlLd => None,
-        mime::Type::HexTuples => Some("hext"),
-        // mime::Type::Html => Some("rdfa"),
-        mime::Type::JsonLd => Some("json-ld"),
-        mime::Type::N3 => Some("n3"),
-        mime::Type::NQuads => Some("nquads"),
-        mime::Type::NTriples => Some("nt"),
-        // mime::Type::RdfA => Some("rdfa"),
-        mime::Type::TriG => Some("trig"),
-        mime::Type::RdfXml => Some("xml"),
-        // mime::Type::RdfXml => Some("pretty-xml"),
-        mime::Type::TriX => Some("trix"),
-        mime::Type::Turtle => Some("turtle"),
-    }
-}
-
 /// Checks if an external command is available
 /// and we have the rights to execute it.
+///
+/// This blocks (briefly), but does not need an async runtime,
+/// even with the `async` feature enabled.
 #[must_use]
 pub fn is_cli_cmd_available(cmd: &str) -> bool {
-    process::Command::new(cmd).spawn().is_ok()
+    std::process::Command::new(cmd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|mut child| {
+            // We only needed to know whether it starts;
+            // it might have exited already, so errors are of no interest.
+            let _ = child.kill();
+            let _ = child.wait();
+        })
+        .is_ok()
+}
+
+/// Detects the version of an external command,
+/// by calling it with `--version`.
+/// Returns the first non-empty line of its output,
+/// or `None` if it could not be invoked or failed.
+///
+/// The command is only called once;
+/// later calls return the cached result.
+#[must_use]
+pub fn cli_cmd_version(cmd: &str) -> Option<String> {
+    static VERSIONS: LazyLock<Mutex<HashMap<String, Option<String>>>> =
+        LazyLock::new(Mutex::default);
+
+    let lock = || VERSIONS.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(version) = lock().get(cmd) {
+        return version.clone();
+    }
+    // Not detected while locked, to not hold up other tools.
+    let version = detect_cli_cmd_version(cmd);
+    lock().insert(cmd.to_owned(), version.clone());
+    version
+}
+
+fn detect_cli_cmd_version(cmd: &str) -> Option<String> {
+    let output = std::process::Command::new(cmd)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    // Some tools print their version to stderr.
+    [output.stdout, output.stderr].iter().find_map(|out| {
+        String::from_utf8_lossy(out)
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(ToOwned::to_owned)
+    })
+}
+
+/// Checks whether the installed version of the converter's tool
+/// is one it supports (see [`Converter::supported_versions`]).
+/// If the version can not be detected, it is assumed to be supported.
+///
+/// # Errors
+///
+/// Returns `Error::UnsupportedToolVersion` if the installed version
+/// is not supported.
+pub fn check_version(converter: &dyn Converter) -> Result<(), Error> {
+    let supported = converter.supported_versions();
+    if supported.is_any() {
+        return Ok(());
+    }
+    let Some(found) = converter.version() else {
+        return Ok(());
+    };
+    match Version::extract(&found) {
+        Some(version) if !supported.contains(&version) => Err(Error::UnsupportedToolVersion {
+            tool: converter.info().name,
+            found,
+            supported,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// All the registered converters, the preferred ones first,
+/// whether they are available or not.
+pub fn converters() -> impl Iterator<Item = &'static dyn Converter> {
+    CONVERTERS.iter().map(AsRef::as_ref)
 }
 
 fn handle_cli_cmd_output(
@@ -203,12 +430,15 @@ fn handle_cli_cmd_output(
         task: task.to_owned(),
     })?;
     if !output.status.success() {
-        return Err(Error::ExtCmdUnsuccessfull {
-            cmd: cmd.to_owned(),
-            task: task.to_owned(),
-            exit_code: output.status.code().unwrap_or(-1),
-            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
-        });
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(
+            stderr::parse(&stderr).unwrap_or_else(|| Error::ExtCmdUnsuccessfull {
+                cmd: cmd.to_owned(),
+                task: task.to_owned(),
+                exit_code: output.status.code().unwrap_or(-1),
+                stderr: stderr.to_string(),
+            }),
+        );
     }
 
     Ok(())
@@ -226,11 +456,17 @@ fn handle_cli_cmd_output(
 /// or we do not have the permission to execute it.
 /// Returns `Error::ExtCmdUnsuccessfull` if the command was executed,
 /// but somethign went wrong/failed (exit state != 0).
+/// If the reason for that is recognized in its error output,
+/// `Error::Syntax` or `Error::Unsupported` is returned instead
+/// (see [`stderr::parse`]).
+/// Returns `Error::Saturated` if too many external commands
+/// are running and waiting already (see [`process_limit`]).
 pub fn cli_cmd<I, S>(cmd: &str, task: &str, args: I) -> Result<(), Error>
 where
     I: IntoIterator<Item = S> + Send,
     S: AsRef<OsStr>,
 {
+    let _permit = process_limit::acquire()?;
     handle_cli_cmd_output(
         cmd,
         task,
@@ -250,12 +486,18 @@ where
 /// or we do not have the permission to execute it.
 /// Returns `Error::ExtCmdUnsuccessfull` if the command was executed,
 /// but something went wrong/failed (exit state != 0).
+/// If the reason for that is recognized in its error output,
+/// `Error::Syntax` or `Error::Unsupported` is returned instead
+/// (see [`stderr::parse`]).
+/// Returns `Error::Saturated` if too many external commands
+/// are running and waiting already (see [`process_limit`]).
 #[cfg(feature = "async")]
 pub async fn cli_cmd_async<I, S>(cmd: &str, task: &str, args: I) -> Result<(), Error>
 where
     I: IntoIterator<Item = S> + Send,
     S: AsRef<OsStr>,
 {
+    let _permit = process_limit::acquire_async().await?;
     handle_cli_cmd_output(
         cmd,
         task,
@@ -263,62 +505,782 @@ where
     )
 }
 
-/// Converts from one RDF format to another.
+/// Selects the converter to convert from one RDF format to another,
+/// with the default options.
+///
+/// # Errors
+///
+/// See [`select_converter_with`].
+pub fn select_converter(from: &OntFile, to: &OntFile) -> Result<&'static dyn Converter, Error> {
+    select_converter_with(from, to, &ConversionOptions::default())
+}
+
+/// Selects the converter to convert from one RDF format to another.
+///
+/// If both formats support RDF-star,
+/// converters retaining quoted triples are preferred.
+/// Converters not providing the minimal quality asked for
+/// are only used if no other one can do the conversion.
 ///
 /// # Errors
 ///
 /// Returns `Error::NonMachineReadableSource` if conversion would be necessary,
 /// but the source is not machine readable.
+/// Returns `Error::StarDowngrade` if the conversion would drop quoted triples,
+/// and that is not allowed by the `options`.
+/// Returns `Error::UnsupportedToolVersion` if the only converters
+/// that could do the conversion are of an unsupported version.
 /// Returns `Error::NoConverter` if the conversion is not supported.
-pub fn select_converter(from: &OntFile, to: &OntFile) -> Result<&'static dyn Converter, Error> {
-    if !from.mime_type.is_machine_readable() {
-        return Err(Error::NonMachineReadableSource {
-            from: from.mime_type,
-        });
+pub fn select_converter_with(
+    from: &OntFile,
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<&'static dyn Converter, Error> {
+    select_for_types(from.mime_type, to.mime_type, options)
+}
+
+/// Like [`select_converter_with`], but probing the tools
+/// (see [`Converter::is_available`] and [`check_version`])
+/// on tokio's blocking thread pool.
+///
+/// # Errors
+///
+/// See [`select_converter_with`].
+#[cfg(feature = "async")]
+pub async fn select_converter_with_async(
+    from: &OntFile,
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<&'static dyn Converter, Error> {
+    let (from_type, to_type, options_owned) = (from.mime_type, to.mime_type, options.clone());
+    probe_blocking(move || select_for_types(from_type, to_type, &options_owned)).await
+}
+
+/// Runs something probing the tools on tokio's blocking thread pool.
+#[cfg(feature = "async")]
+async fn probe_blocking<T, F>(probe: F) -> Result<T, Error>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, Error> + Send + 'static,
+{
+    tokio::task::spawn_blocking(probe)
+        .await
+        .map_err(|err| Error::Io(io::Error::other(err)))?
+}
+
+/// See [`select_converter_with`].
+fn select_for_types(
+    from_type: mime::Type,
+    to_type: mime::Type,
+    options: &ConversionOptions,
+) -> Result<&'static dyn Converter, Error> {
+    if !from_type.is_machine_readable() {
+        return Err(Error::NonMachineReadableSource { from: from_type });
     }
 
-    if from.mime_type == to.mime_type {
+    if from_type == to_type {
         return Err(Error::NoConversionRequired);
     }
 
-    for converter in CONVERTERS.iter() {
-        if converter.supports(from.mime_type, to.mime_type) && converter.is_available() {
-            return Ok(converter.as_ref());
+    if from_type.star()
+        && !to_type.star()
+        && to_type.is_machine_readable()
+        && !options.allow_star_downgrade
+    {
+        return Err(Error::StarDowngrade {
+            from: from_type,
+            to: to_type,
+        });
+    }
+
+    let star = from_type.star() && to_type.star();
+    let mut candidates = CONVERTERS.iter().collect::<Vec<_>>();
+    if star {
+        // stable, so otherwise the order remains
+        candidates.sort_by_key(|converter| !converter.supports_star());
+    }
+
+    let mut unsupported_version = None;
+    let mut lower_quality = None;
+    for converter in candidates {
+        if !converter.supports(from_type, to_type) || !converter.is_available() {
+            continue;
+        }
+        let quality_ok = options
+            .min_quality
+            .is_none_or(|min_quality| converter.info().quality <= min_quality);
+        if !quality_ok && lower_quality.is_some() {
+            continue;
+        }
+        match check_version(converter.as_ref()) {
+            Ok(()) if quality_ok => return Ok(converter.as_ref()),
+            Ok(()) => lower_quality = Some(converter.as_ref()),
+            Err(err) => {
+                unsupported_version.get_or_insert(err);
+            }
         }
     }
 
-    Err(Error::NoConverter {
-        from: from.mime_type,
-        to: to.mime_type,
-    })
+    if let Some(converter) = lower_quality {
+        tracing::warn!(
+            "None of the available converters from {} to {} provides the quality {:?}; falling back to {}, which only provides {:?}",
+            from_type,
+            to_type,
+            options.min_quality,
+            converter.info().name,
+            converter.info().quality
+        );
+        return Ok(converter);
+    }
+
+    Err(unsupported_version.unwrap_or(Error::NoConverter {
+        from: from_type,
+        to: to_type,
+    }))
 }
 
-/// Converts from one RDF format to another.
+/// The file converters write into,
+/// before it gets atomically moved to `to`,
+/// so a failed or interrupted conversion never leaves
+/// a partially written output file behind.
+fn temp_sibling(to: &OntFile) -> OntFile {
+    OntFile {
+        file: util::sibling_temp_path(&to.file),
+        mime_type: to.mime_type,
+    }
+}
+
+/// Converts from one RDF format to another,
+/// with the default options.
 ///
 /// # Errors
 ///
-/// Returns `Error::NonMachineReadableSource` if conversion would be necessary,
-/// but the source is not machine readable.
-/// Returns `Error::NoConverter` if the conversion is not supported.
-/// Returns `Error::*` if conversion failed.
+/// See [`convert_with`].
 pub fn convert(from: &OntFile, to: &OntFile) -> Result<Info, Error> {
-    let converter = select_converter(from, to)?;
-    converter.convert(from, to).map(|()| converter.info())
+    convert_with(from, to, &ConversionOptions::default())
 }
 
 /// Converts from one RDF format to another.
 ///
 /// # Errors
 ///
-/// Returns `Error::NonMachineReadableSource` if conversion would be necessary,
-/// but the source is not machine readable.
-/// Returns `Error::NoConverter` if the conversion is not supported.
+/// Returns any error of [`select_converter_with`].
+/// Returns `Error::N3Formulae` if converting N3 formulae is refused
+/// (see [`N3FormulaPolicy`]).
 /// Returns `Error::*` if conversion failed.
+pub fn convert_with(
+    from: &OntFile,
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<Info, Error> {
+    run_selected(select_converter_with(from, to, options)?, from, to, options)
+}
+
+/// Whether N3 formulae have to be dealt with in this conversion
+/// (see [`N3FormulaPolicy`]).
+#[cfg(feature = "oxrdfio")]
+fn may_lose_n3_formulae(from: &OntFile, to: &OntFile) -> bool {
+    from.mime_type == mime::Type::N3 && to.mime_type != mime::Type::N3
+}
+
+/// Applies the policy to the N3 `content`.
+/// Returns the N3 file to convert from instead,
+/// if formulae were stripped.
+#[cfg(feature = "oxrdfio")]
+fn handle_n3_formulae(
+    content: &[u8],
+    from: &OntFile,
+    to: &OntFile,
+    policy: N3FormulaPolicy,
+) -> Result<Option<(work_dir::WorkDir, OntFile)>, Error> {
+    let Some(stripped) = n3::strip_formulae(content)? else {
+        return Ok(None);
+    };
+    match policy {
+        N3FormulaPolicy::Refuse => Err(Error::N3Formulae {
+            statements: stripped.statements,
+            to: to.mime_type,
+        }),
+        N3FormulaPolicy::Strip => {
+            tracing::warn!(
+                "Dropping {} N3 statements with formulae or variables from '{}', as they can not be represented in {}",
+                stripped.statements,
+                from.file.display(),
+                to.mime_type
+            );
+            let dir = work_dir::WorkDir::new("n3")?;
+            let file = dir.path().join("stripped.n3");
+            std::fs::write(&file, graph::serialize(&stripped.graph, mime::Type::N3)?)?;
+            Ok(Some((
+                dir,
+                OntFile {
+                    file,
+                    mime_type: mime::Type::N3,
+                },
+            )))
+        }
+    }
+}
+
+/// Converts with the given converter, selected for these files,
+/// taking care of N3 formulae first (see [`N3FormulaPolicy`]).
+fn run_selected(
+    converter: &dyn Converter,
+    from: &OntFile,
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<Info, Error> {
+    #[cfg(feature = "oxrdfio")]
+    if may_lose_n3_formulae(from, to) {
+        let content = std::fs::read(&from.file)?;
+        if let Some((dir, stripped)) = handle_n3_formulae(&content, from, to, options.n3_formulae)?
+        {
+            let res = run_converter(converter, &stripped, to, options);
+            if res.is_ok() {
+                dir.finish();
+            }
+            return res;
+        }
+    }
+    run_converter(converter, from, to, options)
+}
+
+/// Converts with the given converter,
+/// through a temporary file (see [`temp_sibling`]).
+fn run_converter(
+    converter: &dyn Converter,
+    from: &OntFile,
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<Info, Error> {
+    let tmp = temp_sibling(to);
+    let res = converter
+        .convert_with_options(from, &tmp, options)
+        .and_then(|()| {
+            if tmp.mime_type == mime::Type::JsonLd && !options.json_ld.is_as_written() {
+                jsonld::apply_to_file(&tmp.file, &options.json_ld)?;
+            }
+            util::persist_atomic(&tmp.file, &to.file).map_err(Error::from)
+        });
+    if res.is_err() {
+        util::discard_temp(&tmp.file);
+    }
+    res.map(|()| converter.info())
+}
+
+/// Converts from one RDF format to another,
+/// with the default options.
+///
+/// # Errors
+///
+/// See [`convert_with_async`].
 #[cfg(feature = "async")]
 pub async fn convert_async(from: &OntFile, to: &OntFile) -> Result<Info, Error> {
-    let converter = select_converter(from, to)?;
-    converter
-        .convert_async(from, to)
+    convert_with_async(from, to, &ConversionOptions::default()).await
+}
+
+/// Converts from one RDF format to another.
+///
+/// # Errors
+///
+/// Returns any error of [`select_converter_with`].
+/// Returns `Error::N3Formulae` if converting N3 formulae is refused
+/// (see [`N3FormulaPolicy`]).
+/// Returns `Error::*` if conversion failed.
+#[cfg(feature = "async")]
+pub async fn convert_with_async(
+    from: &OntFile,
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<Info, Error> {
+    let converter = select_converter_with_async(from, to, options).await?;
+    run_selected_async(converter, from, to, options).await
+}
+
+/// Converts with the given converter, selected for these files,
+/// taking care of N3 formulae first (see [`N3FormulaPolicy`]).
+#[cfg(feature = "async")]
+async fn run_selected_async(
+    converter: &dyn Converter,
+    from: &OntFile,
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<Info, Error> {
+    #[cfg(feature = "oxrdfio")]
+    if may_lose_n3_formulae(from, to) {
+        let content = tokio::fs::read(&from.file).await?;
+        if let Some((dir, stripped)) = handle_n3_formulae(&content, from, to, options.n3_formulae)?
+        {
+            let res = run_converter_async(converter, &stripped, to, options).await;
+            if res.is_ok() {
+                dir.finish();
+            }
+            return res;
+        }
+    }
+    run_converter_async(converter, from, to, options).await
+}
+
+/// Converts with the given converter,
+/// through a temporary file (see [`temp_sibling`]).
+#[cfg(feature = "async")]
+async fn run_converter_async(
+    converter: &dyn Converter,
+    from: &OntFile,
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<Info, Error> {
+    let tmp = temp_sibling(to);
+    let mut res = converter
+        .convert_with_options_async(from, &tmp, options)
+        .await;
+    if res.is_ok() && tmp.mime_type == mime::Type::JsonLd && !options.json_ld.is_as_written() {
+        res = jsonld::apply_to_file_async(&tmp.file, &options.json_ld).await;
+    }
+    if res.is_ok() {
+        res = util::persist_atomic_async(&tmp.file, &to.file)
+            .await
+            .map_err(Error::from);
+    }
+    if res.is_err() {
+        util::discard_temp(&tmp.file);
+    }
+    res.map(|()| converter.info())
+}
+
+/// Chooses which one of several files containing the same ontology
+/// (e.g. all its cached formats) to convert into `to`,
+/// and with which converter.
+///
+/// This is the combination providing the best conversion (see [`Info`]),
+/// the first one of them in case of a tie.
+///
+/// # Errors
+///
+/// Returns `Error::NoConversionRequired` if one of the `sources`
+/// is in the target format already.
+/// Returns `Error::NoSources` if there are no `sources`.
+/// Returns the error of [`select_converter_with`] for the first of the `sources`,
+/// if none of them can be converted.
+pub fn select_source<'a>(
+    sources: &'a [OntFile],
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<(&'a OntFile, &'static dyn Converter), Error> {
+    let source_types = sources.iter().map(|source| source.mime_type).collect();
+    let (idx, converter) = select_source_for_types(source_types, to.mime_type, options)?;
+    source_at(sources, idx, converter)
+}
+
+/// Like [`select_source`], but probing the tools
+/// (see [`Converter::is_available`] and [`check_version`])
+/// on tokio's blocking thread pool.
+///
+/// # Errors
+///
+/// See [`select_source`].
+#[cfg(feature = "async")]
+pub async fn select_source_async<'a>(
+    sources: &'a [OntFile],
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<(&'a OntFile, &'static dyn Converter), Error> {
+    let source_types = sources.iter().map(|source| source.mime_type).collect();
+    let (to_type, options_owned) = (to.mime_type, options.clone());
+    let (idx, converter) =
+        probe_blocking(move || select_source_for_types(source_types, to_type, &options_owned))
+            .await?;
+    source_at(sources, idx, converter)
+}
+
+/// Pairs the chosen source with its converter.
+fn source_at<'a>(
+    sources: &'a [OntFile],
+    idx: usize,
+    converter: &'static dyn Converter,
+) -> Result<(&'a OntFile, &'static dyn Converter), Error> {
+    // The index is always one of the sources.
+    sources
+        .get(idx)
+        .map(|source| (source, converter))
+        .ok_or(Error::NoSources)
+}
+
+/// See [`select_source`];
+/// returns the index of the chosen source.
+fn select_source_for_types(
+    source_types: Vec<mime::Type>,
+    to_type: mime::Type,
+    options: &ConversionOptions,
+) -> Result<(usize, &'static dyn Converter), Error> {
+    if source_types.contains(&to_type) {
+        return Err(Error::NoConversionRequired);
+    }
+    let mut best: Option<(usize, &'static dyn Converter)> = None;
+    let mut first_err = None;
+    for (idx, source_type) in source_types.into_iter().enumerate() {
+        match select_for_types(source_type, to_type, options) {
+            Ok(converter) => {
+                if best.is_none_or(|(_, best_converter)| converter.info() < best_converter.info()) {
+                    best = Some((idx, converter));
+                }
+            }
+            Err(err) => {
+                first_err.get_or_insert(err);
+            }
+        }
+    }
+    best.ok_or_else(|| first_err.unwrap_or(Error::NoSources))
+}
+
+/// Converts the best suited one of several files containing the same ontology
+/// (see [`select_source`]) into `to`.
+///
+/// Returns the file converted from, and the info of the converter used.
+///
+/// # Errors
+///
+/// Returns any error of [`select_source`].
+/// Returns `Error::*` if conversion failed.
+pub fn convert_from_best<'a>(
+    sources: &'a [OntFile],
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<(&'a OntFile, Info), Error> {
+    let (from, converter) = select_source(sources, to, options)?;
+    run_selected(converter, from, to, options).map(|info| (from, info))
+}
+
+/// Converts the best suited one of several files containing the same ontology
+/// (see [`select_source`]) into `to`.
+///
+/// Returns the file converted from, and the info of the converter used.
+///
+/// # Errors
+///
+/// Returns any error of [`select_source`].
+/// Returns `Error::*` if conversion failed.
+#[cfg(feature = "async")]
+pub async fn convert_from_best_async<'a>(
+    sources: &'a [OntFile],
+    to: &OntFile,
+    options: &ConversionOptions,
+) -> Result<(&'a OntFile, Info), Error> {
+    let (from, converter) = select_source_async(sources, to, options).await?;
+    run_selected_async(converter, from, to, options)
         .await
-        .map(|()| converter.info())
+        .map(|info| (from, info))
+}
+
+/// Detects the format of a file to be written, from its extension.
+fn detect_output_format(output: &Path) -> Result<mime::Type, Error> {
+    output
+        .extension()
+        .ok_or_else(|| mime::ParseError::NoFileExtension(output.to_owned()))
+        .and_then(|ext| mime::Type::from_file_ext(&ext.to_string_lossy()))
+        .map_err(|source| Error::UnknownFormat {
+            file: output.to_owned(),
+            source,
+        })
+}
+
+/// Converts the file `input` into the file `output`,
+/// detecting the formats if they are not given:
+/// the input format from the file extension or content,
+/// the output format from the file extension.
+///
+/// # Errors
+///
+/// Returns `Error::UnknownFormat` if a format was not given
+/// and could not be detected.
+/// Returns any error of [`convert`] otherwise.
+pub fn convert_file(
+    input: &Path,
+    output: &Path,
+    from: Option<mime::Type>,
+    to: Option<mime::Type>,
+) -> Result<Info, Error> {
+    let from_type = match from {
+        Some(typ) => typ,
+        None => mime::Type::from_path(input).map_err(|source| Error::UnknownFormat {
+            file: input.to_owned(),
+            source,
+        })?,
+    };
+    let to_type = match to {
+        Some(typ) => typ,
+        None => detect_output_format(output)?,
+    };
+    convert(
+        &OntFile {
+            file: input.to_owned(),
+            mime_type: from_type,
+        },
+        &OntFile {
+            file: output.to_owned(),
+            mime_type: to_type,
+        },
+    )
+}
+
+/// Converts the file `input` into the file `output`,
+/// detecting the formats if they are not given:
+/// the input format from the file extension or content,
+/// the output format from the file extension.
+///
+/// # Errors
+///
+/// Returns `Error::UnknownFormat` if a format was not given
+/// and could not be detected.
+/// Returns any error of [`convert_async`] otherwise.
+#[cfg(feature = "async")]
+pub async fn convert_file_async(
+    input: &Path,
+    output: &Path,
+    from: Option<mime::Type>,
+    to: Option<mime::Type>,
+) -> Result<Info, Error> {
+    let from_type = match from {
+        Some(typ) => typ,
+        None => {
+            mime::Type::from_path_async(input)
+                .await
+                .map_err(|source| Error::UnknownFormat {
+                    file: input.to_owned(),
+                    source,
+                })?
+        }
+    };
+    let to_type = match to {
+        Some(typ) => typ,
+        None => detect_output_format(output)?,
+    };
+    convert_async(
+        &OntFile {
+            file: input.to_owned(),
+            mime_type: from_type,
+        },
+        &OntFile {
+            file: output.to_owned(),
+            mime_type: to_type,
+        },
+    )
+    .await
+}
+
+/// The files `from` gets converted into by [`convert_to_many`]:
+/// one per target format, next to it, with the same file stem.
+/// The format of `from` itself is skipped.
+fn fan_out_targets(from: &OntFile, targets: &[mime::Type]) -> Vec<OntFile> {
+    let mut to_files: Vec<OntFile> = Vec::new();
+    for &target in targets {
+        if target != from.mime_type && !to_files.iter().any(|to| to.mime_type == target) {
+            to_files.push(OntFile {
+                file: from.file.with_extension(target.file_ext()),
+                mime_type: target,
+            });
+        }
+    }
+    to_files
+}
+
+/// Converts `from` into each of the `targets` formats
+/// (e.g. [`mime::Type::main`]).
+///
+/// The results are written next to `from`, with the same file stem.
+/// At most `concurrency` conversions run at the same time (at least one).
+///
+/// Returns the result for each target format, in the given order,
+/// skipping the format of `from` itself and duplicates.
+#[must_use]
+pub fn convert_to_many(
+    from: &OntFile,
+    targets: &[mime::Type],
+    concurrency: usize,
+) -> Vec<(mime::Type, Result<Info, Error>)> {
+    let to_files = fan_out_targets(from, targets);
+    let mut results = Vec::with_capacity(to_files.len());
+    for chunk in to_files.chunks(concurrency.max(1)) {
+        std::thread::scope(|scope| {
+            let mut handles = Vec::with_capacity(chunk.len());
+            for to in chunk {
+                handles.push(scope.spawn(move || (to.mime_type, convert(from, to))));
+            }
+            results.extend(handles.into_iter().map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            }));
+        });
+    }
+    results
+}
+
+/// Converts `from` into each of the `targets` formats
+/// (e.g. [`mime::Type::main`]).
+///
+/// The results are written next to `from`, with the same file stem.
+/// At most `concurrency` conversions run at the same time (at least one).
+///
+/// Returns the result for each target format, in the given order,
+/// skipping the format of `from` itself and duplicates.
+#[cfg(feature = "async")]
+pub async fn convert_to_many_async(
+    from: &OntFile,
+    targets: &[mime::Type],
+    concurrency: usize,
+) -> Vec<(mime::Type, Result<Info, Error>)> {
+    let mut pending = fan_out_targets(from, targets).into_iter().enumerate();
+    let mut running = tokio::task::JoinSet::new();
+    let mut results = Vec::new();
+    loop {
+        while running.len() < concurrency.max(1) {
+            let Some((idx, to)) = pending.next() else {
+                break;
+            };
+            let from_owned = OntFile {
+                file: from.file.clone(),
+                mime_type: from.mime_type,
+            };
+            running.spawn(async move {
+                let res = convert_async(&from_owned, &to).await;
+                (idx, to.mime_type, res)
+            });
+        }
+        let Some(joined) = running.join_next().await else {
+            break;
+        };
+        results.push(joined.unwrap_or_else(|err| std::panic::resume_unwind(err.into_panic())));
+    }
+    results.sort_by_key(|(idx, _, _)| *idx);
+    results
+        .into_iter()
+        .map(|(_, typ, res)| (typ, res))
+        .collect()
+}
+
+/// Materializes the triples entailed by the ontology in `from`
+/// under the given regime (see [`reasoning::materialize`]),
+/// and writes them together with the asserted ones into `to`.
+///
+/// Returns the number of triples inferred.
+///
+/// # Errors
+///
+/// Returns `Error::NoNativeSupport` if one of the formats
+/// is not supported by `OxRDF` I/O.
+/// Returns `Error::Syntax` if the input is not valid.
+/// Returns `Error::Io` if reading or writing failed.
+#[cfg(feature = "oxrdfio")]
+pub fn reason(
+    from: &OntFile,
+    to: &OntFile,
+    entailment: reasoning::Entailment,
+) -> Result<usize, Error> {
+    let mut graph = graph::parse(&std::fs::read(&from.file)?, from.mime_type)?;
+    let inferred = reasoning::materialize(&mut graph, entailment);
+    util::write_atomic(&to.file, graph::serialize(&graph, to.mime_type)?)?;
+    Ok(inferred)
+}
+
+/// Materializes the triples entailed by the ontology in `from`
+/// under the given regime (see [`reasoning::materialize`]),
+/// and writes them together with the asserted ones into `to`.
+///
+/// Returns the number of triples inferred.
+///
+/// # Errors
+///
+/// Returns `Error::NoNativeSupport` if one of the formats
+/// is not supported by `OxRDF` I/O.
+/// Returns `Error::Syntax` if the input is not valid.
+/// Returns `Error::Io` if reading or writing failed.
+#[cfg(all(feature = "oxrdfio", feature = "async"))]
+pub async fn reason_async(
+    from: &OntFile,
+    to: &OntFile,
+    entailment: reasoning::Entailment,
+) -> Result<usize, Error> {
+    let mut graph = graph::parse(&tokio::fs::read(&from.file).await?, from.mime_type)?;
+    let inferred = reasoning::materialize(&mut graph, entailment);
+    util::write_atomic_async(&to.file, graph::serialize(&graph, to.mime_type)?).await?;
+    Ok(inferred)
+}
+
+/// Extracts the SHACL shapes from the ontology in `from`
+/// (see [`shapes::extract`]), and writes only them into `to`.
+///
+/// Returns the number of triples written.
+///
+/// # Errors
+///
+/// Returns `Error::NoNativeSupport` if one of the formats
+/// is not supported by `OxRDF` I/O.
+/// Returns `Error::Syntax` if the input is not valid.
+/// Returns `Error::Io` if reading or writing failed.
+#[cfg(feature = "oxrdfio")]
+pub fn extract_shapes(from: &OntFile, to: &OntFile) -> Result<usize, Error> {
+    let graph = graph::parse(&std::fs::read(&from.file)?, from.mime_type)?;
+    let shapes = shapes::extract(&graph);
+    util::write_atomic(&to.file, graph::serialize(&shapes, to.mime_type)?)?;
+    Ok(shapes.len())
+}
+
+/// Extracts the SHACL shapes from the ontology in `from`
+/// (see [`shapes::extract`]), and writes only them into `to`.
+///
+/// Returns the number of triples written.
+///
+/// # Errors
+///
+/// Returns `Error::NoNativeSupport` if one of the formats
+/// is not supported by `OxRDF` I/O.
+/// Returns `Error::Syntax` if the input is not valid.
+/// Returns `Error::Io` if reading or writing failed.
+#[cfg(all(feature = "oxrdfio", feature = "async"))]
+pub async fn extract_shapes_async(from: &OntFile, to: &OntFile) -> Result<usize, Error> {
+    let graph = graph::parse(&tokio::fs::read(&from.file).await?, from.mime_type)?;
+    let shapes = shapes::extract(&graph);
+    util::write_atomic_async(&to.file, graph::serialize(&shapes, to.mime_type)?).await?;
+    Ok(shapes.len())
+}
+
+/// Checks whether the RDF graphs in the two files are isomorphic.
+///
+/// They are if they are the same up to the naming of blank nodes.
+/// Named graphs are merged into the default graph,
+/// so the formats of the two files need not support the same.
+///
+/// # Errors
+///
+/// Returns `Error::NoNativeSupport` if one of the formats
+/// is not supported by `OxRDF` I/O.
+/// Returns `Error::Syntax` if one of the files is not valid.
+/// Returns `Error::Io` if reading failed.
+#[cfg(feature = "oxrdfio")]
+pub fn isomorphic(one: &OntFile, other: &OntFile) -> Result<bool, Error> {
+    Ok(graph::isomorphic(
+        graph::parse(&std::fs::read(&one.file)?, one.mime_type)?,
+        graph::parse(&std::fs::read(&other.file)?, other.mime_type)?,
+    ))
+}
+
+/// Checks whether the RDF graphs in the two files are isomorphic.
+///
+/// They are if they are the same up to the naming of blank nodes.
+/// Named graphs are merged into the default graph,
+/// so the formats of the two files need not support the same.
+///
+/// # Errors
+///
+/// Returns `Error::NoNativeSupport` if one of the formats
+/// is not supported by `OxRDF` I/O.
+/// Returns `Error::Syntax` if one of the files is not valid.
+/// Returns `Error::Io` if reading failed.
+#[cfg(all(feature = "oxrdfio", feature = "async"))]
+pub async fn isomorphic_async(one: &OntFile, other: &OntFile) -> Result<bool, Error> {
+    Ok(graph::isomorphic(
+        graph::parse(&tokio::fs::read(&one.file).await?, one.mime_type)?,
+        graph::parse(&tokio::fs::read(&other.file).await?, other.mime_type)?,
+    ))
 }
@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! N3 goes beyond RDF with formulae (`{ ... }`), variables and rules,
+//! e.g. `{ ?x a :A } => { ?x a :B } .`.
+//!
+//! None of the other formats can represent these,
+//! and the converters either fail on them or silently drop them,
+//! so they are dealt with before converting (see [`super::N3FormulaPolicy`]).
+
+use std::collections::HashSet;
+
+use oxrdf::{BlankNode, Graph, GraphName, Subject, Term, Triple};
+use oxttl::n3::{N3Parser, N3Quad, N3Term};
+
+/// The predicate of rules (`=>`),
+/// which is dropped even if its formulae are empty
+/// (and thus not recognizable as such).
+const LOG_IMPLIES: &str = "http://www.w3.org/2000/10/swap/log#implies";
+
+/// The plain RDF part of an N3 document.
+pub struct Stripped {
+    pub graph: Graph,
+    /// How many statements were dropped,
+    /// because they are or use formulae or variables.
+    pub statements: usize,
+}
+
+fn to_subject(term: N3Term, formulae: &HashSet<BlankNode>) -> Option<Subject> {
+    match term {
+        N3Term::NamedNode(node) => Some(node.into()),
+        N3Term::BlankNode(node) if !formulae.contains(&node) => Some(node.into()),
+        N3Term::Triple(triple) => Some((*triple).into()),
+        N3Term::BlankNode(_) | N3Term::Literal(_) | N3Term::Variable(_) => None,
+    }
+}
+
+fn to_object(term: N3Term, formulae: &HashSet<BlankNode>) -> Option<Term> {
+    match term {
+        N3Term::NamedNode(node) => Some(node.into()),
+        N3Term::BlankNode(node) if !formulae.contains(&node) => Some(node.into()),
+        N3Term::Literal(literal) => Some(literal.into()),
+        N3Term::Triple(triple) => Some((*triple).into()),
+        N3Term::BlankNode(_) | N3Term::Variable(_) => None,
+    }
+}
+
+/// Returns the statement as a plain RDF triple,
+/// or `None` if it is N3 specific.
+fn to_triple(quad: N3Quad, formulae: &HashSet<BlankNode>) -> Option<Triple> {
+    if quad.graph_name != GraphName::DefaultGraph {
+        return None;
+    }
+    let N3Term::NamedNode(predicate) = quad.predicate else {
+        return None;
+    };
+    if predicate.as_str() == LOG_IMPLIES {
+        return None;
+    }
+    Some(Triple::new(
+        to_subject(quad.subject, formulae)?,
+        predicate,
+        to_object(quad.object, formulae)?,
+    ))
+}
+
+/// Parses an N3 document, dropping all formulae, variables and rules.
+/// Returns `None` if there are none, so the document is plain RDF.
+///
+/// # Errors
+///
+/// Returns `Error::Syntax` if the content is not valid N3.
+pub fn strip_formulae(content: &[u8]) -> Result<Option<Stripped>, super::Error> {
+    let quads = N3Parser::new()
+        .for_reader(content)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| super::oxrdfio::map_rdf_parse_error(err.into()))?;
+    // Each formula is parsed as a graph named by a blank node,
+    // which is also what stands for the formula in other statements.
+    let formulae = quads
+        .iter()
+        .filter_map(|quad| match &quad.graph_name {
+            GraphName::BlankNode(node) => Some(node.clone()),
+            GraphName::NamedNode(_) | GraphName::DefaultGraph => None,
+        })
+        .collect::<HashSet<_>>();
+    let mut graph = Graph::new();
+    let mut statements = 0;
+    for quad in quads {
+        match to_triple(quad, &formulae) {
+            Some(triple) => {
+                graph.insert(&triple);
+            }
+            None => statements += 1,
+        }
+    }
+    Ok((statements > 0).then_some(Stripped { graph, statements }))
+}
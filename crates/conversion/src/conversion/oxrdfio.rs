@@ -2,53 +2,220 @@
 //
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
+use std::collections::BTreeMap;
+use std::io::Write;
+
 #[cfg(feature = "async")]
 use async_trait::async_trait;
+use oxrdf::{GraphName, NamedNode, Quad, Subject, Term};
 use oxrdfio::{RdfFormat, RdfParseError, RdfParser, RdfSerializer};
 #[cfg(feature = "async")]
 use tokio::fs;
+#[cfg(feature = "async")]
+use tokio::io::AsyncWriteExt;
 
-use super::OntFile;
+use super::{ConversionOptions, OntFile};
+use rdfoothills_iri::Iri;
 use rdfoothills_mime as mime;
 
 #[derive(Debug, Default)]
 pub struct Converter;
 
 impl Converter {
-    const fn to_oxrdf_format(fmt: mime::Type) -> Option<RdfFormat> {
-        match fmt {
-            mime::Type::N3 => Some(RdfFormat::N3),
-            mime::Type::NQuads | mime::Type::NQuadsStar => Some(RdfFormat::NQuads),
-            mime::Type::NTriples | mime::Type::NTriplesStar => Some(RdfFormat::NTriples),
-            mime::Type::OwlXml | mime::Type::RdfXml => Some(RdfFormat::RdfXml),
-            mime::Type::TriG | mime::Type::TriGStar => Some(RdfFormat::TriG),
-            mime::Type::Turtle | mime::Type::TurtleStar => Some(RdfFormat::Turtle),
-            mime::Type::BinaryRdf
-            | mime::Type::Csvw
-            | mime::Type::Hdt
-            | mime::Type::HexTuples
-            | mime::Type::Html
-            | mime::Type::JsonLd
-            | mime::Type::Microdata
-            | mime::Type::NdJsonLd
-            | mime::Type::OwlFunctional
-            | mime::Type::RdfA
-            | mime::Type::RdfJson
-            | mime::Type::TriX
-            | mime::Type::Tsvw
-            | mime::Type::YamlLd => None,
+    const fn supports_format(fmt: mime::Type) -> bool {
+        fmt.to_rdf_format().is_some()
+    }
+}
+
+/// Whether prefixes and a base IRI can be read from the format,
+/// and prefixes can be written to it,
+/// in which case they are carried over from the source
+/// (see [`Harvest`]).
+const fn has_prefixes(fmt: RdfFormat) -> bool {
+    matches!(
+        fmt,
+        RdfFormat::N3 | RdfFormat::RdfXml | RdfFormat::TriG | RdfFormat::Turtle
+    )
+}
+
+/// Whether a base IRI can be declared in the format,
+/// with the IRIs relative to it (see [`Relativizer`]).
+const fn has_base_declaration(fmt: RdfFormat) -> bool {
+    matches!(fmt, RdfFormat::N3 | RdfFormat::TriG | RdfFormat::Turtle)
+}
+
+/// What is collected from the source while parsing it,
+/// to be carried over into the target.
+#[derive(Default)]
+struct Harvest {
+    prefixes: BTreeMap<String, String>,
+    base_iri: Option<String>,
+}
+
+impl Harvest {
+    /// Collects the prefixes and the base IRI in scope at the current step of parsing.
+    ///
+    /// This has to be done repeatedly, because in RDF/XML,
+    /// they go out of scope with the element declaring them;
+    /// if one is declared multiple times, the first declaration wins.
+    fn collect<'a>(
+        &mut self,
+        prefixes: impl Iterator<Item = (&'a str, &'a str)>,
+        base_iri: Option<&str>,
+    ) {
+        for (name, iri) in prefixes {
+            if !self.prefixes.contains_key(name) {
+                self.prefixes.insert(name.to_owned(), iri.to_owned());
+            }
+        }
+        if self.base_iri.is_none() {
+            self.base_iri = base_iri.map(ToOwned::to_owned);
         }
     }
 
-    const fn supports_format(fmt: mime::Type) -> bool {
-        Self::to_oxrdf_format(fmt).is_some()
+    /// Collects the prefixes and the base IRI of the whole source,
+    /// in a pass of its own, which does not keep any of the quads,
+    /// so the conversion itself can stream them.
+    fn read(from: &OntFile, from_fmt: RdfFormat) -> Result<Self, super::Error> {
+        let mut harvest = Self::default();
+        let in_file = std::fs::File::open(&from.file)?;
+        let mut reader = RdfParser::from_format(from_fmt).for_reader(in_file);
+        while let Some(quad_res) = reader.next() {
+            quad_res.map_err(map_rdf_parse_error)?;
+            harvest.collect(reader.prefixes(), reader.base_iri());
+        }
+        harvest.collect(reader.prefixes(), reader.base_iri());
+        Ok(harvest)
+    }
+
+    /// See [`Self::read`].
+    #[cfg(feature = "async")]
+    async fn read_async(from: &OntFile, from_fmt: RdfFormat) -> Result<Self, super::Error> {
+        let mut harvest = Self::default();
+        let in_file = fs::File::open(&from.file).await?;
+        let mut reader = RdfParser::from_format(from_fmt).for_tokio_async_reader(in_file);
+        while let Some(quad_res) = reader.next().await {
+            quad_res.map_err(map_rdf_parse_error)?;
+            harvest.collect(reader.prefixes(), reader.base_iri());
+        }
+        harvest.collect(reader.prefixes(), reader.base_iri());
+        Ok(harvest)
+    }
+
+    /// Sets the harvested prefixes on the serializer.
+    fn serializer(&self, to_fmt: RdfFormat) -> Result<RdfSerializer, super::Error> {
+        let mut serializer = RdfSerializer::from_format(to_fmt);
+        for (name, iri) in &self.prefixes {
+            // RDF/XML has no syntax for an empty prefix.
+            if name.is_empty() && to_fmt == RdfFormat::RdfXml {
+                continue;
+            }
+            serializer = serializer
+                .with_prefix(name.as_str(), iri.as_str())
+                .map_err(|err| super::Error::Syntax {
+                    message: format!("Invalid IRI of prefix '{name}': {err}"),
+                    line: None,
+                })?;
+        }
+        Ok(serializer)
+    }
+
+    /// Sets up writing IRIs relative to the base IRI chosen by the `policy`,
+    /// if there is one, and the format supports it.
+    fn relativizer(
+        &self,
+        to_fmt: RdfFormat,
+        policy: &super::BaseIri,
+    ) -> Result<Option<Relativizer>, super::Error> {
+        let chosen = match policy {
+            super::BaseIri::Absolute => None,
+            super::BaseIri::Source => self.base_iri.as_deref(),
+            super::BaseIri::Explicit(base_iri) => Some(base_iri.as_str()),
+        };
+        match chosen {
+            Some(base_iri) if has_base_declaration(to_fmt) => {
+                Relativizer::new(base_iri, self.prefixes.values().cloned().collect()).map(Some)
+            }
+            _ => Ok(None),
+        }
     }
 }
 
-fn map_rdf_parse_error(parse_err: RdfParseError) -> super::Error {
+/// Writes IRIs relative to a base IRI, where that is possible.
+///
+/// The serializers of `OxRDF` I/O write IRIs as they are,
+/// so relative ones end up relative in the target.
+struct Relativizer {
+    base: Iri,
+    /// IRIs starting with any of these are left absolute,
+    /// so they get abbreviated with their prefix instead.
+    prefixes: Vec<String>,
+}
+
+impl Relativizer {
+    fn new(base_iri: &str, prefixes: Vec<String>) -> Result<Self, super::Error> {
+        let base = Iri::parse(base_iri.to_owned())
+            .ok()
+            .filter(Iri::is_absolute)
+            .ok_or_else(|| super::Error::InvalidBaseIri(base_iri.to_owned()))?;
+        Ok(Self { base, prefixes })
+    }
+
+    /// The base IRI declaration, to be written before anything else.
+    fn declaration(&self) -> String {
+        format!("@base <{}> .\n", self.base)
+    }
+
+    /// Only IRIs within the base IRI's "directory" are made relative;
+    /// others, like `//example.org/a` or `../a`, would not make anything clearer.
+    fn named_node(&self, node: NamedNode) -> NamedNode {
+        if self
+            .prefixes
+            .iter()
+            .any(|prefix| node.as_str().starts_with(prefix.as_str()))
+        {
+            return node;
+        }
+        let relative = Iri::parse(node.as_str().to_owned())
+            .ok()
+            .and_then(|iri| rdfoothills_iri::relativize(&self.base, &iri).ok())
+            .filter(|relative| {
+                !relative.is_absolute()
+                    && !relative.as_str().starts_with('/')
+                    && !relative.as_str().starts_with("..")
+            });
+        relative.map_or(node, |rel| NamedNode::new_unchecked(rel.into_inner()))
+    }
+
+    fn quad(&self, quad: Quad) -> Quad {
+        Quad {
+            subject: match quad.subject {
+                Subject::NamedNode(node) => self.named_node(node).into(),
+                other @ (Subject::BlankNode(_) | Subject::Triple(_)) => other,
+            },
+            predicate: self.named_node(quad.predicate),
+            object: match quad.object {
+                Term::NamedNode(node) => self.named_node(node).into(),
+                other @ (Term::BlankNode(_) | Term::Literal(_) | Term::Triple(_)) => other,
+            },
+            graph_name: match quad.graph_name {
+                GraphName::NamedNode(node) => self.named_node(node).into(),
+                other @ (GraphName::BlankNode(_) | GraphName::DefaultGraph) => other,
+            },
+        }
+    }
+}
+
+pub fn map_rdf_parse_error(parse_err: RdfParseError) -> super::Error {
     match parse_err {
         RdfParseError::Io(io_err) => super::Error::Io(io_err),
-        RdfParseError::Syntax(syntax_err) => super::Error::Syntax(syntax_err.to_string()),
+        RdfParseError::Syntax(syntax_err) => super::Error::Syntax {
+            message: syntax_err.to_string(),
+            // 0-based in OxRDF I/O
+            line: syntax_err
+                .location()
+                .map(|location| location.start.line + 1),
+        },
     }
 }
 
@@ -56,7 +223,7 @@ fn map_rdf_parse_error(parse_err: RdfParseError) -> super::Error {
 impl super::Converter for Converter {
     fn info(&self) -> super::Info {
         super::Info {
-            quality: super::Quality::Data,
+            quality: super::Quality::Prefixes,
             priority: super::Priority::High,
             typ: super::Type::Native,
             name: "OxRDF I/O",
@@ -71,40 +238,103 @@ impl super::Converter for Converter {
         Self::supports_format(from) && Self::supports_format(to)
     }
 
+    fn supports_star(&self) -> bool {
+        true
+    }
+
     fn convert(&self, from: &OntFile, to: &OntFile) -> Result<(), super::Error> {
-        let from_fmt = Self::to_oxrdf_format(from.mime_type)
+        self.convert_with_options(from, to, &ConversionOptions::default())
+    }
+
+    #[cfg(feature = "async")]
+    async fn convert_async(&self, from: &OntFile, to: &OntFile) -> Result<(), super::Error> {
+        self.convert_with_options_async(from, to, &ConversionOptions::default())
+            .await
+    }
+
+    fn convert_with_options(
+        &self,
+        from: &OntFile,
+        to: &OntFile,
+        options: &ConversionOptions,
+    ) -> Result<(), super::Error> {
+        let from_fmt = from
+            .mime_type
+            .to_rdf_format()
             .expect("convert called with an invalid (-> unsupported by OxRDF) input format");
-        let to_fmt = Self::to_oxrdf_format(to.mime_type)
+        let to_fmt = to
+            .mime_type
+            .to_rdf_format()
             .expect("convert called with an invalid (-> unsupported by OxRDF) output format");
 
-        let in_file = std::fs::File::open(&from.file);
-        let reader = RdfParser::from_format(from_fmt).for_reader(in_file.unwrap());
-        let out_file = std::fs::File::create(&to.file);
-        let mut writer = RdfSerializer::from_format(to_fmt).for_writer(out_file.unwrap());
+        // The prefixes and the base IRI are only all known
+        // once the whole source is parsed,
+        // but they have to be set before writing anything.
+        let harvest = if has_prefixes(from_fmt) && has_prefixes(to_fmt) {
+            Harvest::read(from, from_fmt)?
+        } else {
+            Harvest::default()
+        };
+        let in_file = std::fs::File::open(&from.file)?;
+        let reader = RdfParser::from_format(from_fmt).for_reader(in_file);
+        let relativizer = harvest.relativizer(to_fmt, &options.base_iri)?;
+        let mut out_file = std::fs::File::create(&to.file)?;
+        if let Some(rel) = &relativizer {
+            out_file.write_all(rel.declaration().as_bytes())?;
+        }
+        let relativize = |quad: Quad| match &relativizer {
+            Some(rel) => rel.quad(quad),
+            None => quad,
+        };
+        let mut writer = harvest.serializer(to_fmt)?.for_writer(out_file);
         for quad_res in reader {
             let quad = quad_res.map_err(map_rdf_parse_error)?;
-            writer.serialize_quad(&quad)?;
+            writer.serialize_quad(&relativize(quad))?;
         }
+        writer.finish()?.sync_all()?;
 
         Ok(())
     }
 
     #[cfg(feature = "async")]
-    async fn convert_async(&self, from: &OntFile, to: &OntFile) -> Result<(), super::Error> {
-        let from_fmt = Self::to_oxrdf_format(from.mime_type)
+    async fn convert_with_options_async(
+        &self,
+        from: &OntFile,
+        to: &OntFile,
+        options: &ConversionOptions,
+    ) -> Result<(), super::Error> {
+        let from_fmt = from
+            .mime_type
+            .to_rdf_format()
             .expect("convert called with an invalid (-> unsupported by OxRDF) input format");
-        let to_fmt = Self::to_oxrdf_format(to.mime_type)
+        let to_fmt = to
+            .mime_type
+            .to_rdf_format()
             .expect("convert called with an invalid (-> unsupported by OxRDF) output format");
 
-        let in_file = fs::File::open(&from.file).await;
-        let mut reader = RdfParser::from_format(from_fmt).for_tokio_async_reader(in_file.unwrap());
-        let out_file = fs::File::create(&to.file).await;
-        let mut writer =
-            RdfSerializer::from_format(to_fmt).for_tokio_async_writer(out_file.unwrap());
+        // See `convert_with_options`.
+        let harvest = if has_prefixes(from_fmt) && has_prefixes(to_fmt) {
+            Harvest::read_async(from, from_fmt).await?
+        } else {
+            Harvest::default()
+        };
+        let in_file = fs::File::open(&from.file).await?;
+        let mut reader = RdfParser::from_format(from_fmt).for_tokio_async_reader(in_file);
+        let relativizer = harvest.relativizer(to_fmt, &options.base_iri)?;
+        let mut out_file = fs::File::create(&to.file).await?;
+        if let Some(rel) = &relativizer {
+            out_file.write_all(rel.declaration().as_bytes()).await?;
+        }
+        let relativize = |quad: Quad| match &relativizer {
+            Some(rel) => rel.quad(quad),
+            None => quad,
+        };
+        let mut writer = harvest.serializer(to_fmt)?.for_tokio_async_writer(out_file);
         while let Some(quad_res) = reader.next().await {
             let quad = quad_res.map_err(map_rdf_parse_error)?;
-            writer.serialize_quad(&quad).await?;
+            writer.serialize_quad(&relativize(quad)).await?;
         }
+        writer.finish().await?.sync_all().await?;
 
         Ok(())
     }
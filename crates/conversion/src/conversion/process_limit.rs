@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! A process-wide limit on how many external converter processes
+//! may run at the same time, with a bounded queue of those waiting.
+//!
+//! This prevents a burst of conversions from forking dozens of
+//! Python or Java processes at once.
+//! By default, there is no limit.
+
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+/// How many external processes may run and wait to run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Limit {
+    /// How many processes may run at the same time;
+    /// 0 is treated as 1.
+    pub max_running: usize,
+    /// How many processes may wait for others to finish,
+    /// before further ones are refused.
+    pub max_queued: usize,
+}
+
+/// The number of external processes running and waiting to run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Status {
+    pub running: usize,
+    pub queued: usize,
+}
+
+/// Returned instead of waiting, if the queue is full already.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Too many external converters are running ({}) or waiting to run ({}) already; try again later", .0.max_running, .0.max_queued)]
+pub struct Saturated(pub Limit);
+
+struct State {
+    limit: Option<Limit>,
+    status: Status,
+}
+
+impl State {
+    fn is_full(&self) -> bool {
+        self.limit
+            .is_some_and(|limit| self.status.running >= limit.max_running.max(1))
+    }
+}
+
+static STATE: Mutex<State> = Mutex::new(State {
+    limit: None,
+    status: Status {
+        running: 0,
+        queued: 0,
+    },
+});
+
+/// Notified whenever a process finishes or the limit changes.
+static CHANGED: Condvar = Condvar::new();
+
+fn lock() -> MutexGuard<'static, State> {
+    STATE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Sets (or with `None`, removes) the limit.
+/// Processes running already are not affected.
+pub fn set_limit(limit: Option<Limit>) {
+    lock().limit = limit;
+    CHANGED.notify_all();
+}
+
+#[must_use]
+pub fn limit() -> Option<Limit> {
+    lock().limit
+}
+
+#[must_use]
+pub fn status() -> Status {
+    lock().status
+}
+
+/// The right to run one external process;
+/// releases its slot when dropped.
+#[derive(Debug)]
+pub struct Permit(());
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        lock().status.running -= 1;
+        CHANGED.notify_all();
+    }
+}
+
+/// Waits until there is a free slot to run an external process in.
+///
+/// # Errors
+///
+/// Returns `Saturated` right away,
+/// if there is no free slot and the queue is full.
+pub fn acquire() -> Result<Permit, Saturated> {
+    let mut state = lock();
+    if state.is_full() {
+        if let Some(limit) = state.limit {
+            if state.status.queued >= limit.max_queued {
+                return Err(Saturated(limit));
+            }
+        }
+        state.status.queued += 1;
+        while state.is_full() {
+            state = CHANGED.wait(state).unwrap_or_else(PoisonError::into_inner);
+        }
+        state.status.queued -= 1;
+    }
+    state.status.running += 1;
+    drop(state);
+    Ok(Permit(()))
+}
+
+/// Like [`acquire`], but waits on tokio's blocking thread pool.
+///
+/// # Errors
+///
+/// Returns `Error::Saturated` right away,
+/// if there is no free slot and the queue is full.
+#[cfg(feature = "async")]
+pub async fn acquire_async() -> Result<Permit, super::Error> {
+    tokio::task::spawn_blocking(acquire)
+        .await
+        .map_err(|err| super::Error::Io(std::io::Error::other(err)))?
+        .map_err(super::Error::from)
+}
@@ -0,0 +1,141 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Describes where cached and converted ontology files come from,
+//! using the PROV Ontology (PROV-O).
+//!
+//! This complements the OCAA analysis of an ontology's IRI,
+//! which describes the ontology itself, not a specific copy of it.
+
+use std::time::SystemTime;
+
+use oxrdf::vocab::{rdf, rdfs, xsd};
+use oxrdf::{BlankNode, Literal, NamedNode, Triple};
+use rdfoothills_base::util;
+use rdfoothills_vocab::{dcterms, owl, prov};
+
+use super::Converter;
+
+/// The software that generated an artifact.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tool {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+impl Tool {
+    /// Describes the tool behind a converter.
+    /// This detects its version, which might run an external command.
+    #[must_use]
+    pub fn of(converter: &dyn Converter) -> Self {
+        Self {
+            name: converter.info().name.to_owned(),
+            version: converter.version(),
+        }
+    }
+}
+
+/// The provenance of a single artifact,
+/// e.g. an ontology downloaded into the cache,
+/// or the result of converting such a download into an other format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Provenance {
+    /// The IRI identifying the artifact itself.
+    pub artifact: NamedNode,
+    /// The IRI of what the artifact was generated from:
+    /// the upstream IRI for a download,
+    /// or the artifact it was converted from for a conversion.
+    pub source: NamedNode,
+    /// When the artifact was generated,
+    /// i.e. when it was retrieved or converted.
+    pub generated_at: Option<SystemTime>,
+    /// The software that generated the artifact.
+    pub tool: Option<Tool>,
+    /// The SHA-256 hash of the content of `source`
+    /// as it was used to generate the artifact, in lower-case hex.
+    pub input_sha256: Option<String>,
+}
+
+impl Provenance {
+    #[must_use]
+    pub const fn new(artifact: NamedNode, source: NamedNode) -> Self {
+        Self {
+            artifact,
+            source,
+            generated_at: None,
+            tool: None,
+            input_sha256: None,
+        }
+    }
+
+    /// Converts this into RDF triples.
+    ///
+    /// The artifact is described as a `prov:Entity`,
+    /// derived from the source,
+    /// generated by an (anonymous) `prov:Activity` that used the source
+    /// and was associated with the tool as a `prov:SoftwareAgent`.
+    #[must_use]
+    pub fn to_triples(&self) -> Vec<Triple> {
+        let artifact = &self.artifact;
+        let source = &self.source;
+        let activity = BlankNode::default();
+        let mut triples = vec![
+            Triple::new(artifact.clone(), rdf::TYPE, prov::ENTITY),
+            Triple::new(source.clone(), rdf::TYPE, prov::ENTITY),
+            Triple::new(activity.clone(), rdf::TYPE, prov::ACTIVITY),
+            Triple::new(activity.clone(), prov::USED, source.clone()),
+            Triple::new(artifact.clone(), prov::WAS_GENERATED_BY, activity.clone()),
+        ];
+        if artifact != source {
+            triples.push(Triple::new(
+                artifact.clone(),
+                prov::WAS_DERIVED_FROM,
+                source.clone(),
+            ));
+        }
+        if let Some(generated_at) = self.generated_at {
+            let date_time =
+                Literal::new_typed_literal(util::format_utc(generated_at), xsd::DATE_TIME);
+            triples.push(Triple::new(
+                artifact.clone(),
+                prov::GENERATED_AT_TIME,
+                date_time.clone(),
+            ));
+            triples.push(Triple::new(
+                activity.clone(),
+                prov::ENDED_AT_TIME,
+                date_time,
+            ));
+        }
+        if let Some(tool) = &self.tool {
+            let agent = BlankNode::default();
+            triples.push(Triple::new(
+                activity,
+                prov::WAS_ASSOCIATED_WITH,
+                agent.clone(),
+            ));
+            triples.push(Triple::new(agent.clone(), rdf::TYPE, prov::SOFTWARE_AGENT));
+            triples.push(Triple::new(
+                agent.clone(),
+                rdfs::LABEL,
+                Literal::new_simple_literal(&tool.name),
+            ));
+            if let Some(version) = &tool.version {
+                triples.push(Triple::new(
+                    agent,
+                    owl::VERSION_INFO,
+                    Literal::new_simple_literal(version),
+                ));
+            }
+        }
+        if let Some(hash) = &self.input_sha256 {
+            triples.push(Triple::new(
+                source.clone(),
+                dcterms::IDENTIFIER,
+                Literal::new_simple_literal(format!("sha256:{hash}")),
+            ));
+        }
+        triples
+    }
+}
@@ -3,13 +3,13 @@
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
 use std::ffi::OsStr;
+use std::sync::LazyLock;
 
 #[cfg(feature = "async")]
 use async_trait::async_trait;
-use once_cell::sync::Lazy;
 
 use super::OntFile;
-use rdfoothills_mime as mime;
+use rdfoothills_mime::{self as mime, tool_names};
 
 #[derive(Debug, Default)]
 pub struct Converter;
@@ -17,7 +17,7 @@ pub struct Converter;
 const CLI_CMD: &str = "pylode";
 const CLI_CMD_DESC: &str = "RDF to HTML conversion";
 
-static PYLODE_ARGS_BEGIN: Lazy<Vec<&'static OsStr>> = Lazy::new(|| {
+static PYLODE_ARGS_BEGIN: LazyLock<Vec<&'static OsStr>> = LazyLock::new(|| {
     vec![
         OsStr::new("--sort"),
         OsStr::new("--css"),
@@ -70,8 +70,20 @@ impl super::Converter for Converter {
         super::is_cli_cmd_available(CLI_CMD)
     }
 
+    fn version(&self) -> Option<String> {
+        super::cli_cmd_version(CLI_CMD)
+    }
+
+    /// pyLODE 3 changed the command-line interface to the one we use.
+    fn supported_versions(&self) -> super::VersionRange {
+        super::VersionRange {
+            min: Some(&[3]),
+            max: None,
+        }
+    }
+
     fn supports(&self, from: mime::Type, to: mime::Type) -> bool {
-        to == mime::Type::Html && super::to_rdflib_format(from).is_some()
+        to == mime::Type::Html && tool_names::rdflib(from).is_some()
     }
 
     fn convert(&self, from: &OntFile, to: &OntFile) -> Result<(), super::Error> {
@@ -8,7 +8,7 @@ use std::ffi::OsStr;
 use async_trait::async_trait;
 
 use super::OntFile;
-use rdfoothills_mime as mime;
+use rdfoothills_mime::{self as mime, tool_names};
 
 #[derive(Debug, Default)]
 pub struct Converter;
@@ -43,11 +43,11 @@ macro_rules! convert_args {
             OsStr::new("--output"),
             $to.file.as_os_str(),
             OsStr::new("--read"),
-            OsStr::new(super::to_rdflib_format($from.mime_type).expect(
+            OsStr::new(tool_names::rdflib($from.mime_type).expect(
                 "rdf-convert called with an invalid (-> unsupported by RDFlib) source type",
             )),
             OsStr::new("--write"),
-            OsStr::new(super::to_rdflib_format($to.mime_type).expect(
+            OsStr::new(tool_names::rdflib($to.mime_type).expect(
                 "rdf-convert called with an invalid (-> unsupported by RDFlib) target type",
             )),
         ]
@@ -69,8 +69,12 @@ impl super::Converter for Converter {
         super::is_cli_cmd_available(CLI_CMD)
     }
 
+    fn version(&self) -> Option<String> {
+        super::cli_cmd_version(CLI_CMD)
+    }
+
     fn supports(&self, from: mime::Type, to: mime::Type) -> bool {
-        super::to_rdflib_format(from).is_some() && super::to_rdflib_format(to).is_some()
+        tool_names::rdflib(from).is_some() && tool_names::rdflib(to).is_some()
     }
 
     fn convert(&self, from: &OntFile, to: &OntFile) -> Result<(), super::Error> {
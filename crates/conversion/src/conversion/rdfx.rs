@@ -8,7 +8,7 @@ use std::ffi::OsStr;
 use async_trait::async_trait;
 
 use super::OntFile;
-use rdfoothills_mime as mime;
+use rdfoothills_mime::{self as mime, tool_names};
 
 #[derive(Debug, Default)]
 pub struct Converter;
@@ -55,12 +55,17 @@ impl Converter {
             | mime::Type::OwlFunctional
             | mime::Type::RdfA
             | mime::Type::RdfJson
+            | mime::Type::SparqlResultsCsv
+            | mime::Type::SparqlResultsJson
+            | mime::Type::SparqlResultsTsv
+            | mime::Type::SparqlResultsXml
             | mime::Type::TriG
             | mime::Type::TriGStar
             | mime::Type::TriX
             | mime::Type::Tsvw
             | mime::Type::TurtleStar
-            | mime::Type::YamlLd => false,
+            | mime::Type::YamlLd
+            | mime::Type::Other(_) => false,
         }
     }
 }
@@ -71,7 +76,7 @@ macro_rules! convert_args {
             OsStr::new("convert"),
             OsStr::new("--format"),
             OsStr::new(
-                super::to_rdflib_format($to.mime_type)
+                tool_names::rdflib($to.mime_type)
                     .expect("rdfx called with an invalid (-> unsupported by RDFlib) target type"),
             ),
             OsStr::new("--output"),
@@ -96,6 +101,10 @@ impl super::Converter for Converter {
         super::is_cli_cmd_available(CLI_CMD)
     }
 
+    fn version(&self) -> Option<String> {
+        super::cli_cmd_version(CLI_CMD)
+    }
+
     fn supports(&self, from: mime::Type, to: mime::Type) -> bool {
         Self::supports_format(from) && Self::supports_format(to)
     }
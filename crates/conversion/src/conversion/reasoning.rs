@@ -0,0 +1,203 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Native, forward-chaining materialization of entailed triples,
+//! covering RDFS and a subset of OWL 2 RL.
+//!
+//! This is no full reasoner (no consistency checks, no class expressions),
+//! but enough to make the implicit type- and property-hierarchies
+//! of an ontology explicit.
+
+use std::fmt;
+use std::str::FromStr;
+
+use oxrdf::vocab::{rdf, rdfs};
+use oxrdf::{Graph, NamedNodeRef, SubjectRef, TermRef, Triple, TripleRef};
+use rdfoothills_vocab::owl;
+
+/// The entailment regimes that can be materialized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Entailment {
+    /// The RDFS rules about domains, ranges, sub-classes and sub-properties
+    /// (`rdfs2`, `rdfs3`, `rdfs5`, `rdfs7`, `rdfs9` and `rdfs11`).
+    Rdfs,
+    /// The RDFS rules, plus these of OWL 2 RL:
+    /// equivalent classes and properties,
+    /// inverse, symmetric and transitive properties,
+    /// and the symmetry and transitivity of `owl:sameAs`.
+    OwlRl,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("Unknown entailment regime '{0}'; supported are: rdfs, owlrl")]
+pub struct UnknownEntailment(pub String);
+
+impl Entailment {
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Rdfs => "rdfs",
+            Self::OwlRl => "owlrl",
+        }
+    }
+}
+
+impl fmt::Display for Entailment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Entailment {
+    type Err = UnknownEntailment;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "rdfs" => Ok(Self::Rdfs),
+            "owlrl" | "owl-rl" | "owl2rl" => Ok(Self::OwlRl),
+            _ => Err(UnknownEntailment(s.to_owned())),
+        }
+    }
+}
+
+/// Literals can not be subjects; everything else can.
+const fn term_as_subject(term: TermRef<'_>) -> Option<SubjectRef<'_>> {
+    match term {
+        TermRef::NamedNode(node) => Some(SubjectRef::NamedNode(node)),
+        TermRef::BlankNode(node) => Some(SubjectRef::BlankNode(node)),
+        TermRef::Triple(triple) => Some(SubjectRef::Triple(triple)),
+        TermRef::Literal(_) => None,
+    }
+}
+
+/// The named-node objects of all the triples with the given subject and predicate.
+fn named_objects<'a>(
+    graph: &'a Graph,
+    subject: SubjectRef<'a>,
+    predicate: NamedNodeRef<'a>,
+) -> impl Iterator<Item = NamedNodeRef<'a>> {
+    graph
+        .objects_for_subject_predicate(subject, predicate)
+        .filter_map(|object| match object {
+            TermRef::NamedNode(node) => Some(node),
+            TermRef::BlankNode(_) | TermRef::Literal(_) | TermRef::Triple(_) => None,
+        })
+}
+
+fn push_new(graph: &Graph, inferred: &mut Vec<Triple>, triple: TripleRef<'_>) {
+    if !graph.contains(triple) {
+        inferred.push(triple.into_owned());
+    }
+}
+
+/// Applies all rules of the regime to a single triple of the graph,
+/// collecting the entailed triples that are not in the graph yet.
+fn infer(graph: &Graph, entailment: Entailment, triple: TripleRef<'_>, inferred: &mut Vec<Triple>) {
+    let TripleRef {
+        subject,
+        predicate,
+        object,
+    } = triple;
+    let mut add = |subj: SubjectRef<'_>, pred: NamedNodeRef<'_>, obj: TermRef<'_>| {
+        push_new(graph, inferred, TripleRef::new(subj, pred, obj));
+    };
+    // rdfs2
+    for class in graph.objects_for_subject_predicate(predicate, rdfs::DOMAIN) {
+        add(subject, rdf::TYPE, class);
+    }
+    // rdfs7
+    for super_property in named_objects(graph, predicate.into(), rdfs::SUB_PROPERTY_OF) {
+        add(subject, super_property, object);
+    }
+    let Some(obj_subject) = term_as_subject(object) else {
+        return;
+    };
+    // rdfs3
+    for class in graph.objects_for_subject_predicate(predicate, rdfs::RANGE) {
+        add(obj_subject, rdf::TYPE, class);
+    }
+    // rdfs9
+    if predicate == rdf::TYPE {
+        for super_class in graph.objects_for_subject_predicate(obj_subject, rdfs::SUB_CLASS_OF) {
+            add(subject, rdf::TYPE, super_class);
+        }
+    }
+    // rdfs5, rdfs11
+    if predicate == rdfs::SUB_CLASS_OF || predicate == rdfs::SUB_PROPERTY_OF {
+        for super_term in graph.objects_for_subject_predicate(obj_subject, predicate) {
+            add(subject, predicate, super_term);
+        }
+    }
+    if entailment == Entailment::Rdfs {
+        return;
+    }
+    // scm-eqc1, scm-eqp1
+    for (equivalent, sub) in [
+        (owl::EQUIVALENT_CLASS, rdfs::SUB_CLASS_OF),
+        (owl::EQUIVALENT_PROPERTY, rdfs::SUB_PROPERTY_OF),
+    ] {
+        if predicate == equivalent {
+            add(subject, sub, object);
+            add(obj_subject, sub, subject.into());
+        }
+    }
+    // prp-symp
+    if graph.contains(TripleRef::new(
+        predicate,
+        rdf::TYPE,
+        owl::SYMMETRIC_PROPERTY,
+    )) {
+        add(obj_subject, predicate, subject.into());
+    }
+    // prp-trp, eq-trans
+    if predicate == owl::SAME_AS
+        || graph.contains(TripleRef::new(
+            predicate,
+            rdf::TYPE,
+            owl::TRANSITIVE_PROPERTY,
+        ))
+    {
+        for next in graph.objects_for_subject_predicate(obj_subject, predicate) {
+            add(subject, predicate, next);
+        }
+    }
+    // eq-sym
+    if predicate == owl::SAME_AS {
+        add(obj_subject, predicate, subject.into());
+    }
+    // prp-inv1, prp-inv2
+    let inverses = named_objects(graph, predicate.into(), owl::INVERSE_OF).chain(
+        graph
+            .subjects_for_predicate_object(owl::INVERSE_OF, predicate)
+            .filter_map(|inverse| match inverse {
+                SubjectRef::NamedNode(node) => Some(node),
+                SubjectRef::BlankNode(_) | SubjectRef::Triple(_) => None,
+            }),
+    );
+    for inverse in inverses {
+        add(obj_subject, inverse, subject.into());
+    }
+}
+
+/// Adds all the triples entailed by `graph` under the given regime to it,
+/// until nothing new can be inferred anymore.
+/// Returns the number of triples added.
+pub fn materialize(graph: &mut Graph, entailment: Entailment) -> usize {
+    let mut added = 0;
+    loop {
+        let mut inferred = Vec::new();
+        for triple in &*graph {
+            infer(graph, entailment, triple, &mut inferred);
+        }
+        let added_before = added;
+        for triple in &inferred {
+            if graph.insert(triple) {
+                added += 1;
+            }
+        }
+        if added == added_before {
+            return added;
+        }
+    }
+}
@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Extracts the SHACL shapes from an ontology,
+//! for validators that need them without the rest of it.
+
+use std::collections::HashSet;
+
+use oxrdf::vocab::rdf;
+use oxrdf::{Graph, SubjectRef, TermRef};
+use rdfoothills_vocab::sh;
+
+/// The predicates whose named-node objects are shapes themselves,
+/// and thus get extracted too.
+const SHAPE_PREDICATES: &[oxrdf::NamedNodeRef<'static>] = &[sh::PROPERTY, sh::NODE];
+
+/// Returns all the shapes in `graph`.
+///
+/// These are every `sh:NodeShape` and `sh:PropertyShape`,
+/// with all the triples about them,
+/// and recursively those about the blank nodes they refer to
+/// (nested property shapes, RDF lists, ...)
+/// and the shapes they refer to through `sh:property` or `sh:node`.
+#[must_use]
+pub fn extract(graph: &Graph) -> Graph {
+    let mut pending = [sh::NODE_SHAPE, sh::PROPERTY_SHAPE]
+        .into_iter()
+        .flat_map(|typ| graph.subjects_for_predicate_object(rdf::TYPE, typ))
+        .collect::<Vec<_>>();
+    let mut visited = HashSet::new();
+    let mut shapes = Graph::new();
+    while let Some(subject) = pending.pop() {
+        if !visited.insert(subject) {
+            continue;
+        }
+        for triple in graph.triples_for_subject(subject) {
+            shapes.insert(triple);
+            match triple.object {
+                TermRef::BlankNode(node) => pending.push(SubjectRef::BlankNode(node)),
+                TermRef::NamedNode(node) if SHAPE_PREDICATES.contains(&triple.predicate) => {
+                    pending.push(SubjectRef::NamedNode(node));
+                }
+                TermRef::NamedNode(_) | TermRef::Literal(_) | TermRef::Triple(_) => {}
+            }
+        }
+    }
+    shapes
+}
@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Extracts the actual problem from the error output of external tools,
+//! so it can be reported as a structured error,
+//! instead of as a whole (Python) stack-trace.
+//!
+//! All the CLI tools we use (rdfx, rdf-convert and pyLODE)
+//! are based on `RDFLib`, so they fail with the same exceptions.
+
+use super::Error;
+
+/// The message of the last exception of the given type in a Python stack-trace,
+/// which is everything after `<module>.<exception>: `.
+fn exception_message<'a>(stderr: &'a str, exception: &str) -> Option<&'a str> {
+    let marker = format!("{exception}: ");
+    let start = stderr.rfind(&marker)? + marker.len();
+    stderr.get(start..).map(str::trim)
+}
+
+/// Like [`exception_message`], but only the first line of it.
+fn exception_message_line<'a>(stderr: &'a str, exception: &str) -> Option<&'a str> {
+    exception_message(stderr, exception)
+        .and_then(|message| message.lines().next())
+        .map(str::trim)
+}
+
+/// Parses the number at the start of `text`.
+fn leading_number(text: &str) -> Option<u64> {
+    text.split(|chr: char| !chr.is_ascii_digit())
+        .next()
+        .and_then(|digits| digits.parse().ok())
+}
+
+/// `RDFLib`'s Turtle and N3 parser, e.g.:
+///
+/// ```text
+/// at line 3 of <>:
+/// Bad syntax (expected '.' or '}' or ']' at end of statement) at ^ in:
+/// "..."
+/// ```
+fn bad_syntax(message: &str) -> Error {
+    let line = message.strip_prefix("at line ").and_then(leading_number);
+    let reason = message
+        .split_once("Bad syntax (")
+        .and_then(|(_, rest)| rest.rsplit_once(") at ^ in:"))
+        .map_or(message, |(reason, _)| reason);
+    Error::Syntax {
+        message: reason.to_owned(),
+        line,
+    }
+}
+
+/// Python's XML parser, used by `RDFLib` for RDF/XML, e.g.:
+/// `file:///tmp/ont.rdf:3:2: not well-formed (invalid token)`.
+fn sax_parse_exception(message: &str) -> Error {
+    let parsed = message.split_once(": ").and_then(|(location, reason)| {
+        let mut parts = location.rsplit(':');
+        let _column = parts.next()?;
+        let line = parts.next().and_then(leading_number)?;
+        Some((reason, line))
+    });
+    let (reason, line) = parsed.map_or((message, None), |(reason, line)| (reason, Some(line)));
+    Error::Syntax {
+        message: reason.to_owned(),
+        line,
+    }
+}
+
+/// Python's JSON parser, used by `RDFLib` for JSON-LD, e.g.:
+/// `Expecting value: line 1 column 1 (char 0)`.
+fn json_decode_error(message: &str) -> Error {
+    let parsed = message
+        .rsplit_once(": line ")
+        .and_then(|(reason, location)| Some((reason, leading_number(location)?)));
+    let (reason, line) = parsed.map_or((message, None), |(reason, line)| (reason, Some(line)));
+    Error::Syntax {
+        message: reason.to_owned(),
+        line,
+    }
+}
+
+/// Recognizes the known errors in the error output of an `RDFLib` based tool.
+/// Returns `None` if the failure is of an unknown kind.
+#[must_use]
+pub fn parse(stderr: &str) -> Option<Error> {
+    if let Some(message) = exception_message(stderr, "BadSyntax") {
+        return Some(bad_syntax(message));
+    }
+    if let Some(message) = exception_message_line(stderr, "SAXParseException") {
+        return Some(sax_parse_exception(message));
+    }
+    if let Some(message) = exception_message_line(stderr, "JSONDecodeError") {
+        return Some(json_decode_error(message));
+    }
+    // The N-Triples and N-Quads parsers do not report line numbers.
+    if let Some(message) = exception_message_line(stderr, "ParseError") {
+        return Some(Error::Syntax {
+            message: message.to_owned(),
+            line: None,
+        });
+    }
+    // An unknown format, e.g. "No plugin registered for (xyz, <class 'rdflib.parser.Parser'>)".
+    if let Some(message) = exception_message_line(stderr, "PluginException") {
+        return Some(Error::Unsupported(message.to_owned()));
+    }
+    None
+}
@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! The versions of external tools,
+//! and the ranges of them a converter supports.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A dot-separated, numeric version, e.g. `3.0.5`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Version(pub Vec<u64>);
+
+impl Version {
+    /// Extracts the first version number from the output of a tool,
+    /// e.g. `3.0.5` from `pyLODE, version 3.0.5 (Python 3.11)`.
+    /// Returns `None` if there is no number in there.
+    #[must_use]
+    pub fn extract(text: &str) -> Option<Self> {
+        let from_number = text.trim_start_matches(|chr: char| !chr.is_ascii_digit());
+        if from_number.is_empty() {
+            return None;
+        }
+        let parts = from_number
+            .split(|chr: char| !chr.is_ascii_digit() && chr != '.')
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map_while(|part| part.parse().ok())
+            .collect();
+        Some(Self(parts))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_parts(f, &self.0)
+    }
+}
+
+fn fmt_parts(f: &mut fmt::Formatter<'_>, parts: &[u64]) -> fmt::Result {
+    for (idx, part) in parts.iter().enumerate() {
+        if idx > 0 {
+            f.write_str(".")?;
+        }
+        write!(f, "{part}")?;
+    }
+    Ok(())
+}
+
+/// Compares only as many parts as `bound` has,
+/// treating missing parts of `version` as 0,
+/// so e.g. `2.4.1` compares as equal to a bound of `2`.
+fn cmp_to_bound(version: &[u64], bound: &[u64]) -> Ordering {
+    bound
+        .iter()
+        .enumerate()
+        .map(|(idx, bound_part)| version.get(idx).unwrap_or(&0).cmp(bound_part))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// The versions of a tool a converter supports, bounds included.
+/// A bound only restricts the parts it specifies,
+/// so e.g. a maximum of `2` allows any `2.x.y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct VersionRange {
+    pub min: Option<&'static [u64]>,
+    pub max: Option<&'static [u64]>,
+}
+
+impl VersionRange {
+    /// Supports any version.
+    pub const ANY: Self = Self {
+        min: None,
+        max: None,
+    };
+
+    #[must_use]
+    pub const fn is_any(&self) -> bool {
+        self.min.is_none() && self.max.is_none()
+    }
+
+    #[must_use]
+    pub fn contains(&self, version: &Version) -> bool {
+        self.min
+            .is_none_or(|min| cmp_to_bound(&version.0, min).is_ge())
+            && self
+                .max
+                .is_none_or(|max| cmp_to_bound(&version.0, max).is_le())
+    }
+}
+
+impl fmt::Display for VersionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.min, self.max) {
+            (None, None) => f.write_str("any"),
+            (Some(min), None) => {
+                f.write_str(">= ")?;
+                fmt_parts(f, min)
+            }
+            (None, Some(max)) => {
+                f.write_str("<= ")?;
+                fmt_parts(f, max)?;
+                f.write_str(".*")
+            }
+            (Some(min), Some(max)) => {
+                f.write_str(">= ")?;
+                fmt_parts(f, min)?;
+                f.write_str(", <= ")?;
+                fmt_parts(f, max)?;
+                f.write_str(".*")
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::ffi::OsStr;
+use std::path::Path;
+
+#[cfg(feature = "async")]
+use async_trait::async_trait;
+
+use super::work_dir::WorkDir;
+use super::OntFile;
+use rdfoothills_mime as mime;
+
+#[derive(Debug, Default)]
+pub struct Converter;
+
+const CLI_CMD: &str = "widoco";
+const CLI_CMD_DESC: &str = "RDF to HTML conversion";
+
+/// The file WIDOCO writes the (single page) documentation to,
+/// within its output folder.
+const OUTPUT_FILE_NAME: &str = "index-en.html";
+
+impl Converter {
+    fn widoco<I, S>(args: I) -> Result<(), super::Error>
+    where
+        I: IntoIterator<Item = S> + Send,
+        S: AsRef<OsStr>,
+    {
+        super::cli_cmd(CLI_CMD, CLI_CMD_DESC, args)
+    }
+
+    #[cfg(feature = "async")]
+    async fn widoco_async<I, S>(args: I) -> Result<(), super::Error>
+    where
+        I: IntoIterator<Item = S> + Send,
+        S: AsRef<OsStr>,
+    {
+        super::cli_cmd_async(CLI_CMD, CLI_CMD_DESC, args).await
+    }
+
+    const fn supports_format(fmt: mime::Type) -> bool {
+        matches!(
+            fmt,
+            mime::Type::JsonLd
+                | mime::Type::NTriples
+                | mime::Type::OwlXml
+                | mime::Type::RdfXml
+                | mime::Type::Turtle
+        )
+    }
+}
+
+/// WIDOCO writes a whole folder (documentation, resources, visualization);
+/// we let it write into a temporary one,
+/// and only copy the main HTML file to the target.
+fn convert_args<'a>(from: &'a OntFile, out_dir: &'a Path) -> [&'a OsStr; 10] {
+    [
+        OsStr::new("-ontFile"),
+        from.file.as_os_str(),
+        OsStr::new("-outFolder"),
+        out_dir.as_os_str(),
+        OsStr::new("-lang"),
+        OsStr::new("en"),
+        OsStr::new("-getOntologyMetadata"),
+        OsStr::new("-uniteSections"),
+        OsStr::new("-noPlaceHolderText"),
+        OsStr::new("-rewriteAll"),
+    ]
+}
+
+#[cfg_attr(feature = "async", async_trait)]
+impl super::Converter for Converter {
+    fn info(&self) -> super::Info {
+        super::Info {
+            quality: super::Quality::Data,
+            priority: super::Priority::Low,
+            typ: super::Type::Cli,
+            name: "WIDOCO",
+        }
+    }
+
+    fn is_available(&self) -> bool {
+        super::is_cli_cmd_available(CLI_CMD)
+    }
+
+    fn supports(&self, from: mime::Type, to: mime::Type) -> bool {
+        to == mime::Type::Html && Self::supports_format(from)
+    }
+
+    fn convert(&self, from: &OntFile, to: &OntFile) -> Result<(), super::Error> {
+        let out_dir = WorkDir::new(CLI_CMD)?;
+        Self::widoco(convert_args(from, out_dir.path()))?;
+        std::fs::copy(out_dir.path().join(OUTPUT_FILE_NAME), &to.file)?;
+        out_dir.finish();
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    async fn convert_async(&self, from: &OntFile, to: &OntFile) -> Result<(), super::Error> {
+        let out_dir = WorkDir::new(CLI_CMD)?;
+        Self::widoco_async(convert_args(from, out_dir.path())).await?;
+        tokio::fs::copy(out_dir.path().join(OUTPUT_FILE_NAME), &to.file).await?;
+        out_dir.finish();
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Temporary working directories for single conversions,
+//! e.g. for tools that write more than just the output file.
+
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+/// If this environment variable is set (to anything but `0` or `false`),
+/// the working directories of failed conversions are kept by default,
+/// for debugging.
+pub const KEEP_ON_FAILURE_ENV_VAR: &str = "RDFOOTHILLS_KEEP_FAILED_WORK_DIRS";
+
+/// The prefix of the names of all working directories,
+/// to make it easy to find the ones kept after a failure.
+const PREFIX: &str = "rdfoothills-";
+
+fn keep_on_failure_default() -> bool {
+    env::var(KEEP_ON_FAILURE_ENV_VAR).is_ok_and(|value| {
+        !value.is_empty() && value != "0" && !value.eq_ignore_ascii_case("false")
+    })
+}
+
+/// A temporary directory for a single conversion.
+///
+/// It is removed when dropped - also on error or panic -
+/// unless the conversion was not marked as finished
+/// and the directory is to be kept on failure
+/// (see [`Self::keep_on_failure`]).
+#[derive(Debug)]
+pub struct WorkDir {
+    path: PathBuf,
+    /// Only `None` after it was decided to keep the directory.
+    dir: Option<TempDir>,
+    keep_on_failure: bool,
+    finished: bool,
+}
+
+impl WorkDir {
+    /// Creates a new, empty working directory
+    /// in the system's temporary directory.
+    /// `purpose` becomes part of its name, e.g. the name of the tool.
+    ///
+    /// Whether it is kept on failure defaults to
+    /// the [`KEEP_ON_FAILURE_ENV_VAR`] environment variable.
+    ///
+    /// # Errors
+    ///
+    /// If the directory cannot be created.
+    pub fn new(purpose: &str) -> io::Result<Self> {
+        let dir = tempfile::Builder::new()
+            .prefix(&format!("{PREFIX}{purpose}-"))
+            .tempdir()?;
+        Ok(Self {
+            path: dir.path().to_owned(),
+            dir: Some(dir),
+            keep_on_failure: keep_on_failure_default(),
+            finished: false,
+        })
+    }
+
+    /// Whether to keep the directory if it is dropped
+    /// without the conversion being marked as finished.
+    #[must_use]
+    pub const fn keep_on_failure(mut self, keep: bool) -> Self {
+        self.keep_on_failure = keep;
+        self
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Marks the conversion as successful,
+    /// and removes the directory.
+    pub fn finish(mut self) {
+        self.finished = true;
+    }
+}
+
+impl Drop for WorkDir {
+    fn drop(&mut self) {
+        if self.keep_on_failure && !self.finished {
+            if let Some(dir) = self.dir.take() {
+                let _ = dir.into_path();
+            }
+        }
+    }
+}
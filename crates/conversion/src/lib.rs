@@ -5,6 +5,12 @@
 mod conversion;
 pub use conversion::*;
 
+// Only used by the integration tests and benchmarks.
+#[cfg(test)]
+use criterion as _;
+#[cfg(test)]
+use tokio as _;
+
 // This tests rust code in the README with doc-tests.
 // Though, It will not appear in the generated documentaton.
 #[doc = include_str!("../README.md")]
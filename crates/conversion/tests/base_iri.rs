@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use common::{ont_file, ont_file_with};
+use rdfoothills_conversion::{self as conversion, BaseIri, ConversionOptions, Error, OntFile};
+use rdfoothills_mime as mime;
+
+const TURTLE: &str = "@base <http://example.org/ont/> .
+@prefix schema: <http://schema.org/> .
+<a> <p> <b#c> .
+<a> schema:name \"a\" .
+<a> <p> <http://example.com/other> .
+";
+
+fn convert(base_iri: BaseIri, to_type: mime::Type, dir: &Path) -> Result<OntFile, Error> {
+    let from = ont_file_with(dir, "from", mime::Type::Turtle, TURTLE);
+    let to = ont_file(dir, "to", to_type);
+    let options = ConversionOptions {
+        base_iri,
+        ..ConversionOptions::default()
+    };
+    conversion::convert_with(&from, &to, &options)?;
+    assert!(conversion::isomorphic(&from, &to).unwrap());
+    Ok(to)
+}
+
+#[test]
+fn test_absolute_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let to = convert(BaseIri::Absolute, mime::Type::TriG, dir.path()).unwrap();
+    let content = fs::read_to_string(&to.file).unwrap();
+    assert!(!content.contains("@base"), "{content}");
+    assert!(content.contains("<http://example.org/ont/a>"), "{content}");
+}
+
+#[test]
+fn test_source_base_preserved() {
+    let dir = tempfile::tempdir().unwrap();
+    let to = convert(BaseIri::Source, mime::Type::TriG, dir.path()).unwrap();
+    let content = fs::read_to_string(&to.file).unwrap();
+    assert!(
+        content.starts_with("@base <http://example.org/ont/> .\n"),
+        "{content}"
+    );
+    assert!(content.contains("<a> <p> <b#c>"), "{content}");
+    assert!(content.contains("schema:name"), "{content}");
+    assert!(content.contains("<http://example.com/other>"), "{content}");
+}
+
+#[test]
+fn test_explicit_base() {
+    let dir = tempfile::tempdir().unwrap();
+    let to = convert(
+        BaseIri::Explicit("http://example.org/".to_owned()),
+        mime::Type::N3,
+        dir.path(),
+    )
+    .unwrap();
+    let content = fs::read_to_string(&to.file).unwrap();
+    assert!(
+        content.starts_with("@base <http://example.org/> .\n"),
+        "{content}"
+    );
+    assert!(content.contains("<ont/a> <ont/p> <ont/b#c>"), "{content}");
+}
+
+#[test]
+fn test_base_ignored_without_declarations() {
+    let dir = tempfile::tempdir().unwrap();
+    let to = convert(BaseIri::Source, mime::Type::NTriples, dir.path()).unwrap();
+    let content = fs::read_to_string(&to.file).unwrap();
+    assert!(content.contains("<http://example.org/ont/a>"), "{content}");
+}
+
+#[test]
+fn test_invalid_base() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(matches!(
+        convert(
+            BaseIri::Explicit("not/absolute".to_owned()),
+            mime::Type::TriG,
+            dir.path()
+        ),
+        Err(Error::InvalidBaseIri(_))
+    ));
+}
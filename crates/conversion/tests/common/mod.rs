@@ -0,0 +1,29 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Helpers shared by the integration tests.
+
+// Not every test uses every helper.
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::Path;
+
+use rdfoothills_conversion::OntFile;
+use rdfoothills_mime as mime;
+
+/// The file `<name>.<ext>` in `dir`, of the given format.
+pub fn ont_file(dir: &Path, name: &str, mime_type: mime::Type) -> OntFile {
+    OntFile {
+        file: dir.join(format!("{name}.{}", mime_type.file_ext())),
+        mime_type,
+    }
+}
+
+/// Like [`ont_file`], but also writes the `content` into it.
+pub fn ont_file_with(dir: &Path, name: &str, mime_type: mime::Type, content: &str) -> OntFile {
+    let file = ont_file(dir, name, mime_type);
+    fs::write(&file.file, content).unwrap();
+    file
+}
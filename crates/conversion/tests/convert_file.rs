@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+use std::fs;
+
+use rdfoothills_conversion::{self as conversion, Converter, Error, OntFile};
+use rdfoothills_mime as mime;
+
+const TURTLE: &str = "<http://example.org/s> <http://example.org/p> \"o\" .\n";
+
+fn native() -> &'static dyn Converter {
+    conversion::converters()
+        .find(|converter| converter.info().name == "OxRDF I/O")
+        .unwrap()
+}
+
+/// An input and an output file that can not be opened or created.
+fn unreachable_files(dir: &std::path::Path) -> [(OntFile, OntFile); 2] {
+    let existing = dir.join("ont.ttl");
+    fs::write(&existing, TURTLE).unwrap();
+    let ont_file = |file| OntFile {
+        file,
+        mime_type: mime::Type::Turtle,
+    };
+    [
+        (
+            ont_file(dir.join("missing.ttl")),
+            ont_file(dir.join("ont.nt")),
+        ),
+        (
+            ont_file(existing),
+            ont_file(dir.join("missing").join("ont.nt")),
+        ),
+    ]
+}
+
+#[test]
+fn test_convert_file_detects_formats() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("ont.ttl");
+    let output = dir.path().join("ont.nt");
+    fs::write(&input, TURTLE).unwrap();
+    conversion::convert_file(&input, &output, None, None).unwrap();
+    assert_eq!(fs::read_to_string(&output).unwrap(), TURTLE);
+    // No temporary files are left behind.
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 2);
+}
+
+#[test]
+fn test_convert_file_explicit_formats() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("ont.data");
+    let output = dir.path().join("ont.out");
+    fs::write(&input, TURTLE).unwrap();
+    conversion::convert_file(
+        &input,
+        &output,
+        Some(mime::Type::Turtle),
+        Some(mime::Type::NTriples),
+    )
+    .unwrap();
+    assert_eq!(fs::read_to_string(&output).unwrap(), TURTLE);
+}
+
+#[test]
+fn test_convert_file_unknown_output_format() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("ont.ttl");
+    fs::write(&input, TURTLE).unwrap();
+    let res = conversion::convert_file(&input, &dir.path().join("ont"), None, None);
+    assert!(matches!(res, Err(Error::UnknownFormat { .. })));
+}
+
+#[test]
+fn test_native_io_error() {
+    let dir = tempfile::tempdir().unwrap();
+    for (from, to) in unreachable_files(dir.path()) {
+        let res = native().convert(&from, &to);
+        assert!(matches!(res, Err(Error::Io(_))), "{res:?}");
+    }
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_native_io_error_async() {
+    let dir = tempfile::tempdir().unwrap();
+    for (from, to) in unreachable_files(dir.path()) {
+        let res = native().convert_async(&from, &to).await;
+        assert!(matches!(res, Err(Error::Io(_))), "{res:?}");
+    }
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_convert_file_async() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("ont.ttl");
+    let output = dir.path().join("ont.nt");
+    fs::write(&input, TURTLE).unwrap();
+    conversion::convert_file_async(&input, &output, None, None)
+        .await
+        .unwrap();
+    assert_eq!(fs::read_to_string(&output).unwrap(), TURTLE);
+}
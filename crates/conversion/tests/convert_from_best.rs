@@ -0,0 +1,78 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+mod common;
+
+use std::fs;
+
+use common::ont_file;
+use rdfoothills_conversion::{self as conversion, ConversionOptions, Error};
+use rdfoothills_mime as mime;
+
+const TURTLE: &str = "<http://example.org/s> <http://example.org/p> \"o\" .\n";
+
+#[test]
+fn test_skips_unusable_sources() {
+    let dir = tempfile::tempdir().unwrap();
+    let sources = [
+        ont_file(dir.path(), "ont", mime::Type::Html),
+        ont_file(dir.path(), "ont", mime::Type::TurtleStar),
+        ont_file(dir.path(), "ont", mime::Type::Turtle),
+    ];
+    fs::write(&sources[2].file, TURTLE).unwrap();
+    let to = ont_file(dir.path(), "ont", mime::Type::NTriples);
+    let (from, _info) =
+        conversion::convert_from_best(&sources, &to, &ConversionOptions::default()).unwrap();
+    assert_eq!(from.mime_type, mime::Type::Turtle);
+    assert_eq!(fs::read_to_string(&to.file).unwrap(), TURTLE);
+}
+
+#[test]
+fn test_no_conversion_required() {
+    let dir = tempfile::tempdir().unwrap();
+    let sources = [
+        ont_file(dir.path(), "ont", mime::Type::Turtle),
+        ont_file(dir.path(), "ont", mime::Type::NTriples),
+    ];
+    let to = ont_file(dir.path(), "ont", mime::Type::NTriples);
+    assert!(matches!(
+        conversion::select_source(&sources, &to, &ConversionOptions::default()),
+        Err(Error::NoConversionRequired)
+    ));
+}
+
+#[test]
+fn test_no_usable_source() {
+    let dir = tempfile::tempdir().unwrap();
+    let to = ont_file(dir.path(), "ont", mime::Type::NTriples);
+    assert!(matches!(
+        conversion::select_source(&[], &to, &ConversionOptions::default()),
+        Err(Error::NoSources)
+    ));
+    let sources = [ont_file(dir.path(), "ont", mime::Type::Html)];
+    assert!(matches!(
+        conversion::select_source(&sources, &to, &ConversionOptions::default()),
+        Err(Error::NonMachineReadableSource {
+            from: mime::Type::Html
+        })
+    ));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_convert_from_best_async() {
+    let dir = tempfile::tempdir().unwrap();
+    let sources = [ont_file(dir.path(), "ont", mime::Type::Turtle)];
+    fs::write(&sources[0].file, TURTLE).unwrap();
+    let to = ont_file(dir.path(), "ont", mime::Type::NTriples);
+    let (from, _info) =
+        conversion::convert_from_best_async(&sources, &to, &ConversionOptions::default())
+            .await
+            .unwrap();
+    assert_eq!(from.mime_type, mime::Type::Turtle);
+    assert_eq!(fs::read_to_string(&to.file).unwrap(), TURTLE);
+}
@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+use std::fs;
+
+use rdfoothills_conversion::{self as conversion, OntFile};
+use rdfoothills_mime as mime;
+
+const TARGETS: &[mime::Type] = &[
+    mime::Type::NTriples,
+    mime::Type::Turtle,
+    mime::Type::RdfXml,
+    mime::Type::NTriples,
+    mime::Type::NQuads,
+];
+
+fn create_source(dir: &tempfile::TempDir) -> OntFile {
+    let file = dir.path().join("ont.ttl");
+    fs::write(
+        &file,
+        "<http://example.org/s> <http://example.org/p> \"o\" .\n",
+    )
+    .unwrap();
+    OntFile {
+        file,
+        mime_type: mime::Type::Turtle,
+    }
+}
+
+fn check_results(
+    dir: &tempfile::TempDir,
+    results: &[(mime::Type, Result<conversion::Info, conversion::Error>)],
+) {
+    assert_eq!(
+        results.iter().map(|(typ, _)| *typ).collect::<Vec<_>>(),
+        [mime::Type::NTriples, mime::Type::RdfXml, mime::Type::NQuads]
+    );
+    for (typ, res) in results {
+        assert!(res.is_ok());
+        assert!(dir.path().join(format!("ont.{}", typ.file_ext())).is_file());
+    }
+}
+
+#[test]
+fn test_convert_to_many() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = create_source(&dir);
+    let results = conversion::convert_to_many(&from, TARGETS, 2);
+    check_results(&dir, &results);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_convert_to_many_async() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = create_source(&dir);
+    let results = conversion::convert_to_many_async(&from, TARGETS, 2).await;
+    check_results(&dir, &results);
+}
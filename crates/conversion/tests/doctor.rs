@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_conversion::{self as conversion, doctor};
+
+#[test]
+fn test_diagnose_all() {
+    let diagnoses = doctor::diagnose();
+    assert_eq!(diagnoses.len(), conversion::converters().count());
+    for diagnosis in &diagnoses {
+        assert_eq!(diagnosis.self_test.is_some(), diagnosis.available);
+        assert!(diagnosis.to_string().starts_with(diagnosis.info.name));
+    }
+}
+
+#[cfg(feature = "oxrdfio")]
+#[test]
+fn test_diagnose_native() {
+    use conversion::doctor::SelfTest;
+    use conversion::Type;
+    use rdfoothills_mime as mime;
+
+    let native = conversion::converters()
+        .find(|converter| converter.info().typ == Type::Native)
+        .unwrap();
+    let diagnosis = doctor::diagnose_converter(native);
+    assert!(diagnosis.is_healthy());
+    assert_eq!(
+        diagnosis.self_test,
+        Some(SelfTest::Passed {
+            target: mime::Type::NTriples,
+            round_trip: true,
+        })
+    );
+}
+
+#[cfg(feature = "oxrdfio")]
+#[test]
+fn test_benchmark_native() {
+    use conversion::Type;
+
+    let benchmarks = doctor::benchmark(&[1, 100], 2);
+    let native = benchmarks
+        .iter()
+        .filter(|benchmark| benchmark.info.typ == Type::Native)
+        .collect::<Vec<_>>();
+    assert!(!native.is_empty());
+    for benchmark in native {
+        assert!(benchmark.result.is_ok(), "{benchmark}");
+    }
+}
+
+#[test]
+fn test_synthetic_ontology() {
+    let turtle = doctor::synthetic_ontology(3);
+    assert!(turtle.contains("ex:Class2 a owl:Class ;"));
+    assert!(!turtle.contains("ex:Class3 a"));
+}
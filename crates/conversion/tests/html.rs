@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+use std::fs;
+
+use rdfoothills_conversion::{self as conversion, doctor, Converter, OntFile, Priority};
+use rdfoothills_mime as mime;
+
+const TURTLE: &str = r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix ex: <http://example.org/ont#> .
+
+ex: a owl:Ontology ;
+    rdfs:label "Example <ontology>"@en ;
+    rdfs:comment "An ontology for testing."@en .
+
+ex:Animal a owl:Class ;
+    rdfs:label "Tier"@de , "Animal"@en ;
+    rdfs:comment "A living being."@en .
+
+ex:eats a owl:ObjectProperty , rdf:Property ;
+    rdfs:label "eats"@en .
+
+ex:name a owl:DatatypeProperty .
+"#;
+
+fn native_html() -> &'static dyn Converter {
+    conversion::converters()
+        .find(|converter| converter.info().priority == Priority::Fallback)
+        .unwrap()
+}
+
+#[test]
+fn test_native_html_render() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = OntFile {
+        file: dir.path().join("ont.ttl"),
+        mime_type: mime::Type::Turtle,
+    };
+    let to = OntFile {
+        file: dir.path().join("ont.html"),
+        mime_type: mime::Type::Html,
+    };
+    fs::write(
+        &from.file,
+        TURTLE.replace("rdf:Property", "owl:AnnotationProperty"),
+    )
+    .unwrap();
+    native_html().convert(&from, &to).unwrap();
+    let html = fs::read_to_string(&to.file).unwrap();
+    assert!(html.contains("<title>Example &lt;ontology&gt;</title>"));
+    assert!(html.contains("<p>An ontology for testing.</p>"));
+    assert!(html.contains(
+        "<tr id=\"Animal\"><td><code>http://example.org/ont#Animal</code></td>\
+         <td>Animal</td><td>A living being.</td></tr>"
+    ));
+    // Listed once, with the first matching type.
+    assert_eq!(html.matches("<tr id=\"eats\">").count(), 1);
+    assert!(html.contains("<td>Object property</td><td>eats</td>"));
+    // Falls back to the local name as label.
+    assert!(html.contains("<td>Datatype property</td><td>name</td><td></td>"));
+}
+
+#[test]
+fn test_native_html_self_test() {
+    let converter = native_html();
+    assert!(converter.supports(mime::Type::RdfXml, mime::Type::Html));
+    assert!(!converter.supports(mime::Type::Turtle, mime::Type::NTriples));
+    assert_eq!(
+        doctor::diagnose_converter(converter).self_test,
+        Some(doctor::SelfTest::Passed {
+            target: mime::Type::Html,
+            round_trip: false,
+        })
+    );
+}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+mod common;
+
+use common::ont_file_with;
+use rdfoothills_conversion::{self as conversion, Error};
+use rdfoothills_mime as mime;
+
+const TURTLE: &str = "@prefix ex: <http://example.org/> .
+ex:a ex:knows [ ex:name \"b\" ; ex:knows [ ex:name \"c\" ] ] .
+";
+
+/// The same graph, with other blank node labels and in another order.
+const N_TRIPLES: &str = "_:y <http://example.org/name> \"c\" .
+_:x <http://example.org/knows> _:y .
+<http://example.org/a> <http://example.org/knows> _:x .
+_:x <http://example.org/name> \"b\" .
+";
+
+/// The blank nodes swapped, which makes for another graph.
+const N_TRIPLES_SWAPPED: &str = "_:y <http://example.org/name> \"b\" .
+_:x <http://example.org/knows> _:y .
+<http://example.org/a> <http://example.org/knows> _:x .
+_:x <http://example.org/name> \"c\" .
+";
+
+/// The same graph, split over a named and the default graph.
+const TRIG: &str = "@prefix ex: <http://example.org/> .
+ex:g { ex:a ex:knows _:x . _:x ex:name \"b\" . }
+_:x ex:knows [ ex:name \"c\" ] .
+";
+
+#[test]
+fn test_isomorphic_across_formats() {
+    let dir = tempfile::tempdir().unwrap();
+    let turtle = ont_file_with(dir.path(), "one", mime::Type::Turtle, TURTLE);
+    let n_triples = ont_file_with(dir.path(), "other", mime::Type::NTriples, N_TRIPLES);
+    assert!(conversion::isomorphic(&turtle, &n_triples).unwrap());
+    assert!(conversion::isomorphic(&n_triples, &turtle).unwrap());
+}
+
+#[test]
+fn test_not_isomorphic() {
+    let dir = tempfile::tempdir().unwrap();
+    let turtle = ont_file_with(dir.path(), "one", mime::Type::Turtle, TURTLE);
+    let swapped = ont_file_with(dir.path(), "other", mime::Type::NTriples, N_TRIPLES_SWAPPED);
+    assert!(!conversion::isomorphic(&turtle, &swapped).unwrap());
+    let fewer = ont_file_with(
+        dir.path(),
+        "fewer",
+        mime::Type::NTriples,
+        N_TRIPLES
+            .lines()
+            .skip(1)
+            .collect::<Vec<_>>()
+            .join("\n")
+            .as_str(),
+    );
+    assert!(!conversion::isomorphic(&turtle, &fewer).unwrap());
+}
+
+#[test]
+fn test_named_graphs_merged() {
+    let dir = tempfile::tempdir().unwrap();
+    let trig = ont_file_with(dir.path(), "one", mime::Type::TriG, TRIG);
+    let n_triples = ont_file_with(dir.path(), "other", mime::Type::NTriples, N_TRIPLES);
+    assert!(conversion::isomorphic(&trig, &n_triples).unwrap());
+}
+
+#[test]
+fn test_not_natively_supported() {
+    let dir = tempfile::tempdir().unwrap();
+    let turtle = ont_file_with(dir.path(), "one", mime::Type::Turtle, TURTLE);
+    let json_ld = ont_file_with(dir.path(), "other", mime::Type::JsonLd, "");
+    assert!(matches!(
+        conversion::isomorphic(&turtle, &json_ld),
+        Err(Error::NoNativeSupport(mime::Type::JsonLd))
+    ));
+}
@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_conversion::jsonld::{self, Context, Form, Options};
+use rdfoothills_conversion::Error;
+use serde_json::{json, Value};
+
+/// Expanded JSON-LD, as written by the converters.
+const EXPANDED: &str = r#"[
+  {
+    "@id": "http://example.org/alice",
+    "@type": ["http://schema.org/Person"],
+    "http://schema.org/name": [{"@value": "Alice"}],
+    "http://schema.org/knows": [{"@id": "_:n1"}]
+  },
+  {
+    "@id": "_:n1",
+    "@type": ["http://schema.org/Person"],
+    "http://schema.org/name": [{"@value": "Bob"}]
+  },
+  {
+    "@id": "http://example.org/doc",
+    "@type": ["http://schema.org/CreativeWork"],
+    "http://purl.org/dc/terms/creator": [{"@id": "http://example.org/alice"}]
+  }
+]"#;
+
+const CONTEXT: &str = r#"{
+  "@context": {
+    "@vocab": "http://schema.org/",
+    "dct": "http://purl.org/dc/terms/",
+    "ex": "http://example.org/"
+  }
+}"#;
+
+fn apply(context: Option<Context>, form: Form) -> Value {
+    let output = jsonld::apply(EXPANDED, &Options { context, form }).unwrap();
+    serde_json::from_str(&output).unwrap()
+}
+
+/// The value at a JSON pointer, or `null`.
+fn at<'doc>(document: &'doc Value, pointer: &str) -> &'doc Value {
+    document.pointer(pointer).unwrap_or(&Value::Null)
+}
+
+#[test]
+fn test_expanded_as_written() {
+    let options = Options {
+        context: Some(Context::Inline(CONTEXT.to_owned())),
+        form: Form::Expanded,
+    };
+    assert!(options.is_as_written());
+    assert_eq!(jsonld::apply(EXPANDED, &options).unwrap(), EXPANDED);
+}
+
+#[test]
+fn test_compacted() {
+    let document = apply(Some(Context::Inline(CONTEXT.to_owned())), Form::Compacted);
+    assert_eq!(
+        at(&document, "/@context/ex"),
+        "http://example.org/",
+        "{document:#}"
+    );
+    assert_eq!(
+        at(&document, "/@graph").as_array().map(Vec::len),
+        Some(3),
+        "{document:#}"
+    );
+    assert_eq!(
+        at(&document, "/@graph/0"),
+        &json!({
+            "@id": "ex:alice",
+            "@type": "Person",
+            "name": "Alice",
+            "knows": {"@id": "_:n1"}
+        }),
+        "{document:#}"
+    );
+    assert_eq!(
+        at(&document, "/@graph/2/dct:creator"),
+        &json!({"@id": "ex:alice"}),
+        "{document:#}"
+    );
+}
+
+#[test]
+fn test_flattened() {
+    let document = apply(None, Form::Flattened);
+    let nodes = document.as_array().unwrap();
+    assert_eq!(nodes.len(), 3, "{document:#}");
+    assert!(
+        nodes.iter().all(|node| at(node, "/@id").is_string()),
+        "{document:#}"
+    );
+    assert!(
+        nodes.iter().any(|node| at(node, "/@id") == "_:b0"
+            && at(node, "/http:~1~1schema.org~1name/0/@value") == "Bob"),
+        "{document:#}"
+    );
+}
+
+#[test]
+fn test_framed_by_type() {
+    let frame = r#"{
+  "@context": {"@vocab": "http://schema.org/"},
+  "@type": "CreativeWork"
+}"#;
+    let document = apply(None, Form::Framed(frame.to_owned()));
+    assert_eq!(
+        at(&document, "/@context"),
+        &json!({"@vocab": "http://schema.org/"}),
+        "{document:#}"
+    );
+    assert_eq!(at(&document, "/@type"), "CreativeWork", "{document:#}");
+    let creator = at(&document, "/http:~1~1purl.org~1dc~1terms~1creator");
+    assert_eq!(at(creator, "/name"), "Alice", "{document:#}");
+    assert_eq!(at(creator, "/knows/name"), "Bob", "{document:#}");
+}
+
+#[test]
+fn test_unknown_remote_context() {
+    let document = apply(
+        Some(Context::Url {
+            url: "https://schema.org/".to_owned(),
+            document: None,
+        }),
+        Form::Compacted,
+    );
+    assert_eq!(at(&document, "/@context"), "https://schema.org/");
+    let alice = at(&document, "/@graph/0");
+    // Nothing is known about the context, so all has to stay as it was.
+    assert_eq!(
+        at(alice, "/http:~1~1schema.org~1name"),
+        &json!({"@value": "Alice"}),
+        "{document:#}"
+    );
+    assert_eq!(
+        at(alice, "/@id"),
+        "http://example.org/alice",
+        "{document:#}"
+    );
+}
+
+#[test]
+fn test_remote_context_with_document() {
+    let document = apply(
+        Some(Context::Url {
+            url: "https://example.org/context.jsonld".to_owned(),
+            document: Some(CONTEXT.to_owned()),
+        }),
+        Form::Compacted,
+    );
+    assert_eq!(
+        at(&document, "/@context"),
+        "https://example.org/context.jsonld"
+    );
+    assert_eq!(at(&document, "/@graph/1/name"), "Bob", "{document:#}");
+}
+
+#[test]
+fn test_invalid_input() {
+    let compacted = |context: &str| Options {
+        context: Some(Context::Inline(context.to_owned())),
+        form: Form::Compacted,
+    };
+    assert!(matches!(
+        jsonld::apply(EXPANDED, &compacted("{")),
+        Err(Error::JsonLd(_))
+    ));
+    assert!(matches!(
+        jsonld::apply(EXPANDED, &compacted(r#"["https://schema.org/"]"#)),
+        Err(Error::JsonLd(_))
+    ));
+    assert!(matches!(
+        jsonld::apply(
+            EXPANDED,
+            &Options {
+                context: None,
+                form: Form::Framed("[]".to_owned()),
+            }
+        ),
+        Err(Error::JsonLd(_))
+    ));
+    assert!(matches!(
+        jsonld::apply(r#"{"@context": {}}"#, &compacted(CONTEXT)),
+        Err(Error::JsonLd(_))
+    ));
+}
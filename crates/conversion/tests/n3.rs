@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+mod common;
+
+use std::fs;
+
+use common::{ont_file, ont_file_with};
+use rdfoothills_conversion::{
+    self as conversion, ConversionOptions, Error, N3FormulaPolicy, OntFile,
+};
+use rdfoothills_mime as mime;
+
+const N3_RULES: &str = "@prefix : <http://example.org/> .
+:a a :A .
+:a :label \"a\" .
+{ ?x a :A } => { ?x a :B } .
+";
+
+const N3_PLAIN: &str = "@prefix : <http://example.org/> .
+:a a :A .
+";
+
+#[test]
+fn test_n3_formulae_refused_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = ont_file_with(dir.path(), "ont", mime::Type::N3, N3_RULES);
+    let to = ont_file(dir.path(), "ont", mime::Type::NTriples);
+    assert!(matches!(
+        conversion::convert(&from, &to),
+        Err(Error::N3Formulae {
+            statements: 3,
+            to: mime::Type::NTriples
+        })
+    ));
+    assert!(!to.file.exists());
+}
+
+#[test]
+fn test_n3_formulae_stripped() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = ont_file_with(dir.path(), "ont", mime::Type::N3, N3_RULES);
+    let to = ont_file(dir.path(), "ont", mime::Type::NTriples);
+    let options = ConversionOptions {
+        n3_formulae: N3FormulaPolicy::Strip,
+        ..ConversionOptions::default()
+    };
+    conversion::convert_with(&from, &to, &options).unwrap();
+    let converted = fs::read_to_string(&to.file).unwrap();
+    assert_eq!(converted.lines().count(), 2, "{converted}");
+    assert!(converted.contains("<http://example.org/a> <http://example.org/label> \"a\""));
+    assert!(!converted.contains("http://example.org/B"));
+}
+
+#[test]
+fn test_n3_without_formulae_converted() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = ont_file_with(dir.path(), "ont", mime::Type::N3, N3_PLAIN);
+    let to = ont_file(dir.path(), "ont", mime::Type::NTriples);
+    conversion::convert(&from, &to).unwrap();
+    assert_eq!(fs::read_to_string(&to.file).unwrap().lines().count(), 1);
+}
+
+#[test]
+fn test_n3_formulae_preserved_in_n3() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = ont_file_with(dir.path(), "ont", mime::Type::N3, N3_RULES);
+    let to = OntFile {
+        file: dir.path().join("copy.n3"),
+        mime_type: mime::Type::N3,
+    };
+    assert!(matches!(
+        conversion::convert(&from, &to),
+        Err(Error::NoConversionRequired)
+    ));
+}
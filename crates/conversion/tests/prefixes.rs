@@ -0,0 +1,119 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+use std::fs;
+use std::path::Path;
+
+use rdfoothills_conversion::{self as conversion, Converter, OntFile, Quality};
+use rdfoothills_mime as mime;
+
+const TURTLE: &str = "@prefix ex: <http://example.org/> .
+@prefix : <http://example.com/> .
+ex:s ex:p :o .
+";
+
+fn native() -> &'static dyn Converter {
+    conversion::converters()
+        .find(|converter| converter.info().name == "OxRDF I/O")
+        .unwrap()
+}
+
+fn convert(from_type: mime::Type, content: &str, to_type: mime::Type, dir: &Path) -> String {
+    let from = OntFile {
+        file: dir.join(format!("from.{}", from_type.file_ext())),
+        mime_type: from_type,
+    };
+    let to = OntFile {
+        file: dir.join(format!("to.{}", to_type.file_ext())),
+        mime_type: to_type,
+    };
+    fs::write(&from.file, content).unwrap();
+    native().convert(&from, &to).unwrap();
+    fs::read_to_string(&to.file).unwrap()
+}
+
+#[test]
+fn test_native_quality() {
+    assert_eq!(native().info().quality, Quality::Prefixes);
+}
+
+#[test]
+fn test_prefixes_kept_in_trig() {
+    let dir = tempfile::tempdir().unwrap();
+    let trig = convert(mime::Type::Turtle, TURTLE, mime::Type::TriG, dir.path());
+    assert!(
+        trig.contains("@prefix ex: <http://example.org/> ."),
+        "{trig}"
+    );
+    assert!(trig.contains("@prefix : <http://example.com/> ."), "{trig}");
+    assert!(trig.contains("ex:s ex:p :o"), "{trig}");
+}
+
+#[test]
+fn test_prefixes_kept_in_rdf_xml() {
+    let dir = tempfile::tempdir().unwrap();
+    let rdf_xml = convert(mime::Type::Turtle, TURTLE, mime::Type::RdfXml, dir.path());
+    assert!(
+        rdf_xml.contains("xmlns:ex=\"http://example.org/\""),
+        "{rdf_xml}"
+    );
+    // There is no empty prefix in RDF/XML.
+    assert!(!rdf_xml.contains("xmlns:=\""), "{rdf_xml}");
+    let turtle = convert(mime::Type::RdfXml, &rdf_xml, mime::Type::Turtle, dir.path());
+    assert!(
+        turtle.contains("@prefix ex: <http://example.org/> ."),
+        "{turtle}"
+    );
+}
+
+#[test]
+fn test_no_prefixes_in_n_triples() {
+    let dir = tempfile::tempdir().unwrap();
+    let n_triples = convert(mime::Type::Turtle, TURTLE, mime::Type::NTriples, dir.path());
+    assert_eq!(
+        n_triples.trim(),
+        "<http://example.org/s> <http://example.org/p> <http://example.com/o> ."
+    );
+}
+
+#[test]
+fn test_prefixes_declared_late() {
+    let dir = tempfile::tempdir().unwrap();
+    let turtle = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .
+@prefix ex: <http://example.org/> .
+ex:t ex:p ex:o .
+";
+    let trig = convert(mime::Type::Turtle, turtle, mime::Type::TriG, dir.path());
+    assert!(
+        trig.contains("@prefix ex: <http://example.org/> ."),
+        "{trig}"
+    );
+    assert!(trig.contains("ex:s ex:p ex:o"), "{trig}");
+    assert!(trig.contains("ex:t ex:p ex:o"), "{trig}");
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_prefixes_kept_async() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = OntFile {
+        file: dir.path().join("from.ttl"),
+        mime_type: mime::Type::Turtle,
+    };
+    let to = OntFile {
+        file: dir.path().join("to.trig"),
+        mime_type: mime::Type::TriG,
+    };
+    fs::write(&from.file, TURTLE).unwrap();
+    native().convert_async(&from, &to).await.unwrap();
+    let trig = fs::read_to_string(&to.file).unwrap();
+    assert!(
+        trig.contains("@prefix ex: <http://example.org/> ."),
+        "{trig}"
+    );
+    assert!(trig.contains("ex:s ex:p :o"), "{trig}");
+}
@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use std::thread;
+use std::time::Duration;
+
+use rdfoothills_conversion::process_limit::{self, Limit, Saturated, Status};
+
+// The limit is process-wide, so this is all in one test.
+#[test]
+fn test_limit() {
+    let limit = Limit {
+        max_running: 1,
+        max_queued: 1,
+    };
+    process_limit::set_limit(Some(limit));
+
+    let first = process_limit::acquire().unwrap();
+    let queued = thread::spawn(|| {
+        let _permit = process_limit::acquire().unwrap();
+    });
+    while process_limit::status().queued == 0 {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(
+        process_limit::status(),
+        Status {
+            running: 1,
+            queued: 1
+        }
+    );
+    assert_eq!(process_limit::acquire().unwrap_err(), Saturated(limit));
+
+    drop(first);
+    queued.join().unwrap();
+    assert_eq!(process_limit::status(), Status::default());
+
+    process_limit::set_limit(None);
+    let _permits = [
+        process_limit::acquire().unwrap(),
+        process_limit::acquire().unwrap(),
+        process_limit::acquire().unwrap(),
+    ];
+    assert_eq!(process_limit::status().running, 3);
+}
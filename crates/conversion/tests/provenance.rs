@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+use std::time::{Duration, SystemTime};
+
+use oxrdf::vocab::rdf;
+use oxrdf::{Graph, LiteralRef, NamedNode, NamedNodeRef, SubjectRef, TermRef, TripleRef};
+use rdfoothills_conversion::provenance::{Provenance, Tool};
+use rdfoothills_vocab::{dcterms, owl, prov};
+
+const ARTIFACT: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("http://localhost:3000/cache/ontology.ttl");
+const SOURCE: NamedNodeRef<'static> = NamedNodeRef::new_unchecked("http://example.org/ontology");
+
+fn single_object<'a>(
+    graph: &'a Graph,
+    subject: impl Into<SubjectRef<'a>>,
+    predicate: NamedNodeRef<'a>,
+) -> TermRef<'a> {
+    let objects = graph
+        .objects_for_subject_predicate(subject, predicate)
+        .collect::<Vec<_>>();
+    let [object] = objects.as_slice() else {
+        panic!("Expected exactly one object of {predicate}, got {objects:?}");
+    };
+    *object
+}
+
+#[test]
+fn test_full() {
+    let mut provenance = Provenance::new(ARTIFACT.into_owned(), SOURCE.into_owned());
+    provenance.generated_at = Some(SystemTime::UNIX_EPOCH + Duration::from_secs(951_827_696));
+    provenance.tool = Some(Tool {
+        name: "rdfx".to_owned(),
+        version: Some("0.2.1".to_owned()),
+    });
+    provenance.input_sha256 = Some("ab12".to_owned());
+    let graph = provenance.to_triples().iter().collect::<Graph>();
+
+    assert!(graph.contains(TripleRef::new(ARTIFACT, rdf::TYPE, prov::ENTITY)));
+    assert!(graph.contains(TripleRef::new(ARTIFACT, prov::WAS_DERIVED_FROM, SOURCE)));
+    assert_eq!(
+        single_object(&graph, ARTIFACT, prov::GENERATED_AT_TIME),
+        LiteralRef::new_typed_literal("2000-02-29T12:34:56Z", oxrdf::vocab::xsd::DATE_TIME).into()
+    );
+    assert_eq!(
+        single_object(&graph, SOURCE, dcterms::IDENTIFIER),
+        LiteralRef::new_simple_literal("sha256:ab12").into()
+    );
+
+    let TermRef::BlankNode(activity) = single_object(&graph, ARTIFACT, prov::WAS_GENERATED_BY)
+    else {
+        panic!("The activity should be a blank node");
+    };
+    assert!(graph.contains(TripleRef::new(activity, rdf::TYPE, prov::ACTIVITY)));
+    assert!(graph.contains(TripleRef::new(activity, prov::USED, SOURCE)));
+    let TermRef::BlankNode(agent) = single_object(&graph, activity, prov::WAS_ASSOCIATED_WITH)
+    else {
+        panic!("The agent should be a blank node");
+    };
+    assert!(graph.contains(TripleRef::new(agent, rdf::TYPE, prov::SOFTWARE_AGENT)));
+    assert_eq!(
+        single_object(&graph, agent, owl::VERSION_INFO),
+        LiteralRef::new_simple_literal("0.2.1").into()
+    );
+}
+
+#[test]
+fn test_minimal() {
+    let source = NamedNode::from(SOURCE);
+    let graph = Provenance::new(source.clone(), source)
+        .to_triples()
+        .iter()
+        .collect::<Graph>();
+    assert!(graph.contains(TripleRef::new(SOURCE, rdf::TYPE, prov::ENTITY)));
+    assert!(!graph.contains(TripleRef::new(SOURCE, prov::WAS_DERIVED_FROM, SOURCE)));
+    assert_eq!(
+        graph
+            .objects_for_subject_predicate(SOURCE, prov::GENERATED_AT_TIME)
+            .count(),
+        0
+    );
+    assert_eq!(
+        graph
+            .triples_for_predicate(prov::WAS_ASSOCIATED_WITH)
+            .count(),
+        0
+    );
+}
@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+mod common;
+
+use common::ont_file;
+use rdfoothills_conversion::{self as conversion, ConversionOptions, Quality};
+use rdfoothills_mime as mime;
+
+#[test]
+fn test_quality_satisfied() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = ont_file(dir.path(), "ont", mime::Type::Turtle);
+    let to = ont_file(dir.path(), "ont", mime::Type::NTriples);
+    let options = ConversionOptions {
+        min_quality: Some(Quality::Data),
+        ..ConversionOptions::default()
+    };
+    let converter = conversion::select_converter_with(&from, &to, &options).unwrap();
+    assert!(converter.info().quality <= Quality::Data);
+}
+
+#[test]
+fn test_quality_fallback() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = ont_file(dir.path(), "ont", mime::Type::Turtle);
+    let to = ont_file(dir.path(), "ont", mime::Type::NTriples);
+    let options = ConversionOptions {
+        min_quality: Some(Quality::PreservesComments),
+        ..ConversionOptions::default()
+    };
+    let fallback = conversion::select_converter_with(&from, &to, &options).unwrap();
+    let best = conversion::select_converter(&from, &to).unwrap();
+    if fallback.info().quality > Quality::PreservesComments {
+        // Nothing qualified, so the best of the others was chosen.
+        assert_eq!(fallback.info(), best.info());
+    }
+}
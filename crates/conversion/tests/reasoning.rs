@@ -0,0 +1,128 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+use std::fs;
+
+use rdfoothills_conversion::reasoning::Entailment;
+use rdfoothills_conversion::{self as conversion, Error, OntFile};
+use rdfoothills_mime as mime;
+
+const TURTLE: &str = r"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix ex: <http://example.org/> .
+
+ex:Dog rdfs:subClassOf ex:Mammal .
+ex:Mammal rdfs:subClassOf ex:Animal .
+ex:owns rdfs:domain ex:Person ;
+    rdfs:range ex:Animal ;
+    owl:inverseOf ex:ownedBy .
+ex:bob ex:owns ex:rex .
+ex:rex a ex:Dog .
+";
+
+fn ont_files(dir: &tempfile::TempDir) -> (OntFile, OntFile) {
+    let from = OntFile {
+        file: dir.path().join("ont.ttl"),
+        mime_type: mime::Type::Turtle,
+    };
+    let to = OntFile {
+        file: dir.path().join("ont.nt"),
+        mime_type: mime::Type::NTriples,
+    };
+    fs::write(&from.file, TURTLE).unwrap();
+    (from, to)
+}
+
+fn has(ntriples: &str, subject: &str, predicate: &str, object: &str) -> bool {
+    ntriples.contains(&format!("<{subject}> <{predicate}> <{object}> .\n"))
+}
+
+const TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const SUB_CLASS_OF: &str = "http://www.w3.org/2000/01/rdf-schema#subClassOf";
+
+#[test]
+fn test_reason_rdfs() {
+    let dir = tempfile::tempdir().unwrap();
+    let (from, to) = ont_files(&dir);
+    let inferred = conversion::reason(&from, &to, Entailment::Rdfs).unwrap();
+    let out = fs::read_to_string(&to.file).unwrap();
+    assert_eq!(out.lines().count(), 7 + inferred);
+    assert!(has(
+        &out,
+        "http://example.org/Dog",
+        SUB_CLASS_OF,
+        "http://example.org/Animal"
+    ));
+    assert!(has(
+        &out,
+        "http://example.org/rex",
+        TYPE,
+        "http://example.org/Animal"
+    ));
+    assert!(has(
+        &out,
+        "http://example.org/rex",
+        TYPE,
+        "http://example.org/Mammal"
+    ));
+    assert!(has(
+        &out,
+        "http://example.org/bob",
+        TYPE,
+        "http://example.org/Person"
+    ));
+    assert!(!has(
+        &out,
+        "http://example.org/rex",
+        "http://example.org/ownedBy",
+        "http://example.org/bob"
+    ));
+}
+
+#[test]
+fn test_reason_owlrl() {
+    let dir = tempfile::tempdir().unwrap();
+    let (from, to) = ont_files(&dir);
+    conversion::reason(&from, &to, Entailment::OwlRl).unwrap();
+    let out = fs::read_to_string(&to.file).unwrap();
+    assert!(has(
+        &out,
+        "http://example.org/rex",
+        TYPE,
+        "http://example.org/Animal"
+    ));
+    assert!(has(
+        &out,
+        "http://example.org/rex",
+        "http://example.org/ownedBy",
+        "http://example.org/bob"
+    ));
+}
+
+#[test]
+fn test_reason_unsupported_format() {
+    let dir = tempfile::tempdir().unwrap();
+    let (from, mut to) = ont_files(&dir);
+    to.mime_type = mime::Type::Html;
+    assert!(matches!(
+        conversion::reason(&from, &to, Entailment::Rdfs),
+        Err(Error::NoNativeSupport(mime::Type::Html))
+    ));
+    assert!(!to.file.exists());
+}
+
+#[test]
+fn test_entailment_from_str() {
+    for entailment in [Entailment::Rdfs, Entailment::OwlRl] {
+        assert_eq!(
+            entailment.to_string().parse::<Entailment>().unwrap(),
+            entailment
+        );
+    }
+    assert_eq!("OWL-RL".parse::<Entailment>().unwrap(), Entailment::OwlRl);
+    assert!("owldl".parse::<Entailment>().is_err());
+}
@@ -0,0 +1,163 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Converts each of the fixture ontologies in `tests/fixtures`
+//! with each available converter between each pair of formats it supports,
+//! and checks that the result is isomorphic to the fixture.
+//!
+//! Converters whose tool is not installed are skipped.
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rdfoothills_conversion::{self as conversion, Converter, OntFile};
+use rdfoothills_mime as mime;
+
+/// The formats converted from and to.
+/// The fixtures are in Turtle;
+/// they get converted into the other formats first,
+/// to be converted from there.
+const FORMATS: &[mime::Type] = &[
+    mime::Type::JsonLd,
+    mime::Type::N3,
+    mime::Type::NQuads,
+    mime::Type::NQuadsStar,
+    mime::Type::NTriples,
+    mime::Type::NTriplesStar,
+    mime::Type::OwlXml,
+    mime::Type::RdfXml,
+    mime::Type::TriG,
+    mime::Type::TriGStar,
+    mime::Type::Turtle,
+    mime::Type::TurtleStar,
+];
+
+/// Conversions that are known to lose or alter information,
+/// by converter name and target format, with the reason;
+/// see the docs of the converters.
+/// These are not checked.
+const KNOWN_LOSSES: &[(&str, mime::Type, &str)] = &[];
+
+fn fixtures() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut fixtures = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ttl"))
+        .collect::<Vec<_>>();
+    fixtures.sort();
+    fixtures
+}
+
+/// Converts `from` into `target` with the converter,
+/// if necessary converting the result back to Turtle,
+/// and returns the file to check.
+/// Returns `None` if the result can not be checked.
+fn round_trip(
+    converter: &dyn Converter,
+    from: &OntFile,
+    target: mime::Type,
+    dir: &Path,
+) -> Option<Result<OntFile, String>> {
+    let to = OntFile {
+        file: dir.join(format!("converted.{}", target.file_ext())),
+        mime_type: target,
+    };
+    if let Err(err) = converter.convert(from, &to) {
+        return Some(Err(err.to_string()));
+    }
+    if target.to_rdf_format().is_some() {
+        return Some(Ok(to));
+    }
+    if !converter.supports(target, mime::Type::Turtle) {
+        return None;
+    }
+    let back = OntFile {
+        file: dir.join("back.ttl"),
+        mime_type: mime::Type::Turtle,
+    };
+    Some(
+        converter
+            .convert(&to, &back)
+            .map(|()| back)
+            .map_err(|err| err.to_string()),
+    )
+}
+
+/// Converts the fixture into all the formats the converter supports.
+fn sources(converter: &dyn Converter, fixture: &Path, dir: &Path) -> Vec<OntFile> {
+    let turtle = OntFile {
+        file: fixture.to_owned(),
+        mime_type: mime::Type::Turtle,
+    };
+    let mut sources = FORMATS
+        .iter()
+        .filter(|&&typ| typ != mime::Type::Turtle && converter.supports(mime::Type::Turtle, typ))
+        .filter_map(|&typ| {
+            let source = OntFile {
+                file: dir.join(format!("source.{}", typ.file_ext())),
+                mime_type: typ,
+            };
+            // A failure here is reported as a failure of the Turtle source.
+            converter.convert(&turtle, &source).ok().map(|()| source)
+        })
+        .collect::<Vec<_>>();
+    sources.push(turtle);
+    sources
+}
+
+#[test]
+fn test_round_trips() {
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for fixture in fixtures() {
+        let expected = OntFile {
+            file: fixture.clone(),
+            mime_type: mime::Type::Turtle,
+        };
+        for converter in conversion::converters().filter(|converter| converter.is_available()) {
+            let name = converter.info().name;
+            let sources_dir = tempfile::tempdir().unwrap();
+            for from in sources(converter, &fixture, sources_dir.path()) {
+                for &target in FORMATS {
+                    if target == from.mime_type
+                        || !converter.supports(from.mime_type, target)
+                        || KNOWN_LOSSES
+                            .iter()
+                            .any(|&(lossy, typ, _)| lossy == name && typ == target)
+                    {
+                        continue;
+                    }
+                    let dir = tempfile::tempdir().unwrap();
+                    let case = format!(
+                        "{} ({}) -> {target} with {name}",
+                        fixture.display(),
+                        from.mime_type
+                    );
+                    let Some(checked_res) = round_trip(converter, &from, target, dir.path()) else {
+                        continue;
+                    };
+                    match checked_res.and_then(|result| {
+                        conversion::isomorphic(&expected, &result)
+                            .map(|same| (same, result))
+                            .map_err(|err| err.to_string())
+                    }) {
+                        Ok((true, _)) => {}
+                        Ok((false, result)) => failures.push(format!(
+                            "{case}: the graphs differ; got:\n{}",
+                            fs::read_to_string(&result.file).unwrap_or_default()
+                        )),
+                        Err(err) => failures.push(format!("{case}: {err}")),
+                    }
+                    checked += 1;
+                }
+            }
+        }
+    }
+    assert!(checked > 0, "Nothing was checked");
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+use std::fs;
+
+use rdfoothills_conversion::{self as conversion, OntFile};
+use rdfoothills_mime as mime;
+
+const TURTLE: &str = r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix sh: <http://www.w3.org/ns/shacl#> .
+@prefix ex: <http://example.org/> .
+
+ex: a owl:Ontology .
+ex:Person a owl:Class ;
+    rdfs:label "Person" .
+
+ex:PersonShape a sh:NodeShape ;
+    sh:targetClass ex:Person ;
+    sh:property [
+        sh:path ex:name ;
+        sh:minCount 1 ;
+    ] ;
+    sh:property ex:AgeShape .
+
+ex:AgeShape sh:path ex:age ;
+    sh:maxCount 1 .
+"#;
+
+#[test]
+fn test_extract_shapes() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = OntFile {
+        file: dir.path().join("ont.ttl"),
+        mime_type: mime::Type::Turtle,
+    };
+    let to = OntFile {
+        file: dir.path().join("shapes.nt"),
+        mime_type: mime::Type::NTriples,
+    };
+    fs::write(&from.file, TURTLE).unwrap();
+    let triples = conversion::extract_shapes(&from, &to).unwrap();
+    let out = fs::read_to_string(&to.file).unwrap();
+    assert_eq!(triples, 8);
+    assert_eq!(out.lines().count(), triples);
+    assert!(out.contains("<http://example.org/name>"));
+    assert!(out.contains("<http://example.org/AgeShape> <http://www.w3.org/ns/shacl#maxCount>"));
+    assert!(!out.contains("Ontology"));
+    assert!(!out.contains("rdf-schema#label"));
+}
@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "oxrdfio")]
+
+mod common;
+
+use std::fs;
+
+use common::ont_file;
+use rdfoothills_conversion::{self as conversion, ConversionOptions, Error};
+use rdfoothills_mime as mime;
+
+const TURTLE_STAR: &str = "<http://example.org/s> <http://example.org/p> <http://example.org/o> .
+<< <http://example.org/s> <http://example.org/p> <http://example.org/o> >> <http://example.org/certainty> \"0.9\" .
+";
+
+#[test]
+fn test_star_to_star_retains_quoted_triples() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = ont_file(dir.path(), "ont", mime::Type::TurtleStar);
+    let to = ont_file(dir.path(), "ont", mime::Type::NTriplesStar);
+    fs::write(&from.file, TURTLE_STAR).unwrap();
+    let converter = conversion::select_converter(&from, &to).unwrap();
+    assert!(converter.supports_star());
+    conversion::convert(&from, &to).unwrap();
+    assert!(fs::read_to_string(&to.file).unwrap().contains("<<"));
+}
+
+#[test]
+fn test_star_downgrade_refused() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = ont_file(dir.path(), "ont", mime::Type::TurtleStar);
+    let to = ont_file(dir.path(), "ont", mime::Type::RdfXml);
+    assert!(matches!(
+        conversion::select_converter(&from, &to),
+        Err(Error::StarDowngrade {
+            from: mime::Type::TurtleStar,
+            to: mime::Type::RdfXml
+        })
+    ));
+}
+
+#[test]
+fn test_star_downgrade_allowed() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = ont_file(dir.path(), "ont", mime::Type::TurtleStar);
+    let to = ont_file(dir.path(), "ont", mime::Type::RdfXml);
+    let options = ConversionOptions {
+        allow_star_downgrade: true,
+        ..ConversionOptions::default()
+    };
+    assert!(conversion::select_converter_with(&from, &to, &options).is_ok());
+}
+
+#[test]
+fn test_star_to_html() {
+    let dir = tempfile::tempdir().unwrap();
+    let from = ont_file(dir.path(), "ont", mime::Type::TurtleStar);
+    let to = ont_file(dir.path(), "ont", mime::Type::Html);
+    assert!(conversion::select_converter(&from, &to).is_ok());
+}
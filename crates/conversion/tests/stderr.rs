@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_conversion::{stderr, Error};
+
+const TRACEBACK_HEAD: &str = r#"Traceback (most recent call last):
+  File "/usr/bin/rdfx", line 8, in <module>
+    sys.exit(main())
+  File "/usr/lib/python3/dist-packages/rdflib/graph.py", line 1501, in parse
+    parser.parse(source, self, **args)
+"#;
+
+fn parse(exception: &str) -> Option<(String, Option<u64>)> {
+    match stderr::parse(&format!("{TRACEBACK_HEAD}{exception}\n")) {
+        Some(Error::Syntax { message, line }) => Some((message, line)),
+        Some(other) => panic!("Unexpected error: {other}"),
+        None => None,
+    }
+}
+
+#[test]
+fn test_turtle() {
+    let exception = r#"rdflib.plugins.parsers.notation3.BadSyntax: at line 3 of <>:
+Bad syntax (expected '.' or '}' or ']' at end of statement) at ^ in:
+"...b'ex:a ex:b ex:c'^b' ex:d ex:e .'""#;
+    assert_eq!(
+        parse(exception),
+        Some((
+            "expected '.' or '}' or ']' at end of statement".to_owned(),
+            Some(3)
+        ))
+    );
+}
+
+#[test]
+fn test_rdf_xml() {
+    let exception = "xml.sax._exceptions.SAXParseException: file:///tmp/ont.rdf:12:4: not well-formed (invalid token)";
+    assert_eq!(
+        parse(exception),
+        Some(("not well-formed (invalid token)".to_owned(), Some(12)))
+    );
+}
+
+#[test]
+fn test_json_ld() {
+    let exception = "json.decoder.JSONDecodeError: Expecting value: line 7 column 1 (char 42)";
+    assert_eq!(
+        parse(exception),
+        Some(("Expecting value".to_owned(), Some(7)))
+    );
+}
+
+#[test]
+fn test_n_triples() {
+    let exception = "rdflib.plugins.parsers.ntriples.ParseError: Invalid line: '<a> <b> .'";
+    assert_eq!(
+        parse(exception),
+        Some(("Invalid line: '<a> <b> .'".to_owned(), None))
+    );
+}
+
+#[test]
+fn test_unsupported() {
+    let exception = "rdflib.plugin.PluginException: No plugin registered for (xyz, <class 'rdflib.parser.Parser'>)";
+    let Some(Error::Unsupported(message)) =
+        stderr::parse(&format!("{TRACEBACK_HEAD}{exception}\n"))
+    else {
+        panic!("Expected an unsupported error");
+    };
+    assert_eq!(
+        message,
+        "No plugin registered for (xyz, <class 'rdflib.parser.Parser'>)"
+    );
+}
+
+#[test]
+fn test_unknown() {
+    assert_eq!(parse("ValueError: something else"), None);
+    assert_eq!(parse(""), None);
+}
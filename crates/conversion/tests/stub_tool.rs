@@ -0,0 +1,159 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(unix)]
+
+//! Tests the sync APIs with a CLI converter being installed,
+//! which is faked by a stub `rdfx` on the `PATH`.
+//! They have to run outside of any async runtime.
+
+use std::env;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use rdfoothills_conversion::{self as conversion, doctor, OntFile};
+use rdfoothills_mime as mime;
+
+const STUB_OUTPUT: &str = "<http://example.org/s> <http://example.org/p> \"o\" .\n";
+
+/// Not natively supported, so it has to be converted with the stub.
+const JSON_LD: &str = r#"{"@id": "http://example.org/s", "http://example.org/p": "o"}"#;
+
+/// Mimics `rdfx`: prints a version, and writes a fixed triple
+/// into the file given with `--output` when converting.
+const STUB_RDFX: &str = r#"#!/bin/sh
+case "$1" in
+--version)
+    echo "rdfx 0.1.0"
+    ;;
+convert)
+    while [ $# -gt 0 ]; do
+        if [ "$1" = "--output" ]; then
+            out="$2"
+        fi
+        shift
+    done
+    echo '<http://example.org/s> <http://example.org/p> "o" .' > "$out"
+    ;;
+esac
+"#;
+
+/// Counts how often it was called, in a file next to it.
+const STUB_COUNTED: &str = r#"#!/bin/sh
+echo "$@" >> "$0.calls"
+echo "counted 1.2.3"
+"#;
+
+/// Puts the stubs on the `PATH`, once for all tests,
+/// and returns the directory containing them.
+/// All tests have to call this before running anything,
+/// so no other process gets forked while the stubs are written.
+fn stub_tools() -> &'static Path {
+    static DIR: OnceLock<tempfile::TempDir> = OnceLock::new();
+    DIR.get_or_init(|| {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, script) in [("rdfx", STUB_RDFX), ("counted", STUB_COUNTED)] {
+            let stub = dir.path().join(name);
+            fs::write(&stub, script).unwrap();
+            fs::set_permissions(&stub, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        let path = env::var_os("PATH").unwrap_or_default();
+        let paths = std::iter::once(dir.path().to_owned()).chain(env::split_paths(&path));
+        env::set_var("PATH", env::join_paths(paths).unwrap());
+        dir
+    })
+    .path()
+}
+
+#[test]
+fn test_diagnose_with_cli_tool() {
+    stub_tools();
+    let diagnoses = doctor::diagnose();
+    let rdfx = diagnoses
+        .iter()
+        .find(|diagnosis| diagnosis.info.name == "rdfx")
+        .unwrap();
+    assert!(rdfx.is_healthy(), "{rdfx}");
+    assert_eq!(rdfx.version.as_deref(), Some("rdfx 0.1.0"));
+}
+
+#[test]
+fn test_convert_file_with_cli_tool() {
+    stub_tools();
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("ont.jsonld");
+    let output = dir.path().join("ont.ttl");
+    fs::write(&input, JSON_LD).unwrap();
+    let info = conversion::convert_file(&input, &output, None, None).unwrap();
+    assert_eq!(info.name, "rdfx");
+    assert_eq!(fs::read_to_string(&output).unwrap(), STUB_OUTPUT);
+}
+
+#[test]
+fn test_convert_to_many_with_cli_tool() {
+    stub_tools();
+    let dir = tempfile::tempdir().unwrap();
+    let from = OntFile {
+        file: dir.path().join("ont.jsonld"),
+        mime_type: mime::Type::JsonLd,
+    };
+    fs::write(&from.file, JSON_LD).unwrap();
+    let targets = [mime::Type::Turtle, mime::Type::NTriples];
+    let results = conversion::convert_to_many(&from, &targets, 2);
+    assert_eq!(results.len(), targets.len());
+    for (typ, res) in results {
+        assert_eq!(res.unwrap().name, "rdfx", "{typ}");
+        let to = from.file.with_extension(typ.file_ext());
+        assert_eq!(fs::read_to_string(to).unwrap(), STUB_OUTPUT);
+    }
+}
+
+#[test]
+fn test_benchmark_with_cli_tool() {
+    stub_tools();
+    let benchmarks = doctor::benchmark(&[1, 10], 1);
+    let rdfx = benchmarks
+        .iter()
+        .filter(|benchmark| benchmark.info.name == "rdfx")
+        .collect::<Vec<_>>();
+    assert_eq!(rdfx.len(), 2);
+    for benchmark in rdfx {
+        assert!(benchmark.result.is_ok(), "{benchmark}");
+    }
+}
+
+#[test]
+fn test_version_detected_once() {
+    let dir = stub_tools();
+    for _ in 0..3 {
+        assert_eq!(
+            conversion::cli_cmd_version("counted").as_deref(),
+            Some("counted 1.2.3")
+        );
+    }
+    let calls = fs::read_to_string(dir.join("counted.calls")).unwrap();
+    assert_eq!(calls, "--version\n");
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_convert_async_with_cli_tool() {
+    stub_tools();
+    let dir = tempfile::tempdir().unwrap();
+    let from = OntFile {
+        file: dir.path().join("ont.jsonld"),
+        mime_type: mime::Type::JsonLd,
+    };
+    let to = OntFile {
+        file: dir.path().join("ont.ttl"),
+        mime_type: mime::Type::Turtle,
+    };
+    fs::write(&from.file, JSON_LD).unwrap();
+    let info = conversion::convert_async(&from, &to).await.unwrap();
+    assert_eq!(info.name, "rdfx");
+    assert_eq!(fs::read_to_string(&to.file).unwrap(), STUB_OUTPUT);
+}
@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_conversion::version::{Version, VersionRange};
+
+#[test]
+fn test_extract() {
+    assert_eq!(Version::extract("3.0.5"), Some(Version(vec![3, 0, 5])));
+    assert_eq!(
+        Version::extract("pyLODE, version 3.0.5 (Python 3.11)"),
+        Some(Version(vec![3, 0, 5]))
+    );
+    assert_eq!(Version::extract("rdfx v0.2"), Some(Version(vec![0, 2])));
+    assert_eq!(Version::extract("unknown"), None);
+}
+
+#[test]
+fn test_range() {
+    let range = VersionRange {
+        min: Some(&[2, 1]),
+        max: Some(&[3]),
+    };
+    assert!(!range.contains(&Version(vec![2])));
+    assert!(!range.contains(&Version(vec![2, 0, 9])));
+    assert!(range.contains(&Version(vec![2, 1])));
+    assert!(range.contains(&Version(vec![3, 9, 9])));
+    assert!(!range.contains(&Version(vec![4])));
+    assert!(VersionRange::ANY.contains(&Version(vec![0])));
+    assert_eq!(range.to_string(), ">= 2.1, <= 3.*");
+    assert_eq!(VersionRange::ANY.to_string(), "any");
+}
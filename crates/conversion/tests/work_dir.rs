@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use std::fs;
+use std::panic;
+
+use rdfoothills_conversion::work_dir::WorkDir;
+
+#[test]
+fn test_removed_when_finished() {
+    let work_dir = WorkDir::new("test").unwrap().keep_on_failure(true);
+    let path = work_dir.path().to_owned();
+    fs::write(path.join("out.ttl"), "").unwrap();
+    work_dir.finish();
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_removed_on_failure() {
+    let work_dir = WorkDir::new("test").unwrap().keep_on_failure(false);
+    let path = work_dir.path().to_owned();
+    assert!(path.is_dir());
+    drop(work_dir);
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_removed_on_panic() {
+    let work_dir = WorkDir::new("test").unwrap().keep_on_failure(false);
+    let path = work_dir.path().to_owned();
+    let res = panic::catch_unwind(move || {
+        let _work_dir = work_dir;
+        panic!("conversion failed");
+    });
+    assert!(res.is_err());
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_kept_on_failure() {
+    let work_dir = WorkDir::new("test").unwrap().keep_on_failure(true);
+    let path = work_dir.path().to_owned();
+    drop(work_dir);
+    assert!(path.is_dir());
+    fs::remove_dir_all(path).unwrap();
+}
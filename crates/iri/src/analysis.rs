@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::ops::Deref;
+
+use oxiri::IriRef;
+use oxrdf::{vocab::rdf, NamedNodeRef, SubjectRef, Triple};
+use rdfoothills_vocab::{basics::rdf_bool, ocaa};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Hosts of services providing permanent URLs,
+/// which are recommended for ontology IRIs.
+pub const PURL_HOSTS: &[&str] = &[
+    "w3id.org",
+    "purl.org",
+    "purl.obolibrary.org",
+    "purl.archive.org",
+];
+
+/// File extensions of RDF serializations,
+/// which should not be part of an ontology IRI.
+const RDF_FILE_EXTS: &[&str] = &[
+    "jsonld", "n3", "nq", "nt", "owl", "rdf", "trig", "ttl", "xml",
+];
+
+/// The results of checking an ontology IRI/namespace
+/// against best practices.
+/// Each check corresponds to a property of the OCAA vocabulary.
+// NOTE: This is a report, so a bunch of bools is what we want here.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IriAnalysis {
+    /// Whether the IRI consists of ASCII characters only,
+    /// and is thus a valid URI as well.
+    pub uri_compatible: bool,
+    /// Whether the scheme is `http` or `https`.
+    pub uses_http: bool,
+    /// Whether the host is one of [`PURL_HOSTS`].
+    pub uses_purl: bool,
+    /// Whether the IRI ends with `/` or `#`.
+    pub ends_well: bool,
+    /// Whether the IRI has no query part.
+    pub has_no_query: bool,
+    /// Whether the path is non-empty,
+    /// consists of unreserved characters only
+    /// and does not end in an RDF file extension (e.g. `.owl`).
+    /// See <https://more.metadatacenter.org/recommended-iri-patterns-ontologies-and-their-terms>.
+    pub path_follows_best_practice: bool,
+}
+
+/// Extracts the host from the authority part of an IRI,
+/// stripping user-info and port.
+#[must_use]
+pub fn host(authority: &str) -> &str {
+    let host_and_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_user_info, host_and_port)| host_and_port);
+    if host_and_port.starts_with('[') {
+        // IPv6 literal
+        return host_and_port
+            .split_once(']')
+            .map_or(host_and_port, |(ip, _port)| ip.trim_start_matches('['));
+    }
+    host_and_port
+        .split_once(':')
+        .map_or(host_and_port, |(host, _port)| host)
+}
+
+fn uses_purl(iri_authority: Option<&str>) -> bool {
+    iri_authority.is_some_and(|authority| {
+        let host = host(authority).to_ascii_lowercase();
+        PURL_HOSTS.iter().any(|purl_host| {
+            host == *purl_host
+                || host
+                    .strip_suffix(purl_host)
+                    .is_some_and(|sub| sub.ends_with('.'))
+        })
+    })
+}
+
+fn path_follows_best_practice(path: &str) -> bool {
+    let trimmed = path.trim_matches('/');
+    let has_rdf_file_ext = trimmed
+        .rsplit('/')
+        .next()
+        .and_then(|last_segment| last_segment.rsplit_once('.'))
+        .is_some_and(|(_stem, ext)| RDF_FILE_EXTS.contains(&ext.to_ascii_lowercase().as_str()));
+    !trimmed.is_empty()
+        && !has_rdf_file_ext
+        && trimmed
+            .chars()
+            .all(|chr| chr.is_ascii_alphanumeric() || matches!(chr, '-' | '.' | '_' | '~' | '/'))
+}
+
+impl IriAnalysis {
+    /// Checks the given IRI against all the best practices.
+    #[must_use]
+    pub fn new<T: Deref<Target = str>>(iri: &IriRef<T>) -> Self {
+        Self {
+            uri_compatible: iri.as_str().is_ascii(),
+            uses_http: iri.scheme().is_some_and(|scheme| {
+                scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+            }),
+            uses_purl: uses_purl(iri.authority()),
+            ends_well: iri.as_str().ends_with('/') || iri.as_str().ends_with('#'),
+            has_no_query: iri.query().is_none(),
+            path_follows_best_practice: path_follows_best_practice(iri.path()),
+        }
+    }
+
+    /// Whether all the checks passed.
+    #[must_use]
+    pub const fn all_passed(&self) -> bool {
+        self.uri_compatible
+            && self.uses_http
+            && self.uses_purl
+            && self.ends_well
+            && self.has_no_query
+            && self.path_follows_best_practice
+    }
+
+    /// The results of the checks,
+    /// each with the OCAA property representing it.
+    #[must_use]
+    pub const fn properties(&self) -> [(NamedNodeRef<'static>, bool); 6] {
+        [
+            (ocaa::URI_COMPATIBLE, self.uri_compatible),
+            (ocaa::USES_HTTP, self.uses_http),
+            (ocaa::USES_PURL, self.uses_purl),
+            (ocaa::ENDS_WELL, self.ends_well),
+            (ocaa::HAS_NO_QUERY, self.has_no_query),
+            (
+                ocaa::PATH_FOLLOWS_BEST_PRACTISE,
+                self.path_follows_best_practice,
+            ),
+        ]
+    }
+
+    /// Represents this analysis in RDF,
+    /// as an `ocaa:IriAnalysis` with the given subject.
+    #[must_use]
+    pub fn to_triples(&self, subject: SubjectRef<'_>) -> Vec<Triple> {
+        let mut triples = vec![Triple::new(subject, rdf::TYPE, ocaa::IRI_ANALYSIS)];
+        triples.extend(
+            self.properties()
+                .into_iter()
+                .map(|(property, value)| Triple::new(subject, property, rdf_bool(value))),
+        );
+        triples
+    }
+}
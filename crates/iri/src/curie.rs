@@ -0,0 +1,157 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::fmt;
+use std::str::FromStr;
+
+use oxiri::{IriParseError, IriRef};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{Iri, PrefixMap};
+
+#[derive(Error, Debug)]
+pub enum CurieError {
+    #[error("'{0}' is not a CURIE; it lacks the ':' separating prefix and reference")]
+    MissingColon(String),
+    #[error("The CURIE prefix '{0}' is not a valid NCName")]
+    InvalidPrefix(String),
+    #[error("The CURIE reference '{0}' is not a valid relative IRI reference")]
+    InvalidReference(String),
+    #[error("The CURIE prefix '{0}' is unknown")]
+    UnknownPrefix(String),
+    #[error(transparent)]
+    InvalidIri(#[from] IriParseError),
+}
+
+/// A compact IRI, e.g. `sh:path`,
+/// consisting of a prefix and a reference relative to the namespace
+/// the prefix stands for.
+///
+/// See the [CURIE Syntax 1.0](https://www.w3.org/TR/curie/) spec.
+/// Unlike in there, the colon is mandatory,
+/// and the default prefix is written as the empty one (e.g. `:path`).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Curie {
+    /// The prefix, e.g. `sh`; may be empty.
+    pub prefix: String,
+    /// The part after the colon, e.g. `path`.
+    pub reference: String,
+}
+
+/// Whether `chr` is a `NameStartChar` of XML, excluding the colon.
+const fn is_name_start_char(chr: char) -> bool {
+    matches!(chr,
+        'A'..='Z'
+        | '_'
+        | 'a'..='z'
+        | '\u{C0}'..='\u{D6}'
+        | '\u{D8}'..='\u{F6}'
+        | '\u{F8}'..='\u{2FF}'
+        | '\u{370}'..='\u{37D}'
+        | '\u{37F}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFD}'
+        | '\u{10000}'..='\u{EFFFF}')
+}
+
+/// Whether `chr` is a `NameChar` of XML, excluding the colon.
+const fn is_name_char(chr: char) -> bool {
+    is_name_start_char(chr)
+        || matches!(chr,
+            '-'
+            | '.'
+            | '0'..='9'
+            | '\u{B7}'
+            | '\u{300}'..='\u{36F}'
+            | '\u{203F}'..='\u{2040}')
+}
+
+/// Whether `name` is a valid [NCName](https://www.w3.org/TR/xml-names/#NT-NCName),
+/// as required for CURIE prefixes.
+#[must_use]
+pub fn is_ncname(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(is_name_start_char) && chars.all(is_name_char)
+}
+
+impl Curie {
+    /// Creates a new instance of `Curie`,
+    /// without validating the parts.
+    #[must_use]
+    pub fn new(prefix: impl Into<String>, reference: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            reference: reference.into(),
+        }
+    }
+
+    /// Checks whether the prefix is empty or an `NCName`,
+    /// and the reference is a relative IRI reference.
+    ///
+    /// # Errors
+    ///
+    /// If either part is invalid.
+    pub fn validate(&self) -> Result<(), CurieError> {
+        if !self.prefix.is_empty() && !is_ncname(&self.prefix) {
+            return Err(CurieError::InvalidPrefix(self.prefix.clone()));
+        }
+        if IriRef::parse(self.reference.as_str()).is_err() {
+            return Err(CurieError::InvalidReference(self.reference.clone()));
+        }
+        Ok(())
+    }
+
+    /// Expands this CURIE into a full IRI,
+    /// using the namespace its prefix is mapped to.
+    ///
+    /// # Errors
+    ///
+    /// If the prefix is unknown,
+    /// or the expanded IRI is invalid.
+    pub fn to_iri(&self, prefixes: &PrefixMap) -> Result<Iri, CurieError> {
+        let namespace = prefixes
+            .get(&self.prefix)
+            .ok_or_else(|| CurieError::UnknownPrefix(self.prefix.clone()))?;
+        Ok(Iri::parse(format!("{namespace}{}", self.reference))?)
+    }
+
+    /// Compacts a full IRI into a CURIE,
+    /// using the longest matching namespace.
+    #[must_use]
+    pub fn from_iri(iri: &Iri, prefixes: &PrefixMap) -> Option<Self> {
+        prefixes.compress(iri.as_str())
+    }
+}
+
+impl FromStr for Curie {
+    type Err = CurieError;
+
+    /// Parses and validates a CURIE like `sh:path`,
+    /// also accepting the "safe" form `[sh:path]`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unwrapped = s
+            .strip_prefix('[')
+            .and_then(|inner| inner.strip_suffix(']'))
+            .unwrap_or(s);
+        let (prefix, reference) = unwrapped
+            .split_once(':')
+            .ok_or_else(|| CurieError::MissingColon(s.to_owned()))?;
+        let curie = Self::new(prefix, reference);
+        curie.validate()?;
+        Ok(curie)
+    }
+}
+
+impl fmt::Display for Curie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.prefix, self.reference)
+    }
+}
@@ -5,12 +5,28 @@
 use oxiri::{IriParseError, IriRef};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::relative::{self, RelativeIriError};
+use crate::{is_ncname, split_term, well_known_prefix};
 
 pub type Iri = IriRef<String>;
 
 pub const PREFIX_EMPTY: &str = "";
 pub const PREFIX_EMPTY_ID: &str = "__NO_PREFIX_ID__";
 
+#[derive(Error, Debug)]
+pub enum PrefixError {
+    #[error("The prefix '{0}' is neither empty nor a valid NCName")]
+    InvalidPrefix(String),
+    #[error(transparent)]
+    InvalidIri(#[from] IriParseError),
+    #[error("The IRI <{0}> does not end with a namespace delimiter ('/' or '#')")]
+    NoDelimiter(String),
+    #[error("The IRI <{0}> has no local name to derive a namespace from")]
+    NoLocalName(String),
+}
+
 // TODO Find a better name then Prefix; maybe: OntId, OntIdPair, NamespaceId, RefixAndIri, PrefIri, ...
 /// Represents a kind of basic ID for a set of RDF triples
 /// that could be though of as being in one namespace,
@@ -43,14 +59,42 @@ impl Prefix {
     ///
     /// # Errors
     ///
-    /// Returns an `IriParseError` if the given `iri` is invalid.
-    pub fn new(prefix_id: String, iri: String) -> Result<Self, IriParseError> {
+    /// - the given `prefix_id` is neither empty nor an `NCName`
+    /// - the given `iri` is invalid
+    pub fn new(prefix_id: String, iri: String) -> Result<Self, PrefixError> {
+        if prefix_id != PREFIX_EMPTY && !is_ncname(&prefix_id) {
+            return Err(PrefixError::InvalidPrefix(prefix_id));
+        }
         Ok(Self {
             prefix: prefix_id,
             iri: IriRef::parse(iri)?,
         })
     }
 
+    /// Creates the prefix for the namespace of the given term,
+    /// e.g. `owl` with `http://www.w3.org/2002/07/owl#`
+    /// for `http://www.w3.org/2002/07/owl#Class`.
+    /// The namespace is derived with [`split_term`],
+    /// and the prefix is the well-known one for it,
+    /// or empty if there is none.
+    ///
+    /// # Errors
+    ///
+    /// - the given `term_iri` has no local name
+    /// - the derived namespace is not a valid IRI
+    pub fn for_term(term_iri: &str) -> Result<Self, PrefixError> {
+        let (namespace, local_name) = split_term(term_iri);
+        if local_name.is_empty() {
+            return Err(PrefixError::NoLocalName(term_iri.to_owned()));
+        }
+        Self::new(
+            well_known_prefix(namespace)
+                .unwrap_or(PREFIX_EMPTY)
+                .to_owned(),
+            namespace.to_owned(),
+        )
+    }
+
     /// Returns the `@base` of the IRI.
     /// This is simply the IRI without the last character.
     ///
@@ -61,22 +105,35 @@ impl Prefix {
     /// - `http://schema.org/` -> \
     ///   `http://schema.org`
     ///
-    /// # Panics
+    /// # Errors
     ///
     /// If the IRI does not end with a common delimiter, e.g. `#` or `/`.
-    #[must_use]
-    pub fn base(&self) -> &str {
+    pub fn base(&self) -> Result<&str, PrefixError> {
         let iri_str = self.iri.as_str();
-        if iri_str.ends_with('#') || iri_str.ends_with('/') {
-            #[allow(clippy::indexing_slicing)]
-            #[allow(clippy::string_slice)]
-            &iri_str[0..self.iri.as_str().len() - 1]
-        } else {
-            panic!(
-                "IRI {} is not a base plus common delimiter suffix ('/' or '#')",
-                self.iri
-            );
-        }
+        iri_str
+            .strip_suffix('#')
+            .or_else(|| iri_str.strip_suffix('/'))
+            .ok_or_else(|| PrefixError::NoDelimiter(iri_str.to_owned()))
+    }
+
+    /// Resolves a (possibly relative) IRI reference
+    /// against the IRI of this prefix.
+    ///
+    /// # Errors
+    ///
+    /// If `reference` is not a valid IRI reference.
+    pub fn resolve(&self, reference: &str) -> Result<Iri, RelativeIriError> {
+        relative::resolve(&self.iri, reference)
+    }
+
+    /// Expresses an absolute IRI relative to the IRI of this prefix.
+    ///
+    /// # Errors
+    ///
+    /// If either of the IRIs is not absolute,
+    /// or `iri` can not be expressed relative to the IRI of this prefix.
+    pub fn relativize(&self, iri: &Iri) -> Result<Iri, RelativeIriError> {
+        relative::relativize(&self.iri, iri)
     }
 
     /// Returns a _non empty_ "version" of the prefix-ID.
@@ -2,8 +2,26 @@
 //
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
+mod analysis;
+mod curie;
 mod iri;
+#[cfg(feature = "lov")]
+pub mod lov;
+mod normalize;
+#[cfg(feature = "prefix-cc")]
+pub mod prefix_cc;
+mod prefix_map;
+mod relative;
+mod split;
+mod well_known;
+pub use analysis::*;
+pub use curie::*;
 pub use iri::*;
+pub use normalize::*;
+pub use prefix_map::*;
+pub use relative::*;
+pub use split::*;
+pub use well_known::*;
 
 // This tests rust code in the README with doc-tests.
 // Though, It will not appear in the generated documentaton.
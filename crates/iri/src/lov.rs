@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Looks up vocabularies on [Linked Open Vocabularies (LOV)](https://lov.linkeddata.es),
+//! to discover metadata and an alternative download location,
+//! e.g. when the original one is down.
+
+use std::io;
+
+use serde_json::Value;
+use url::Url;
+
+const API_BASE_URL: &str = "https://lov.linkeddata.es/dataset/lov/api/v2/vocabulary";
+
+/// What LOV knows about a vocabulary.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vocabulary {
+    pub prefix: String,
+    /// The IRI of the vocabulary itself.
+    pub uri: String,
+    /// The namespace of the terms of the vocabulary.
+    pub namespace: String,
+    /// The title, preferably in English.
+    pub title: Option<String>,
+    /// Where LOV archived the latest version of the vocabulary.
+    /// This is where the content actually comes from
+    /// when downloading from here instead of from `uri`.
+    pub latest_version_url: Option<String>,
+    /// When the latest version was issued (ISO 8601).
+    pub latest_version_issued: Option<String>,
+}
+
+fn str_field(json: &Value, key: &str) -> Option<String> {
+    json.get(key).and_then(Value::as_str).map(ToOwned::to_owned)
+}
+
+fn pick_title(json: &Value) -> Option<String> {
+    let titles = json.get("titles")?.as_array()?;
+    titles
+        .iter()
+        .find(|title| title.get("lang").and_then(Value::as_str) == Some("en"))
+        .or_else(|| titles.first())
+        .and_then(|title| str_field(title, "value"))
+}
+
+impl Vocabulary {
+    /// Parses a vocabulary from a response of the LOV API
+    /// (`/vocabulary/info` or an entry of `/vocabulary/list`).
+    /// Returns `None` if it is missing any of the required fields.
+    #[must_use]
+    pub fn from_lov_json(json: &Value) -> Option<Self> {
+        let latest = json
+            .get("versions")
+            .and_then(Value::as_array)
+            .and_then(|versions| {
+                versions
+                    .iter()
+                    .filter(|version| version.get("fileURL").is_some())
+                    .max_by_key(|version| version.get("issued").and_then(Value::as_str))
+            });
+        Some(Self {
+            prefix: str_field(json, "prefix")?,
+            uri: str_field(json, "uri")?,
+            namespace: str_field(json, "nsp")?,
+            title: pick_title(json),
+            latest_version_url: latest.and_then(|version| str_field(version, "fileURL")),
+            latest_version_issued: latest.and_then(|version| str_field(version, "issued")),
+        })
+    }
+}
+
+/// Fetches a JSON document from the LOV API.
+/// Returns `None` if LOV does not know about what was asked for.
+async fn fetch(url: Url) -> io::Result<Option<Value>> {
+    tokio::task::spawn_blocking(move || {
+        tracing::debug!("Looking up '{url}' ...");
+        let response = match ureq::get(url.as_str()).call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(404, _)) => return Ok(None),
+            Err(err) => return Err(io::Error::other(err)),
+        };
+        serde_json::from_reader(response.into_reader())
+            .map(Some)
+            .map_err(io::Error::other)
+    })
+    .await?
+}
+
+/// Looks up a vocabulary by its preferred prefix.
+///
+/// # Errors
+///
+/// If the request fails or the response is invalid.
+pub async fn lookup_vocab(prefix: &str) -> io::Result<Option<Vocabulary>> {
+    let url = Url::parse_with_params(&format!("{API_BASE_URL}/info"), [("vocab", prefix)])
+        .map_err(io::Error::other)?;
+    Ok(fetch(url)
+        .await?
+        .as_ref()
+        .and_then(Vocabulary::from_lov_json))
+}
+
+/// Looks up the vocabulary with the given namespace or IRI.
+///
+/// # Errors
+///
+/// If a request fails or a response is invalid.
+pub async fn lookup_namespace(namespace: &str) -> io::Result<Option<Vocabulary>> {
+    let url = Url::parse(&format!("{API_BASE_URL}/list")).map_err(io::Error::other)?;
+    let Some(list) = fetch(url).await? else {
+        return Ok(None);
+    };
+    let found = list.as_array().and_then(|vocabs| {
+        vocabs
+            .iter()
+            .filter_map(Vocabulary::from_lov_json)
+            .find(|vocab| vocab.namespace == namespace || vocab.uri == namespace)
+    });
+    match found {
+        // The list does not contain the versions.
+        Some(vocab) => Ok(lookup_vocab(&vocab.prefix).await?.or(Some(vocab))),
+        None => Ok(None),
+    }
+}
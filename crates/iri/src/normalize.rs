@@ -0,0 +1,146 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::ops::Deref;
+
+use oxiri::IriRef;
+
+use crate::Iri;
+
+/// Syntax-based normalization of IRIs,
+/// as described in [RFC 3987, section 5.3.2](https://www.rfc-editor.org/rfc/rfc3987#section-5.3.2).
+pub trait Normalize {
+    /// Returns an equivalent IRI in normalized form,
+    /// so that IRIs that only differ in their syntax compare as equal.
+    ///
+    /// This applies:
+    ///
+    /// - case normalization:
+    ///   lower-case scheme and host,
+    ///   upper-case hexadecimal digits in percent-encodings
+    /// - percent-encoding normalization:
+    ///   decoding of percent-encoded unreserved ASCII characters
+    /// - path segment normalization:
+    ///   removal of `.` and `..` segments,
+    ///   if the IRI has a scheme or an authority
+    #[must_use]
+    fn normalized(&self) -> Iri;
+}
+
+/// Whether `chr` is an unreserved ASCII character,
+/// which never needs to be percent-encoded.
+const fn is_unreserved(chr: char) -> bool {
+    chr.is_ascii_alphanumeric() || matches!(chr, '-' | '.' | '_' | '~')
+}
+
+/// Decodes percent-encoded unreserved characters
+/// and upper-cases the hexadecimal digits of the remaining percent-encodings.
+/// If `lowercase` is set, the (not percent-encoded) ASCII letters
+/// are lower-cased.
+fn normalize_percent_encoding(part: &str, lowercase: bool) -> String {
+    let mut normalized = String::with_capacity(part.len());
+    let mut chars = part.chars();
+    while let Some(chr) = chars.next() {
+        if chr != '%' {
+            normalized.push(if lowercase {
+                chr.to_ascii_lowercase()
+            } else {
+                chr
+            });
+            continue;
+        }
+        // NOTE: The IRI was already validated,
+        //       so each '%' is followed by two hexadecimal digits.
+        let (Some(high), Some(low)) = (chars.next(), chars.next()) else {
+            normalized.push(chr);
+            continue;
+        };
+        let decoded = high
+            .to_digit(16)
+            .zip(low.to_digit(16))
+            .and_then(|(high_val, low_val)| char::from_u32(high_val * 16 + low_val))
+            .filter(|decoded| is_unreserved(*decoded));
+        match decoded {
+            Some(unreserved) if lowercase => normalized.push(unreserved.to_ascii_lowercase()),
+            Some(unreserved) => normalized.push(unreserved),
+            None => {
+                normalized.push('%');
+                normalized.push(high.to_ascii_uppercase());
+                normalized.push(low.to_ascii_uppercase());
+            }
+        }
+    }
+    normalized
+}
+
+/// Removes the `.` and `..` segments from a path,
+/// as described in [RFC 3986, section 5.2.4](https://www.rfc-editor.org/rfc/rfc3986#section-5.2.4).
+fn remove_dot_segments(path: &str) -> String {
+    let absolute = path.starts_with('/');
+    let mut segments = path.split('/').peekable();
+    if absolute {
+        segments.next();
+    }
+    let mut output = Vec::new();
+    while let Some(segment) = segments.next() {
+        let is_last = segments.peek().is_none();
+        match segment {
+            "." => {}
+            ".." => {
+                output.pop();
+            }
+            _ => {
+                output.push(segment);
+                continue;
+            }
+        }
+        // NOTE: A trailing dot segment denotes a directory
+        if is_last {
+            output.push("");
+        }
+    }
+    let joined = output.join("/");
+    if absolute {
+        format!("/{joined}")
+    } else {
+        joined
+    }
+}
+
+impl<T: Deref<Target = str>> Normalize for IriRef<T> {
+    fn normalized(&self) -> Iri {
+        let mut normalized = String::with_capacity(self.as_str().len());
+        if let Some(scheme) = self.scheme() {
+            normalized.push_str(&scheme.to_ascii_lowercase());
+            normalized.push(':');
+        }
+        if let Some(authority) = self.authority() {
+            normalized.push_str("//");
+            let host_and_port = match authority.rsplit_once('@') {
+                Some((user_info, host_and_port)) => {
+                    normalized.push_str(&normalize_percent_encoding(user_info, false));
+                    normalized.push('@');
+                    host_and_port
+                }
+                None => authority,
+            };
+            normalized.push_str(&normalize_percent_encoding(host_and_port, true));
+        }
+        let path = normalize_percent_encoding(self.path(), false);
+        if self.scheme().is_some() || self.authority().is_some() {
+            normalized.push_str(&remove_dot_segments(&path));
+        } else {
+            normalized.push_str(&path);
+        }
+        if let Some(query) = self.query() {
+            normalized.push('?');
+            normalized.push_str(&normalize_percent_encoding(query, false));
+        }
+        if let Some(fragment) = self.fragment() {
+            normalized.push('#');
+            normalized.push_str(&normalize_percent_encoding(fragment, false));
+        }
+        IriRef::parse_unchecked(normalized)
+    }
+}
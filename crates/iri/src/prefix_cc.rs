@@ -0,0 +1,57 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Looks up prefixes and namespaces
+//! that are not well-known on <https://prefix.cc>.
+
+use std::collections::HashMap;
+use std::io;
+
+use url::Url;
+
+const BASE_URL: &str = "https://prefix.cc";
+
+/// Fetches a JSON object mapping prefixes to namespaces from prefix.cc.
+/// Returns `None` if prefix.cc does not know about what was asked for.
+async fn fetch(url: String) -> io::Result<Option<HashMap<String, String>>> {
+    tokio::task::spawn_blocking(move || {
+        tracing::debug!("Looking up '{url}' ...");
+        let response = match ureq::get(&url).call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(404, _)) => return Ok(None),
+            Err(err) => return Err(io::Error::other(err)),
+        };
+        serde_json::from_reader(response.into_reader())
+            .map(Some)
+            .map_err(io::Error::other)
+    })
+    .await?
+}
+
+/// Looks up the namespace most commonly used for a prefix.
+///
+/// # Errors
+///
+/// If the request fails or the response is invalid.
+pub async fn lookup_namespace(prefix: &str) -> io::Result<Option<String>> {
+    Ok(fetch(format!("{BASE_URL}/{prefix}.file.json"))
+        .await?
+        .and_then(|mut prefixes| prefixes.remove(prefix)))
+}
+
+/// Looks up the prefix most commonly used for a namespace.
+///
+/// # Errors
+///
+/// If the request fails or the response is invalid.
+pub async fn lookup_prefix(namespace: &str) -> io::Result<Option<String>> {
+    let url = Url::parse_with_params(
+        &format!("{BASE_URL}/reverse"),
+        [("uri", namespace), ("format", "json")],
+    )
+    .map_err(io::Error::other)?;
+    Ok(fetch(url.into())
+        .await?
+        .and_then(|prefixes| prefixes.into_keys().next()))
+}
@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Curie, CurieError, Iri};
+
+/// A set of prefixes, each mapped to the namespace it stands for,
+/// as declared with `@prefix` in Turtle, for example.
+///
+/// Iterating over it yields the prefixes in alphabetical order.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PrefixMap {
+    prefixes: BTreeMap<String, String>,
+}
+
+impl PrefixMap {
+    /// Creates an empty map.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            prefixes: BTreeMap::new(),
+        }
+    }
+
+    /// Maps `prefix` to `namespace`.
+    /// Returns the namespace it was previously mapped to, if any.
+    pub fn insert(
+        &mut self,
+        prefix: impl Into<String>,
+        namespace: impl Into<String>,
+    ) -> Option<String> {
+        self.prefixes.insert(prefix.into(), namespace.into())
+    }
+
+    /// Returns the namespace `prefix` is mapped to.
+    #[must_use]
+    pub fn get(&self, prefix: &str) -> Option<&str> {
+        self.prefixes.get(prefix).map(String::as_str)
+    }
+
+    /// The number of prefixes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.prefixes.len()
+    }
+
+    /// Whether there are no prefixes at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.prefixes.is_empty()
+    }
+
+    /// Iterates over all (prefix, namespace) pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.prefixes
+            .iter()
+            .map(|(prefix, namespace)| (prefix.as_str(), namespace.as_str()))
+    }
+
+    /// Finds the (prefix, namespace) pair
+    /// with the longest namespace that `iri` starts with.
+    #[must_use]
+    pub fn longest_match(&self, iri: &str) -> Option<(&str, &str)> {
+        self.iter()
+            .filter(|(_prefix, namespace)| iri.starts_with(namespace))
+            .max_by_key(|(_prefix, namespace)| namespace.len())
+    }
+
+    /// Expands a compact IRI like `sh:path` into a full IRI,
+    /// if its prefix is known.
+    #[must_use]
+    pub fn expand(&self, curie: &str) -> Option<String> {
+        let (prefix, reference) = curie.split_once(':')?;
+        self.get(prefix)
+            .map(|namespace| format!("{namespace}{reference}"))
+    }
+
+    /// Compacts a full IRI into a CURIE,
+    /// using the longest matching namespace.
+    #[must_use]
+    pub fn compress(&self, iri: &str) -> Option<Curie> {
+        let (prefix, namespace) = self.longest_match(iri)?;
+        iri.strip_prefix(namespace)
+            .map(|reference| Curie::new(prefix, reference))
+    }
+
+    /// Resolves user input that may either be a CURIE
+    /// with a prefix known to this map, or a full IRI.
+    ///
+    /// # Errors
+    ///
+    /// If the input is neither a valid CURIE with a known prefix,
+    /// nor a valid IRI.
+    pub fn resolve(&self, iri_or_curie: &str) -> Result<Iri, CurieError> {
+        match iri_or_curie.parse::<Curie>() {
+            Ok(curie) if self.get(&curie.prefix).is_some() => curie.to_iri(self),
+            _ => Ok(Iri::parse(iri_or_curie.to_owned())?),
+        }
+    }
+}
+
+impl<P: Into<String>, N: Into<String>> FromIterator<(P, N)> for PrefixMap {
+    fn from_iter<I: IntoIterator<Item = (P, N)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<P: Into<String>, N: Into<String>> Extend<(P, N)> for PrefixMap {
+    fn extend<I: IntoIterator<Item = (P, N)>>(&mut self, iter: I) {
+        for (prefix, namespace) in iter {
+            self.insert(prefix, namespace);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use oxiri::IriParseError;
+use thiserror::Error;
+
+use crate::Iri;
+
+#[derive(Error, Debug)]
+pub enum RelativeIriError {
+    #[error("Failed to resolve '{reference}' against the base IRI <{base}>: {source}")]
+    Resolve {
+        base: String,
+        reference: String,
+        source: IriParseError,
+    },
+    #[error("The IRI <{0}> is not absolute")]
+    NotAbsolute(String),
+    #[error("The IRI <{iri}> can not be expressed relative to <{base}>")]
+    Relativize { base: String, iri: String },
+}
+
+fn absolute(iri: &Iri) -> Result<oxiri::Iri<&str>, RelativeIriError> {
+    oxiri::Iri::parse(iri.as_str()).map_err(|_| RelativeIriError::NotAbsolute(iri.to_string()))
+}
+
+/// Resolves a (possibly relative) IRI reference against a base IRI,
+/// e.g. `../b#c` against `http://example.org/a/` to `http://example.org/b#c`.
+///
+/// # Errors
+///
+/// If `reference` is not a valid IRI reference.
+pub fn resolve(base: &Iri, reference: &str) -> Result<Iri, RelativeIriError> {
+    base.resolve(reference)
+        .map_err(|source| RelativeIriError::Resolve {
+            base: base.to_string(),
+            reference: reference.to_owned(),
+            source,
+        })
+}
+
+/// Expresses an absolute IRI relative to a base IRI,
+/// as compact as possible,
+/// e.g. `http://example.org/a/b#c` relative to `http://example.org/a/` as `b#c`.
+/// This is the inverse of [`resolve`].
+///
+/// # Errors
+///
+/// If either of the IRIs is not absolute,
+/// or `iri` can not be expressed relative to `base`.
+pub fn relativize(base: &Iri, iri: &Iri) -> Result<Iri, RelativeIriError> {
+    absolute(base)?
+        .relativize(&absolute(iri)?)
+        .map_err(|_| RelativeIriError::Relativize {
+            base: base.to_string(),
+            iri: iri.to_string(),
+        })
+}
@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+/// Splits the IRI of a term into its namespace and its local name.
+///
+/// This uses the common heuristics:
+/// The namespace is everything up to and including the last `#`,
+/// or - if there is none - the last `/`,
+/// or - if there is none either - the last `:` (as used in URNs).
+///
+/// If no local name can be found, it is empty.
+///
+/// # Examples
+///
+/// ```
+/// use rdfoothills_iri::split_term;
+///
+/// assert_eq!(
+///     split_term("http://www.w3.org/2002/07/owl#Class"),
+///     ("http://www.w3.org/2002/07/owl#", "Class")
+/// );
+/// assert_eq!(
+///     split_term("http://schema.org/name"),
+///     ("http://schema.org/", "name")
+/// );
+/// assert_eq!(split_term("urn:isbn:0451450523"), ("urn:isbn:", "0451450523"));
+/// assert_eq!(split_term("http://example.org"), ("http://example.org", ""));
+/// ```
+#[must_use]
+pub fn split_term(iri: &str) -> (&str, &str) {
+    let split_idx = iri
+        .rfind('#')
+        .or_else(|| iri.rfind('/'))
+        .or_else(|| iri.rfind(':'))
+        .map_or(0, |sep_idx| sep_idx + 1);
+    let (namespace, local_name) = iri.split_at(split_idx);
+    // NOTE: The slashes introducing the authority do not end a namespace.
+    if namespace.ends_with("//") {
+        (iri, "")
+    } else {
+        (namespace, local_name)
+    }
+}
+
+/// Whether the terms of the given namespace are separated from it by a `#`,
+/// e.g. `http://www.w3.org/2002/07/owl#`.
+#[must_use]
+pub fn is_hash_namespace(namespace: &str) -> bool {
+    namespace.ends_with('#')
+}
+
+/// Whether the terms of the given namespace are separated from it by a `/`,
+/// e.g. `http://schema.org/`.
+#[must_use]
+pub fn is_slash_namespace(namespace: &str) -> bool {
+    namespace.ends_with('/')
+}
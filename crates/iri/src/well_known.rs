@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use crate::PrefixMap;
+
+/// Commonly used prefixes, mapped to their namespaces,
+/// sorted by prefix.
+/// These mostly follow the most popular choices on <https://prefix.cc>.
+pub const WELL_KNOWN_PREFIXES: &[(&str, &str)] = &[
+    ("as", "https://www.w3.org/ns/activitystreams#"),
+    ("bibo", "http://purl.org/ontology/bibo/"),
+    ("cc", "http://creativecommons.org/ns#"),
+    ("dbo", "http://dbpedia.org/ontology/"),
+    ("dc", "http://purl.org/dc/elements/1.1/"),
+    ("dcat", "http://www.w3.org/ns/dcat#"),
+    ("dcterms", "http://purl.org/dc/terms/"),
+    ("dctype", "http://purl.org/dc/dcmitype/"),
+    ("doap", "http://usefulinc.com/ns/doap#"),
+    ("foaf", "http://xmlns.com/foaf/0.1/"),
+    ("geo", "http://www.w3.org/2003/01/geo/wgs84_pos#"),
+    ("geosparql", "http://www.opengis.net/ont/geosparql#"),
+    ("gr", "http://purl.org/goodrelations/v1#"),
+    ("ldp", "http://www.w3.org/ns/ldp#"),
+    ("oa", "http://www.w3.org/ns/oa#"),
+    ("ocaa", "https://w3id.org/oseg/ont/ocaa#"),
+    ("odrl", "http://www.w3.org/ns/odrl/2/"),
+    ("org", "http://www.w3.org/ns/org#"),
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("prov", "http://www.w3.org/ns/prov#"),
+    ("qb", "http://purl.org/linked-data/cube#"),
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("schema", "http://schema.org/"),
+    ("sh", "http://www.w3.org/ns/shacl#"),
+    ("sioc", "http://rdfs.org/sioc/ns#"),
+    ("skos", "http://www.w3.org/2004/02/skos/core#"),
+    ("skosxl", "http://www.w3.org/2008/05/skos-xl#"),
+    ("sosa", "http://www.w3.org/ns/sosa/"),
+    ("ssn", "http://www.w3.org/ns/ssn/"),
+    ("time", "http://www.w3.org/2006/time#"),
+    ("vann", "http://purl.org/vocab/vann/"),
+    ("vcard", "http://www.w3.org/2006/vcard/ns#"),
+    ("void", "http://rdfs.org/ns/void#"),
+    ("vs", "http://www.w3.org/2003/06/sw-vocab-status/ns#"),
+    ("wd", "http://www.wikidata.org/entity/"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+];
+
+/// Looks up the namespace of a well-known prefix,
+/// e.g. `http://www.w3.org/2002/07/owl#` for `owl`.
+#[must_use]
+pub fn well_known_namespace(prefix: &str) -> Option<&'static str> {
+    WELL_KNOWN_PREFIXES
+        .binary_search_by_key(&prefix, |(pf, _ns)| pf)
+        .ok()
+        .and_then(|idx| WELL_KNOWN_PREFIXES.get(idx))
+        .map(|(_pf, ns)| *ns)
+}
+
+/// Looks up the well-known prefix of a namespace,
+/// e.g. `owl` for `http://www.w3.org/2002/07/owl#`.
+#[must_use]
+pub fn well_known_prefix(namespace: &str) -> Option<&'static str> {
+    WELL_KNOWN_PREFIXES
+        .iter()
+        .find(|(_pf, ns)| *ns == namespace)
+        .map(|(pf, _ns)| *pf)
+}
+
+impl PrefixMap {
+    /// Creates a map containing all the [`WELL_KNOWN_PREFIXES`].
+    #[must_use]
+    pub fn well_known() -> Self {
+        WELL_KNOWN_PREFIXES.iter().copied().collect()
+    }
+}
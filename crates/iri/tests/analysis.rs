@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use oxrdf::{NamedNode, Subject};
+use rdfoothills_iri::{host, Iri, IriAnalysis};
+
+fn analyze(iri: &str) -> IriAnalysis {
+    IriAnalysis::new(&Iri::parse(iri.to_owned()).unwrap())
+}
+
+#[test]
+fn test_host() {
+    assert_eq!(host("w3id.org"), "w3id.org");
+    assert_eq!(host("user:pw@example.org:8080"), "example.org");
+    assert_eq!(host("[::1]:8080"), "::1");
+}
+
+#[test]
+fn test_best_practice() {
+    let analysis = analyze("https://w3id.org/oseg/ont/ocaa#");
+    assert!(analysis.all_passed());
+    assert!(analyze("http://www.w3id.org/oseg/").uses_purl);
+    assert!(analyze("http://purl.obolibrary.org/obo/").uses_purl);
+}
+
+#[test]
+fn test_bad_practice() {
+    let analysis = analyze("ftp://example.org/ont/täst.owl?version=2");
+    assert!(!analysis.uri_compatible);
+    assert!(!analysis.uses_http);
+    assert!(!analysis.uses_purl);
+    assert!(!analysis.ends_well);
+    assert!(!analysis.has_no_query);
+    assert!(!analysis.path_follows_best_practice);
+    assert!(!analyze("http://notpurl.org/ont/").uses_purl);
+    assert!(!analyze("https://w3id.org/").path_follows_best_practice);
+    assert!(!analyze("https://w3id.org/ont.ttl").path_follows_best_practice);
+}
+
+#[test]
+fn test_to_triples() {
+    let subject: Subject = NamedNode::new_unchecked("http://example.org/analysis").into();
+    let triples = analyze("https://w3id.org/ont/").to_triples(subject.as_ref());
+    assert_eq!(triples.len(), 7);
+    assert!(triples
+        .iter()
+        .skip(1)
+        .all(|triple| triple.object.to_string()
+            == "\"true\"^^<http://www.w3.org/2001/XMLSchema#boolean>"));
+}
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_iri::{is_ncname, Curie, CurieError, Iri, PrefixMap};
+
+fn prefixes() -> PrefixMap {
+    [
+        ("sh", "http://www.w3.org/ns/shacl#"),
+        ("", "http://example.org/"),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn test_ncname() {
+    assert!(is_ncname("sh"));
+    assert!(is_ncname("_x-1.y"));
+    assert!(is_ncname("été"));
+    assert!(!is_ncname(""));
+    assert!(!is_ncname("1sh"));
+    assert!(!is_ncname("-sh"));
+    assert!(!is_ncname("s:h"));
+    assert!(!is_ncname("s h"));
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        "sh:path".parse::<Curie>().unwrap(),
+        Curie::new("sh", "path")
+    );
+    assert_eq!(
+        "[sh:path]".parse::<Curie>().unwrap(),
+        Curie::new("sh", "path")
+    );
+    assert_eq!(":Thing".parse::<Curie>().unwrap(), Curie::new("", "Thing"));
+    assert_eq!("ex:".parse::<Curie>().unwrap(), Curie::new("ex", ""));
+    assert_eq!("sh:path".parse::<Curie>().unwrap().to_string(), "sh:path");
+    assert!(matches!(
+        "path".parse::<Curie>(),
+        Err(CurieError::MissingColon(_))
+    ));
+    assert!(matches!(
+        "1sh:path".parse::<Curie>(),
+        Err(CurieError::InvalidPrefix(_))
+    ));
+    assert!(matches!(
+        "sh:pa th".parse::<Curie>(),
+        Err(CurieError::InvalidReference(_))
+    ));
+}
+
+#[test]
+fn test_iri_conversion() {
+    let prefixes = prefixes();
+    let iri = Curie::new("sh", "path").to_iri(&prefixes).unwrap();
+    assert_eq!(iri.as_str(), "http://www.w3.org/ns/shacl#path");
+    assert_eq!(
+        Curie::from_iri(&iri, &prefixes),
+        Some(Curie::new("sh", "path"))
+    );
+    assert!(matches!(
+        Curie::new("foaf", "name").to_iri(&prefixes),
+        Err(CurieError::UnknownPrefix(_))
+    ));
+}
+
+#[test]
+fn test_resolve() {
+    let prefixes = prefixes();
+    assert_eq!(
+        prefixes.resolve("sh:path").unwrap().as_str(),
+        "http://www.w3.org/ns/shacl#path"
+    );
+    assert_eq!(
+        prefixes.resolve(":Thing").unwrap().as_str(),
+        "http://example.org/Thing"
+    );
+    assert_eq!(
+        prefixes.resolve("http://xmlns.com/foaf/0.1/name").unwrap(),
+        Iri::parse("http://xmlns.com/foaf/0.1/name".to_owned()).unwrap()
+    );
+    assert!(prefixes.resolve("http://exa mple.org").is_err());
+}
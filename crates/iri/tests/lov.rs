@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(feature = "lov")]
+
+use rdfoothills_iri::lov::Vocabulary;
+
+#[test]
+fn test_from_lov_json() {
+    let json = serde_json::json!({
+        "prefix": "foaf",
+        "uri": "http://xmlns.com/foaf/0.1/",
+        "nsp": "http://xmlns.com/foaf/0.1/",
+        "titles": [
+            { "value": "Vocabulaire FOAF", "lang": "fr" },
+            { "value": "Friend of a Friend vocabulary", "lang": "en" },
+        ],
+        "versions": [
+            {
+                "name": "v2014-01-14",
+                "issued": "2014-01-14T00:00:00.000Z",
+                "fileURL": "https://lov.linkeddata.es/dataset/lov/vocabs/foaf/versions/2014-01-14.n3",
+            },
+            {
+                "name": "v2010-08-09",
+                "issued": "2010-08-09T00:00:00.000Z",
+                "fileURL": "https://lov.linkeddata.es/dataset/lov/vocabs/foaf/versions/2010-08-09.n3",
+            },
+        ],
+    });
+    let vocab = Vocabulary::from_lov_json(&json).unwrap();
+    assert_eq!(vocab.prefix, "foaf");
+    assert_eq!(vocab.namespace, "http://xmlns.com/foaf/0.1/");
+    assert_eq!(
+        vocab.title.as_deref(),
+        Some("Friend of a Friend vocabulary")
+    );
+    assert_eq!(
+        vocab.latest_version_url.as_deref(),
+        Some("https://lov.linkeddata.es/dataset/lov/vocabs/foaf/versions/2014-01-14.n3")
+    );
+    assert_eq!(
+        vocab.latest_version_issued.as_deref(),
+        Some("2014-01-14T00:00:00.000Z")
+    );
+}
+
+#[test]
+fn test_from_lov_json_incomplete() {
+    let json = serde_json::json!({ "prefix": "foaf" });
+    assert_eq!(Vocabulary::from_lov_json(&json), None);
+    let json = serde_json::json!({
+        "prefix": "ex",
+        "uri": "http://example.org/",
+        "nsp": "http://example.org/",
+    });
+    let vocab = Vocabulary::from_lov_json(&json).unwrap();
+    assert_eq!(vocab.title, None);
+    assert_eq!(vocab.latest_version_url, None);
+}
@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_iri::{Iri, Normalize};
+
+fn normalized(iri: &str) -> String {
+    Iri::parse(iri.to_owned())
+        .unwrap()
+        .normalized()
+        .into_inner()
+}
+
+#[test]
+fn test_case() {
+    assert_eq!(
+        normalized("HTTP://User@Example.ORG:8080/Path/%c3%a9?Q#F"),
+        "http://User@example.org:8080/Path/%C3%A9?Q#F"
+    );
+}
+
+#[test]
+fn test_percent_encoding() {
+    assert_eq!(
+        normalized("http://example.org/%7Euser/%41%2f%2D?a=%62#%5f"),
+        "http://example.org/~user/A%2F-?a=b#_"
+    );
+    assert_eq!(normalized("http://%45xample.org/"), "http://example.org/");
+}
+
+#[test]
+fn test_dot_segments() {
+    assert_eq!(normalized("http://a/b/c/./../../g"), "http://a/g");
+    assert_eq!(normalized("http://a/b/c/.."), "http://a/b/");
+    assert_eq!(normalized("http://a/b/c/."), "http://a/b/c/");
+    assert_eq!(normalized("http://a/../../g"), "http://a/g");
+    assert_eq!(normalized("http://a/b//../c"), "http://a/b/c");
+    assert_eq!(normalized("urn:a/./b"), "urn:a/b");
+    // Relative references are left alone
+    assert_eq!(normalized("../a/./b"), "../a/./b");
+}
+
+#[test]
+fn test_equivalence() {
+    let iri_a = Iri::parse("http://Example.org/ont/../ont#%54hing".to_owned()).unwrap();
+    let iri_b = Iri::parse("http://example.org/ont#Thing".to_owned()).unwrap();
+    assert_ne!(iri_a, iri_b);
+    assert_eq!(iri_a.normalized(), iri_b.normalized());
+    assert_eq!(iri_b.normalized(), iri_b);
+}
@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_iri::{Prefix, PrefixError, PREFIX_EMPTY_ID};
+
+#[test]
+fn test_new() {
+    assert!(Prefix::new(
+        "xsd".to_owned(),
+        "http://www.w3.org/2001/XMLSchema#".to_owned()
+    )
+    .is_ok());
+    assert!(matches!(
+        Prefix::new("1x".to_owned(), "http://example.org/".to_owned()),
+        Err(PrefixError::InvalidPrefix(_))
+    ));
+    assert!(matches!(
+        Prefix::new("ex".to_owned(), "http://exa mple.org/".to_owned()),
+        Err(PrefixError::InvalidIri(_))
+    ));
+}
+
+#[test]
+fn test_base() {
+    let schema = Prefix::new("schema".to_owned(), "http://schema.org/".to_owned()).unwrap();
+    assert_eq!(schema.base().unwrap(), "http://schema.org");
+    let no_delim = Prefix::new("ex".to_owned(), "http://example.org/ont".to_owned()).unwrap();
+    assert!(matches!(no_delim.base(), Err(PrefixError::NoDelimiter(_))));
+}
+
+#[test]
+fn test_for_term() {
+    let owl = Prefix::for_term("http://www.w3.org/2002/07/owl#Class").unwrap();
+    assert_eq!(owl.prefix, "owl");
+    assert_eq!(owl.iri.as_str(), "http://www.w3.org/2002/07/owl#");
+    let unknown = Prefix::for_term("http://example.org/ont/Thing").unwrap();
+    assert_eq!(unknown.prefix_id(), PREFIX_EMPTY_ID);
+    assert_eq!(unknown.base().unwrap(), "http://example.org/ont");
+    assert!(matches!(
+        Prefix::for_term("http://example.org/ont/"),
+        Err(PrefixError::NoLocalName(_))
+    ));
+}
@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_iri::{Curie, PrefixMap};
+
+fn prefixes() -> PrefixMap {
+    [
+        ("sh", "http://www.w3.org/ns/shacl#"),
+        ("ex", "http://example.org/"),
+        ("exv", "http://example.org/vocab/"),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn test_insert() {
+    let mut prefixes = prefixes();
+    assert_eq!(prefixes.len(), 3);
+    assert_eq!(
+        prefixes.insert("ex", "http://example.com/"),
+        Some("http://example.org/".to_owned())
+    );
+    assert_eq!(prefixes.len(), 3);
+    assert_eq!(prefixes.get("ex"), Some("http://example.com/"));
+    assert_eq!(
+        prefixes
+            .iter()
+            .map(|(prefix, _)| prefix)
+            .collect::<Vec<_>>(),
+        ["ex", "exv", "sh"]
+    );
+}
+
+#[test]
+fn test_expand() {
+    let prefixes = prefixes();
+    assert_eq!(
+        prefixes.expand("sh:path").as_deref(),
+        Some("http://www.w3.org/ns/shacl#path")
+    );
+    assert_eq!(prefixes.expand("foaf:name"), None);
+    assert_eq!(prefixes.expand("path"), None);
+}
+
+#[test]
+fn test_compress() {
+    let prefixes = prefixes();
+    assert_eq!(
+        prefixes.compress("http://www.w3.org/ns/shacl#path"),
+        Some(Curie::new("sh", "path"))
+    );
+    // The longest namespace wins
+    assert_eq!(
+        prefixes.compress("http://example.org/vocab/Thing"),
+        Some(Curie::new("exv", "Thing"))
+    );
+    assert_eq!(
+        prefixes
+            .compress("http://example.org/thing")
+            .map(|curie| curie.to_string()),
+        Some("ex:thing".to_owned())
+    );
+    assert_eq!(prefixes.compress("http://xmlns.com/foaf/0.1/name"), None);
+}
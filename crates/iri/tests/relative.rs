@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_iri::{relativize, resolve, Iri, Prefix, RelativeIriError};
+
+fn iri(iri_str: &str) -> Iri {
+    Iri::parse(iri_str.to_owned()).unwrap()
+}
+
+#[test]
+fn test_resolve() {
+    let base = iri("http://example.org/a/b");
+    assert_eq!(
+        resolve(&base, "c#d").unwrap(),
+        iri("http://example.org/a/c#d")
+    );
+    assert_eq!(resolve(&base, "../x").unwrap(), iri("http://example.org/x"));
+    assert_eq!(
+        resolve(&base, "#f").unwrap(),
+        iri("http://example.org/a/b#f")
+    );
+    assert!(matches!(
+        resolve(&base, "a b"),
+        Err(RelativeIriError::Resolve { .. })
+    ));
+}
+
+#[test]
+fn test_relativize() {
+    let base = iri("http://example.org/a/");
+    let relative = relativize(&base, &iri("http://example.org/a/b#c")).unwrap();
+    assert_eq!(relative.as_str(), "b#c");
+    assert_eq!(
+        resolve(&base, &relative).unwrap(),
+        iri("http://example.org/a/b#c")
+    );
+    assert!(matches!(
+        relativize(&base, &iri("b#c")),
+        Err(RelativeIriError::NotAbsolute(_))
+    ));
+}
+
+#[test]
+fn test_prefix() {
+    let prefix = Prefix::new("schema".to_owned(), "http://schema.org/".to_owned()).unwrap();
+    let name = prefix.resolve("name").unwrap();
+    assert_eq!(name, iri("http://schema.org/name"));
+    assert_eq!(prefix.relativize(&name).unwrap().as_str(), "name");
+}
@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_iri::{is_hash_namespace, is_slash_namespace, split_term};
+
+#[test]
+fn test_split_term() {
+    assert_eq!(
+        split_term("http://example.org/ont#sub/Thing"),
+        ("http://example.org/ont#", "sub/Thing")
+    );
+    assert_eq!(
+        split_term("http://example.org/ont#"),
+        ("http://example.org/ont#", "")
+    );
+    assert_eq!(
+        split_term("http://example.org/ont/"),
+        ("http://example.org/ont/", "")
+    );
+    assert_eq!(split_term("Thing"), ("", "Thing"));
+}
+
+#[test]
+fn test_namespace_kinds() {
+    let (hash_ns, _) = split_term("http://www.w3.org/2002/07/owl#Class");
+    assert!(is_hash_namespace(hash_ns));
+    assert!(!is_slash_namespace(hash_ns));
+    let (slash_ns, _) = split_term("http://schema.org/name");
+    assert!(is_slash_namespace(slash_ns));
+    assert!(!is_hash_namespace(slash_ns));
+}
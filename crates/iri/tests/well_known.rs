@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_iri::{well_known_namespace, well_known_prefix, PrefixMap, WELL_KNOWN_PREFIXES};
+
+#[test]
+fn test_sorted_and_unique() {
+    assert!(WELL_KNOWN_PREFIXES
+        .windows(2)
+        .all(|pair| pair.first().unwrap().0 < pair.last().unwrap().0));
+    let prefixes = PrefixMap::well_known();
+    assert_eq!(prefixes.len(), WELL_KNOWN_PREFIXES.len());
+    for (_prefix, namespace) in WELL_KNOWN_PREFIXES {
+        assert!(well_known_prefix(namespace).is_some());
+    }
+}
+
+#[test]
+fn test_lookup() {
+    assert_eq!(
+        well_known_namespace("owl"),
+        Some("http://www.w3.org/2002/07/owl#")
+    );
+    assert_eq!(well_known_namespace("nope"), None);
+    assert_eq!(well_known_prefix("http://schema.org/"), Some("schema"));
+    assert_eq!(well_known_prefix("http://example.org/"), None);
+    assert_eq!(
+        PrefixMap::well_known().expand("xsd:string").as_deref(),
+        Some("http://www.w3.org/2001/XMLSchema#string")
+    );
+}
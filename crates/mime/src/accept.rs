@@ -0,0 +1,161 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::{convert::Infallible, str::FromStr};
+
+use crate::Type;
+
+const WILDCARD: &str = "*";
+/// The maximum quality value, representing `q=1`.
+pub const QUALITY_MAX: u16 = 1000;
+
+/// A single media range within an HTTP `Accept` header,
+/// for example `text/turtle`, `text/*;q=0.5` or `*/*;q=0.1`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MediaRange {
+    /// The main type in lower-case, e.g. `text` or `*`.
+    pub typ: String,
+    /// The sub-type (including a potential suffix) in lower-case,
+    /// e.g. `turtle`, `ld+json` or `*`.
+    pub sub_type: String,
+    /// The quality/weight in thousandths, `0..=1000`;
+    /// `q=0.8` is represented as `800`.
+    pub quality: u16,
+}
+
+impl MediaRange {
+    fn parse(entry: &str) -> Option<Self> {
+        let mut parts = entry.split(';');
+        let (typ, sub_type) = parts.next()?.trim().split_once('/')?;
+        if typ.is_empty() || sub_type.is_empty() || (typ == WILDCARD && sub_type != WILDCARD) {
+            return None;
+        }
+        let mut quality = QUALITY_MAX;
+        for param in parts {
+            if let Some((name, value)) = param.split_once('=') {
+                if name.trim().eq_ignore_ascii_case("q") {
+                    quality = parse_quality(value.trim())?;
+                }
+            }
+        }
+        Some(Self {
+            typ: typ.to_lowercase(),
+            sub_type: sub_type.to_lowercase(),
+            quality,
+        })
+    }
+
+    /// How specific this range is;
+    /// `*/*` -> 0, `text/*` -> 1, `text/turtle` -> 2.
+    #[must_use]
+    pub fn specificity(&self) -> u8 {
+        u8::from(self.typ != WILDCARD) + u8::from(self.sub_type != WILDCARD)
+    }
+
+    /// Whether this range includes the given MIME type
+    /// of the form `"<type>/<subtype>"`.
+    #[must_use]
+    pub fn matches(&self, mime_type: &str) -> bool {
+        mime_type.split_once('/').is_some_and(|(typ, sub_type)| {
+            (self.typ == WILDCARD || self.typ.eq_ignore_ascii_case(typ))
+                && (self.sub_type == WILDCARD || self.sub_type.eq_ignore_ascii_case(sub_type))
+        })
+    }
+}
+
+/// Parses a quality value like `0.8` into thousandths (`800`).
+fn parse_quality(value: &str) -> Option<u16> {
+    let (int_part, frac_part) = value.split_once('.').unwrap_or((value, ""));
+    if frac_part.len() > 3 || !frac_part.chars().all(|chr| chr.is_ascii_digit()) {
+        return None;
+    }
+    let int: u16 = match int_part {
+        "0" => 0,
+        "1" => 1,
+        _ => return None,
+    };
+    let frac: u16 = if frac_part.is_empty() {
+        0
+    } else {
+        format!("{frac_part:0<3}").parse().ok()?
+    };
+    let quality = int * QUALITY_MAX + frac;
+    (quality <= QUALITY_MAX).then_some(quality)
+}
+
+/// The parsed content of an HTTP `Accept` header.
+///
+/// A real world example from Firefox:
+/// `text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,image/png,image/svg+xml,*/*;q=0.8`
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct AcceptHeader {
+    /// The media ranges in order of appearance.
+    pub ranges: Vec<MediaRange>,
+}
+
+impl FromStr for AcceptHeader {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s))
+    }
+}
+
+impl AcceptHeader {
+    /// Parses the value of an HTTP `Accept` header.
+    /// Invalid entries are silently skipped.
+    #[must_use]
+    pub fn parse(header: &str) -> Self {
+        Self {
+            ranges: header.split(',').filter_map(MediaRange::parse).collect(),
+        }
+    }
+
+    /// Returns the quality (in thousandths) with which the given type is accepted,
+    /// or `None` if it is not accepted at all.
+    ///
+    /// For each of the MIME types of `typ`,
+    /// the most specific matching range decides the quality.
+    #[must_use]
+    pub fn quality(&self, typ: Type) -> Option<u16> {
+        typ.mime_types()
+            .iter()
+            .filter_map(|mime_type| {
+                self.ranges
+                    .iter()
+                    .filter(|range| range.matches(mime_type))
+                    .max_by_key(|range| range.specificity())
+                    .map(|range| range.quality)
+            })
+            .max()
+            .filter(|quality| *quality > 0)
+    }
+
+    /// Whether the given type is accepted (with a quality above zero).
+    #[must_use]
+    pub fn accepts(&self, typ: Type) -> bool {
+        self.quality(typ).is_some()
+    }
+}
+
+/// Chooses the type the client prefers most
+/// out of the ones we have `available`.
+///
+/// On equal quality, the type listed first in `available` wins,
+/// so it should be ordered by the server's preference
+/// (e.g. cheapest to deliver first).
+///
+/// Returns `None` if none of the `available` types is accepted.
+#[must_use]
+pub fn choose_best(available: &[Type], accepted: &AcceptHeader) -> Option<Type> {
+    let mut best: Option<(Type, u16)> = None;
+    for typ in available {
+        if let Some(quality) = accepted.quality(*typ) {
+            if best.is_none_or(|(_, best_quality)| quality > best_quality) {
+                best = Some((*typ, quality));
+            }
+        }
+    }
+    best.map(|(typ, _)| typ)
+}
@@ -2,8 +2,29 @@
 //
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
+mod accept;
 mod mime;
+#[cfg(feature = "oxrdf")]
+mod oxrdf;
+#[cfg(feature = "oxrdfio")]
+mod oxrdfio;
+mod params;
+mod registry;
+#[cfg(feature = "serde")]
+pub mod serde_mime_type;
+pub mod tool_names;
+#[cfg(feature = "url")]
+mod url;
+pub use accept::*;
 pub use mime::*;
+pub use params::*;
+pub use registry::*;
+#[cfg(feature = "url")]
+pub use url::*;
+
+// Only used by the integration tests.
+#[cfg(test)]
+use serde_json as _;
 
 // This tests rust code in the README with doc-tests.
 // Though, It will not appear in the generated documentaton.
@@ -6,7 +6,6 @@ use mediatype::{
     names::{APPLICATION, TEXT},
     MediaType,
 };
-use once_cell::sync::Lazy;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::{
@@ -15,11 +14,12 @@ use std::{
     fmt::Display,
     path::{Path as StdPath, PathBuf},
 };
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, str::FromStr, sync::LazyLock};
 use thiserror::Error;
 #[cfg(feature = "async")]
 use tokio::fs;
 
+use crate::{CustomType, Registry};
 use rdfoothills_base::hasher;
 
 #[derive(Error, Debug)]
@@ -47,8 +47,15 @@ pub enum ParseError {
 
     #[error("File content is not recognized at all")]
     UnidentifiedContent,
+
+    #[error("Neither the path nor the query of the URL '{0}' indicate a known type")]
+    UnrecognizedUrl(String),
 }
 
+/// The namespace of the W3C [Unique URIs for File Formats](
+/// https://www.w3.org/ns/formats/) registry.
+pub const NS_BASE_FORMATS: &str = "http://www.w3.org/ns/formats/";
+
 const MIME_TYPE_BINARY_RDF: &str = "application/x-binary-rdf";
 const MIME_TYPE_CSVW: &str = "text/csv";
 // const MIME_TYPE_HDT: &str = "NONE"; // See <https://www.w3.org/submissions/2011/SUBM-HDT-20110330/#media>: "The media type of HDT is the media type of their parts"
@@ -70,6 +77,10 @@ const MIME_TYPE_OWL_XML: &str = "application/owl+xml";
 const MIME_TYPE_RDF_A: &str = "text/html";
 const MIME_TYPE_RDF_JSON: &str = "application/rdf+json";
 const MIME_TYPE_RDF_XML: &str = "application/rdf+xml";
+const MIME_TYPE_SPARQL_RESULTS_CSV: &str = "text/csv";
+const MIME_TYPE_SPARQL_RESULTS_JSON: &str = "application/sparql-results+json";
+const MIME_TYPE_SPARQL_RESULTS_TSV: &str = "text/tab-separated-values";
+const MIME_TYPE_SPARQL_RESULTS_XML: &str = "application/sparql-results+xml";
 const MIME_TYPE_TRIG: &str = "text/trig";
 const MIME_TYPE_TRIG_STAR: &str = "application/x-trigstar";
 const MIME_TYPE_TRIX: &str = "application/trix";
@@ -159,6 +170,23 @@ const MEDIA_TYPE_RDF_XML: MediaType = MediaType::from_parts(
 );
 const MEDIA_TYPE_RDF_XML_2: MediaType = MediaType::new(APPLICATION, mediatype::names::XML);
 const MEDIA_TYPE_RDF_XML_3: MediaType = MediaType::new(TEXT, mediatype::names::XML);
+// NOTE CSV and TSV results share their media types with CSVW and TSVW,
+//      so they can not be identified by media type alone.
+const MEDIA_TYPE_SPARQL_RESULTS_CSV: MediaType = MediaType::new(TEXT, mediatype::names::CSV);
+const MEDIA_TYPE_SPARQL_RESULTS_JSON: MediaType = MediaType::from_parts(
+    APPLICATION,
+    mediatype::names::SPARQL_RESULTS,
+    Some(mediatype::names::JSON),
+    &[],
+);
+const MEDIA_TYPE_SPARQL_RESULTS_TSV: MediaType =
+    MediaType::new(TEXT, mediatype::names::TAB_SEPARATED_VALUES);
+const MEDIA_TYPE_SPARQL_RESULTS_XML: MediaType = MediaType::from_parts(
+    APPLICATION,
+    mediatype::names::SPARQL_RESULTS,
+    Some(mediatype::names::XML),
+    &[],
+);
 const MEDIA_TYPE_TRIG: MediaType = MediaType::new(APPLICATION, mediatype::names::TRIG);
 const MEDIA_TYPE_TRIG_2: MediaType =
     MediaType::new(APPLICATION, mediatype::Name::new_unchecked("x-trig"));
@@ -206,6 +234,8 @@ const FEXT_RDF_JSON: &str = "rj";
 const FEXT_RDF_XML: &str = "rdf";
 const FEXT_RDF_XML_2: &str = "rdfs";
 const FEXT_RDF_XML_3: &str = "owl";
+const FEXT_SPARQL_RESULTS_JSON: &str = "srj";
+const FEXT_SPARQL_RESULTS_XML: &str = "srx";
 const FEXT_TRIG: &str = "trig";
 const FEXT_TRIG_STAR: &str = "trigs";
 const FEXT_TRIX: &str = "trix";
@@ -235,6 +265,10 @@ const FEXTS_OWL_FUNCTIONAL: &[&str] = &[FEXT_OWL_FUNCTIONAL];
 const FEXTS_RDF_A: &[&str] = &[FEXT_HTML, FEXT_XHTML, FEXT_HTML_2];
 const FEXTS_RDF_JSON: &[&str] = &[FEXT_RDF_JSON];
 const FEXTS_RDF_XML: &[&str] = &[FEXT_RDF_XML, FEXT_RDF_XML_2, FEXT_RDF_XML_3, FEXT_XML];
+const FEXTS_SPARQL_RESULTS_CSV: &[&str] = &[FEXT_CSV];
+const FEXTS_SPARQL_RESULTS_JSON: &[&str] = &[FEXT_SPARQL_RESULTS_JSON];
+const FEXTS_SPARQL_RESULTS_TSV: &[&str] = &[FEXT_TSV];
+const FEXTS_SPARQL_RESULTS_XML: &[&str] = &[FEXT_SPARQL_RESULTS_XML];
 const FEXTS_TRIG: &[&str] = &[FEXT_TRIG];
 const FEXTS_TRIG_STAR: &[&str] = &[FEXT_TRIG_STAR];
 const FEXTS_TRIX: &[&str] = &[FEXT_TRIX, FEXT_XML];
@@ -243,12 +277,14 @@ const FEXTS_TURTLE: &[&str] = &[FEXT_TURTLE];
 const FEXTS_TURTLE_STAR: &[&str] = &[FEXT_TURTLE_STAR];
 const FEXTS_YAML_LD: &[&str] = &[FEXT_YAML_LD, FEXT_YAML_LD_2];
 
+/// Identifies the type from the given media type,
+/// ignoring its parameters.
 pub fn media_type2type(media_type: &MediaType) -> Option<Type> {
-    let search_hash = hasher::hash_num(media_type);
+    let search_hash = hasher::hash_num(media_type.essence());
     MEDIA_TYPE_2_MIME.get(&search_hash).copied()
 }
 
-pub static MEDIA_TYPE_2_MIME: Lazy<HashMap<u64, Type>> = Lazy::new(|| {
+pub static MEDIA_TYPE_2_MIME: LazyLock<HashMap<u64, Type>> = LazyLock::new(|| {
     vec![
         (MEDIA_TYPE_BINARY_RDF, Type::BinaryRdf),
         (MEDIA_TYPE_CSVW, Type::Csvw),
@@ -277,6 +313,10 @@ pub static MEDIA_TYPE_2_MIME: Lazy<HashMap<u64, Type>> = Lazy::new(|| {
         (MEDIA_TYPE_RDF_XML, Type::RdfXml),
         (MEDIA_TYPE_RDF_XML_2, Type::RdfXml),
         (MEDIA_TYPE_RDF_XML_3, Type::RdfXml),
+        // (MEDIA_TYPE_SPARQL_RESULTS_CSV, Type::SparqlResultsCsv), // NOTE Same as CSVW
+        (MEDIA_TYPE_SPARQL_RESULTS_JSON, Type::SparqlResultsJson),
+        // (MEDIA_TYPE_SPARQL_RESULTS_TSV, Type::SparqlResultsTsv), // NOTE Same as TSVW
+        (MEDIA_TYPE_SPARQL_RESULTS_XML, Type::SparqlResultsXml),
         (MEDIA_TYPE_TRIG, Type::TriG),
         (MEDIA_TYPE_TRIG_2, Type::TriG),
         (MEDIA_TYPE_TRIG_STAR, Type::TriGStar),
@@ -293,7 +333,46 @@ pub static MEDIA_TYPE_2_MIME: Lazy<HashMap<u64, Type>> = Lazy::new(|| {
     .collect()
 });
 
-/// The different mime-types of RDF serialization formats.
+/// Meta-data about a single [`Type`],
+/// collected from its individual accessor functions.
+///
+/// This is useful for enumerating all the supported formats,
+/// for example to generate help texts or capability listings.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TypeInfo {
+    pub typ: Type,
+    /// See [`Type::name`].
+    pub name: &'static str,
+    /// See [`Type::mime_types`].
+    pub mime_types: &'static [&'static str],
+    /// See [`Type::file_exts`].
+    pub file_exts: &'static [&'static str],
+    /// See [`Type::is_machine_readable`].
+    pub machine_readable: bool,
+    /// See [`Type::star`].
+    pub star: bool,
+    /// See [`Type::is_dataset_format`].
+    pub dataset: bool,
+    /// See [`Type::is_binary`].
+    pub binary: bool,
+    /// See [`Type::is_owl_syntax`].
+    pub owl_syntax: bool,
+    /// See [`Type::standard_definition_url`].
+    pub standard_definition_url: &'static str,
+}
+
+/// The meta-data of all the known types,
+/// in the same order as [`Type::all`].
+pub static TYPE_INFOS: LazyLock<Vec<TypeInfo>> =
+    LazyLock::new(|| Type::all().iter().map(|typ| typ.info()).collect());
+
+/// The different mime-types of RDF serialization formats
+/// (plus HTML and the SPARQL query results formats).
+///
+/// NOTE: The `serde` derive (de-)serializes the variant names;
+/// use [`crate::serde_mime_type`] to use MIME type strings instead.
 #[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Type {
@@ -316,6 +395,10 @@ pub enum Type {
     RdfA,
     RdfJson,
     RdfXml,
+    SparqlResultsCsv,
+    SparqlResultsJson,
+    SparqlResultsTsv,
+    SparqlResultsXml,
     TriG,
     TriGStar,
     TriX,
@@ -323,6 +406,14 @@ pub enum Type {
     Turtle,
     TurtleStar,
     YamlLd,
+    /// A format registered at runtime, see [`crate::Registry`].
+    ///
+    /// NOTE: The `serde` derive skips this variant,
+    /// so serializing it fails with an error,
+    /// and it is never deserialized.
+    /// Use [`crate::serde_mime_type`] where registered types may occur.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Other(&'static CustomType),
 }
 
 impl FromStr for Type {
@@ -359,6 +450,60 @@ impl Type {
         vec![Self::Html, Self::JsonLd, Self::RdfXml, Self::Turtle]
     }
 
+    /// All the built-in types, in alphabetical order.
+    /// See [`Registry::registered`] for the custom ones.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[
+            Self::BinaryRdf,
+            Self::Csvw,
+            Self::Hdt,
+            Self::HexTuples,
+            Self::Html,
+            Self::JsonLd,
+            Self::Microdata,
+            Self::N3,
+            Self::NdJsonLd,
+            Self::NQuads,
+            Self::NQuadsStar,
+            Self::NTriples,
+            Self::NTriplesStar,
+            Self::OwlFunctional,
+            Self::OwlXml,
+            Self::RdfA,
+            Self::RdfJson,
+            Self::RdfXml,
+            Self::SparqlResultsCsv,
+            Self::SparqlResultsJson,
+            Self::SparqlResultsTsv,
+            Self::SparqlResultsXml,
+            Self::TriG,
+            Self::TriGStar,
+            Self::TriX,
+            Self::Tsvw,
+            Self::Turtle,
+            Self::TurtleStar,
+            Self::YamlLd,
+        ]
+    }
+
+    /// Collects all the meta-data of this type into one struct.
+    #[must_use]
+    pub const fn info(self) -> TypeInfo {
+        TypeInfo {
+            typ: self,
+            name: self.name(),
+            mime_types: self.mime_types(),
+            file_exts: self.file_exts(),
+            machine_readable: self.is_machine_readable(),
+            star: self.star(),
+            dataset: self.is_dataset_format(),
+            binary: self.is_binary(),
+            owl_syntax: self.is_owl_syntax(),
+            standard_definition_url: self.standard_definition_url(),
+        }
+    }
+
     /// Tries to parse a MIME type of the form `"<type>/<subtype>"`,
     /// for example `"text/turtle"`,
     /// as a known RDF serialization format type.
@@ -389,6 +534,7 @@ impl Type {
             return Err(ParseError::CouldBeAny(media_type.to_string()));
         }
         media_type2type(media_type)
+            .or_else(|| Registry::media_type2type(media_type))
             .ok_or_else(|| ParseError::UnrecognizedContentType(media_type.to_string()))
     }
 
@@ -398,7 +544,14 @@ impl Type {
     ///
     /// Will return `ParseError::UnrecognizedFileExtension` if the extension is not supported.
     pub fn from_file_ext(file_ext: &str) -> Result<Self, ParseError> {
-        Ok(match file_ext.to_lowercase().as_str() {
+        let file_ext_lower = file_ext.to_lowercase();
+        Self::from_builtin_file_ext(&file_ext_lower)
+            .or_else(|| Registry::file_ext2type(&file_ext_lower))
+            .ok_or_else(|| ParseError::UnrecognizedFileExtension(file_ext.to_string()))
+    }
+
+    pub(crate) fn from_builtin_file_ext(file_ext_lower: &str) -> Option<Self> {
+        Some(match file_ext_lower {
             FEXT_BINARY_RDF => Self::BinaryRdf,
             FEXT_CSVW | FEXT_CSV => Self::Csvw,
             FEXT_HDT => Self::Hdt,
@@ -415,6 +568,8 @@ impl Type {
             FEXT_OWL_XML => Self::OwlXml,
             FEXT_RDF_JSON => Self::RdfJson,
             FEXT_RDF_XML | FEXT_RDF_XML_2 | FEXT_RDF_XML_3 | FEXT_XML => Self::RdfXml,
+            FEXT_SPARQL_RESULTS_JSON => Self::SparqlResultsJson,
+            FEXT_SPARQL_RESULTS_XML => Self::SparqlResultsXml,
             FEXT_TRIG => Self::TriG,
             FEXT_TRIG_STAR => Self::TriGStar,
             FEXT_TRIX => Self::TriX,
@@ -422,7 +577,7 @@ impl Type {
             FEXT_TURTLE => Self::Turtle,
             FEXT_TURTLE_STAR => Self::TurtleStar,
             FEXT_YAML_LD | FEXT_YAML_LD_2 => Self::YamlLd,
-            _ => return Err(ParseError::UnrecognizedFileExtension(file_ext.to_string())),
+            _ => return None,
         })
     }
 
@@ -517,6 +672,10 @@ impl Type {
             Self::RdfA => MIME_TYPE_RDF_A,
             Self::RdfJson => MIME_TYPE_RDF_JSON,
             Self::RdfXml | Self::Hdt => MIME_TYPE_RDF_XML, // See <https://www.w3.org/submissions/2011/SUBM-HDT-20110330/#media>: "The media type of HDT is the media type of their parts. The Header SHOULD be represented in an RDF syntax. The normative format of the Header is [RDF/XML]"
+            Self::SparqlResultsCsv => MIME_TYPE_SPARQL_RESULTS_CSV,
+            Self::SparqlResultsJson => MIME_TYPE_SPARQL_RESULTS_JSON,
+            Self::SparqlResultsTsv => MIME_TYPE_SPARQL_RESULTS_TSV,
+            Self::SparqlResultsXml => MIME_TYPE_SPARQL_RESULTS_XML,
             Self::TriG => MIME_TYPE_TRIG,
             Self::TriGStar => MIME_TYPE_TRIG_STAR,
             Self::TriX => MIME_TYPE_TRIX,
@@ -524,6 +683,7 @@ impl Type {
             Self::Turtle => MIME_TYPE_TURTLE,
             Self::TurtleStar => MIME_TYPE_TURTLE_STAR,
             Self::YamlLd => MIME_TYPE_YAML_LD,
+            Self::Other(custom) => custom.mime_type,
         }
     }
 
@@ -548,6 +708,10 @@ impl Type {
             Self::RdfA => &[MIME_TYPE_RDF_A],
             Self::RdfJson => &[MIME_TYPE_RDF_JSON],
             Self::RdfXml | Self::Hdt => &[MIME_TYPE_RDF_XML], // See <https://www.w3.org/submissions/2011/SUBM-HDT-20110330/#media>: "The media type of HDT is the media type of their parts. The Header SHOULD be represented in an RDF syntax. The normative format of the Header is [RDF/XML]"
+            Self::SparqlResultsCsv => &[MIME_TYPE_SPARQL_RESULTS_CSV],
+            Self::SparqlResultsJson => &[MIME_TYPE_SPARQL_RESULTS_JSON],
+            Self::SparqlResultsTsv => &[MIME_TYPE_SPARQL_RESULTS_TSV],
+            Self::SparqlResultsXml => &[MIME_TYPE_SPARQL_RESULTS_XML],
             Self::TriG => &[MIME_TYPE_TRIG],
             Self::TriGStar => &[MIME_TYPE_TRIG_STAR],
             Self::TriX => &[MIME_TYPE_TRIX],
@@ -555,6 +719,7 @@ impl Type {
             Self::Turtle => &[MIME_TYPE_TURTLE],
             Self::TurtleStar => &[MIME_TYPE_TURTLE_STAR, MIME_TYPE_TURTLE_STAR_2],
             Self::YamlLd => &[MIME_TYPE_YAML_LD],
+            Self::Other(custom) => custom.mime_types,
         }
     }
 
@@ -580,6 +745,10 @@ impl Type {
             Self::RdfA => MEDIA_TYPE_RDF_A,
             Self::RdfJson => MEDIA_TYPE_RDF_JSON,
             Self::RdfXml | Self::Hdt => MEDIA_TYPE_RDF_XML, // See <https://www.w3.org/submissions/2011/SUBM-HDT-20110330/#media>: "The media type of HDT is the media type of their parts. The Header SHOULD be represented in an RDF syntax. The normative format of the Header is [RDF/XML]"
+            Self::SparqlResultsCsv => MEDIA_TYPE_SPARQL_RESULTS_CSV,
+            Self::SparqlResultsJson => MEDIA_TYPE_SPARQL_RESULTS_JSON,
+            Self::SparqlResultsTsv => MEDIA_TYPE_SPARQL_RESULTS_TSV,
+            Self::SparqlResultsXml => MEDIA_TYPE_SPARQL_RESULTS_XML,
             Self::TriG => MEDIA_TYPE_TRIG,
             Self::TriGStar => MEDIA_TYPE_TRIG_STAR,
             Self::TriX => MEDIA_TYPE_TRIX,
@@ -587,6 +756,12 @@ impl Type {
             Self::Turtle => MEDIA_TYPE_TURTLE,
             Self::TurtleStar => MEDIA_TYPE_TURTLE_STAR,
             Self::YamlLd => MEDIA_TYPE_YAML_LD,
+            Self::Other(custom) => MediaType::from_parts(
+                custom.media_type.ty,
+                custom.media_type.subty,
+                custom.media_type.suffix,
+                &[],
+            ),
         }
     }
 
@@ -611,6 +786,10 @@ impl Type {
             Self::OwlXml => FEXT_OWL_XML,
             Self::RdfJson => FEXT_RDF_JSON,
             Self::RdfXml => FEXT_RDF_XML,
+            Self::SparqlResultsCsv => FEXT_CSV,
+            Self::SparqlResultsJson => FEXT_SPARQL_RESULTS_JSON,
+            Self::SparqlResultsTsv => FEXT_TSV,
+            Self::SparqlResultsXml => FEXT_SPARQL_RESULTS_XML,
             Self::TriG => FEXT_TRIG,
             Self::TriGStar => FEXT_TRIG_STAR,
             Self::TriX => FEXT_TRIX,
@@ -618,6 +797,7 @@ impl Type {
             Self::Turtle => FEXT_TURTLE,
             Self::TurtleStar => FEXT_TURTLE_STAR,
             Self::YamlLd => FEXT_YAML_LD,
+            Self::Other(custom) => custom.file_ext,
         }
     }
 
@@ -644,6 +824,10 @@ impl Type {
             Self::RdfA => FEXTS_RDF_A,
             Self::RdfJson => FEXTS_RDF_JSON,
             Self::RdfXml => FEXTS_RDF_XML,
+            Self::SparqlResultsCsv => FEXTS_SPARQL_RESULTS_CSV,
+            Self::SparqlResultsJson => FEXTS_SPARQL_RESULTS_JSON,
+            Self::SparqlResultsTsv => FEXTS_SPARQL_RESULTS_TSV,
+            Self::SparqlResultsXml => FEXTS_SPARQL_RESULTS_XML,
             Self::TriG => FEXTS_TRIG,
             Self::TriGStar => FEXTS_TRIG_STAR,
             Self::TriX => FEXTS_TRIX,
@@ -651,6 +835,7 @@ impl Type {
             Self::Turtle => FEXTS_TURTLE,
             Self::TurtleStar => FEXTS_TURTLE_STAR,
             Self::YamlLd => FEXTS_YAML_LD,
+            Self::Other(custom) => custom.file_exts,
         }
     }
 
@@ -676,6 +861,10 @@ impl Type {
             Self::RdfA => "RDFa",
             Self::RdfJson => "RDF/JSON",
             Self::RdfXml => "RDF/XML",
+            Self::SparqlResultsCsv => "SPARQL-Results-CSV",
+            Self::SparqlResultsJson => "SPARQL-Results-JSON",
+            Self::SparqlResultsTsv => "SPARQL-Results-TSV",
+            Self::SparqlResultsXml => "SPARQL-Results-XML",
             Self::TriG => "TriG",
             Self::TriGStar => "TriG-star",
             Self::TriX => "TriX",
@@ -683,6 +872,7 @@ impl Type {
             Self::Turtle => "Turtle",
             Self::TurtleStar => "Turtle-star",
             Self::YamlLd => "YAML-LD",
+            Self::Other(custom) => custom.name,
         }
     }
 
@@ -709,6 +899,10 @@ impl Type {
             | Self::RdfA
             | Self::RdfJson
             | Self::RdfXml
+            | Self::SparqlResultsCsv
+            | Self::SparqlResultsJson
+            | Self::SparqlResultsTsv
+            | Self::SparqlResultsXml
             | Self::TriG
             | Self::TriGStar
             | Self::TriX
@@ -716,6 +910,168 @@ impl Type {
             | Self::Turtle
             | Self::TurtleStar
             | Self::YamlLd => true,
+            Self::Other(custom) => custom.machine_readable,
+        }
+    }
+
+    /// Whether the format supports named graphs (RDF datasets),
+    /// vs only a single (default) graph.
+    ///
+    /// When converting from a dataset format to a non-dataset format,
+    /// the graph names get lost.
+    #[must_use]
+    pub const fn is_dataset_format(self) -> bool {
+        match self {
+            Self::BinaryRdf
+            | Self::HexTuples
+            | Self::JsonLd
+            | Self::NdJsonLd
+            | Self::NQuads
+            | Self::NQuadsStar
+            | Self::TriG
+            | Self::TriGStar
+            | Self::TriX
+            | Self::YamlLd => true,
+            Self::Csvw
+            | Self::Hdt
+            | Self::Html
+            | Self::Microdata
+            | Self::N3
+            | Self::NTriples
+            | Self::NTriplesStar
+            | Self::OwlFunctional
+            | Self::OwlXml
+            | Self::RdfA
+            | Self::RdfJson
+            | Self::RdfXml
+            | Self::SparqlResultsCsv
+            | Self::SparqlResultsJson
+            | Self::SparqlResultsTsv
+            | Self::SparqlResultsXml
+            | Self::Tsvw
+            | Self::Turtle
+            | Self::TurtleStar => false,
+            Self::Other(custom) => custom.dataset,
+        }
+    }
+
+    /// Whether the format is binary,
+    /// vs textual (see [`Self::is_textual`]).
+    #[must_use]
+    pub const fn is_binary(self) -> bool {
+        match self {
+            Self::BinaryRdf | Self::Hdt => true,
+            Self::Csvw
+            | Self::HexTuples
+            | Self::Html
+            | Self::JsonLd
+            | Self::Microdata
+            | Self::N3
+            | Self::NdJsonLd
+            | Self::NQuads
+            | Self::NQuadsStar
+            | Self::NTriples
+            | Self::NTriplesStar
+            | Self::OwlFunctional
+            | Self::OwlXml
+            | Self::RdfA
+            | Self::RdfJson
+            | Self::RdfXml
+            | Self::SparqlResultsCsv
+            | Self::SparqlResultsJson
+            | Self::SparqlResultsTsv
+            | Self::SparqlResultsXml
+            | Self::TriG
+            | Self::TriGStar
+            | Self::TriX
+            | Self::Tsvw
+            | Self::Turtle
+            | Self::TurtleStar
+            | Self::YamlLd => false,
+            Self::Other(custom) => custom.binary,
+        }
+    }
+
+    /// Whether the format is textual,
+    /// which means it may be processed line-/stream-wise as text.
+    #[must_use]
+    pub const fn is_textual(self) -> bool {
+        !self.is_binary()
+    }
+
+    /// Whether this is one of the OWL specific syntaxes
+    /// (vs a generic RDF serialization format).
+    #[must_use]
+    pub const fn is_owl_syntax(self) -> bool {
+        match self {
+            Self::OwlFunctional | Self::OwlXml => true,
+            Self::BinaryRdf
+            | Self::Csvw
+            | Self::Hdt
+            | Self::HexTuples
+            | Self::Html
+            | Self::JsonLd
+            | Self::Microdata
+            | Self::N3
+            | Self::NdJsonLd
+            | Self::NQuads
+            | Self::NQuadsStar
+            | Self::NTriples
+            | Self::NTriplesStar
+            | Self::RdfA
+            | Self::RdfJson
+            | Self::RdfXml
+            | Self::SparqlResultsCsv
+            | Self::SparqlResultsJson
+            | Self::SparqlResultsTsv
+            | Self::SparqlResultsXml
+            | Self::TriG
+            | Self::TriGStar
+            | Self::TriX
+            | Self::Tsvw
+            | Self::Turtle
+            | Self::TurtleStar
+            | Self::YamlLd
+            | Self::Other(_) => false,
+        }
+    }
+
+    /// Returns the IRI of the format in the W3C [Unique URIs for File Formats](
+    /// https://www.w3.org/ns/formats/) registry,
+    /// if it is listed there.
+    #[must_use]
+    pub const fn formats_iri(self) -> Option<&'static str> {
+        match self {
+            Self::JsonLd => Some("http://www.w3.org/ns/formats/JSON-LD"),
+            Self::Microdata => Some("http://www.w3.org/ns/formats/microdata"),
+            Self::N3 => Some("http://www.w3.org/ns/formats/N3"),
+            Self::NQuads => Some("http://www.w3.org/ns/formats/N-Quads"),
+            Self::NTriples => Some("http://www.w3.org/ns/formats/N-Triples"),
+            Self::OwlFunctional => Some("http://www.w3.org/ns/formats/OWL_Functional"),
+            Self::OwlXml => Some("http://www.w3.org/ns/formats/OWL_XML"),
+            Self::RdfA => Some("http://www.w3.org/ns/formats/RDFa"),
+            Self::RdfJson => Some("http://www.w3.org/ns/formats/RDF_JSON"),
+            Self::RdfXml => Some("http://www.w3.org/ns/formats/RDF_XML"),
+            Self::SparqlResultsCsv => Some("http://www.w3.org/ns/formats/SPARQL_Results_CSV"),
+            Self::SparqlResultsJson => Some("http://www.w3.org/ns/formats/SPARQL_Results_JSON"),
+            Self::SparqlResultsTsv => Some("http://www.w3.org/ns/formats/SPARQL_Results_TSV"),
+            Self::SparqlResultsXml => Some("http://www.w3.org/ns/formats/SPARQL_Results_XML"),
+            Self::TriG => Some("http://www.w3.org/ns/formats/TriG"),
+            Self::Turtle => Some("http://www.w3.org/ns/formats/Turtle"),
+            Self::BinaryRdf
+            | Self::Csvw
+            | Self::Hdt
+            | Self::HexTuples
+            | Self::Html
+            | Self::NdJsonLd
+            | Self::NQuadsStar
+            | Self::NTriplesStar
+            | Self::TriGStar
+            | Self::TriX
+            | Self::Tsvw
+            | Self::TurtleStar
+            | Self::YamlLd
+            | Self::Other(_) => None,
         }
     }
 
@@ -745,6 +1101,10 @@ impl Type {
             Self::RdfA => "https://www.w3.org/2001/sw/wiki/RDFa",
             Self::RdfJson => "http://www.w3.org/ns/formats/RDF_JSON",
             Self::RdfXml => "http://www.w3.org/ns/formats/RDF_XML",
+            Self::SparqlResultsCsv => "http://www.w3.org/ns/formats/SPARQL_Results_CSV",
+            Self::SparqlResultsJson => "http://www.w3.org/ns/formats/SPARQL_Results_JSON",
+            Self::SparqlResultsTsv => "http://www.w3.org/ns/formats/SPARQL_Results_TSV",
+            Self::SparqlResultsXml => "http://www.w3.org/ns/formats/SPARQL_Results_XML",
             Self::TriG => "http://www.w3.org/ns/formats/TriG",
             Self::TriGStar => "https://w3c.github.io/rdf-star/cg-spec/editors_draft.html#trig-star",
             Self::TriX => "https://en.wikipedia.org/wiki/TriX_(serialization_format)",
@@ -755,6 +1115,7 @@ impl Type {
             Self::YamlLd => {
                 "https://www.w3.org/community/reports/json-ld/CG-FINAL-yaml-ld-20231206/"
             }
+            Self::Other(custom) => custom.standard_definition_url,
         }
     }
 
@@ -762,7 +1123,11 @@ impl Type {
     #[must_use]
     pub const fn star(self) -> bool {
         match self {
-            Self::BinaryRdf | Self::NTriplesStar | Self::TriGStar | Self::TurtleStar => true,
+            Self::BinaryRdf
+            | Self::NQuadsStar
+            | Self::NTriplesStar
+            | Self::TriGStar
+            | Self::TurtleStar => true,
             Self::Csvw
             | Self::Hdt
             | Self::HexTuples
@@ -772,18 +1137,22 @@ impl Type {
             | Self::N3
             | Self::NdJsonLd
             | Self::NQuads
-            | Self::NQuadsStar
             | Self::NTriples
             | Self::OwlFunctional
             | Self::OwlXml
             | Self::RdfA
             | Self::RdfJson
             | Self::RdfXml
+            | Self::SparqlResultsCsv
+            | Self::SparqlResultsJson
+            | Self::SparqlResultsTsv
+            | Self::SparqlResultsXml
             | Self::TriG
             | Self::TriX
             | Self::Tsvw
             | Self::Turtle
             | Self::YamlLd => false,
+            Self::Other(custom) => custom.star,
         }
     }
 
@@ -792,8 +1161,4 @@ impl Type {
     pub fn is_default(self) -> bool {
         self == Self::default()
     }
-
-    // pub fn rdf_literal(self) -> Term {
-    //     Term::Literal(Literal::new_simple_literal(self.mime_type)),
-    // }
 }
@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use oxrdf::{Literal, NamedNodeRef, Term};
+
+use crate::Type;
+
+impl Type {
+    /// Returns the (primary) MIME type as an RDF literal,
+    /// for example to be used as the object of `dcterms:format`.
+    #[must_use]
+    pub fn rdf_literal(self) -> Term {
+        Term::Literal(Literal::new_simple_literal(self.mime_type()))
+    }
+
+    /// Returns the IRI of the format in the W3C [Unique URIs for File Formats](
+    /// https://www.w3.org/ns/formats/) registry as an RDF node,
+    /// if it is listed there.
+    /// See [`Self::formats_iri`].
+    #[must_use]
+    pub const fn formats_named_node(self) -> Option<NamedNodeRef<'static>> {
+        match self.formats_iri() {
+            Some(iri) => Some(NamedNodeRef::new_unchecked(iri)),
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use oxrdfio::RdfFormat;
+
+use crate::Type;
+
+impl Type {
+    /// Returns the respective format of the [`oxrdfio`](
+    /// https://crates.io/crates/oxrdfio) crate,
+    /// if it supports this type.
+    ///
+    /// NOTE: The RDF-star variants are mapped to their plain counterparts,
+    /// as `oxrdfio` parses and serializes RDF-star with those.
+    #[must_use]
+    pub const fn to_rdf_format(self) -> Option<RdfFormat> {
+        match self {
+            Self::N3 => Some(RdfFormat::N3),
+            Self::NQuads | Self::NQuadsStar => Some(RdfFormat::NQuads),
+            Self::NTriples | Self::NTriplesStar => Some(RdfFormat::NTriples),
+            Self::OwlXml | Self::RdfXml => Some(RdfFormat::RdfXml),
+            Self::TriG | Self::TriGStar => Some(RdfFormat::TriG),
+            Self::Turtle | Self::TurtleStar => Some(RdfFormat::Turtle),
+            Self::BinaryRdf
+            | Self::Csvw
+            | Self::Hdt
+            | Self::HexTuples
+            | Self::Html
+            | Self::JsonLd
+            | Self::Microdata
+            | Self::NdJsonLd
+            | Self::OwlFunctional
+            | Self::RdfA
+            | Self::RdfJson
+            | Self::SparqlResultsCsv
+            | Self::SparqlResultsJson
+            | Self::SparqlResultsTsv
+            | Self::SparqlResultsXml
+            | Self::TriX
+            | Self::Tsvw
+            | Self::YamlLd
+            | Self::Other(_) => None,
+        }
+    }
+
+    /// Returns the type corresponding to the given format
+    /// of the [`oxrdfio`](https://crates.io/crates/oxrdfio) crate.
+    ///
+    /// Returns `None` for formats unknown to this crate,
+    /// which might get added to `oxrdfio` in the future.
+    #[must_use]
+    pub const fn from_rdf_format(format: RdfFormat) -> Option<Self> {
+        #[allow(clippy::wildcard_enum_match_arm)]
+        match format {
+            RdfFormat::N3 => Some(Self::N3),
+            RdfFormat::NQuads => Some(Self::NQuads),
+            RdfFormat::NTriples => Some(Self::NTriples),
+            RdfFormat::RdfXml => Some(Self::RdfXml),
+            RdfFormat::TriG => Some(Self::TriG),
+            RdfFormat::Turtle => Some(Self::Turtle),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use mediatype::{MediaType, ReadParams};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt::Display, str::FromStr};
+
+use crate::{ParseError, Type};
+
+pub const PARAM_CHARSET: &str = "charset";
+pub const PARAM_PROFILE: &str = "profile";
+
+/// See <https://www.w3.org/TR/json-ld11/#iana-considerations>
+pub const JSON_LD_PROFILE_EXPANDED: &str = "http://www.w3.org/ns/json-ld#expanded";
+pub const JSON_LD_PROFILE_COMPACTED: &str = "http://www.w3.org/ns/json-ld#compacted";
+pub const JSON_LD_PROFILE_FLATTENED: &str = "http://www.w3.org/ns/json-ld#flattened";
+pub const JSON_LD_PROFILE_FRAMED: &str = "http://www.w3.org/ns/json-ld#framed";
+
+/// A [`Type`] plus the parameters of the media type it was identified from,
+/// for example `charset=utf-8`,
+/// or the `profile` of JSON-LD (expanded, compacted, ...).
+///
+/// # Examples
+///
+/// - `text/turtle; charset=utf-8`
+/// - `application/ld+json; profile="http://www.w3.org/ns/json-ld#compacted"`
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParameterizedType {
+    pub typ: Type,
+    /// The parameters in order of appearance.
+    /// Names are in lower-case, values are unquoted.
+    pub params: Vec<(String, String)>,
+}
+
+impl From<Type> for ParameterizedType {
+    fn from(typ: Type) -> Self {
+        Self::new(typ)
+    }
+}
+
+impl FromStr for ParameterizedType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_mime_type(s)
+    }
+}
+
+impl Display for ParameterizedType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.typ.mime_type().fmt(f)?;
+        for (name, value) in &self.params {
+            write!(f, "; {name}={}", quote(value))?;
+        }
+        Ok(())
+    }
+}
+
+/// Quotes a parameter value, if required.
+fn quote(value: &str) -> Cow<'_, str> {
+    if !value.is_empty() && mediatype::Value::new(value).is_some() {
+        Cow::Borrowed(value)
+    } else {
+        Cow::Owned(format!(
+            "\"{}\"",
+            value.replace('\\', "\\\\").replace('"', "\\\"")
+        ))
+    }
+}
+
+impl ParameterizedType {
+    /// Creates an instance without any parameters.
+    #[must_use]
+    pub const fn new(typ: Type) -> Self {
+        Self {
+            typ,
+            params: Vec::new(),
+        }
+    }
+
+    /// Adds a parameter, replacing any previous value of the same name.
+    #[must_use]
+    pub fn with_param<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        let name_lower = name.into().to_lowercase();
+        self.params.retain(|(existing, _)| *existing != name_lower);
+        self.params.push((name_lower, value.into()));
+        self
+    }
+
+    /// Tries to parse a MIME type of the form `"<type>/<subtype>[; <name>=<value>]*"`,
+    /// for example `"text/turtle; charset=utf-8"`,
+    /// as a known RDF serialization format type plus its parameters.
+    ///
+    /// # Errors
+    ///
+    /// See [`Type::from_mime_type`].
+    pub fn from_mime_type<'a, T>(mime_type: T) -> Result<Self, ParseError>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let mime_type_cow = mime_type.into();
+        let media_type = MediaType::parse(mime_type_cow.as_ref())?;
+        Self::from_media_type(&media_type)
+    }
+
+    /// Tries to identify the type and its parameters
+    /// from the given type from the [mediatype](
+    /// https://crates.io/crates/mediatype) crate.
+    ///
+    /// # Errors
+    ///
+    /// See [`Type::from_media_type`].
+    pub fn from_media_type(media_type: &MediaType) -> Result<Self, ParseError> {
+        let typ = Type::from_media_type(media_type)?;
+        let params = media_type
+            .params()
+            .map(|(name, value)| {
+                (
+                    name.as_str().to_lowercase(),
+                    value.unquoted_str().into_owned(),
+                )
+            })
+            .collect();
+        Ok(Self { typ, params })
+    }
+
+    /// Returns the value of the parameter with the given name, if present.
+    /// If the parameter appears more then once, the last value is returned.
+    #[must_use]
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .rev()
+            .find(|(param_name, _)| param_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value of the `charset` parameter, if present.
+    #[must_use]
+    pub fn charset(&self) -> Option<&str> {
+        self.param(PARAM_CHARSET)
+    }
+
+    /// Returns the value of the `profile` parameter, if present.
+    #[must_use]
+    pub fn profile(&self) -> Option<&str> {
+        self.param(PARAM_PROFILE)
+    }
+
+    /// Returns the individual IRIs within the (space separated) `profile` parameter.
+    pub fn profiles(&self) -> impl Iterator<Item = &str> {
+        self.profile().into_iter().flat_map(str::split_whitespace)
+    }
+
+    /// Whether the `profile` parameter contains the given IRI,
+    /// for example [`JSON_LD_PROFILE_COMPACTED`].
+    #[must_use]
+    pub fn has_profile(&self, profile: &str) -> bool {
+        self.profiles().any(|prf| prf == profile)
+    }
+}
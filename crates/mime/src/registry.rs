@@ -0,0 +1,197 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use mediatype::MediaType;
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, PoisonError, RwLock},
+};
+use thiserror::Error;
+
+use crate::Type;
+use rdfoothills_base::hasher;
+
+#[derive(Error, Debug)]
+pub enum RegistryError {
+    #[error("Not a valid/parsable content-type format: '{0}'")]
+    InvalidFormat(#[from] mediatype::MediaTypeError),
+
+    #[error("The content-type '{0}' is already registered for the type {1}")]
+    DuplicateContentType(String, Type),
+
+    #[error("The file extension '{0}' is already registered for the type {1}")]
+    DuplicateFileExtension(String, Type),
+}
+
+/// Describes a serialization format that is not built into this crate,
+/// but registered at runtime through [`Registry::register`].
+///
+/// It is represented by [`Type::Other`].
+///
+/// # Examples
+///
+/// ```
+/// use mediatype::{names::APPLICATION, MediaType, Name};
+/// use rdfoothills_mime::{CustomType, Registry, Type};
+///
+/// static RDF_THRIFT: CustomType = CustomType {
+///     name: "RDF-Thrift",
+///     mime_type: "application/rdf+thrift",
+///     mime_types: &["application/rdf+thrift"],
+///     media_type: MediaType::from_parts(
+///         APPLICATION,
+///         Name::new_unchecked("rdf"),
+///         Some(Name::new_unchecked("thrift")),
+///         &[],
+///     ),
+///     file_ext: "rt",
+///     file_exts: &["rt", "trdf"],
+///     machine_readable: true,
+///     star: false,
+///     dataset: true,
+///     binary: true,
+///     standard_definition_url: "https://jena.apache.org/documentation/io/rdf-binary.html",
+/// };
+///
+/// let typ = Registry::register(&RDF_THRIFT).unwrap();
+/// assert_eq!(Type::from_file_ext("trdf").unwrap(), typ);
+/// assert_eq!(Type::from_mime_type("application/rdf+thrift").unwrap(), typ);
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct CustomType {
+    /// See [`Type::name`].
+    pub name: &'static str,
+    /// See [`Type::mime_type`].
+    pub mime_type: &'static str,
+    /// See [`Type::mime_types`].
+    pub mime_types: &'static [&'static str],
+    /// See [`Type::media_type`].
+    pub media_type: MediaType<'static>,
+    /// See [`Type::file_ext`].
+    pub file_ext: &'static str,
+    /// See [`Type::file_exts`].
+    pub file_exts: &'static [&'static str],
+    /// See [`Type::is_machine_readable`].
+    pub machine_readable: bool,
+    /// See [`Type::star`].
+    pub star: bool,
+    /// See [`Type::is_dataset_format`].
+    pub dataset: bool,
+    /// See [`Type::is_binary`].
+    pub binary: bool,
+    /// See [`Type::standard_definition_url`].
+    pub standard_definition_url: &'static str,
+}
+
+/// The runtime extension point for serialization formats
+/// not built into this crate.
+///
+/// Registered types are consulted after the built-in ones,
+/// when identifying a type by MIME type or file extension.
+#[derive(Debug, Default)]
+pub struct Registry {
+    types: Vec<Type>,
+    media_types: HashMap<u64, Type>,
+    file_exts: HashMap<String, Type>,
+}
+
+static REGISTRY: LazyLock<RwLock<Registry>> = LazyLock::new(|| RwLock::new(Registry::default()));
+
+impl Registry {
+    /// Registers a custom serialization format globally,
+    /// and returns the type that represents it from then on.
+    /// Registering the same format twice is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// - If one of the MIME types is not a valid MIME type.
+    /// - If one of the MIME types is already registered for an other type
+    ///   (built-in or custom).
+    /// - If one of the file extensions is already registered for an other type
+    ///   (built-in or custom).
+    pub fn register(custom: &'static CustomType) -> Result<Type, RegistryError> {
+        REGISTRY
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .add(custom)
+    }
+
+    fn add(&mut self, custom: &'static CustomType) -> Result<Type, RegistryError> {
+        let typ = Type::Other(custom);
+        if self.types.contains(&typ) {
+            return Ok(typ);
+        }
+
+        let mut media_type_hashes = Vec::new();
+        for mime_type in custom.mime_types.iter().chain([&custom.mime_type]) {
+            let media_type = MediaType::parse(mime_type)?;
+            let existing =
+                crate::media_type2type(&media_type).or_else(|| self.lookup_media_type(&media_type));
+            if let Some(existing_typ) = existing {
+                return Err(RegistryError::DuplicateContentType(
+                    (*mime_type).to_owned(),
+                    existing_typ,
+                ));
+            }
+            media_type_hashes.push(hasher::hash_num(media_type.essence()));
+        }
+        for file_ext in custom.file_exts.iter().chain([&custom.file_ext]) {
+            let file_ext_lower = file_ext.to_lowercase();
+            let existing = Type::from_builtin_file_ext(&file_ext_lower)
+                .or_else(|| self.file_exts.get(&file_ext_lower).copied());
+            if let Some(existing_typ) = existing {
+                return Err(RegistryError::DuplicateFileExtension(
+                    (*file_ext).to_owned(),
+                    existing_typ,
+                ));
+            }
+        }
+
+        for media_type_hash in media_type_hashes {
+            self.media_types.insert(media_type_hash, typ);
+        }
+        for file_ext in custom.file_exts.iter().chain([&custom.file_ext]) {
+            self.file_exts.insert(file_ext.to_lowercase(), typ);
+        }
+        self.types.push(typ);
+        Ok(typ)
+    }
+
+    /// All the custom types registered so far, in order of registration.
+    #[must_use]
+    pub fn registered() -> Vec<Type> {
+        REGISTRY
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .types
+            .clone()
+    }
+
+    fn lookup_media_type(&self, media_type: &MediaType) -> Option<Type> {
+        self.media_types
+            .get(&hasher::hash_num(media_type.essence()))
+            .copied()
+    }
+
+    /// Looks up a custom type by media type, ignoring its parameters.
+    #[must_use]
+    pub fn media_type2type(media_type: &MediaType) -> Option<Type> {
+        REGISTRY
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .lookup_media_type(media_type)
+    }
+
+    /// Looks up a custom type by file extension (case-insensitive).
+    #[must_use]
+    pub fn file_ext2type(file_ext: &str) -> Option<Type> {
+        REGISTRY
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .file_exts
+            .get(&file_ext.to_lowercase())
+            .copied()
+    }
+}
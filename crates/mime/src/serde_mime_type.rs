@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! (De-)Serializes a [`Type`] as its canonical MIME type string.
+//!
+//! This results in e.g. `"text/turtle"`,
+//! instead of the enum variant name (e.g. `"Turtle"`).
+//! Deserialization accepts any known MIME type alias,
+//! including the ones of types registered in the [`crate::Registry`].
+//!
+//! NOTE: A few types share their MIME type with another one,
+//! and thus come back as that other type after a round-trip:
+//!
+//! - [`Type::RdfA`] as [`Type::Html`]
+//! - [`Type::SparqlResultsCsv`] as [`Type::Csvw`]
+//! - [`Type::SparqlResultsTsv`] as [`Type::Tsvw`]
+//! - [`Type::Hdt`] (which has no MIME type of its own) as [`Type::RdfXml`]
+//!
+//! Use the derived (variant name) serialization if that matters.
+//!
+//! # Examples
+//!
+//! ```
+//! use rdfoothills_mime::Type;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "rdfoothills_mime::serde_mime_type")]
+//!     format: Type,
+//!     #[serde(with = "rdfoothills_mime::serde_mime_type::option")]
+//!     fallback_format: Option<Type>,
+//! }
+//! ```
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+use crate::Type;
+
+/// Serializes the type as its primary MIME type.
+///
+/// # Errors
+///
+/// If the serializer fails to write a string.
+pub fn serialize<S>(typ: &Type, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(typ.mime_type())
+}
+
+/// Deserializes the type from any of its known MIME types.
+///
+/// # Errors
+///
+/// If the value is not a string, or not a recognized MIME type.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Type, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mime_type = String::deserialize(deserializer)?;
+    Type::from_mime_type(mime_type.as_str())
+        .or_else(|err| {
+            // Not all primary MIME types are recognized as media types,
+            // but they still have to come back as what they were serialized from.
+            Type::all()
+                .iter()
+                .copied()
+                .find(|typ| typ.mime_type() == mime_type)
+                .ok_or(err)
+        })
+        .map_err(D::Error::custom)
+}
+
+/// The same as the parent module, but for `Option<Type>`.
+pub mod option {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::Type;
+
+    /// Serializes the type as its primary MIME type, or as none.
+    ///
+    /// # Errors
+    ///
+    /// If the serializer fails to write a string.
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S>(typ_opt: &Option<Type>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match typ_opt {
+            Some(typ) => serializer.serialize_some(typ.mime_type()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes the type from any of its known MIME types, or from none.
+    ///
+    /// # Errors
+    ///
+    /// If the value is neither none nor a string, or not a recognized MIME type.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Type>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "super")] Type);
+
+        Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(typ)| typ))
+    }
+}
@@ -0,0 +1,197 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! The identifiers that external (CLI) tools use
+//! for the serialization formats represented by [`Type`].
+
+use crate::Type;
+
+/// The external tools we know the format identifiers of.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Tool {
+    /// The python [RDFlib](https://rdflib.readthedocs.io),
+    /// and tools based on it, like `rdf-convert` and `pylode`.
+    RdfLib,
+    /// The [Apache Jena](https://jena.apache.org/documentation/io/) `riot` CLI tool.
+    JenaRiot,
+    /// The [Raptor](https://librdf.org/raptor/rapper.html) `rapper` CLI tool.
+    Rapper,
+    /// The [ROBOT](https://robot.obolibrary.org/convert) OWL tool.
+    Robot,
+}
+
+impl Tool {
+    /// All the known tools.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::RdfLib, Self::JenaRiot, Self::Rapper, Self::Robot]
+    }
+
+    /// Returns the identifier this tool uses for the given format,
+    /// or `None` if the tool does not support it.
+    #[must_use]
+    pub const fn format_name(self, typ: Type) -> Option<&'static str> {
+        match self {
+            Self::RdfLib => rdflib(typ),
+            Self::JenaRiot => jena_riot(typ),
+            Self::Rapper => rapper(typ),
+            Self::Robot => robot(typ),
+        }
+    }
+
+    /// Whether this tool supports the given format.
+    #[must_use]
+    pub const fn supports(self, typ: Type) -> bool {
+        self.format_name(typ).is_some()
+    }
+}
+
+/// The format identifier as used by [RDFlib](https://rdflib.readthedocs.io).
+#[must_use]
+pub const fn rdflib(typ: Type) -> Option<&'static str> {
+    match typ {
+        Type::HexTuples => Some("hext"),
+        Type::JsonLd => Some("json-ld"),
+        Type::N3 => Some("n3"),
+        Type::NQuads => Some("nquads"),
+        Type::NTriples => Some("nt"),
+        Type::RdfXml => Some("xml"),
+        Type::TriG => Some("trig"),
+        Type::TriX => Some("trix"),
+        Type::Turtle => Some("turtle"),
+        Type::BinaryRdf
+        | Type::Csvw
+        | Type::Hdt
+        | Type::Html
+        | Type::Microdata
+        | Type::NdJsonLd
+        | Type::NQuadsStar
+        | Type::NTriplesStar
+        | Type::OwlFunctional
+        | Type::OwlXml
+        | Type::RdfA
+        | Type::RdfJson
+        | Type::SparqlResultsCsv
+        | Type::SparqlResultsJson
+        | Type::SparqlResultsTsv
+        | Type::SparqlResultsXml
+        | Type::TriGStar
+        | Type::Tsvw
+        | Type::TurtleStar
+        | Type::YamlLd
+        | Type::Other(_) => None,
+    }
+}
+
+/// The format identifier as used by the `--syntax` and `--output` options
+/// of the [Apache Jena](https://jena.apache.org/documentation/io/) `riot` tool.
+///
+/// NOTE: Jena reads and writes RDF-star with the plain formats.
+#[must_use]
+pub const fn jena_riot(typ: Type) -> Option<&'static str> {
+    match typ {
+        Type::JsonLd => Some("JSON-LD"),
+        Type::NQuads | Type::NQuadsStar => Some("N-QUADS"),
+        Type::NTriples | Type::NTriplesStar => Some("N-TRIPLES"),
+        Type::RdfJson => Some("RDF/JSON"),
+        Type::RdfXml => Some("RDF/XML"),
+        Type::TriG | Type::TriGStar => Some("TRIG"),
+        Type::TriX => Some("TRIX"),
+        Type::Turtle | Type::TurtleStar => Some("TURTLE"),
+        Type::BinaryRdf
+        | Type::Csvw
+        | Type::Hdt
+        | Type::HexTuples
+        | Type::Html
+        | Type::Microdata
+        | Type::N3
+        | Type::NdJsonLd
+        | Type::OwlFunctional
+        | Type::OwlXml
+        | Type::RdfA
+        | Type::SparqlResultsCsv
+        | Type::SparqlResultsJson
+        | Type::SparqlResultsTsv
+        | Type::SparqlResultsXml
+        | Type::Tsvw
+        | Type::YamlLd
+        | Type::Other(_) => None,
+    }
+}
+
+/// The format identifier as used by the `--input` and `--output` options
+/// of the [Raptor](https://librdf.org/raptor/rapper.html) `rapper` tool.
+#[must_use]
+pub const fn rapper(typ: Type) -> Option<&'static str> {
+    match typ {
+        Type::NQuads => Some("nquads"),
+        Type::NTriples => Some("ntriples"),
+        Type::RdfA => Some("rdfa"),
+        Type::RdfJson => Some("json"),
+        Type::RdfXml => Some("rdfxml"),
+        Type::TriG => Some("trig"),
+        Type::Turtle => Some("turtle"),
+        Type::BinaryRdf
+        | Type::Csvw
+        | Type::Hdt
+        | Type::HexTuples
+        | Type::Html
+        | Type::JsonLd
+        | Type::Microdata
+        | Type::N3
+        | Type::NdJsonLd
+        | Type::NQuadsStar
+        | Type::NTriplesStar
+        | Type::OwlFunctional
+        | Type::OwlXml
+        | Type::SparqlResultsCsv
+        | Type::SparqlResultsJson
+        | Type::SparqlResultsTsv
+        | Type::SparqlResultsXml
+        | Type::TriGStar
+        | Type::TriX
+        | Type::Tsvw
+        | Type::TurtleStar
+        | Type::YamlLd
+        | Type::Other(_) => None,
+    }
+}
+
+/// The format identifier as used by the `--format` option
+/// of the [ROBOT](https://robot.obolibrary.org/convert) tool.
+#[must_use]
+pub const fn robot(typ: Type) -> Option<&'static str> {
+    match typ {
+        Type::OwlFunctional => Some("ofn"),
+        Type::OwlXml => Some("owx"),
+        Type::RdfXml => Some("owl"),
+        Type::Turtle => Some("ttl"),
+        Type::BinaryRdf
+        | Type::Csvw
+        | Type::Hdt
+        | Type::HexTuples
+        | Type::Html
+        | Type::JsonLd
+        | Type::Microdata
+        | Type::N3
+        | Type::NdJsonLd
+        | Type::NQuads
+        | Type::NQuadsStar
+        | Type::NTriples
+        | Type::NTriplesStar
+        | Type::RdfA
+        | Type::RdfJson
+        | Type::SparqlResultsCsv
+        | Type::SparqlResultsJson
+        | Type::SparqlResultsTsv
+        | Type::SparqlResultsXml
+        | Type::TriG
+        | Type::TriGStar
+        | Type::TriX
+        | Type::Tsvw
+        | Type::TurtleStar
+        | Type::YamlLd
+        | Type::Other(_) => None,
+    }
+}
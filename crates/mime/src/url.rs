@@ -0,0 +1,57 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use url::Url;
+
+use crate::{ParseError, Type};
+
+/// Names of URL query parameters commonly used to request a format.
+///
+/// These are used for example by LOV (`?format=ttl`)
+/// or [OntoPortal](https://ontoportal.org/) (`?output=application/rdf+xml`).
+pub const FORMAT_QUERY_PARAMS: &[&str] = &["format", "_format", "output", "output_format", "mime"];
+
+impl Type {
+    /// Tries to identify the type from a query parameter value,
+    /// which might be a MIME type (`application/rdf+xml`),
+    /// a file extension (`ttl`)
+    /// or the name of the format (`Turtle`).
+    fn from_format_param(value: &str) -> Option<Self> {
+        if value.contains('/') {
+            return Self::from_mime_type(value).ok();
+        }
+        Self::from_file_ext(value).ok().or_else(|| {
+            Self::all()
+                .iter()
+                .find(|typ| typ.name().eq_ignore_ascii_case(value))
+                .copied()
+        })
+    }
+
+    /// Tries to identify the type from a URL,
+    /// first from well-known query parameters (see [`FORMAT_QUERY_PARAMS`]),
+    /// then from the file extension of the last path segment.
+    ///
+    /// # Errors
+    ///
+    /// Will return `ParseError::UnrecognizedUrl` if neither indicates a known type.
+    pub fn from_url(url: &Url) -> Result<Self, ParseError> {
+        for (key, value) in url.query_pairs() {
+            if FORMAT_QUERY_PARAMS
+                .iter()
+                .any(|param| key.eq_ignore_ascii_case(param))
+            {
+                if let Some(typ) = Self::from_format_param(&value) {
+                    return Ok(typ);
+                }
+            }
+        }
+
+        url.path_segments()
+            .and_then(Iterator::last)
+            .and_then(|file_name| file_name.rsplit_once('.'))
+            .and_then(|(_, file_ext)| Self::from_file_ext(file_ext).ok())
+            .ok_or_else(|| ParseError::UnrecognizedUrl(url.to_string()))
+    }
+}
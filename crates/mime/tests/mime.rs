@@ -2,10 +2,227 @@
 //
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
-use rdfoothills_mime::Type;
-use std::str::FromStr;
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_mime::{
+    choose_best, tool_names::Tool, AcceptHeader, ParameterizedType, Type, JSON_LD_PROFILE_COMPACTED,
+};
+use std::{collections::HashSet, str::FromStr};
 
 #[test]
 fn test_format() {
     Type::from_str("text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,image/png,image/svg+xml,*/*;q=0.8").unwrap();
 }
+
+#[test]
+fn test_sparql_results() {
+    assert_eq!(
+        Type::from_mime_type("application/sparql-results+json").unwrap(),
+        Type::SparqlResultsJson
+    );
+    assert_eq!(
+        Type::from_mime_type("application/sparql-results+xml").unwrap(),
+        Type::SparqlResultsXml
+    );
+    assert_eq!(Type::from_file_ext("srj").unwrap(), Type::SparqlResultsJson);
+    assert_eq!(Type::from_file_ext("srx").unwrap(), Type::SparqlResultsXml);
+}
+
+#[test]
+fn test_all() {
+    let all = Type::all();
+    let mut seen = HashSet::new();
+    for typ in all {
+        assert!(seen.insert(typ), "{typ:?} is listed twice");
+        assert!(typ.mime_types().contains(&typ.mime_type()));
+        assert!(typ.file_exts().contains(&typ.file_ext()));
+    }
+    assert_eq!(rdfoothills_mime::TYPE_INFOS.len(), all.len());
+}
+
+#[test]
+fn test_tool_names() {
+    // In the order of `Tool::all()`: RDFLib, Jena RIOT, Rapper, ROBOT
+    let names = |typ| {
+        Tool::all()
+            .iter()
+            .map(|tool| tool.format_name(typ))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(Type::Turtle),
+        [Some("turtle"), Some("TURTLE"), Some("turtle"), Some("ttl")]
+    );
+    assert_eq!(
+        names(Type::RdfXml),
+        [Some("xml"), Some("RDF/XML"), Some("rdfxml"), Some("owl")]
+    );
+    assert_eq!(
+        names(Type::NTriples),
+        [Some("nt"), Some("N-TRIPLES"), Some("ntriples"), None]
+    );
+    assert_eq!(names(Type::TurtleStar), [None, Some("TURTLE"), None, None]);
+    assert_eq!(names(Type::OwlXml), [None, None, None, Some("owx")]);
+    assert_eq!(names(Type::Hdt), [None, None, None, None]);
+    assert!(Tool::all().iter().all(|tool| !tool.supports(Type::Hdt)));
+}
+
+#[cfg(feature = "oxrdfio")]
+#[test]
+fn test_rdf_format_round_trip() {
+    for typ in Type::all() {
+        if let Some(rdf_format) = typ.to_rdf_format() {
+            let back = Type::from_rdf_format(rdf_format).unwrap();
+            assert_eq!(back.to_rdf_format(), Some(rdf_format));
+        }
+    }
+}
+
+#[test]
+fn test_params() {
+    let typ = ParameterizedType::from_mime_type(
+        r#"application/ld+json; charset=UTF-8; profile="http://www.w3.org/ns/json-ld#compacted""#,
+    )
+    .unwrap();
+    assert_eq!(typ.typ, Type::JsonLd);
+    assert_eq!(typ.charset(), Some("UTF-8"));
+    assert!(typ.has_profile(JSON_LD_PROFILE_COMPACTED));
+    assert_eq!(
+        typ.to_string(),
+        r#"application/ld+json; charset=UTF-8; profile="http://www.w3.org/ns/json-ld#compacted""#
+    );
+    assert_eq!(
+        Type::from_mime_type("text/turtle; charset=utf-8").unwrap(),
+        Type::Turtle
+    );
+}
+
+#[test]
+fn test_classification() {
+    assert!(Type::TriG.is_dataset_format());
+    assert!(!Type::Turtle.is_dataset_format());
+    assert!(Type::Hdt.is_binary());
+    assert!(Type::Turtle.is_textual());
+    assert!(Type::OwlXml.is_owl_syntax());
+    assert!(!Type::RdfXml.is_owl_syntax());
+}
+
+#[test]
+fn test_choose_best() {
+    let accepted = AcceptHeader::parse("text/html;q=0.5,text/turtle,application/*;q=0.8,*/*;q=0.1");
+    assert_eq!(
+        choose_best(&[Type::RdfXml, Type::Html], &accepted),
+        Some(Type::RdfXml)
+    );
+    assert_eq!(
+        choose_best(&[Type::Html, Type::Turtle], &accepted),
+        Some(Type::Turtle)
+    );
+    assert_eq!(
+        choose_best(&[Type::Hdt], &AcceptHeader::parse("text/turtle")),
+        None
+    );
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn test_from_url() {
+    let from_url = |url: &str| Type::from_url(&url::Url::parse(url).unwrap());
+    assert_eq!(
+        from_url("https://example.org/ont/onto.ttl").unwrap(),
+        Type::Turtle
+    );
+    assert_eq!(
+        from_url("https://example.org/ont?format=ttl").unwrap(),
+        Type::Turtle
+    );
+    assert_eq!(
+        from_url("https://example.org/ont.ttl?output=application/rdf%2Bxml").unwrap(),
+        Type::RdfXml
+    );
+    assert!(from_url("https://example.org/ont").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_mime_type() {
+    use serde::de::value::{Error, StrDeserializer};
+
+    let deserialize = |mime_type| {
+        rdfoothills_mime::serde_mime_type::deserialize(StrDeserializer::<Error>::new(mime_type))
+    };
+    assert_eq!(deserialize("text/turtle").unwrap(), Type::Turtle);
+    assert_eq!(deserialize("application/x-turtle").unwrap(), Type::Turtle);
+    assert!(deserialize("Turtle").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_mime_type_round_trip() {
+    use rdfoothills_mime::serde_mime_type;
+
+    // These share their MIME type with another type.
+    let shared = [
+        (Type::RdfA, Type::Html),
+        (Type::SparqlResultsCsv, Type::Csvw),
+        (Type::SparqlResultsTsv, Type::Tsvw),
+        (Type::Hdt, Type::RdfXml),
+    ];
+    for &typ in Type::all() {
+        let expected = shared
+            .iter()
+            .find(|(sharing, _)| *sharing == typ)
+            .map_or(typ, |&(_, other)| other);
+        let value = serde_mime_type::serialize(&typ, serde_json::value::Serializer).unwrap();
+        assert_eq!(value, typ.mime_type());
+        assert_eq!(
+            serde_mime_type::deserialize(value).unwrap(),
+            expected,
+            "{typ:?}"
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_custom_type() {
+    use mediatype::{names::APPLICATION, MediaType, Name};
+    use rdfoothills_mime::{serde_mime_type, CustomType, Registry};
+
+    static CUSTOM: CustomType = CustomType {
+        name: "Custom",
+        mime_type: "application/x-rdfoothills-custom",
+        mime_types: &["application/x-rdfoothills-custom"],
+        media_type: MediaType::from_parts(
+            APPLICATION,
+            Name::new_unchecked("x-rdfoothills-custom"),
+            None,
+            &[],
+        ),
+        file_ext: "rdfoothills-custom",
+        file_exts: &["rdfoothills-custom"],
+        machine_readable: true,
+        star: false,
+        dataset: false,
+        binary: false,
+        standard_definition_url: "https://example.org/custom",
+    };
+
+    let typ = Registry::register(&CUSTOM).unwrap();
+    assert_eq!(serde_json::to_string(&Type::Turtle).unwrap(), r#""Turtle""#);
+    // The derived implementation can not represent registered types ...
+    assert!(serde_json::to_string(&typ).is_err());
+    // ... but the MIME type string one can.
+    let value = serde_mime_type::serialize(&typ, serde_json::value::Serializer).unwrap();
+    assert_eq!(value, "application/x-rdfoothills-custom");
+    assert_eq!(serde_mime_type::deserialize(value).unwrap(), typ);
+}
+
+#[test]
+fn test_formats_iri() {
+    assert_eq!(
+        Type::Turtle.formats_iri(),
+        Some("http://www.w3.org/ns/formats/Turtle")
+    );
+    assert_eq!(Type::Hdt.formats_iri(), None);
+}
@@ -0,0 +1,85 @@
+#!/usr/bin/env bash
+# SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+#
+# SPDX-License-Identifier: Unlicense
+
+# Re-generates the vocabularies in ../src
+# from the ontologies in this directory, using vocabgen.
+#
+# Usage: generate.sh [--check]
+#
+# With --check, nothing is written,
+# but it fails if the generated sources are not up to date,
+# ignoring the vocabgen version they were generated with.
+
+set -Eeuo pipefail
+
+res_dir="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+src_dir="$res_dir/../src"
+# So the generated sources refer to the ontologies by their file names only
+cd "$res_dir"
+check=false
+if [ "${1:-}" = "--check" ]
+then
+	check=true
+fi
+
+header='// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later'
+
+# The vocabularies, each with the vocabgen arguments specific to it
+vocabs=(
+	"dcat -r http://www.w3.org/ns/dcat#catalog=CATALOG_PROPERTY -r http://www.w3.org/ns/dcat#dataset=DATASET_PROPERTY -r http://www.w3.org/ns/dcat#distribution=DISTRIBUTION_PROPERTY"
+	"dcterms"
+	"foaf -r http://xmlns.com/foaf/0.1/family_name=FAMILY_NAME_ARCHAIC -r http://xmlns.com/foaf/0.1/givenname=GIVEN_NAME_ARCHAIC"
+	"prov -r http://www.w3.org/ns/prov#activity=ACTIVITY_PROPERTY -r http://www.w3.org/ns/prov#agent=AGENT_PROPERTY -r http://www.w3.org/ns/prov#entity=ENTITY_PROPERTY"
+	"skos"
+)
+
+out_dir="$(mktemp -d)"
+trap 'rm -rf "$out_dir"' EXIT
+
+function vocabgen() {
+	cargo run --quiet --package rdfoothills-vocabgen \
+		--manifest-path ../../../Cargo.toml -- "$@"
+}
+
+outdated=()
+for vocab_args in "${vocabs[@]}"
+do
+	# shellcheck disable=SC2206
+	args=($vocab_args)
+	name="${args[0]}"
+	mkdir "$out_dir/$name"
+	vocabgen --quiet --force --header "$header" --lang en --strict \
+		--output-directory "$out_dir/$name" "${args[@]:1}" "$name.ttl"
+	generated="$out_dir/$name/$name.rs"
+	rustfmt --edition 2021 "$generated"
+	if ! diff -q \
+		<(grep -v "^// This file was generated by vocabgen " "$generated") \
+		<(grep -v "^// This file was generated by vocabgen " "$src_dir/$name.rs") \
+		> /dev/null
+	then
+		outdated+=("$name")
+	fi
+done
+
+# Only written at the end, as changing the sources
+# would make vocabgen be rebuilt as a modified version in between
+if ! $check
+then
+	for vocab_args in "${vocabs[@]}"
+	do
+		name="${vocab_args%% *}"
+		cp "$out_dir/$name/$name.rs" "$src_dir/$name.rs"
+	done
+	exit 0
+fi
+
+if [ ${#outdated[@]} -gt 0 ]
+then
+	>&2 echo "The generated vocabularies are not up to date: ${outdated[*]}"
+	>&2 echo "Run $0 to re-generate them."
+	exit 1
+fi
@@ -0,0 +1,845 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+// This file was generated by vocabgen v6225f9f from file 'dcat.ttl'.
+// Do not edit it manually; re-generate it instead.
+
+//! [The data catalog vocabulary (DCAT)](
+//! http://www.w3.org/ns/dcat#)
+//! vocabulary.
+
+pub const NS_BASE: &str = "http://www.w3.org/ns/dcat#";
+pub const NS_PREFERRED_PREFIX: &str = "dcat";
+/// The prefix declaration of this vocabulary, for use in SPARQL queries.
+pub const SPARQL_PREFIX: &str = "PREFIX dcat: <http://www.w3.org/ns/dcat#>";
+/// The prefix declaration of this vocabulary, for use in RDF/Turtle documents.
+pub const TURTLE_PREFIX: &str = "@prefix dcat: <http://www.w3.org/ns/dcat#> .";
+
+/// The classes of this vocabulary.
+pub mod classes {
+    use super::NS_BASE;
+    use crate::named_node;
+
+    named_node!(
+        CATALOG,
+        NS_BASE,
+        "Catalog",
+        r#"A curated collection of metadata about resources.
+
+```turtle
+dcat:Catalog
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A curated collection of metadata about resources."@en ;
+  rdfs:label "Catalog"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CATALOG_RECORD,
+        NS_BASE,
+        "CatalogRecord",
+        r#"A record in a catalog, describing the registration of a single resource.
+
+```turtle
+dcat:CatalogRecord
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A record in a catalog, describing the registration of a single resource."@en ;
+  rdfs:label "Catalog Record"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DATA_SERVICE,
+        NS_BASE,
+        "DataService",
+        r#"A site or end-point providing operations related to the discovery of, access to, or processing functions on, data or related resources.
+
+```turtle
+dcat:DataService
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A site or end-point providing operations related to the discovery of, access to, or processing functions on, data or related resources."@en ;
+  rdfs:label "Data service"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DATASET,
+        NS_BASE,
+        "Dataset",
+        r#"A collection of data, published or curated by a single source, and available for access or download in one or more representations.
+
+```turtle
+dcat:Dataset
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A collection of data, published or curated by a single source, and available for access or download in one or more representations."@en ;
+  rdfs:label "Dataset"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DATASET_SERIES,
+        NS_BASE,
+        "DatasetSeries",
+        r#"A collection of datasets that are published separately, but share some characteristics that group them.
+
+```turtle
+dcat:DatasetSeries
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A collection of datasets that are published separately, but share some characteristics that group them."@en ;
+  rdfs:label "Dataset series"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DISTRIBUTION,
+        NS_BASE,
+        "Distribution",
+        r#"A specific representation of a dataset. A dataset might be available in multiple serializations that may differ in various ways, including natural language, media-type or format, schematic organization, temporal and spatial resolution, level of detail or profiles (which might specify any or all of the above).
+
+```turtle
+dcat:Distribution
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A specific representation of a dataset. A dataset might be available in multiple serializations that may differ in various ways, including natural language, media-type or format, schematic organization, temporal and spatial resolution, level of detail or profiles (which might specify any or all of the above)."@en ;
+  rdfs:label "Distribution"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        RELATIONSHIP,
+        NS_BASE,
+        "Relationship",
+        r#"An association class for attaching additional information to a relationship between DCAT Resources.
+
+```turtle
+dcat:Relationship
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "An association class for attaching additional information to a relationship between DCAT Resources."@en ;
+  rdfs:label "Relationship"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        RESOURCE,
+        NS_BASE,
+        "Resource",
+        r#"Resource published or curated by a single agent.
+
+```turtle
+dcat:Resource
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "Resource published or curated by a single agent."@en ;
+  rdfs:label "Catalogued resource"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ROLE,
+        NS_BASE,
+        "Role",
+        r#"A role is the function of a resource or agent with respect to another resource, in the context of resource attribution or resource relationships.
+
+```turtle
+dcat:Role
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A role is the function of a resource or agent with respect to another resource, in the context of resource attribution or resource relationships."@en ;
+  rdfs:label "Role"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+}
+
+pub use classes::*;
+
+/// The properties of this vocabulary.
+pub mod properties {
+    use super::NS_BASE;
+    use crate::named_node;
+
+    named_node!(
+        ACCESS_SERVICE,
+        NS_BASE,
+        "accessService",
+        r#"A site or end-point that gives access to the distribution of the dataset.
+
+```turtle
+dcat:accessService
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A site or end-point that gives access to the distribution of the dataset."@en ;
+  rdfs:label "data access service"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ACCESS_URL,
+        NS_BASE,
+        "accessURL",
+        r#"A URL of a resource that gives access to a distribution of the dataset. E.g. landing page, feed, SPARQL endpoint.
+
+```turtle
+dcat:accessURL
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A URL of a resource that gives access to a distribution of the dataset. E.g. landing page, feed, SPARQL endpoint."@en ;
+  rdfs:label "access address"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        BBOX,
+        NS_BASE,
+        "bbox",
+        r#"The geographic bounding box of a resource.
+
+```turtle
+dcat:bbox
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The geographic bounding box of a resource."@en ;
+  rdfs:label "bounding box"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        BYTE_SIZE,
+        NS_BASE,
+        "byteSize",
+        r#"The size of a distribution in bytes.
+
+```turtle
+dcat:byteSize
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The size of a distribution in bytes."@en ;
+  rdfs:label "byte size"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CATALOG_PROPERTY,
+        NS_BASE,
+        "catalog",
+        r#"A catalog whose contents are of interest in the context of this catalog.
+
+```turtle
+dcat:catalog
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A catalog whose contents are of interest in the context of this catalog."@en ;
+  rdfs:label "catalog"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CENTROID,
+        NS_BASE,
+        "centroid",
+        r#"The geographic center (centroid) of a resource.
+
+```turtle
+dcat:centroid
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The geographic center (centroid) of a resource."@en ;
+  rdfs:label "centroid"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        COMPRESS_FORMAT,
+        NS_BASE,
+        "compressFormat",
+        r#"The compression format of the distribution in which the data is contained in a compressed form, e.g. to reduce the size of the downloadable file.
+
+```turtle
+dcat:compressFormat
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The compression format of the distribution in which the data is contained in a compressed form, e.g. to reduce the size of the downloadable file."@en ;
+  rdfs:label "compression format"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CONTACT_POINT,
+        NS_BASE,
+        "contactPoint",
+        r#"Relevant contact information for the catalogued resource. Use of vCard is recommended.
+
+```turtle
+dcat:contactPoint
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "Relevant contact information for the catalogued resource. Use of vCard is recommended."@en ;
+  rdfs:label "contact point"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DATASET_PROPERTY,
+        NS_BASE,
+        "dataset",
+        r#"A collection of data that is listed in the catalog.
+
+```turtle
+dcat:dataset
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A collection of data that is listed in the catalog."@en ;
+  rdfs:label "dataset"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DISTRIBUTION_PROPERTY,
+        NS_BASE,
+        "distribution",
+        r#"An available distribution of the dataset.
+
+```turtle
+dcat:distribution
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "An available distribution of the dataset."@en ;
+  rdfs:label "distribution"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DOWNLOAD_URL,
+        NS_BASE,
+        "downloadURL",
+        r#"The URL of the downloadable file in a given format. E.g. CSV file or RDF file. The format is indicated by the distribution's dcterms:format and/or dcat:mediaType.
+
+```turtle
+dcat:downloadURL
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The URL of the downloadable file in a given format. E.g. CSV file or RDF file. The format is indicated by the distribution's dcterms:format and/or dcat:mediaType."@en ;
+  rdfs:label "download URL"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        END_DATE,
+        NS_BASE,
+        "endDate",
+        r#"The end of the period.
+
+```turtle
+dcat:endDate
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The end of the period."@en ;
+  rdfs:label "end date"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ENDPOINT_DESCRIPTION,
+        NS_BASE,
+        "endpointDescription",
+        r#"A description of the service end-point, including its operations, parameters etc.
+
+```turtle
+dcat:endpointDescription
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A description of the service end-point, including its operations, parameters etc."@en ;
+  rdfs:label "description of service end-point"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ENDPOINT_URL,
+        NS_BASE,
+        "endpointURL",
+        r#"The root location or primary endpoint of the service (a web-resolvable IRI).
+
+```turtle
+dcat:endpointURL
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The root location or primary endpoint of the service (a web-resolvable IRI)."@en ;
+  rdfs:label "service end-point"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        FIRST,
+        NS_BASE,
+        "first",
+        r#"The first resource in an ordered collection or series of resources, to which the current resource belongs.
+
+```turtle
+dcat:first
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The first resource in an ordered collection or series of resources, to which the current resource belongs."@en ;
+  rdfs:label "first"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAD_ROLE,
+        NS_BASE,
+        "hadRole",
+        r#"The function of an entity or agent with respect to another entity or resource.
+
+```turtle
+dcat:hadRole
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The function of an entity or agent with respect to another entity or resource."@en ;
+  rdfs:label "had role"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAS_CURRENT_VERSION,
+        NS_BASE,
+        "hasCurrentVersion",
+        r#"This resource has a more specific, versioned resource with equivalent content.
+
+```turtle
+dcat:hasCurrentVersion
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "This resource has a more specific, versioned resource with equivalent content."@en ;
+  rdfs:label "current version"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAS_VERSION,
+        NS_BASE,
+        "hasVersion",
+        r#"This resource has a more specific, versioned resource.
+
+```turtle
+dcat:hasVersion
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "This resource has a more specific, versioned resource."@en ;
+  rdfs:label "has version"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IN_SERIES,
+        NS_BASE,
+        "inSeries",
+        r#"A dataset series of which the dataset is part.
+
+```turtle
+dcat:inSeries
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A dataset series of which the dataset is part."@en ;
+  rdfs:label "in series"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        KEYWORD,
+        NS_BASE,
+        "keyword",
+        r#"A keyword or tag describing a resource.
+
+```turtle
+dcat:keyword
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A keyword or tag describing a resource."@en ;
+  rdfs:label "keyword"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LANDING_PAGE,
+        NS_BASE,
+        "landingPage",
+        r#"A Web page that can be navigated to in a Web browser to gain access to the catalog, a dataset, its distributions and/or additional information.
+
+```turtle
+dcat:landingPage
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A Web page that can be navigated to in a Web browser to gain access to the catalog, a dataset, its distributions and/or additional information."@en ;
+  rdfs:label "landing page"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LAST,
+        NS_BASE,
+        "last",
+        r#"The last resource in an ordered collection or series of resources, to which the current resource belongs.
+
+```turtle
+dcat:last
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The last resource in an ordered collection or series of resources, to which the current resource belongs."@en ;
+  rdfs:label "last"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MEDIA_TYPE,
+        NS_BASE,
+        "mediaType",
+        r#"The media type of the distribution as defined by IANA.
+
+```turtle
+dcat:mediaType
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The media type of the distribution as defined by IANA."@en ;
+  rdfs:label "media type"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PACKAGE_FORMAT,
+        NS_BASE,
+        "packageFormat",
+        r#"The package format of the distribution in which one or more data files are grouped together, e.g. to enable a set of related files to be downloaded together.
+
+```turtle
+dcat:packageFormat
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The package format of the distribution in which one or more data files are grouped together, e.g. to enable a set of related files to be downloaded together."@en ;
+  rdfs:label "packaging format"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PREV,
+        NS_BASE,
+        "prev",
+        r#"The previous resource (before the current one) in an ordered collection or series of resources.
+
+```turtle
+dcat:prev
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The previous resource (before the current one) in an ordered collection or series of resources."@en ;
+  rdfs:label "previous"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_RELATION,
+        NS_BASE,
+        "qualifiedRelation",
+        r#"Link to a description of a relationship with another resource.
+
+```turtle
+dcat:qualifiedRelation
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "Link to a description of a relationship with another resource."@en ;
+  rdfs:label "qualified relation"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        RECORD,
+        NS_BASE,
+        "record",
+        r#"A record describing the registration of a single dataset or data service that is part of the catalog.
+
+```turtle
+dcat:record
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A record describing the registration of a single dataset or data service that is part of the catalog."@en ;
+  rdfs:label "record"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SERVES_DATASET,
+        NS_BASE,
+        "servesDataset",
+        r#"A collection of data that this data service can distribute.
+
+```turtle
+dcat:servesDataset
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A collection of data that this data service can distribute."@en ;
+  rdfs:label "serves dataset"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SERVICE,
+        NS_BASE,
+        "service",
+        r#"A site or endpoint that is listed in the catalog.
+
+```turtle
+dcat:service
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A site or endpoint that is listed in the catalog."@en ;
+  rdfs:label "service"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SPATIAL_RESOLUTION_IN_METERS,
+        NS_BASE,
+        "spatialResolutionInMeters",
+        r#"Minimum spatial separation resolvable in a dataset, measured in meters.
+
+```turtle
+dcat:spatialResolutionInMeters
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "Minimum spatial separation resolvable in a dataset, measured in meters."@en ;
+  rdfs:label "spatial resolution (metres)"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        START_DATE,
+        NS_BASE,
+        "startDate",
+        r#"The start of the period.
+
+```turtle
+dcat:startDate
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The start of the period."@en ;
+  rdfs:label "start date"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        TEMPORAL_RESOLUTION,
+        NS_BASE,
+        "temporalResolution",
+        r#"Minimum time period resolvable in a dataset.
+
+```turtle
+dcat:temporalResolution
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "Minimum time period resolvable in a dataset."@en ;
+  rdfs:label "temporal resolution"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        THEME,
+        NS_BASE,
+        "theme",
+        r#"A main category of the resource. A resource can have multiple themes.
+
+```turtle
+dcat:theme
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A main category of the resource. A resource can have multiple themes."@en ;
+  rdfs:label "theme"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        THEME_TAXONOMY,
+        NS_BASE,
+        "themeTaxonomy",
+        r#"A knowledge organization system (KOS) used to classify the resources of the catalog.
+
+```turtle
+dcat:themeTaxonomy
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "A knowledge organization system (KOS) used to classify the resources of the catalog."@en ;
+  rdfs:label "themes"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        VERSION,
+        NS_BASE,
+        "version",
+        r#"The version indicator (name or identifier) of a resource.
+
+```turtle
+dcat:version
+  rdfs:isDefinedBy <http://www.w3.org/ns/dcat> ;
+  rdfs:comment "The version indicator (name or identifier) of a resource."@en ;
+  rdfs:label "version"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+}
+
+pub use properties::*;
+
+/// All the terms of this vocabulary.
+#[allow(deprecated)]
+pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[
+    CATALOG,
+    CATALOG_RECORD,
+    DATA_SERVICE,
+    DATASET,
+    DATASET_SERIES,
+    DISTRIBUTION,
+    RELATIONSHIP,
+    RESOURCE,
+    ROLE,
+    ACCESS_SERVICE,
+    ACCESS_URL,
+    BBOX,
+    BYTE_SIZE,
+    CATALOG_PROPERTY,
+    CENTROID,
+    COMPRESS_FORMAT,
+    CONTACT_POINT,
+    DATASET_PROPERTY,
+    DISTRIBUTION_PROPERTY,
+    DOWNLOAD_URL,
+    END_DATE,
+    ENDPOINT_DESCRIPTION,
+    ENDPOINT_URL,
+    FIRST,
+    HAD_ROLE,
+    HAS_CURRENT_VERSION,
+    HAS_VERSION,
+    IN_SERIES,
+    KEYWORD,
+    LANDING_PAGE,
+    LAST,
+    MEDIA_TYPE,
+    PACKAGE_FORMAT,
+    PREV,
+    QUALIFIED_RELATION,
+    RECORD,
+    SERVES_DATASET,
+    SERVICE,
+    SPATIAL_RESOLUTION_IN_METERS,
+    START_DATE,
+    TEMPORAL_RESOLUTION,
+    THEME,
+    THEME_TAXONOMY,
+    VERSION,
+];
+
+/// The deprecated terms of this vocabulary,
+/// each with the version it was deprecated in, a note
+/// and the term replacing it, if any.
+#[allow(
+    deprecated,
+    clippy::needless_raw_string_hashes,
+    clippy::type_complexity
+)]
+pub static DEPRECATIONS: &[(
+    oxrdf::NamedNodeRef<'_>,
+    &str,
+    &str,
+    Option<oxrdf::NamedNodeRef<'_>>,
+)] = &[];
@@ -0,0 +1,1819 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+// This file was generated by vocabgen v6225f9f from file 'dcterms.ttl'.
+// Do not edit it manually; re-generate it instead.
+
+//! [DCMI Metadata Terms (DCTERMS)](
+//! http://purl.org/dc/terms/)
+//! vocabulary.
+
+pub const NS_BASE: &str = "http://purl.org/dc/terms/";
+pub const NS_PREFERRED_PREFIX: &str = "dcterms";
+/// The prefix declaration of this vocabulary, for use in SPARQL queries.
+pub const SPARQL_PREFIX: &str = "PREFIX dcterms: <http://purl.org/dc/terms/>";
+/// The prefix declaration of this vocabulary, for use in RDF/Turtle documents.
+pub const TURTLE_PREFIX: &str = "@prefix dcterms: <http://purl.org/dc/terms/> .";
+
+use crate::named_node;
+
+named_node!(
+    BOX,
+    NS_BASE,
+    "Box",
+    r#"The set of regions in space defined by their geographic coordinates according to the DCMI Box Encoding Scheme.
+
+```turtle
+dcterms:Box
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of regions in space defined by their geographic coordinates according to the DCMI Box Encoding Scheme."@en ;
+  rdfs:label "DCMI Box"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    DCMI_TYPE,
+    NS_BASE,
+    "DCMIType",
+    r#"The set of classes specified by the DCMI Type Vocabulary, used to categorize the nature or genre of the resource.
+
+```turtle
+dcterms:DCMIType
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of classes specified by the DCMI Type Vocabulary, used to categorize the nature or genre of the resource."@en ;
+  rdfs:label "DCMI Type Vocabulary"@en ;
+  rdf:type dcam:VocabularyEncodingScheme ;
+  .
+```
+"#
+);
+
+named_node!(
+    DDC,
+    NS_BASE,
+    "DDC",
+    r#"The set of conceptual resources specified by the Dewey Decimal Classification.
+
+```turtle
+dcterms:DDC
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of conceptual resources specified by the Dewey Decimal Classification."@en ;
+  rdfs:label "DDC"@en ;
+  rdf:type dcam:VocabularyEncodingScheme ;
+  .
+```
+"#
+);
+
+named_node!(
+    IMT,
+    NS_BASE,
+    "IMT",
+    r#"The set of media types specified by the Internet Assigned Numbers Authority.
+
+```turtle
+dcterms:IMT
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of media types specified by the Internet Assigned Numbers Authority."@en ;
+  rdfs:label "IMT"@en ;
+  rdf:type dcam:VocabularyEncodingScheme ;
+  .
+```
+"#
+);
+
+named_node!(
+    ISO_3166,
+    NS_BASE,
+    "ISO3166",
+    r#"The set of codes listed in ISO 3166-1 for the representation of names of countries.
+
+```turtle
+dcterms:ISO3166
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of codes listed in ISO 3166-1 for the representation of names of countries."@en ;
+  rdfs:label "ISO 3166"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    ISO_639_2,
+    NS_BASE,
+    "ISO639-2",
+    r#"The three-letter alphabetic codes listed in ISO639-2 for the representation of names of languages.
+
+```turtle
+dcterms:ISO639-2
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The three-letter alphabetic codes listed in ISO639-2 for the representation of names of languages."@en ;
+  rdfs:label "ISO 639-2"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    ISO_639_3,
+    NS_BASE,
+    "ISO639-3",
+    r#"The set of three-letter codes listed in ISO 639-3 for the representation of names of languages.
+
+```turtle
+dcterms:ISO639-3
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of three-letter codes listed in ISO 639-3 for the representation of names of languages."@en ;
+  rdfs:label "ISO 639-3"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    LCC,
+    NS_BASE,
+    "LCC",
+    r#"The set of conceptual resources specified by the Library of Congress Classification.
+
+```turtle
+dcterms:LCC
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of conceptual resources specified by the Library of Congress Classification."@en ;
+  rdfs:label "LCC"@en ;
+  rdf:type dcam:VocabularyEncodingScheme ;
+  .
+```
+"#
+);
+
+named_node!(
+    LCSH,
+    NS_BASE,
+    "LCSH",
+    r#"The set of labeled concepts specified by the Library of Congress Subject Headings.
+
+```turtle
+dcterms:LCSH
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of labeled concepts specified by the Library of Congress Subject Headings."@en ;
+  rdfs:label "LCSH"@en ;
+  rdf:type dcam:VocabularyEncodingScheme ;
+  .
+```
+"#
+);
+
+named_node!(
+    MESH,
+    NS_BASE,
+    "MESH",
+    r#"The set of labeled concepts specified by the Medical Subject Headings.
+
+```turtle
+dcterms:MESH
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of labeled concepts specified by the Medical Subject Headings."@en ;
+  rdfs:label "MeSH"@en ;
+  rdf:type dcam:VocabularyEncodingScheme ;
+  .
+```
+"#
+);
+
+named_node!(
+    NLM,
+    NS_BASE,
+    "NLM",
+    r#"The set of conceptual resources specified by the National Library of Medicine Classification.
+
+```turtle
+dcterms:NLM
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of conceptual resources specified by the National Library of Medicine Classification."@en ;
+  rdfs:label "NLM"@en ;
+  rdf:type dcam:VocabularyEncodingScheme ;
+  .
+```
+"#
+);
+
+named_node!(
+    PERIOD,
+    NS_BASE,
+    "Period",
+    r#"The set of time intervals defined by their limits according to the DCMI Period Encoding Scheme.
+
+```turtle
+dcterms:Period
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of time intervals defined by their limits according to the DCMI Period Encoding Scheme."@en ;
+  rdfs:label "DCMI Period"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    POINT,
+    NS_BASE,
+    "Point",
+    r#"The set of points in space defined by their geographic coordinates according to the DCMI Point Encoding Scheme.
+
+```turtle
+dcterms:Point
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of points in space defined by their geographic coordinates according to the DCMI Point Encoding Scheme."@en ;
+  rdfs:label "DCMI Point"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    RFC_1766,
+    NS_BASE,
+    "RFC1766",
+    r#"The set of tags, constructed according to RFC 1766, for the identification of languages.
+
+```turtle
+dcterms:RFC1766
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of tags, constructed according to RFC 1766, for the identification of languages."@en ;
+  rdfs:label "RFC 1766"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    RFC_3066,
+    NS_BASE,
+    "RFC3066",
+    r#"The set of tags constructed according to RFC 3066 for the identification of languages.
+
+```turtle
+dcterms:RFC3066
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of tags constructed according to RFC 3066 for the identification of languages."@en ;
+  rdfs:label "RFC 3066"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    RFC_4646,
+    NS_BASE,
+    "RFC4646",
+    r#"The set of tags constructed according to RFC 4646 for the identification of languages.
+
+```turtle
+dcterms:RFC4646
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of tags constructed according to RFC 4646 for the identification of languages."@en ;
+  rdfs:label "RFC 4646"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    RFC_5646,
+    NS_BASE,
+    "RFC5646",
+    r#"The set of tags constructed according to RFC 5646 for the identification of languages.
+
+```turtle
+dcterms:RFC5646
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of tags constructed according to RFC 5646 for the identification of languages."@en ;
+  rdfs:label "RFC 5646"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    TGN,
+    NS_BASE,
+    "TGN",
+    r#"The set of places specified by the Getty Thesaurus of Geographic Names.
+
+```turtle
+dcterms:TGN
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of places specified by the Getty Thesaurus of Geographic Names."@en ;
+  rdfs:label "TGN"@en ;
+  rdf:type dcam:VocabularyEncodingScheme ;
+  .
+```
+"#
+);
+
+named_node!(
+    UDC,
+    NS_BASE,
+    "UDC",
+    r#"The set of conceptual resources specified by the Universal Decimal Classification.
+
+```turtle
+dcterms:UDC
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of conceptual resources specified by the Universal Decimal Classification."@en ;
+  rdfs:label "UDC"@en ;
+  rdf:type dcam:VocabularyEncodingScheme ;
+  .
+```
+"#
+);
+
+named_node!(
+    URI,
+    NS_BASE,
+    "URI",
+    r#"The set of identifiers constructed according to the generic syntax for Uniform Resource Identifiers as specified by the Internet Engineering Task Force.
+
+```turtle
+dcterms:URI
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of identifiers constructed according to the generic syntax for Uniform Resource Identifiers as specified by the Internet Engineering Task Force."@en ;
+  rdfs:label "URI"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+named_node!(
+    W_3_CDTF,
+    NS_BASE,
+    "W3CDTF",
+    r#"The set of dates and times constructed according to the W3C Date and Time Formats Specification.
+
+```turtle
+dcterms:W3CDTF
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The set of dates and times constructed according to the W3C Date and Time Formats Specification."@en ;
+  rdfs:label "W3C-DTF"@en ;
+  rdf:type rdfs:Datatype ;
+  .
+```
+"#
+);
+
+/// The classes of this vocabulary.
+pub mod classes {
+    use super::NS_BASE;
+    use crate::named_node;
+
+    named_node!(
+        AGENT,
+        NS_BASE,
+        "Agent",
+        r#"A resource that acts or has the power to act.
+
+```turtle
+dcterms:Agent
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A resource that acts or has the power to act."@en ;
+  rdfs:label "Agent"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        AGENT_CLASS,
+        NS_BASE,
+        "AgentClass",
+        r#"A group of agents.
+
+```turtle
+dcterms:AgentClass
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A group of agents."@en ;
+  rdfs:label "Agent Class"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        BIBLIOGRAPHIC_RESOURCE,
+        NS_BASE,
+        "BibliographicResource",
+        r#"A book, article, or other documentary resource.
+
+```turtle
+dcterms:BibliographicResource
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A book, article, or other documentary resource."@en ;
+  rdfs:label "Bibliographic Resource"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        FILE_FORMAT,
+        NS_BASE,
+        "FileFormat",
+        r#"A digital resource format.
+
+```turtle
+dcterms:FileFormat
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A digital resource format."@en ;
+  rdfs:label "File Format"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        FREQUENCY,
+        NS_BASE,
+        "Frequency",
+        r#"A rate at which something recurs.
+
+```turtle
+dcterms:Frequency
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A rate at which something recurs."@en ;
+  rdfs:label "Frequency"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        JURISDICTION,
+        NS_BASE,
+        "Jurisdiction",
+        r#"The extent or range of judicial, law enforcement, or other authority.
+
+```turtle
+dcterms:Jurisdiction
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The extent or range of judicial, law enforcement, or other authority."@en ;
+  rdfs:label "Jurisdiction"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LICENSE_DOCUMENT,
+        NS_BASE,
+        "LicenseDocument",
+        r#"A legal document giving official permission to do something with a resource.
+
+```turtle
+dcterms:LicenseDocument
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A legal document giving official permission to do something with a resource."@en ;
+  rdfs:label "License Document"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LINGUISTIC_SYSTEM,
+        NS_BASE,
+        "LinguisticSystem",
+        r#"A system of signs, symbols, sounds, gestures, or rules used in communication.
+
+```turtle
+dcterms:LinguisticSystem
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A system of signs, symbols, sounds, gestures, or rules used in communication."@en ;
+  rdfs:label "Linguistic System"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LOCATION,
+        NS_BASE,
+        "Location",
+        r#"A spatial region or named place.
+
+```turtle
+dcterms:Location
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A spatial region or named place."@en ;
+  rdfs:label "Location"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LOCATION_PERIOD_OR_JURISDICTION,
+        NS_BASE,
+        "LocationPeriodOrJurisdiction",
+        r#"A location, period of time, or jurisdiction.
+
+```turtle
+dcterms:LocationPeriodOrJurisdiction
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A location, period of time, or jurisdiction."@en ;
+  rdfs:label "Location Period Or Jurisdiction"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MEDIA_TYPE,
+        NS_BASE,
+        "MediaType",
+        r#"A file format or physical medium.
+
+```turtle
+dcterms:MediaType
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A file format or physical medium."@en ;
+  rdfs:label "Media Type"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MEDIA_TYPE_OR_EXTENT,
+        NS_BASE,
+        "MediaTypeOrExtent",
+        r#"A media type or extent.
+
+```turtle
+dcterms:MediaTypeOrExtent
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A media type or extent."@en ;
+  rdfs:label "Media Type Or Extent"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        METHOD_OF_ACCRUAL,
+        NS_BASE,
+        "MethodOfAccrual",
+        r#"A method by which resources are added to a collection.
+
+```turtle
+dcterms:MethodOfAccrual
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A method by which resources are added to a collection."@en ;
+  rdfs:label "Method Of Accrual"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        METHOD_OF_INSTRUCTION,
+        NS_BASE,
+        "MethodOfInstruction",
+        r#"A process that is used to engender knowledge, attitudes, and skills.
+
+```turtle
+dcterms:MethodOfInstruction
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A process that is used to engender knowledge, attitudes, and skills."@en ;
+  rdfs:label "Method Of Instruction"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PERIOD_OF_TIME,
+        NS_BASE,
+        "PeriodOfTime",
+        r#"An interval of time that is named or defined by its start and end dates.
+
+```turtle
+dcterms:PeriodOfTime
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "An interval of time that is named or defined by its start and end dates."@en ;
+  rdfs:label "Period Of Time"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PHYSICAL_MEDIUM,
+        NS_BASE,
+        "PhysicalMedium",
+        r#"A physical material or carrier.
+
+```turtle
+dcterms:PhysicalMedium
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A physical material or carrier."@en ;
+  rdfs:label "Physical Medium"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PHYSICAL_RESOURCE,
+        NS_BASE,
+        "PhysicalResource",
+        r#"A material thing.
+
+```turtle
+dcterms:PhysicalResource
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A material thing."@en ;
+  rdfs:label "Physical Resource"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        POLICY,
+        NS_BASE,
+        "Policy",
+        r#"A plan or course of action by an authority, intended to influence and determine decisions, actions, and other matters.
+
+```turtle
+dcterms:Policy
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A plan or course of action by an authority, intended to influence and determine decisions, actions, and other matters."@en ;
+  rdfs:label "Policy"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PROVENANCE_STATEMENT,
+        NS_BASE,
+        "ProvenanceStatement",
+        r#"Any changes in ownership and custody of a resource since its creation that are significant for its authenticity, integrity, and interpretation.
+
+```turtle
+dcterms:ProvenanceStatement
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Any changes in ownership and custody of a resource since its creation that are significant for its authenticity, integrity, and interpretation."@en ;
+  rdfs:label "Provenance Statement"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        RIGHTS_STATEMENT,
+        NS_BASE,
+        "RightsStatement",
+        r#"A statement about the intellectual property rights (IPR) held in or over a resource, a legal document giving official permission to do something with a resource, or a statement about access rights.
+
+```turtle
+dcterms:RightsStatement
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A statement about the intellectual property rights (IPR) held in or over a resource, a legal document giving official permission to do something with a resource, or a statement about access rights."@en ;
+  rdfs:label "Rights Statement"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SIZE_OR_DURATION,
+        NS_BASE,
+        "SizeOrDuration",
+        r#"A dimension or extent, or a time taken to play or execute.
+
+```turtle
+dcterms:SizeOrDuration
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A dimension or extent, or a time taken to play or execute."@en ;
+  rdfs:label "Size Or Duration"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        STANDARD,
+        NS_BASE,
+        "Standard",
+        r#"A reference point against which other things can be evaluated or compared.
+
+```turtle
+dcterms:Standard
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A reference point against which other things can be evaluated or compared."@en ;
+  rdfs:label "Standard"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+}
+
+pub use classes::*;
+
+/// The properties of this vocabulary.
+pub mod properties {
+    use super::NS_BASE;
+    use crate::named_node;
+
+    named_node!(
+        ABSTRACT,
+        NS_BASE,
+        "abstract",
+        r#"A summary of the resource.
+
+```turtle
+dcterms:abstract
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A summary of the resource."@en ;
+  rdfs:label "Abstract"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ACCESS_RIGHTS,
+        NS_BASE,
+        "accessRights",
+        r#"Information about who access the resource or an indication of its security status.
+
+```turtle
+dcterms:accessRights
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Information about who access the resource or an indication of its security status."@en ;
+  rdfs:label "Access Rights"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ACCRUAL_METHOD,
+        NS_BASE,
+        "accrualMethod",
+        r#"The method by which items are added to a collection.
+
+```turtle
+dcterms:accrualMethod
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The method by which items are added to a collection."@en ;
+  rdfs:label "Accrual Method"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ACCRUAL_PERIODICITY,
+        NS_BASE,
+        "accrualPeriodicity",
+        r#"The frequency with which items are added to a collection.
+
+```turtle
+dcterms:accrualPeriodicity
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The frequency with which items are added to a collection."@en ;
+  rdfs:label "Accrual Periodicity"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ACCRUAL_POLICY,
+        NS_BASE,
+        "accrualPolicy",
+        r#"The policy governing the addition of items to a collection.
+
+```turtle
+dcterms:accrualPolicy
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The policy governing the addition of items to a collection."@en ;
+  rdfs:label "Accrual Policy"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ALTERNATIVE,
+        NS_BASE,
+        "alternative",
+        r#"An alternative name for the resource.
+
+```turtle
+dcterms:alternative
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "An alternative name for the resource."@en ;
+  rdfs:label "Alternative"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        AUDIENCE,
+        NS_BASE,
+        "audience",
+        r#"A class of agents for whom the resource is intended or useful.
+
+```turtle
+dcterms:audience
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A class of agents for whom the resource is intended or useful."@en ;
+  rdfs:label "Audience"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        AVAILABLE,
+        NS_BASE,
+        "available",
+        r#"Date that the resource became or will become available.
+
+```turtle
+dcterms:available
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Date that the resource became or will become available."@en ;
+  rdfs:label "Available"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        BIBLIOGRAPHIC_CITATION,
+        NS_BASE,
+        "bibliographicCitation",
+        r#"A bibliographic reference for the resource.
+
+```turtle
+dcterms:bibliographicCitation
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A bibliographic reference for the resource."@en ;
+  rdfs:label "Bibliographic Citation"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CONFORMS_TO,
+        NS_BASE,
+        "conformsTo",
+        r#"An established standard to which the described resource conforms.
+
+```turtle
+dcterms:conformsTo
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "An established standard to which the described resource conforms."@en ;
+  rdfs:label "Conforms To"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CONTRIBUTOR,
+        NS_BASE,
+        "contributor",
+        r#"An entity responsible for making contributions to the resource.
+
+```turtle
+dcterms:contributor
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "An entity responsible for making contributions to the resource."@en ;
+  rdfs:label "Contributor"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        COVERAGE,
+        NS_BASE,
+        "coverage",
+        r#"The spatial or temporal topic of the resource, spatial applicability of the resource, or jurisdiction under which the resource is relevant.
+
+```turtle
+dcterms:coverage
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The spatial or temporal topic of the resource, spatial applicability of the resource, or jurisdiction under which the resource is relevant."@en ;
+  rdfs:label "Coverage"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CREATED,
+        NS_BASE,
+        "created",
+        r#"Date of creation of the resource.
+
+```turtle
+dcterms:created
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Date of creation of the resource."@en ;
+  rdfs:label "Created"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CREATOR,
+        NS_BASE,
+        "creator",
+        r#"An entity responsible for making the resource.
+
+```turtle
+dcterms:creator
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "An entity responsible for making the resource."@en ;
+  rdfs:label "Creator"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DATE,
+        NS_BASE,
+        "date",
+        r#"A point or period of time associated with an event in the lifecycle of the resource.
+
+```turtle
+dcterms:date
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A point or period of time associated with an event in the lifecycle of the resource."@en ;
+  rdfs:label "Date"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DATE_ACCEPTED,
+        NS_BASE,
+        "dateAccepted",
+        r#"Date of acceptance of the resource.
+
+```turtle
+dcterms:dateAccepted
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Date of acceptance of the resource."@en ;
+  rdfs:label "Date Accepted"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DATE_COPYRIGHTED,
+        NS_BASE,
+        "dateCopyrighted",
+        r#"Date of copyright of the resource.
+
+```turtle
+dcterms:dateCopyrighted
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Date of copyright of the resource."@en ;
+  rdfs:label "Date Copyrighted"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DATE_SUBMITTED,
+        NS_BASE,
+        "dateSubmitted",
+        r#"Date of submission of the resource.
+
+```turtle
+dcterms:dateSubmitted
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Date of submission of the resource."@en ;
+  rdfs:label "Date Submitted"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DESCRIPTION,
+        NS_BASE,
+        "description",
+        r#"An account of the resource.
+
+```turtle
+dcterms:description
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "An account of the resource."@en ;
+  rdfs:label "Description"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        EDUCATION_LEVEL,
+        NS_BASE,
+        "educationLevel",
+        r#"A class of agents, defined in terms of progression through an educational or training context, for which the described resource is intended.
+
+```turtle
+dcterms:educationLevel
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A class of agents, defined in terms of progression through an educational or training context, for which the described resource is intended."@en ;
+  rdfs:label "Education Level"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        EXTENT,
+        NS_BASE,
+        "extent",
+        r#"The size or duration of the resource.
+
+```turtle
+dcterms:extent
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The size or duration of the resource."@en ;
+  rdfs:label "Extent"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        FORMAT,
+        NS_BASE,
+        "format",
+        r#"The file format, physical medium, or dimensions of the resource.
+
+```turtle
+dcterms:format
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The file format, physical medium, or dimensions of the resource."@en ;
+  rdfs:label "Format"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAS_FORMAT,
+        NS_BASE,
+        "hasFormat",
+        r#"A related resource that is substantially the same as the pre-existing described resource, but in another format.
+
+```turtle
+dcterms:hasFormat
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource that is substantially the same as the pre-existing described resource, but in another format."@en ;
+  rdfs:label "Has Format"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAS_PART,
+        NS_BASE,
+        "hasPart",
+        r#"A related resource that is included either physically or logically in the described resource.
+
+```turtle
+dcterms:hasPart
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource that is included either physically or logically in the described resource."@en ;
+  rdfs:label "Has Part"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAS_VERSION,
+        NS_BASE,
+        "hasVersion",
+        r#"A related resource that is a version, edition, or adaptation of the described resource.
+
+```turtle
+dcterms:hasVersion
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource that is a version, edition, or adaptation of the described resource."@en ;
+  rdfs:label "Has Version"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IDENTIFIER,
+        NS_BASE,
+        "identifier",
+        r#"An unambiguous reference to the resource within a given context.
+
+```turtle
+dcterms:identifier
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "An unambiguous reference to the resource within a given context."@en ;
+  rdfs:label "Identifier"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        INSTRUCTIONAL_METHOD,
+        NS_BASE,
+        "instructionalMethod",
+        r#"A process, used to engender knowledge, attitudes and skills, that the described resource is designed to support.
+
+```turtle
+dcterms:instructionalMethod
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A process, used to engender knowledge, attitudes and skills, that the described resource is designed to support."@en ;
+  rdfs:label "Instructional Method"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IS_FORMAT_OF,
+        NS_BASE,
+        "isFormatOf",
+        r#"A pre-existing related resource that is substantially the same as the described resource, but in another format.
+
+```turtle
+dcterms:isFormatOf
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A pre-existing related resource that is substantially the same as the described resource, but in another format."@en ;
+  rdfs:label "Is Format Of"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IS_PART_OF,
+        NS_BASE,
+        "isPartOf",
+        r#"A related resource in which the described resource is physically or logically included.
+
+```turtle
+dcterms:isPartOf
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource in which the described resource is physically or logically included."@en ;
+  rdfs:label "Is Part Of"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IS_REFERENCED_BY,
+        NS_BASE,
+        "isReferencedBy",
+        r#"A related resource that references, cites, or otherwise points to the described resource.
+
+```turtle
+dcterms:isReferencedBy
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource that references, cites, or otherwise points to the described resource."@en ;
+  rdfs:label "Is Referenced By"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IS_REPLACED_BY,
+        NS_BASE,
+        "isReplacedBy",
+        r#"A related resource that supplants, displaces, or supersedes the described resource.
+
+```turtle
+dcterms:isReplacedBy
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource that supplants, displaces, or supersedes the described resource."@en ;
+  rdfs:label "Is Replaced By"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IS_REQUIRED_BY,
+        NS_BASE,
+        "isRequiredBy",
+        r#"A related resource that requires the described resource to support its function, delivery, or coherence.
+
+```turtle
+dcterms:isRequiredBy
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource that requires the described resource to support its function, delivery, or coherence."@en ;
+  rdfs:label "Is Required By"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IS_VERSION_OF,
+        NS_BASE,
+        "isVersionOf",
+        r#"A related resource of which the described resource is a version, edition, or adaptation.
+
+```turtle
+dcterms:isVersionOf
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource of which the described resource is a version, edition, or adaptation."@en ;
+  rdfs:label "Is Version Of"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ISSUED,
+        NS_BASE,
+        "issued",
+        r#"Date of formal issuance of the resource.
+
+```turtle
+dcterms:issued
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Date of formal issuance of the resource."@en ;
+  rdfs:label "Issued"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LANGUAGE,
+        NS_BASE,
+        "language",
+        r#"A language of the resource.
+
+```turtle
+dcterms:language
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A language of the resource."@en ;
+  rdfs:label "Language"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LICENSE,
+        NS_BASE,
+        "license",
+        r#"A legal document giving official permission to do something with the resource.
+
+```turtle
+dcterms:license
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A legal document giving official permission to do something with the resource."@en ;
+  rdfs:label "License"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MEDIATOR,
+        NS_BASE,
+        "mediator",
+        r#"An entity that mediates access to the resource.
+
+```turtle
+dcterms:mediator
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "An entity that mediates access to the resource."@en ;
+  rdfs:label "Mediator"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MEDIUM,
+        NS_BASE,
+        "medium",
+        r#"The material or physical carrier of the resource.
+
+```turtle
+dcterms:medium
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The material or physical carrier of the resource."@en ;
+  rdfs:label "Medium"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MODIFIED,
+        NS_BASE,
+        "modified",
+        r#"Date on which the resource was changed.
+
+```turtle
+dcterms:modified
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Date on which the resource was changed."@en ;
+  rdfs:label "Modified"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PROVENANCE,
+        NS_BASE,
+        "provenance",
+        r#"A statement of any changes in ownership and custody of the resource since its creation that are significant for its authenticity, integrity, and interpretation.
+
+```turtle
+dcterms:provenance
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A statement of any changes in ownership and custody of the resource since its creation that are significant for its authenticity, integrity, and interpretation."@en ;
+  rdfs:label "Provenance"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PUBLISHER,
+        NS_BASE,
+        "publisher",
+        r#"An entity responsible for making the resource available.
+
+```turtle
+dcterms:publisher
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "An entity responsible for making the resource available."@en ;
+  rdfs:label "Publisher"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        REFERENCES,
+        NS_BASE,
+        "references",
+        r#"A related resource that is referenced, cited, or otherwise pointed to by the described resource.
+
+```turtle
+dcterms:references
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource that is referenced, cited, or otherwise pointed to by the described resource."@en ;
+  rdfs:label "References"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        RELATION,
+        NS_BASE,
+        "relation",
+        r#"A related resource.
+
+```turtle
+dcterms:relation
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource."@en ;
+  rdfs:label "Relation"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        REPLACES,
+        NS_BASE,
+        "replaces",
+        r#"A related resource that is supplanted, displaced, or superseded by the described resource.
+
+```turtle
+dcterms:replaces
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource that is supplanted, displaced, or superseded by the described resource."@en ;
+  rdfs:label "Replaces"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        REQUIRES,
+        NS_BASE,
+        "requires",
+        r#"A related resource that is required by the described resource to support its function, delivery, or coherence.
+
+```turtle
+dcterms:requires
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource that is required by the described resource to support its function, delivery, or coherence."@en ;
+  rdfs:label "Requires"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        RIGHTS,
+        NS_BASE,
+        "rights",
+        r#"Information about rights held in and over the resource.
+
+```turtle
+dcterms:rights
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Information about rights held in and over the resource."@en ;
+  rdfs:label "Rights"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        RIGHTS_HOLDER,
+        NS_BASE,
+        "rightsHolder",
+        r#"A person or organization owning or managing rights over the resource.
+
+```turtle
+dcterms:rightsHolder
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A person or organization owning or managing rights over the resource."@en ;
+  rdfs:label "Rights Holder"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SOURCE,
+        NS_BASE,
+        "source",
+        r#"A related resource from which the described resource is derived.
+
+```turtle
+dcterms:source
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A related resource from which the described resource is derived."@en ;
+  rdfs:label "Source"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SPATIAL,
+        NS_BASE,
+        "spatial",
+        r#"Spatial characteristics of the resource.
+
+```turtle
+dcterms:spatial
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Spatial characteristics of the resource."@en ;
+  rdfs:label "Spatial"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SUBJECT,
+        NS_BASE,
+        "subject",
+        r#"A topic of the resource.
+
+```turtle
+dcterms:subject
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A topic of the resource."@en ;
+  rdfs:label "Subject"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        TABLE_OF_CONTENTS,
+        NS_BASE,
+        "tableOfContents",
+        r#"A list of subunits of the resource.
+
+```turtle
+dcterms:tableOfContents
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A list of subunits of the resource."@en ;
+  rdfs:label "Table Of Contents"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        TEMPORAL,
+        NS_BASE,
+        "temporal",
+        r#"Temporal characteristics of the resource.
+
+```turtle
+dcterms:temporal
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Temporal characteristics of the resource."@en ;
+  rdfs:label "Temporal"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        TITLE,
+        NS_BASE,
+        "title",
+        r#"A name given to the resource.
+
+```turtle
+dcterms:title
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "A name given to the resource."@en ;
+  rdfs:label "Title"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        TYPE,
+        NS_BASE,
+        "type",
+        r#"The nature or genre of the resource.
+
+```turtle
+dcterms:type
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "The nature or genre of the resource."@en ;
+  rdfs:label "Type"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        VALID,
+        NS_BASE,
+        "valid",
+        r#"Date (often a range) of validity of a resource.
+
+```turtle
+dcterms:valid
+  rdfs:isDefinedBy dcterms: ;
+  rdfs:comment "Date (often a range) of validity of a resource."@en ;
+  rdfs:label "Valid"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+}
+
+pub use properties::*;
+
+/// All the terms of this vocabulary.
+#[allow(deprecated)]
+pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[
+    AGENT,
+    AGENT_CLASS,
+    BIBLIOGRAPHIC_RESOURCE,
+    BOX,
+    DCMI_TYPE,
+    DDC,
+    FILE_FORMAT,
+    FREQUENCY,
+    IMT,
+    ISO_3166,
+    ISO_639_2,
+    ISO_639_3,
+    JURISDICTION,
+    LCC,
+    LCSH,
+    LICENSE_DOCUMENT,
+    LINGUISTIC_SYSTEM,
+    LOCATION,
+    LOCATION_PERIOD_OR_JURISDICTION,
+    MESH,
+    MEDIA_TYPE,
+    MEDIA_TYPE_OR_EXTENT,
+    METHOD_OF_ACCRUAL,
+    METHOD_OF_INSTRUCTION,
+    NLM,
+    PERIOD,
+    PERIOD_OF_TIME,
+    PHYSICAL_MEDIUM,
+    PHYSICAL_RESOURCE,
+    POINT,
+    POLICY,
+    PROVENANCE_STATEMENT,
+    RFC_1766,
+    RFC_3066,
+    RFC_4646,
+    RFC_5646,
+    RIGHTS_STATEMENT,
+    SIZE_OR_DURATION,
+    STANDARD,
+    TGN,
+    UDC,
+    URI,
+    W_3_CDTF,
+    ABSTRACT,
+    ACCESS_RIGHTS,
+    ACCRUAL_METHOD,
+    ACCRUAL_PERIODICITY,
+    ACCRUAL_POLICY,
+    ALTERNATIVE,
+    AUDIENCE,
+    AVAILABLE,
+    BIBLIOGRAPHIC_CITATION,
+    CONFORMS_TO,
+    CONTRIBUTOR,
+    COVERAGE,
+    CREATED,
+    CREATOR,
+    DATE,
+    DATE_ACCEPTED,
+    DATE_COPYRIGHTED,
+    DATE_SUBMITTED,
+    DESCRIPTION,
+    EDUCATION_LEVEL,
+    EXTENT,
+    FORMAT,
+    HAS_FORMAT,
+    HAS_PART,
+    HAS_VERSION,
+    IDENTIFIER,
+    INSTRUCTIONAL_METHOD,
+    IS_FORMAT_OF,
+    IS_PART_OF,
+    IS_REFERENCED_BY,
+    IS_REPLACED_BY,
+    IS_REQUIRED_BY,
+    IS_VERSION_OF,
+    ISSUED,
+    LANGUAGE,
+    LICENSE,
+    MEDIATOR,
+    MEDIUM,
+    MODIFIED,
+    PROVENANCE,
+    PUBLISHER,
+    REFERENCES,
+    RELATION,
+    REPLACES,
+    REQUIRES,
+    RIGHTS,
+    RIGHTS_HOLDER,
+    SOURCE,
+    SPATIAL,
+    SUBJECT,
+    TABLE_OF_CONTENTS,
+    TEMPORAL,
+    TITLE,
+    TYPE,
+    VALID,
+];
+
+/// The deprecated terms of this vocabulary,
+/// each with the version it was deprecated in, a note
+/// and the term replacing it, if any.
+#[allow(
+    deprecated,
+    clippy::needless_raw_string_hashes,
+    clippy::type_complexity
+)]
+pub static DEPRECATIONS: &[(
+    oxrdf::NamedNodeRef<'_>,
+    &str,
+    &str,
+    Option<oxrdf::NamedNodeRef<'_>>,
+)] = &[];
@@ -0,0 +1,1443 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+// This file was generated by vocabgen v6225f9f from file 'foaf.ttl'.
+// Do not edit it manually; re-generate it instead.
+
+//! [Friend of a Friend vocabulary (FOAF)](
+//! http://xmlns.com/foaf/0.1/)
+//! vocabulary.
+
+pub const NS_BASE: &str = "http://xmlns.com/foaf/0.1/";
+pub const NS_PREFERRED_PREFIX: &str = "foaf";
+/// The prefix declaration of this vocabulary, for use in SPARQL queries.
+pub const SPARQL_PREFIX: &str = "PREFIX foaf: <http://xmlns.com/foaf/0.1/>";
+/// The prefix declaration of this vocabulary, for use in RDF/Turtle documents.
+pub const TURTLE_PREFIX: &str = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .";
+
+/// The classes of this vocabulary.
+pub mod classes {
+    use super::NS_BASE;
+    use crate::named_node;
+
+    named_node!(
+        AGENT,
+        NS_BASE,
+        "Agent",
+        r#"An agent (eg. person, group, software or physical artifact).
+
+```turtle
+foaf:Agent
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An agent (eg. person, group, software or physical artifact)."@en ;
+  rdfs:label "Agent"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DOCUMENT,
+        NS_BASE,
+        "Document",
+        r#"A document.
+
+```turtle
+foaf:Document
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A document."@en ;
+  rdfs:label "Document"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        GROUP,
+        NS_BASE,
+        "Group",
+        r#"A class of agents.
+
+```turtle
+foaf:Group
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A class of agents."@en ;
+  rdfs:label "Group"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IMAGE,
+        NS_BASE,
+        "Image",
+        r#"An image.
+
+```turtle
+foaf:Image
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An image."@en ;
+  rdfs:label "Image"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LABEL_PROPERTY,
+        NS_BASE,
+        "LabelProperty",
+        r#"Any RDF property with textual values that serve as labels.
+
+```turtle
+foaf:LabelProperty
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "Any RDF property with textual values that serve as labels."@en ;
+  rdfs:label "Label Property"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ONLINE_ACCOUNT,
+        NS_BASE,
+        "OnlineAccount",
+        r#"An online account.
+
+```turtle
+foaf:OnlineAccount
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An online account."@en ;
+  rdfs:label "Online Account"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ONLINE_CHAT_ACCOUNT,
+        NS_BASE,
+        "OnlineChatAccount",
+        r#"An online chat account.
+
+```turtle
+foaf:OnlineChatAccount
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An online chat account."@en ;
+  rdfs:label "Online Chat Account"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ONLINE_ECOMMERCE_ACCOUNT,
+        NS_BASE,
+        "OnlineEcommerceAccount",
+        r#"An online e-commerce account.
+
+```turtle
+foaf:OnlineEcommerceAccount
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An online e-commerce account."@en ;
+  rdfs:label "Online Ecommerce Account"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ONLINE_GAMING_ACCOUNT,
+        NS_BASE,
+        "OnlineGamingAccount",
+        r#"An online gaming account.
+
+```turtle
+foaf:OnlineGamingAccount
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An online gaming account."@en ;
+  rdfs:label "Online Gaming Account"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ORGANIZATION,
+        NS_BASE,
+        "Organization",
+        r#"An organization.
+
+```turtle
+foaf:Organization
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An organization."@en ;
+  rdfs:label "Organization"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PERSON,
+        NS_BASE,
+        "Person",
+        r#"A person.
+
+```turtle
+foaf:Person
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A person."@en ;
+  rdfs:label "Person"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PERSONAL_PROFILE_DOCUMENT,
+        NS_BASE,
+        "PersonalProfileDocument",
+        r#"A personal profile RDF document.
+
+```turtle
+foaf:PersonalProfileDocument
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A personal profile RDF document."@en ;
+  rdfs:label "Personal Profile Document"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PROJECT,
+        NS_BASE,
+        "Project",
+        r#"A project (a collective endeavour of some kind).
+
+```turtle
+foaf:Project
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A project (a collective endeavour of some kind)."@en ;
+  rdfs:label "Project"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+}
+
+pub use classes::*;
+
+/// The properties of this vocabulary.
+pub mod properties {
+    use super::NS_BASE;
+    use crate::{named_node, named_node_deprecated};
+
+    named_node!(
+        ACCOUNT,
+        NS_BASE,
+        "account",
+        r#"Indicates an account held by this agent.
+
+```turtle
+foaf:account
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "Indicates an account held by this agent."@en ;
+  rdfs:label "account"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ACCOUNT_NAME,
+        NS_BASE,
+        "accountName",
+        r#"Indicates the name (identifier) associated with this online account.
+
+```turtle
+foaf:accountName
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "Indicates the name (identifier) associated with this online account."@en ;
+  rdfs:label "account name"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ACCOUNT_SERVICE_HOMEPAGE,
+        NS_BASE,
+        "accountServiceHomepage",
+        r#"Indicates a homepage of the service provide for this online account.
+
+```turtle
+foaf:accountServiceHomepage
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "Indicates a homepage of the service provide for this online account."@en ;
+  rdfs:label "account service homepage"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        AGE,
+        NS_BASE,
+        "age",
+        r#"The age in years of some agent.
+
+```turtle
+foaf:age
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The age in years of some agent."@en ;
+  rdfs:label "age"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        AIM_CHAT_ID,
+        NS_BASE,
+        "aimChatID",
+        r#"An AIM chat ID.
+
+```turtle
+foaf:aimChatID
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An AIM chat ID."@en ;
+  rdfs:label "aim chat ID"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        BASED_NEAR,
+        NS_BASE,
+        "based_near",
+        r#"A location that something is based near, for some broadly human notion of near.
+
+```turtle
+foaf:based_near
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A location that something is based near, for some broadly human notion of near."@en ;
+  rdfs:label "based near"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        BIRTHDAY,
+        NS_BASE,
+        "birthday",
+        r#"The birthday of this agent, represented in mm-dd string form, eg. '12-31'.
+
+```turtle
+foaf:birthday
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The birthday of this agent, represented in mm-dd string form, eg. '12-31'."@en ;
+  rdfs:label "birthday"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CURRENT_PROJECT,
+        NS_BASE,
+        "currentProject",
+        r#"A current project this person works on.
+
+```turtle
+foaf:currentProject
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A current project this person works on."@en ;
+  rdfs:label "current project"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DEPICTION,
+        NS_BASE,
+        "depiction",
+        r#"A depiction of some thing.
+
+```turtle
+foaf:depiction
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A depiction of some thing."@en ;
+  rdfs:label "depiction"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DEPICTS,
+        NS_BASE,
+        "depicts",
+        r#"A thing depicted in this representation.
+
+```turtle
+foaf:depicts
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A thing depicted in this representation."@en ;
+  rdfs:label "depicts"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DNA_CHECKSUM,
+        NS_BASE,
+        "dnaChecksum",
+        r#"A checksum for the DNA of some thing. Joke.
+
+```turtle
+foaf:dnaChecksum
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A checksum for the DNA of some thing. Joke."@en ;
+  rdfs:label "dna checksum"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        FAMILY_NAME,
+        NS_BASE,
+        "familyName",
+        r#"The family name of some person.
+
+```turtle
+foaf:familyName
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The family name of some person."@en ;
+  rdfs:label "family name"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node_deprecated!(
+        FAMILY_NAME_ARCHAIC,
+        NS_BASE,
+        "family_name",
+        r#"Deprecated; use [`FAMILY_NAME`] instead.
+
+The family name of some person.
+
+```turtle
+foaf:family_name
+  dcterms:isReplacedBy foaf:familyName ;
+  owl:deprecated "true"^^<http://www.w3.org/2001/XMLSchema#boolean> ;
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The family name of some person."@en ;
+  rdfs:label "family name (archaic)"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#,
+        r#""#,
+        r#"use `FAMILY_NAME` instead"#,
+        FAMILY_NAME_ARCHAIC_REPLACEMENT = FAMILY_NAME
+    );
+
+    named_node!(
+        FIRST_NAME,
+        NS_BASE,
+        "firstName",
+        r#"The first name of a person.
+
+```turtle
+foaf:firstName
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The first name of a person."@en ;
+  rdfs:label "first name"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        FOCUS,
+        NS_BASE,
+        "focus",
+        r#"The underlying or 'focal' entity associated with some SKOS-described concept.
+
+```turtle
+foaf:focus
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The underlying or 'focal' entity associated with some SKOS-described concept."@en ;
+  rdfs:label "focus"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        FUNDED_BY,
+        NS_BASE,
+        "fundedBy",
+        r#"An organization funding a project or person.
+
+```turtle
+foaf:fundedBy
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An organization funding a project or person."@en ;
+  rdfs:label "funded by"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        GEEKCODE,
+        NS_BASE,
+        "geekcode",
+        r#"A textual geekcode for this person, see <http://www.geekcode.com/geek.html>.
+
+```turtle
+foaf:geekcode
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A textual geekcode for this person, see <http://www.geekcode.com/geek.html>."@en ;
+  rdfs:label "geekcode"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        GENDER,
+        NS_BASE,
+        "gender",
+        r#"The gender of this agent (typically but not necessarily 'male' or 'female').
+
+```turtle
+foaf:gender
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The gender of this agent (typically but not necessarily 'male' or 'female')."@en ;
+  rdfs:label "gender"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        GIVEN_NAME,
+        NS_BASE,
+        "givenName",
+        r#"The given name of some person.
+
+```turtle
+foaf:givenName
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The given name of some person."@en ;
+  rdfs:label "given name"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node_deprecated!(
+        GIVEN_NAME_ARCHAIC,
+        NS_BASE,
+        "givenname",
+        r#"Deprecated; use [`GIVEN_NAME`] instead.
+
+The given name of some person.
+
+```turtle
+foaf:givenname
+  dcterms:isReplacedBy foaf:givenName ;
+  owl:deprecated "true"^^<http://www.w3.org/2001/XMLSchema#boolean> ;
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The given name of some person."@en ;
+  rdfs:label "given name (archaic)"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#,
+        r#""#,
+        r#"use `GIVEN_NAME` instead"#,
+        GIVEN_NAME_ARCHAIC_REPLACEMENT = GIVEN_NAME
+    );
+
+    named_node_deprecated!(
+        DEPRECATED_HOLDS_ACCOUNT,
+        NS_BASE,
+        "holdsAccount",
+        r#"Deprecated; use [`ACCOUNT`] instead.
+
+Indicates an account held by this agent.
+
+```turtle
+foaf:holdsAccount
+  dcterms:isReplacedBy foaf:account ;
+  owl:deprecated "true"^^<http://www.w3.org/2001/XMLSchema#boolean> ;
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "Indicates an account held by this agent."@en ;
+  rdfs:label "holds account (archaic)"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#,
+        r#""#,
+        r#"use `ACCOUNT` instead"#,
+        DEPRECATED_HOLDS_ACCOUNT_REPLACEMENT = ACCOUNT
+    );
+
+    named_node!(
+        HOMEPAGE,
+        NS_BASE,
+        "homepage",
+        r#"A homepage for some thing.
+
+```turtle
+foaf:homepage
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A homepage for some thing."@en ;
+  rdfs:label "homepage"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ICQ_CHAT_ID,
+        NS_BASE,
+        "icqChatID",
+        r#"An ICQ chat ID.
+
+```turtle
+foaf:icqChatID
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An ICQ chat ID."@en ;
+  rdfs:label "icq chat ID"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IMG,
+        NS_BASE,
+        "img",
+        r#"An image that can be used to represent some thing (ie. those depictions which are particularly representative of something, eg. one's photo on a homepage).
+
+```turtle
+foaf:img
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An image that can be used to represent some thing (ie. those depictions which are particularly representative of something, eg. one's photo on a homepage)."@en ;
+  rdfs:label "img"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        INTEREST,
+        NS_BASE,
+        "interest",
+        r#"A page about a topic of interest to this person.
+
+```turtle
+foaf:interest
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A page about a topic of interest to this person."@en ;
+  rdfs:label "interest"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IS_PRIMARY_TOPIC_OF,
+        NS_BASE,
+        "isPrimaryTopicOf",
+        r#"A document that this thing is the primary topic of.
+
+```turtle
+foaf:isPrimaryTopicOf
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A document that this thing is the primary topic of."@en ;
+  rdfs:label "is primary topic of"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        JABBER_ID,
+        NS_BASE,
+        "jabberID",
+        r#"A jabber ID for something.
+
+```turtle
+foaf:jabberID
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A jabber ID for something."@en ;
+  rdfs:label "jabber ID"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        KNOWS,
+        NS_BASE,
+        "knows",
+        r#"A person known by this person (indicating some level of reciprocated interaction between the parties).
+
+```turtle
+foaf:knows
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A person known by this person (indicating some level of reciprocated interaction between the parties)."@en ;
+  rdfs:label "knows"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LAST_NAME,
+        NS_BASE,
+        "lastName",
+        r#"The last name of a person.
+
+```turtle
+foaf:lastName
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The last name of a person."@en ;
+  rdfs:label "last name"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LOGO,
+        NS_BASE,
+        "logo",
+        r#"A logo representing some thing.
+
+```turtle
+foaf:logo
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A logo representing some thing."@en ;
+  rdfs:label "logo"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MADE,
+        NS_BASE,
+        "made",
+        r#"Something that was made by this agent.
+
+```turtle
+foaf:made
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "Something that was made by this agent."@en ;
+  rdfs:label "made"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MAKER,
+        NS_BASE,
+        "maker",
+        r#"An agent that made this thing.
+
+```turtle
+foaf:maker
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An agent that made this thing."@en ;
+  rdfs:label "maker"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MBOX,
+        NS_BASE,
+        "mbox",
+        r#"A personal mailbox, ie. an Internet mailbox associated with exactly one owner, the first owner of this mailbox.
+
+```turtle
+foaf:mbox
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A personal mailbox, ie. an Internet mailbox associated with exactly one owner, the first owner of this mailbox."@en ;
+  rdfs:label "mbox"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MBOX_SHA_1_SUM,
+        NS_BASE,
+        "mbox_sha1sum",
+        r#"The sha1sum of the URI of an Internet mailbox associated with exactly one owner, the first owner of the mailbox.
+
+```turtle
+foaf:mbox_sha1sum
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The sha1sum of the URI of an Internet mailbox associated with exactly one owner, the first owner of the mailbox."@en ;
+  rdfs:label "mbox sha1sum"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MEMBER,
+        NS_BASE,
+        "member",
+        r#"Indicates a member of a group.
+
+```turtle
+foaf:member
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "Indicates a member of a group."@en ;
+  rdfs:label "member"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MEMBERSHIP_CLASS,
+        NS_BASE,
+        "membershipClass",
+        r#"Indicates the class of individuals that are a member of a group.
+
+```turtle
+foaf:membershipClass
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "Indicates the class of individuals that are a member of a group."@en ;
+  rdfs:label "membership class"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MSN_CHAT_ID,
+        NS_BASE,
+        "msnChatID",
+        r#"An MSN chat ID.
+
+```turtle
+foaf:msnChatID
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An MSN chat ID."@en ;
+  rdfs:label "msn chat ID"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MYERS_BRIGGS,
+        NS_BASE,
+        "myersBriggs",
+        r#"A Myers Briggs (MBTI) personality classification.
+
+```turtle
+foaf:myersBriggs
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A Myers Briggs (MBTI) personality classification."@en ;
+  rdfs:label "myers briggs"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        NAME,
+        NS_BASE,
+        "name",
+        r#"A name for some thing.
+
+```turtle
+foaf:name
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A name for some thing."@en ;
+  rdfs:label "name"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        NICK,
+        NS_BASE,
+        "nick",
+        r#"A short informal nickname characterising an agent (includes login identifiers, IRC and other chat nicknames).
+
+```turtle
+foaf:nick
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A short informal nickname characterising an agent (includes login identifiers, IRC and other chat nicknames)."@en ;
+  rdfs:label "nick"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        OPENID,
+        NS_BASE,
+        "openid",
+        r#"An OpenID for an agent.
+
+```turtle
+foaf:openid
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "An OpenID for an agent."@en ;
+  rdfs:label "openid"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PAGE,
+        NS_BASE,
+        "page",
+        r#"A page or document about this thing.
+
+```turtle
+foaf:page
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A page or document about this thing."@en ;
+  rdfs:label "page"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PAST_PROJECT,
+        NS_BASE,
+        "pastProject",
+        r#"A project this person has previously worked on.
+
+```turtle
+foaf:pastProject
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A project this person has previously worked on."@en ;
+  rdfs:label "past project"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PHONE,
+        NS_BASE,
+        "phone",
+        r#"A phone, specified using fully qualified tel: URI scheme.
+
+```turtle
+foaf:phone
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A phone, specified using fully qualified tel: URI scheme."@en ;
+  rdfs:label "phone"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PLAN,
+        NS_BASE,
+        "plan",
+        r#"A .plan comment, in the tradition of finger and '.plan' files.
+
+```turtle
+foaf:plan
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A .plan comment, in the tradition of finger and '.plan' files."@en ;
+  rdfs:label "plan"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PRIMARY_TOPIC,
+        NS_BASE,
+        "primaryTopic",
+        r#"The primary topic of some page or document.
+
+```turtle
+foaf:primaryTopic
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The primary topic of some page or document."@en ;
+  rdfs:label "primary topic"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PUBLICATIONS,
+        NS_BASE,
+        "publications",
+        r#"A link to the publications of this person.
+
+```turtle
+foaf:publications
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A link to the publications of this person."@en ;
+  rdfs:label "publications"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SCHOOL_HOMEPAGE,
+        NS_BASE,
+        "schoolHomepage",
+        r#"A homepage of a school attended by the person.
+
+```turtle
+foaf:schoolHomepage
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A homepage of a school attended by the person."@en ;
+  rdfs:label "school homepage"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SHA_1,
+        NS_BASE,
+        "sha1",
+        r#"A sha1sum hash, in hex.
+
+```turtle
+foaf:sha1
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A sha1sum hash, in hex."@en ;
+  rdfs:label "sha1"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SKYPE_ID,
+        NS_BASE,
+        "skypeID",
+        r#"A Skype ID.
+
+```turtle
+foaf:skypeID
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A Skype ID."@en ;
+  rdfs:label "skype ID"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        STATUS,
+        NS_BASE,
+        "status",
+        r#"A string expressing what the user is happy for the general public (normally) to know about their current activity.
+
+```turtle
+foaf:status
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A string expressing what the user is happy for the general public (normally) to know about their current activity."@en ;
+  rdfs:label "status"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SURNAME,
+        NS_BASE,
+        "surname",
+        r#"The surname of some person.
+
+```turtle
+foaf:surname
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "The surname of some person."@en ;
+  rdfs:label "surname"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        THEME,
+        NS_BASE,
+        "theme",
+        r#"A theme.
+
+```turtle
+foaf:theme
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A theme."@en ;
+  rdfs:label "theme"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        THUMBNAIL,
+        NS_BASE,
+        "thumbnail",
+        r#"A derived thumbnail image.
+
+```turtle
+foaf:thumbnail
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A derived thumbnail image."@en ;
+  rdfs:label "thumbnail"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        TIPJAR,
+        NS_BASE,
+        "tipjar",
+        r#"A tipjar document for this agent, describing means for payment and reward.
+
+```turtle
+foaf:tipjar
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A tipjar document for this agent, describing means for payment and reward."@en ;
+  rdfs:label "tipjar"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        TITLE,
+        NS_BASE,
+        "title",
+        r#"Title (Mr, Mrs, Ms, Dr. etc).
+
+```turtle
+foaf:title
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "Title (Mr, Mrs, Ms, Dr. etc)."@en ;
+  rdfs:label "title"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        TOPIC,
+        NS_BASE,
+        "topic",
+        r#"A topic of some page or document.
+
+```turtle
+foaf:topic
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A topic of some page or document."@en ;
+  rdfs:label "topic"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        TOPIC_INTEREST,
+        NS_BASE,
+        "topic_interest",
+        r#"A thing of interest to this person.
+
+```turtle
+foaf:topic_interest
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A thing of interest to this person."@en ;
+  rdfs:label "topic interest"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WEBLOG,
+        NS_BASE,
+        "weblog",
+        r#"A weblog of some thing (whether person, group, company etc.).
+
+```turtle
+foaf:weblog
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A weblog of some thing (whether person, group, company etc.)."@en ;
+  rdfs:label "weblog"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WORK_INFO_HOMEPAGE,
+        NS_BASE,
+        "workInfoHomepage",
+        r#"A work info homepage of some person; a page about their work for some organization.
+
+```turtle
+foaf:workInfoHomepage
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A work info homepage of some person; a page about their work for some organization."@en ;
+  rdfs:label "work info homepage"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WORKPLACE_HOMEPAGE,
+        NS_BASE,
+        "workplaceHomepage",
+        r#"A workplace homepage of some person; the homepage of an organization they work for.
+
+```turtle
+foaf:workplaceHomepage
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A workplace homepage of some person; the homepage of an organization they work for."@en ;
+  rdfs:label "workplace homepage"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        YAHOO_CHAT_ID,
+        NS_BASE,
+        "yahooChatID",
+        r#"A Yahoo chat ID.
+
+```turtle
+foaf:yahooChatID
+  rdfs:isDefinedBy foaf: ;
+  rdfs:comment "A Yahoo chat ID."@en ;
+  rdfs:label "yahoo chat ID"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+}
+
+pub use properties::*;
+
+/// All the terms of this vocabulary.
+#[allow(deprecated)]
+pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[
+    AGENT,
+    DOCUMENT,
+    GROUP,
+    IMAGE,
+    LABEL_PROPERTY,
+    ONLINE_ACCOUNT,
+    ONLINE_CHAT_ACCOUNT,
+    ONLINE_ECOMMERCE_ACCOUNT,
+    ONLINE_GAMING_ACCOUNT,
+    ORGANIZATION,
+    PERSON,
+    PERSONAL_PROFILE_DOCUMENT,
+    PROJECT,
+    ACCOUNT,
+    ACCOUNT_NAME,
+    ACCOUNT_SERVICE_HOMEPAGE,
+    AGE,
+    AIM_CHAT_ID,
+    BASED_NEAR,
+    BIRTHDAY,
+    CURRENT_PROJECT,
+    DEPICTION,
+    DEPICTS,
+    DNA_CHECKSUM,
+    FAMILY_NAME,
+    FAMILY_NAME_ARCHAIC,
+    FIRST_NAME,
+    FOCUS,
+    FUNDED_BY,
+    GEEKCODE,
+    GENDER,
+    GIVEN_NAME,
+    GIVEN_NAME_ARCHAIC,
+    DEPRECATED_HOLDS_ACCOUNT,
+    HOMEPAGE,
+    ICQ_CHAT_ID,
+    IMG,
+    INTEREST,
+    IS_PRIMARY_TOPIC_OF,
+    JABBER_ID,
+    KNOWS,
+    LAST_NAME,
+    LOGO,
+    MADE,
+    MAKER,
+    MBOX,
+    MBOX_SHA_1_SUM,
+    MEMBER,
+    MEMBERSHIP_CLASS,
+    MSN_CHAT_ID,
+    MYERS_BRIGGS,
+    NAME,
+    NICK,
+    OPENID,
+    PAGE,
+    PAST_PROJECT,
+    PHONE,
+    PLAN,
+    PRIMARY_TOPIC,
+    PUBLICATIONS,
+    SCHOOL_HOMEPAGE,
+    SHA_1,
+    SKYPE_ID,
+    STATUS,
+    SURNAME,
+    THEME,
+    THUMBNAIL,
+    TIPJAR,
+    TITLE,
+    TOPIC,
+    TOPIC_INTEREST,
+    WEBLOG,
+    WORK_INFO_HOMEPAGE,
+    WORKPLACE_HOMEPAGE,
+    YAHOO_CHAT_ID,
+];
+
+/// The deprecated terms of this vocabulary,
+/// each with the version it was deprecated in, a note
+/// and the term replacing it, if any.
+#[allow(
+    deprecated,
+    clippy::needless_raw_string_hashes,
+    clippy::type_complexity
+)]
+pub static DEPRECATIONS: &[(
+    oxrdf::NamedNodeRef<'_>,
+    &str,
+    &str,
+    Option<oxrdf::NamedNodeRef<'_>>,
+)] = &[
+    (
+        FAMILY_NAME_ARCHAIC,
+        r#""#,
+        r#"use `FAMILY_NAME` instead"#,
+        Some(FAMILY_NAME_ARCHAIC_REPLACEMENT),
+    ),
+    (
+        GIVEN_NAME_ARCHAIC,
+        r#""#,
+        r#"use `GIVEN_NAME` instead"#,
+        Some(GIVEN_NAME_ARCHAIC_REPLACEMENT),
+    ),
+    (
+        DEPRECATED_HOLDS_ACCOUNT,
+        r#""#,
+        r#"use `ACCOUNT` instead"#,
+        Some(DEPRECATED_HOLDS_ACCOUNT_REPLACEMENT),
+    ),
+];
@@ -4,12 +4,43 @@
 
 //! Provides ready to use [`NamedNodeRef`](super::NamedNodeRef)s
 //! for basic RDF vocabularies.
+//!
+//! Each vocabulary module is behind a cargo feature of the same name;
+//! the `full` feature (enabled by default) enables all of them.
+//! The `sophia` feature adds conversion of the terms
+//! into IRIs usable with `sophia_api`, in the module `sophia`.
 
 #![allow(dead_code)]
 
+// NOTE: Only used by the vocabulary modules, which might all be disabled.
+use const_format as _;
+
+#[cfg(feature = "dcat")]
+pub mod dcat;
+#[cfg(feature = "dcterms")]
+pub mod dcterms;
+#[cfg(feature = "foaf")]
+pub mod foaf;
+#[cfg(feature = "ocaa")]
 pub mod ocaa;
+#[cfg(feature = "owl")]
 pub mod owl;
+#[cfg(feature = "prov")]
+pub mod prov;
+#[cfg(feature = "rdfs")]
+pub mod rdfs;
+#[cfg(feature = "schema")]
+pub mod schema;
+#[cfg(feature = "sh")]
 pub mod sh;
+#[cfg(feature = "skos")]
+pub mod skos;
+#[cfg(feature = "sophia")]
+pub mod sophia;
+#[cfg(feature = "vann")]
+pub mod vann;
+#[cfg(feature = "vs")]
+pub mod vs;
 
 use git_version::git_version;
 
@@ -21,6 +52,65 @@ pub struct ReadmeDoctests;
 
 pub const VERSION: &str = git_version!(cargo_prefix = "", fallback = "unknown");
 
+/// The preferred prefixes of all the bundled vocabularies,
+/// mapped to their namespace IRIs.
+const PREFIXES: &[(&str, &str)] = &[
+    #[cfg(feature = "dcat")]
+    (dcat::NS_PREFERRED_PREFIX, dcat::NS_BASE),
+    #[cfg(feature = "dcterms")]
+    (dcterms::NS_PREFERRED_PREFIX, dcterms::NS_BASE),
+    #[cfg(feature = "foaf")]
+    (foaf::NS_PREFERRED_PREFIX, foaf::NS_BASE),
+    #[cfg(feature = "ocaa")]
+    (ocaa::NS_PREFERRED_PREFIX, ocaa::NS_BASE),
+    #[cfg(feature = "owl")]
+    (owl::NS_PREFERRED_PREFIX, owl::NS_BASE),
+    #[cfg(feature = "prov")]
+    (prov::NS_PREFERRED_PREFIX, prov::NS_BASE),
+    #[cfg(feature = "rdfs")]
+    (rdfs::NS_PREFERRED_PREFIX, rdfs::NS_BASE),
+    #[cfg(feature = "schema")]
+    (schema::NS_PREFERRED_PREFIX, schema::NS_BASE),
+    #[cfg(feature = "sh")]
+    (sh::NS_PREFERRED_PREFIX, sh::NS_BASE),
+    #[cfg(feature = "skos")]
+    (skos::NS_PREFERRED_PREFIX, skos::NS_BASE),
+    #[cfg(feature = "vann")]
+    (vann::NS_PREFERRED_PREFIX, vann::NS_BASE),
+    #[cfg(feature = "vs")]
+    (vs::NS_PREFERRED_PREFIX, vs::NS_BASE),
+];
+
+/// The preferred prefixes of all the bundled vocabularies,
+/// together with their namespace IRIs.
+#[must_use]
+pub const fn prefixes() -> &'static [(&'static str, &'static str)] {
+    PREFIXES
+}
+
+/// Expands a CURIE (e.g. `sh:NodeShape`) into a full IRI,
+/// if its prefix is the one of a bundled vocabulary.
+#[must_use]
+pub fn resolve_curie(curie: &str) -> Option<String> {
+    let (prefix, local_name) = curie.split_once(':')?;
+    PREFIXES
+        .iter()
+        .find(|(pf, _ns)| *pf == prefix)
+        .map(|(_pf, ns)| format!("{ns}{local_name}"))
+}
+
+/// Compacts an IRI into a CURIE (e.g. `sh:NodeShape`),
+/// if it is within the namespace of a bundled vocabulary.
+/// If multiple namespaces match, the longest one wins.
+#[must_use]
+pub fn shorten_iri(iri: &str) -> Option<String> {
+    PREFIXES
+        .iter()
+        .filter_map(|(pf, ns)| iri.strip_prefix(ns).map(|local_name| (pf, ns, local_name)))
+        .max_by_key(|(_pf, ns, _local_name)| ns.len())
+        .map(|(pf, _ns, local_name)| format!("{pf}:{local_name}"))
+}
+
 #[macro_export]
 macro_rules! named_node {
     ($const:ident, $base:expr, $node:literal, $doc:literal) => {
@@ -30,6 +120,11 @@ macro_rules! named_node {
     };
 }
 
+/// Like `named_node!`, but marks the term as deprecated.
+///
+/// An optional last argument of the form `X_REPLACEMENT = TERM`
+/// additionally creates the constant `X_REPLACEMENT`,
+/// referring to the term that replaces the deprecated one.
 #[macro_export]
 macro_rules! named_node_deprecated {
     ($const:ident, $base:expr, $node:literal, $doc:literal, $since:literal, $note:literal) => {
@@ -39,19 +134,39 @@ macro_rules! named_node_deprecated {
         pub const $const: oxrdf::NamedNodeRef<'_> =
             oxrdf::NamedNodeRef::new_unchecked(const_format::concatcp!($base, $node));
     };
+    ($const:ident, $base:expr, $node:literal, $doc:literal, $since:literal, $note:literal, $repl_const:ident = $replacement:expr) => {
+        $crate::named_node_deprecated!($const, $base, $node, $doc, $since, $note);
+        #[doc = concat!("The term replacing the deprecated [`", stringify!($const), "`].")]
+        pub const $repl_const: oxrdf::NamedNodeRef<'_> = $replacement;
+    };
 }
 
 #[macro_export]
 macro_rules! typed_literal {
     ($const:ident, $value:literal, $rdf_type:expr) => {
-        pub static $const: Lazy<TermRef<'_>> =
-            Lazy::new(|| TermRef::Literal(LiteralRef::new_typed_literal($value, $rdf_type)));
+        pub static $const: LazyLock<TermRef<'_>> =
+            LazyLock::new(|| TermRef::Literal(LiteralRef::new_typed_literal($value, $rdf_type)));
     };
 }
 
 pub mod basics {
-    use once_cell::sync::Lazy;
-    use oxrdf::{vocab::xsd, LiteralRef, TermRef};
+    use std::str::FromStr;
+
+    use oxrdf::{
+        vocab::xsd, LanguageTagParseError, Literal, LiteralRef, NamedNodeRef, Term, TermRef,
+    };
+    use oxsdatatypes::{Date, DateTime, Decimal};
+    use std::sync::LazyLock;
+    use thiserror::Error;
+
+    /// The ways in which creating a typed literal may fail.
+    #[derive(Error, Debug)]
+    pub enum LiteralError {
+        #[error("'{value}' is not a valid lexical form of the datatype <{datatype}>")]
+        InvalidLexicalForm { value: String, datatype: String },
+        #[error(transparent)]
+        InvalidLanguageTag(#[from] LanguageTagParseError),
+    }
 
     pub const NS_BASE_RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
 
@@ -66,4 +181,74 @@ pub mod basics {
             *BOOL_FALSE
         }
     }
+
+    /// Creates a literal of type `xsd:int`.
+    #[must_use]
+    pub fn rdf_int(value: i32) -> Term {
+        Literal::new_typed_literal(value.to_string(), xsd::INT).into()
+    }
+
+    /// Creates a literal of type `xsd:long`.
+    #[must_use]
+    pub fn rdf_long(value: i64) -> Term {
+        Literal::new_typed_literal(value.to_string(), xsd::LONG).into()
+    }
+
+    /// Creates a literal of type `xsd:double`.
+    /// Infinity and NaN are represented as `INF`, `-INF` and `NaN`.
+    #[must_use]
+    pub fn rdf_double(value: f64) -> Term {
+        Literal::from(value).into()
+    }
+
+    /// Creates a typed literal,
+    /// after checking that `value` is parsable as `T`.
+    fn validated<T: FromStr>(
+        value: &str,
+        datatype: NamedNodeRef<'_>,
+    ) -> Result<Term, LiteralError> {
+        if T::from_str(value).is_err() {
+            return Err(LiteralError::InvalidLexicalForm {
+                value: value.to_owned(),
+                datatype: datatype.as_str().to_owned(),
+            });
+        }
+        Ok(Literal::new_typed_literal(value, datatype).into())
+    }
+
+    /// Creates a literal of type `xsd:decimal`, e.g. from "-12.50".
+    ///
+    /// # Errors
+    ///
+    /// If `value` is not a valid `xsd:decimal` lexical form.
+    pub fn rdf_decimal(value: &str) -> Result<Term, LiteralError> {
+        validated::<Decimal>(value, xsd::DECIMAL)
+    }
+
+    /// Creates a literal of type `xsd:date`, e.g. from "2024-03-01".
+    ///
+    /// # Errors
+    ///
+    /// If `value` is not a valid `xsd:date` lexical form.
+    pub fn rdf_date(value: &str) -> Result<Term, LiteralError> {
+        validated::<Date>(value, xsd::DATE)
+    }
+
+    /// Creates a literal of type `xsd:dateTime`, e.g. from "2024-03-01T12:30:00Z".
+    ///
+    /// # Errors
+    ///
+    /// If `value` is not a valid `xsd:dateTime` lexical form.
+    pub fn rdf_date_time(value: &str) -> Result<Term, LiteralError> {
+        validated::<DateTime>(value, xsd::DATE_TIME)
+    }
+
+    /// Creates a language-tagged string literal (`rdf:langString`).
+    ///
+    /// # Errors
+    ///
+    /// If `lang` is not a valid BCP47 language tag.
+    pub fn rdf_lang_string(value: &str, lang: &str) -> Result<Term, LiteralError> {
+        Ok(Literal::new_language_tagged_literal(value, lang)?.into())
+    }
 }
@@ -164,3 +164,38 @@ named_node!(
     "hasNoQuery",
     "Whether the subject Ontologies IRI/namespace query part is empty, as is best-practise"
 );
+
+/// All the terms of this vocabulary.
+#[allow(deprecated)]
+pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[
+    ONTOLOGY_ANALYSIS,
+    IRI_ANALYSIS,
+    CACHE_ANALYSIS,
+    CONTENT_FORMAT,
+    HAS_ANALYSIS,
+    HAS_CONTENT,
+    HAS_NAMESPACE_IRI,
+    MEDIA_TYPE,
+    HAS_MACHINE_READABLE,
+    HAS_HUMAN_ORIENTED,
+    HAS_ANY,
+    PROVIDED,
+    PROVIDED_BY_NAMESPACE_IRI,
+    URI_COMPATIBLE,
+    USES_HTTP,
+    USES_PURL,
+    ENDS_WELL,
+    PATH_FOLLOWS_BEST_PRACTISE,
+    HAS_NO_QUERY,
+];
+
+/// The deprecated terms of this vocabulary,
+/// each with the version it was deprecated in, a note
+/// and the term replacing it, if any.
+#[allow(deprecated)]
+pub static DEPRECATIONS: &[(
+    oxrdf::NamedNodeRef<'_>,
+    &str,
+    &str,
+    Option<oxrdf::NamedNodeRef<'_>>,
+)] = &[];
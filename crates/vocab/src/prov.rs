@@ -0,0 +1,1493 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+// This file was generated by vocabgen v6225f9f from file 'prov.ttl'.
+// Do not edit it manually; re-generate it instead.
+
+//! [The PROV Ontology (PROV)](
+//! http://www.w3.org/ns/prov#)
+//! vocabulary.
+
+pub const NS_BASE: &str = "http://www.w3.org/ns/prov#";
+pub const NS_PREFERRED_PREFIX: &str = "prov";
+/// The prefix declaration of this vocabulary, for use in SPARQL queries.
+pub const SPARQL_PREFIX: &str = "PREFIX prov: <http://www.w3.org/ns/prov#>";
+/// The prefix declaration of this vocabulary, for use in RDF/Turtle documents.
+pub const TURTLE_PREFIX: &str = "@prefix prov: <http://www.w3.org/ns/prov#> .";
+
+/// The classes of this vocabulary.
+pub mod classes {
+    use super::NS_BASE;
+    use crate::named_node;
+
+    named_node!(
+        ACTIVITY,
+        NS_BASE,
+        "Activity",
+        r#"An activity is something that occurs over a period of time and acts upon or with entities; it may include consuming, processing, transforming, modifying, relocating, using, or generating entities.
+
+```turtle
+prov:Activity
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An activity is something that occurs over a period of time and acts upon or with entities; it may include consuming, processing, transforming, modifying, relocating, using, or generating entities."@en ;
+  rdfs:label "Activity"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ACTIVITY_INFLUENCE,
+        NS_BASE,
+        "ActivityInfluence",
+        r#"The influence of an activity on another activity, entity, or agent.
+
+```turtle
+prov:ActivityInfluence
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The influence of an activity on another activity, entity, or agent."@en ;
+  rdfs:label "Activity Influence"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        AGENT,
+        NS_BASE,
+        "Agent",
+        r#"An agent is something that bears some form of responsibility for an activity taking place, for the existence of an entity, or for another agent's activity.
+
+```turtle
+prov:Agent
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An agent is something that bears some form of responsibility for an activity taking place, for the existence of an entity, or for another agent's activity."@en ;
+  rdfs:label "Agent"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        AGENT_INFLUENCE,
+        NS_BASE,
+        "AgentInfluence",
+        r#"The influence of an agent on another activity, entity, or agent.
+
+```turtle
+prov:AgentInfluence
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The influence of an agent on another activity, entity, or agent."@en ;
+  rdfs:label "Agent Influence"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ASSOCIATION,
+        NS_BASE,
+        "Association",
+        r#"An activity association is an assignment of responsibility to an agent for an activity, indicating that the agent had a role in the activity. It further allows for a plan to be specified, which is the plan intended by the agent to achieve some goals in the context of this activity.
+
+```turtle
+prov:Association
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An activity association is an assignment of responsibility to an agent for an activity, indicating that the agent had a role in the activity. It further allows for a plan to be specified, which is the plan intended by the agent to achieve some goals in the context of this activity."@en ;
+  rdfs:label "Association"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ATTRIBUTION,
+        NS_BASE,
+        "Attribution",
+        r#"Attribution is the ascribing of an entity to an agent.
+
+```turtle
+prov:Attribution
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Attribution is the ascribing of an entity to an agent."@en ;
+  rdfs:label "Attribution"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        BUNDLE,
+        NS_BASE,
+        "Bundle",
+        r#"A bundle is a named set of provenance descriptions, and is itself an entity, so allowing provenance of provenance to be expressed.
+
+```turtle
+prov:Bundle
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A bundle is a named set of provenance descriptions, and is itself an entity, so allowing provenance of provenance to be expressed."@en ;
+  rdfs:label "Bundle"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        COLLECTION,
+        NS_BASE,
+        "Collection",
+        r#"A collection is an entity that provides a structure to some constituents, which are themselves entities. These constituents are said to be member of the collections.
+
+```turtle
+prov:Collection
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A collection is an entity that provides a structure to some constituents, which are themselves entities. These constituents are said to be member of the collections."@en ;
+  rdfs:label "Collection"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        COMMUNICATION,
+        NS_BASE,
+        "Communication",
+        r#"Communication is the exchange of an entity by two activities, one activity using the entity generated by the other.
+
+```turtle
+prov:Communication
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Communication is the exchange of an entity by two activities, one activity using the entity generated by the other."@en ;
+  rdfs:label "Communication"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DELEGATION,
+        NS_BASE,
+        "Delegation",
+        r#"Delegation is the assignment of authority and responsibility to an agent (by itself or by another agent) to carry out a specific activity as a delegate or representative, while the agent it acts on behalf of retains some responsibility for the outcome of the delegated work.
+
+```turtle
+prov:Delegation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Delegation is the assignment of authority and responsibility to an agent (by itself or by another agent) to carry out a specific activity as a delegate or representative, while the agent it acts on behalf of retains some responsibility for the outcome of the delegated work."@en ;
+  rdfs:label "Delegation"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DERIVATION,
+        NS_BASE,
+        "Derivation",
+        r#"A derivation is a transformation of an entity into another, an update of an entity resulting in a new one, or the construction of a new entity based on a pre-existing entity.
+
+```turtle
+prov:Derivation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A derivation is a transformation of an entity into another, an update of an entity resulting in a new one, or the construction of a new entity based on a pre-existing entity."@en ;
+  rdfs:label "Derivation"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        EMPTY_COLLECTION,
+        NS_BASE,
+        "EmptyCollection",
+        r#"An empty collection is a collection without members.
+
+```turtle
+prov:EmptyCollection
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An empty collection is a collection without members."@en ;
+  rdfs:label "Empty Collection"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        END,
+        NS_BASE,
+        "End",
+        r#"End is when an activity is deemed to have been ended by an entity, known as trigger. The activity no longer exists after its end. Any usage, generation, or invalidation involving an activity precedes the activity's end.
+
+```turtle
+prov:End
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "End is when an activity is deemed to have been ended by an entity, known as trigger. The activity no longer exists after its end. Any usage, generation, or invalidation involving an activity precedes the activity's end."@en ;
+  rdfs:label "End"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ENTITY,
+        NS_BASE,
+        "Entity",
+        r#"An entity is a physical, digital, conceptual, or other kind of thing with some fixed aspects; entities may be real or imaginary.
+
+```turtle
+prov:Entity
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An entity is a physical, digital, conceptual, or other kind of thing with some fixed aspects; entities may be real or imaginary."@en ;
+  rdfs:label "Entity"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ENTITY_INFLUENCE,
+        NS_BASE,
+        "EntityInfluence",
+        r#"The influence of an entity on another activity, entity, or agent.
+
+```turtle
+prov:EntityInfluence
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The influence of an entity on another activity, entity, or agent."@en ;
+  rdfs:label "Entity Influence"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        GENERATION,
+        NS_BASE,
+        "Generation",
+        r#"Generation is the completion of production of a new entity by an activity. This entity did not exist before generation and becomes available for usage after this generation.
+
+```turtle
+prov:Generation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Generation is the completion of production of a new entity by an activity. This entity did not exist before generation and becomes available for usage after this generation."@en ;
+  rdfs:label "Generation"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        INFLUENCE,
+        NS_BASE,
+        "Influence",
+        r#"Provides additional descriptions about the binary prov:wasInfluencedBy relation from some influenced activity, entity, or agent to the influencing activity, entity, or agent.
+
+```turtle
+prov:Influence
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Provides additional descriptions about the binary prov:wasInfluencedBy relation from some influenced activity, entity, or agent to the influencing activity, entity, or agent."@en ;
+  rdfs:label "Influence"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        INSTANTANEOUS_EVENT,
+        NS_BASE,
+        "InstantaneousEvent",
+        r#"An instantaneous event, or event for short, happens in the world and marks a change in the world, in its activities and in its entities.
+
+```turtle
+prov:InstantaneousEvent
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An instantaneous event, or event for short, happens in the world and marks a change in the world, in its activities and in its entities."@en ;
+  rdfs:label "Instantaneous Event"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        INVALIDATION,
+        NS_BASE,
+        "Invalidation",
+        r#"Invalidation is the start of the destruction, cessation, or expiry of an existing entity by an activity. The entity is no longer available for use (or further invalidation) after invalidation.
+
+```turtle
+prov:Invalidation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Invalidation is the start of the destruction, cessation, or expiry of an existing entity by an activity. The entity is no longer available for use (or further invalidation) after invalidation."@en ;
+  rdfs:label "Invalidation"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        LOCATION,
+        NS_BASE,
+        "Location",
+        r#"A location can be an identifiable geographic place, but it can also be a non-geographic place such as a directory, row, or column.
+
+```turtle
+prov:Location
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A location can be an identifiable geographic place, but it can also be a non-geographic place such as a directory, row, or column."@en ;
+  rdfs:label "Location"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ORGANIZATION,
+        NS_BASE,
+        "Organization",
+        r#"An organization is a social or legal institution such as a company, society, etc.
+
+```turtle
+prov:Organization
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An organization is a social or legal institution such as a company, society, etc."@en ;
+  rdfs:label "Organization"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PERSON,
+        NS_BASE,
+        "Person",
+        r#"Person agents are people.
+
+```turtle
+prov:Person
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Person agents are people."@en ;
+  rdfs:label "Person"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PLAN,
+        NS_BASE,
+        "Plan",
+        r#"A plan is an entity that represents a set of actions or steps intended by one or more agents to achieve some goals.
+
+```turtle
+prov:Plan
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A plan is an entity that represents a set of actions or steps intended by one or more agents to achieve some goals."@en ;
+  rdfs:label "Plan"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PRIMARY_SOURCE,
+        NS_BASE,
+        "PrimarySource",
+        r#"A primary source for a topic refers to something produced by some agent with direct experience and knowledge about the topic, at the time of the topic's study, without benefit from hindsight.
+
+```turtle
+prov:PrimarySource
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A primary source for a topic refers to something produced by some agent with direct experience and knowledge about the topic, at the time of the topic's study, without benefit from hindsight."@en ;
+  rdfs:label "Primary Source"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUOTATION,
+        NS_BASE,
+        "Quotation",
+        r#"A quotation is the repeat of (some or all of) an entity, such as text or image, by someone who may or may not be its original author.
+
+```turtle
+prov:Quotation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A quotation is the repeat of (some or all of) an entity, such as text or image, by someone who may or may not be its original author."@en ;
+  rdfs:label "Quotation"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        REVISION,
+        NS_BASE,
+        "Revision",
+        r#"A revision is a derivation for which the resulting entity is a revised version of some original.
+
+```turtle
+prov:Revision
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A revision is a derivation for which the resulting entity is a revised version of some original."@en ;
+  rdfs:label "Revision"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ROLE,
+        NS_BASE,
+        "Role",
+        r#"A role is the function of an entity or agent with respect to an activity, in the context of a usage, generation, invalidation, association, start, and end.
+
+```turtle
+prov:Role
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A role is the function of an entity or agent with respect to an activity, in the context of a usage, generation, invalidation, association, start, and end."@en ;
+  rdfs:label "Role"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SOFTWARE_AGENT,
+        NS_BASE,
+        "SoftwareAgent",
+        r#"A software agent is running software.
+
+```turtle
+prov:SoftwareAgent
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A software agent is running software."@en ;
+  rdfs:label "Software Agent"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        START,
+        NS_BASE,
+        "Start",
+        r#"Start is when an activity is deemed to have been started by an entity, known as trigger. The activity did not exist before its start. Any usage, generation, or invalidation involving an activity follows the activity's start.
+
+```turtle
+prov:Start
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Start is when an activity is deemed to have been started by an entity, known as trigger. The activity did not exist before its start. Any usage, generation, or invalidation involving an activity follows the activity's start."@en ;
+  rdfs:label "Start"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        USAGE,
+        NS_BASE,
+        "Usage",
+        r#"Usage is the beginning of utilizing an entity by an activity. Before usage, the activity had not begun to utilize this entity and could not have been affected by the entity.
+
+```turtle
+prov:Usage
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Usage is the beginning of utilizing an entity by an activity. Before usage, the activity had not begun to utilize this entity and could not have been affected by the entity."@en ;
+  rdfs:label "Usage"@en ;
+  rdf:type owl:Class ;
+  .
+```
+"#
+    );
+}
+
+pub use classes::*;
+
+/// The properties of this vocabulary.
+pub mod properties {
+    use super::NS_BASE;
+    use crate::named_node;
+
+    named_node!(
+        ACTED_ON_BEHALF_OF,
+        NS_BASE,
+        "actedOnBehalfOf",
+        r#"Expresses the accountability of an agent towards another agent. The subordinate agent acted on behalf of the responsible agent in an actual activity.
+
+```turtle
+prov:actedOnBehalfOf
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Expresses the accountability of an agent towards another agent. The subordinate agent acted on behalf of the responsible agent in an actual activity."@en ;
+  rdfs:label "acted on behalf of"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ACTIVITY_PROPERTY,
+        NS_BASE,
+        "activity",
+        r#"The activity that was involved in a qualified influence.
+
+```turtle
+prov:activity
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The activity that was involved in a qualified influence."@en ;
+  rdfs:label "activity"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        AGENT_PROPERTY,
+        NS_BASE,
+        "agent",
+        r#"The agent that was involved in a qualified influence.
+
+```turtle
+prov:agent
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The agent that was involved in a qualified influence."@en ;
+  rdfs:label "agent"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ALTERNATE_OF,
+        NS_BASE,
+        "alternateOf",
+        r#"Two alternate entities present aspects of the same thing. These aspects may be the same or different, and the alternate entities may or may not overlap in time.
+
+```turtle
+prov:alternateOf
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Two alternate entities present aspects of the same thing. These aspects may be the same or different, and the alternate entities may or may not overlap in time."@en ;
+  rdfs:label "alternate of"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        AT_LOCATION,
+        NS_BASE,
+        "atLocation",
+        r#"The location of any resource.
+
+```turtle
+prov:atLocation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The location of any resource."@en ;
+  rdfs:label "at location"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        AT_TIME,
+        NS_BASE,
+        "atTime",
+        r#"The time at which an instantaneous event occurred, in the form of xsd:dateTime.
+
+```turtle
+prov:atTime
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The time at which an instantaneous event occurred, in the form of xsd:dateTime."@en ;
+  rdfs:label "at time"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ENDED_AT_TIME,
+        NS_BASE,
+        "endedAtTime",
+        r#"The time at which an activity ended.
+
+```turtle
+prov:endedAtTime
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The time at which an activity ended."@en ;
+  rdfs:label "ended at time"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ENTITY_PROPERTY,
+        NS_BASE,
+        "entity",
+        r#"The entity that was involved in a qualified influence.
+
+```turtle
+prov:entity
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The entity that was involved in a qualified influence."@en ;
+  rdfs:label "entity"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        GENERATED,
+        NS_BASE,
+        "generated",
+        r#"An entity that was generated by this activity.
+
+```turtle
+prov:generated
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An entity that was generated by this activity."@en ;
+  rdfs:label "generated"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        GENERATED_AT_TIME,
+        NS_BASE,
+        "generatedAtTime",
+        r#"The time at which an entity was completely created and is available for use.
+
+```turtle
+prov:generatedAtTime
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The time at which an entity was completely created and is available for use."@en ;
+  rdfs:label "generated at time"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAD_ACTIVITY,
+        NS_BASE,
+        "hadActivity",
+        r#"The optional activity of an influence, which used, generated, invalidated, or was the responsibility of some entity.
+
+```turtle
+prov:hadActivity
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The optional activity of an influence, which used, generated, invalidated, or was the responsibility of some entity."@en ;
+  rdfs:label "had activity"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAD_GENERATION,
+        NS_BASE,
+        "hadGeneration",
+        r#"The optional generation involved in the derivation of an entity.
+
+```turtle
+prov:hadGeneration
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The optional generation involved in the derivation of an entity."@en ;
+  rdfs:label "had generation"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAD_MEMBER,
+        NS_BASE,
+        "hadMember",
+        r#"An entity that is a member of this collection.
+
+```turtle
+prov:hadMember
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An entity that is a member of this collection."@en ;
+  rdfs:label "had member"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAD_PLAN,
+        NS_BASE,
+        "hadPlan",
+        r#"The optional plan adopted by an agent in association with some activity.
+
+```turtle
+prov:hadPlan
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The optional plan adopted by an agent in association with some activity."@en ;
+  rdfs:label "had plan"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAD_PRIMARY_SOURCE,
+        NS_BASE,
+        "hadPrimarySource",
+        r#"Something produced by some agent with direct experience and knowledge about the topic, at the time of the topic's study, without benefit from hindsight.
+
+```turtle
+prov:hadPrimarySource
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Something produced by some agent with direct experience and knowledge about the topic, at the time of the topic's study, without benefit from hindsight."@en ;
+  rdfs:label "had primary source"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAD_ROLE,
+        NS_BASE,
+        "hadRole",
+        r#"The optional role that an entity assumed in the context of an activity.
+
+```turtle
+prov:hadRole
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The optional role that an entity assumed in the context of an activity."@en ;
+  rdfs:label "had role"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAD_USAGE,
+        NS_BASE,
+        "hadUsage",
+        r#"The optional usage involved in the derivation of an entity.
+
+```turtle
+prov:hadUsage
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The optional usage involved in the derivation of an entity."@en ;
+  rdfs:label "had usage"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        INFLUENCED,
+        NS_BASE,
+        "influenced",
+        r#"The inverse of prov:wasInfluencedBy.
+
+```turtle
+prov:influenced
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The inverse of prov:wasInfluencedBy."@en ;
+  rdfs:label "influenced"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        INFLUENCER,
+        NS_BASE,
+        "influencer",
+        r#"Cites the object of an unqualified PROV-O triple whose predicate is a sub-property of prov:wasInfluencedBy.
+
+```turtle
+prov:influencer
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Cites the object of an unqualified PROV-O triple whose predicate is a sub-property of prov:wasInfluencedBy."@en ;
+  rdfs:label "influencer"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        INVALIDATED,
+        NS_BASE,
+        "invalidated",
+        r#"An entity that was invalidated by this activity.
+
+```turtle
+prov:invalidated
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An entity that was invalidated by this activity."@en ;
+  rdfs:label "invalidated"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        INVALIDATED_AT_TIME,
+        NS_BASE,
+        "invalidatedAtTime",
+        r#"The time at which an entity was invalidated (i.e., no longer usable).
+
+```turtle
+prov:invalidatedAtTime
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The time at which an entity was invalidated (i.e., no longer usable)."@en ;
+  rdfs:label "invalidated at time"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_ASSOCIATION,
+        NS_BASE,
+        "qualifiedAssociation",
+        r#"Links an activity to a prov:Association, qualifying with which agent it was associated.
+
+```turtle
+prov:qualifiedAssociation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an activity to a prov:Association, qualifying with which agent it was associated."@en ;
+  rdfs:label "qualified association"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_ATTRIBUTION,
+        NS_BASE,
+        "qualifiedAttribution",
+        r#"Links an entity to a prov:Attribution, qualifying to which agent it is attributed.
+
+```turtle
+prov:qualifiedAttribution
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an entity to a prov:Attribution, qualifying to which agent it is attributed."@en ;
+  rdfs:label "qualified attribution"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_COMMUNICATION,
+        NS_BASE,
+        "qualifiedCommunication",
+        r#"Links an activity to a prov:Communication, qualifying by which other activity it was informed.
+
+```turtle
+prov:qualifiedCommunication
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an activity to a prov:Communication, qualifying by which other activity it was informed."@en ;
+  rdfs:label "qualified communication"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_DELEGATION,
+        NS_BASE,
+        "qualifiedDelegation",
+        r#"Links an agent to a prov:Delegation, qualifying on behalf of which other agent it acted.
+
+```turtle
+prov:qualifiedDelegation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an agent to a prov:Delegation, qualifying on behalf of which other agent it acted."@en ;
+  rdfs:label "qualified delegation"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_DERIVATION,
+        NS_BASE,
+        "qualifiedDerivation",
+        r#"Links an entity to a prov:Derivation, qualifying from which other entity it was derived.
+
+```turtle
+prov:qualifiedDerivation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an entity to a prov:Derivation, qualifying from which other entity it was derived."@en ;
+  rdfs:label "qualified derivation"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_END,
+        NS_BASE,
+        "qualifiedEnd",
+        r#"Links an activity to a prov:End, qualifying how it was ended.
+
+```turtle
+prov:qualifiedEnd
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an activity to a prov:End, qualifying how it was ended."@en ;
+  rdfs:label "qualified end"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_GENERATION,
+        NS_BASE,
+        "qualifiedGeneration",
+        r#"Links an entity to a prov:Generation, qualifying by which activity it was generated.
+
+```turtle
+prov:qualifiedGeneration
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an entity to a prov:Generation, qualifying by which activity it was generated."@en ;
+  rdfs:label "qualified generation"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_INFLUENCE,
+        NS_BASE,
+        "qualifiedInfluence",
+        r#"Links an activity, entity, or agent to a prov:Influence, qualifying by what it was influenced.
+
+```turtle
+prov:qualifiedInfluence
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an activity, entity, or agent to a prov:Influence, qualifying by what it was influenced."@en ;
+  rdfs:label "qualified influence"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_INVALIDATION,
+        NS_BASE,
+        "qualifiedInvalidation",
+        r#"Links an entity to a prov:Invalidation, qualifying by which activity it was invalidated.
+
+```turtle
+prov:qualifiedInvalidation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an entity to a prov:Invalidation, qualifying by which activity it was invalidated."@en ;
+  rdfs:label "qualified invalidation"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_PRIMARY_SOURCE,
+        NS_BASE,
+        "qualifiedPrimarySource",
+        r#"Links an entity to a prov:PrimarySource, qualifying which other entity is its primary source.
+
+```turtle
+prov:qualifiedPrimarySource
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an entity to a prov:PrimarySource, qualifying which other entity is its primary source."@en ;
+  rdfs:label "qualified primary source"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_QUOTATION,
+        NS_BASE,
+        "qualifiedQuotation",
+        r#"Links an entity to a prov:Quotation, qualifying from which other entity it was quoted.
+
+```turtle
+prov:qualifiedQuotation
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an entity to a prov:Quotation, qualifying from which other entity it was quoted."@en ;
+  rdfs:label "qualified quotation"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_REVISION,
+        NS_BASE,
+        "qualifiedRevision",
+        r#"Links an entity to a prov:Revision, qualifying of which other entity it is a revision.
+
+```turtle
+prov:qualifiedRevision
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an entity to a prov:Revision, qualifying of which other entity it is a revision."@en ;
+  rdfs:label "qualified revision"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_START,
+        NS_BASE,
+        "qualifiedStart",
+        r#"Links an activity to a prov:Start, qualifying how it was started.
+
+```turtle
+prov:qualifiedStart
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an activity to a prov:Start, qualifying how it was started."@en ;
+  rdfs:label "qualified start"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        QUALIFIED_USAGE,
+        NS_BASE,
+        "qualifiedUsage",
+        r#"Links an activity to a prov:Usage, qualifying which entity it used.
+
+```turtle
+prov:qualifiedUsage
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Links an activity to a prov:Usage, qualifying which entity it used."@en ;
+  rdfs:label "qualified usage"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SPECIALIZATION_OF,
+        NS_BASE,
+        "specializationOf",
+        r#"An entity that is a specialization of another shares all aspects of the latter, and additionally presents more specific aspects of the same thing as the latter.
+
+```turtle
+prov:specializationOf
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An entity that is a specialization of another shares all aspects of the latter, and additionally presents more specific aspects of the same thing as the latter."@en ;
+  rdfs:label "specialization of"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        STARTED_AT_TIME,
+        NS_BASE,
+        "startedAtTime",
+        r#"The time at which an activity started.
+
+```turtle
+prov:startedAtTime
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The time at which an activity started."@en ;
+  rdfs:label "started at time"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        USED,
+        NS_BASE,
+        "used",
+        r#"An entity that was used by this activity.
+
+```turtle
+prov:used
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An entity that was used by this activity."@en ;
+  rdfs:label "used"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        VALUE,
+        NS_BASE,
+        "value",
+        r#"A direct representation of an entity.
+
+```turtle
+prov:value
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A direct representation of an entity."@en ;
+  rdfs:label "value"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_ASSOCIATED_WITH,
+        NS_BASE,
+        "wasAssociatedWith",
+        r#"An agent that had some (unspecified) responsibility for the occurrence of this activity.
+
+```turtle
+prov:wasAssociatedWith
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An agent that had some (unspecified) responsibility for the occurrence of this activity."@en ;
+  rdfs:label "was associated with"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_ATTRIBUTED_TO,
+        NS_BASE,
+        "wasAttributedTo",
+        r#"Attribution is the ascribing of an entity to an agent.
+
+```turtle
+prov:wasAttributedTo
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Attribution is the ascribing of an entity to an agent."@en ;
+  rdfs:label "was attributed to"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_DERIVED_FROM,
+        NS_BASE,
+        "wasDerivedFrom",
+        r#"A derivation is a transformation of an entity into another, an update of an entity resulting in a new one, or the construction of a new entity based on a pre-existing entity.
+
+```turtle
+prov:wasDerivedFrom
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A derivation is a transformation of an entity into another, an update of an entity resulting in a new one, or the construction of a new entity based on a pre-existing entity."@en ;
+  rdfs:label "was derived from"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_ENDED_BY,
+        NS_BASE,
+        "wasEndedBy",
+        r#"End is when an activity is deemed to have ended. An end may refer to an entity, known as trigger, that terminated the activity.
+
+```turtle
+prov:wasEndedBy
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "End is when an activity is deemed to have ended. An end may refer to an entity, known as trigger, that terminated the activity."@en ;
+  rdfs:label "was ended by"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_GENERATED_BY,
+        NS_BASE,
+        "wasGeneratedBy",
+        r#"The activity that generated this entity.
+
+```turtle
+prov:wasGeneratedBy
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The activity that generated this entity."@en ;
+  rdfs:label "was generated by"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_INFLUENCED_BY,
+        NS_BASE,
+        "wasInfluencedBy",
+        r#"The capability of an activity, entity, or agent to have an effect on another activity, entity, or agent.
+
+```turtle
+prov:wasInfluencedBy
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The capability of an activity, entity, or agent to have an effect on another activity, entity, or agent."@en ;
+  rdfs:label "was influenced by"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_INFORMED_BY,
+        NS_BASE,
+        "wasInformedBy",
+        r#"An activity a2 is dependent on or informed by another activity a1, by way of some unspecified entity that is generated by a1 and used by a2.
+
+```turtle
+prov:wasInformedBy
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An activity a2 is dependent on or informed by another activity a1, by way of some unspecified entity that is generated by a1 and used by a2."@en ;
+  rdfs:label "was informed by"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_INVALIDATED_BY,
+        NS_BASE,
+        "wasInvalidatedBy",
+        r#"The activity that invalidated this entity.
+
+```turtle
+prov:wasInvalidatedBy
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "The activity that invalidated this entity."@en ;
+  rdfs:label "was invalidated by"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_QUOTED_FROM,
+        NS_BASE,
+        "wasQuotedFrom",
+        r#"An entity is derived from an original entity by copying, or 'quoting', some or all of it.
+
+```turtle
+prov:wasQuotedFrom
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "An entity is derived from an original entity by copying, or 'quoting', some or all of it."@en ;
+  rdfs:label "was quoted from"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_REVISION_OF,
+        NS_BASE,
+        "wasRevisionOf",
+        r#"A revision is a derivation that revises an entity into a revised version.
+
+```turtle
+prov:wasRevisionOf
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "A revision is a derivation that revises an entity into a revised version."@en ;
+  rdfs:label "was revision of"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        WAS_STARTED_BY,
+        NS_BASE,
+        "wasStartedBy",
+        r#"Start is when an activity is deemed to have started. A start may refer to an entity, known as trigger, that initiated the activity.
+
+```turtle
+prov:wasStartedBy
+  rdfs:isDefinedBy <http://www.w3.org/ns/prov-o> ;
+  rdfs:comment "Start is when an activity is deemed to have started. A start may refer to an entity, known as trigger, that initiated the activity."@en ;
+  rdfs:label "was started by"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+}
+
+pub use properties::*;
+
+/// All the terms of this vocabulary.
+#[allow(deprecated)]
+pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[
+    ACTIVITY,
+    ACTIVITY_INFLUENCE,
+    AGENT,
+    AGENT_INFLUENCE,
+    ASSOCIATION,
+    ATTRIBUTION,
+    BUNDLE,
+    COLLECTION,
+    COMMUNICATION,
+    DELEGATION,
+    DERIVATION,
+    EMPTY_COLLECTION,
+    END,
+    ENTITY,
+    ENTITY_INFLUENCE,
+    GENERATION,
+    INFLUENCE,
+    INSTANTANEOUS_EVENT,
+    INVALIDATION,
+    LOCATION,
+    ORGANIZATION,
+    PERSON,
+    PLAN,
+    PRIMARY_SOURCE,
+    QUOTATION,
+    REVISION,
+    ROLE,
+    SOFTWARE_AGENT,
+    START,
+    USAGE,
+    ACTED_ON_BEHALF_OF,
+    ACTIVITY_PROPERTY,
+    AGENT_PROPERTY,
+    ALTERNATE_OF,
+    AT_LOCATION,
+    AT_TIME,
+    ENDED_AT_TIME,
+    ENTITY_PROPERTY,
+    GENERATED,
+    GENERATED_AT_TIME,
+    HAD_ACTIVITY,
+    HAD_GENERATION,
+    HAD_MEMBER,
+    HAD_PLAN,
+    HAD_PRIMARY_SOURCE,
+    HAD_ROLE,
+    HAD_USAGE,
+    INFLUENCED,
+    INFLUENCER,
+    INVALIDATED,
+    INVALIDATED_AT_TIME,
+    QUALIFIED_ASSOCIATION,
+    QUALIFIED_ATTRIBUTION,
+    QUALIFIED_COMMUNICATION,
+    QUALIFIED_DELEGATION,
+    QUALIFIED_DERIVATION,
+    QUALIFIED_END,
+    QUALIFIED_GENERATION,
+    QUALIFIED_INFLUENCE,
+    QUALIFIED_INVALIDATION,
+    QUALIFIED_PRIMARY_SOURCE,
+    QUALIFIED_QUOTATION,
+    QUALIFIED_REVISION,
+    QUALIFIED_START,
+    QUALIFIED_USAGE,
+    SPECIALIZATION_OF,
+    STARTED_AT_TIME,
+    USED,
+    VALUE,
+    WAS_ASSOCIATED_WITH,
+    WAS_ATTRIBUTED_TO,
+    WAS_DERIVED_FROM,
+    WAS_ENDED_BY,
+    WAS_GENERATED_BY,
+    WAS_INFLUENCED_BY,
+    WAS_INFORMED_BY,
+    WAS_INVALIDATED_BY,
+    WAS_QUOTED_FROM,
+    WAS_REVISION_OF,
+    WAS_STARTED_BY,
+];
+
+/// The deprecated terms of this vocabulary,
+/// each with the version it was deprecated in, a note
+/// and the term replacing it, if any.
+#[allow(
+    deprecated,
+    clippy::needless_raw_string_hashes,
+    clippy::type_complexity
+)]
+pub static DEPRECATIONS: &[(
+    oxrdf::NamedNodeRef<'_>,
+    &str,
+    &str,
+    Option<oxrdf::NamedNodeRef<'_>>,
+)] = &[];
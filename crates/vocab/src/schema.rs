@@ -0,0 +1,192 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! [Schema.org](
+//! http://schema.org/)
+//! vocabulary (core subset).
+
+use crate::named_node;
+
+pub const NS_BASE: &str = "http://schema.org/";
+pub const NS_PREFERRED_PREFIX: &str = "schema";
+
+// Classes
+named_node!(
+    CREATIVE_WORK,
+    NS_BASE,
+    "CreativeWork",
+    "The most generic kind of creative work, including books, movies, photographs, software programs, etc."
+);
+named_node!(
+    DATASET,
+    NS_BASE,
+    "Dataset",
+    "A body of structured information describing some topic(s) of interest."
+);
+named_node!(
+    ORGANIZATION,
+    NS_BASE,
+    "Organization",
+    "An organization such as a school, NGO, corporation, club, etc."
+);
+named_node!(
+    PERSON,
+    NS_BASE,
+    "Person",
+    "A person (alive, dead, undead, or fictional)."
+);
+named_node!(
+    SOFTWARE_APPLICATION,
+    NS_BASE,
+    "SoftwareApplication",
+    "A software application."
+);
+named_node!(
+    SOFTWARE_SOURCE_CODE,
+    NS_BASE,
+    "SoftwareSourceCode",
+    "Computer programming source code. Example: Full (compile ready) solutions, code snippet samples, scripts, templates."
+);
+named_node!(THING, NS_BASE, "Thing", "The most generic type of item.");
+
+// Properties
+named_node!(
+    AUTHOR,
+    NS_BASE,
+    "author",
+    "The author of this content or rating."
+);
+named_node!(
+    CITATION,
+    NS_BASE,
+    "citation",
+    "A citation or reference to another creative work, such as another publication, web page, scholarly article, etc."
+);
+named_node!(
+    CONTRIBUTOR,
+    NS_BASE,
+    "contributor",
+    "A secondary contributor to the creative work or event."
+);
+named_node!(
+    CREATOR,
+    NS_BASE,
+    "creator",
+    "The creator/author of this creative work."
+);
+named_node!(
+    DATE_CREATED,
+    NS_BASE,
+    "dateCreated",
+    "The date on which the creative work was created or the item was added to a data feed."
+);
+named_node!(
+    DATE_MODIFIED,
+    NS_BASE,
+    "dateModified",
+    "The date on which the creative work was most recently modified or when the item's entry was modified within a data feed."
+);
+named_node!(
+    DATE_PUBLISHED,
+    NS_BASE,
+    "datePublished",
+    "Date of first publication or broadcast."
+);
+named_node!(
+    DESCRIPTION,
+    NS_BASE,
+    "description",
+    "A description of the item."
+);
+named_node!(
+    HAS_PART,
+    NS_BASE,
+    "hasPart",
+    "Indicates an item or creative work that is part of this item, or creative work (in some sense)."
+);
+named_node!(
+    IDENTIFIER,
+    NS_BASE,
+    "identifier",
+    "Any kind of identifier for any kind of thing, such as ISBNs, GTIN codes, UUIDs etc."
+);
+named_node!(
+    IS_PART_OF,
+    NS_BASE,
+    "isPartOf",
+    "Indicates an item or creative work that this item, or creative work (in some sense), is part of."
+);
+named_node!(
+    KEYWORDS,
+    NS_BASE,
+    "keywords",
+    "Keywords or tags used to describe some item. Multiple textual entries in a keywords list are typically delimited by commas, or by repeating the property."
+);
+named_node!(
+    LICENSE,
+    NS_BASE,
+    "license",
+    "A license document that applies to this content, typically indicated by URL."
+);
+named_node!(NAME, NS_BASE, "name", "The name of the item.");
+named_node!(
+    SAME_AS,
+    NS_BASE,
+    "sameAs",
+    "URL of a reference Web page that unambiguously indicates the item's identity. E.g. the URL of the item's Wikipedia page, Wikidata entry, or official website."
+);
+named_node!(
+    SUPERSEDED_BY,
+    NS_BASE,
+    "supersededBy",
+    "Relates a term (i.e. a property, class or enumeration) to one that supersedes it."
+);
+named_node!(URL, NS_BASE, "url", "URL of the item.");
+named_node!(
+    VERSION,
+    NS_BASE,
+    "version",
+    "The version of the creative work embodied by a specified resource."
+);
+
+/// All the terms of this vocabulary.
+#[allow(deprecated)]
+pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[
+    CREATIVE_WORK,
+    DATASET,
+    ORGANIZATION,
+    PERSON,
+    SOFTWARE_APPLICATION,
+    SOFTWARE_SOURCE_CODE,
+    THING,
+    AUTHOR,
+    CITATION,
+    CONTRIBUTOR,
+    CREATOR,
+    DATE_CREATED,
+    DATE_MODIFIED,
+    DATE_PUBLISHED,
+    DESCRIPTION,
+    HAS_PART,
+    IDENTIFIER,
+    IS_PART_OF,
+    KEYWORDS,
+    LICENSE,
+    NAME,
+    SAME_AS,
+    SUPERSEDED_BY,
+    URL,
+    VERSION,
+];
+
+/// The deprecated terms of this vocabulary,
+/// each with the version it was deprecated in, a note
+/// and the term replacing it, if any.
+#[allow(deprecated)]
+pub static DEPRECATIONS: &[(
+    oxrdf::NamedNodeRef<'_>,
+    &str,
+    &str,
+    Option<oxrdf::NamedNodeRef<'_>>,
+)] = &[];
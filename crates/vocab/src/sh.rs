@@ -87,3 +87,58 @@ named_node!(
     "description",
     "Human-readable descriptions for the property in the context of the surrounding shape."
 );
+named_node!(
+    DECLARE,
+    NS_BASE,
+    "declare",
+    "Links a resource with its namespace prefix declarations."
+);
+named_node!(
+    PREFIX,
+    NS_BASE,
+    "prefix",
+    "The prefix of a prefix declaration."
+);
+named_node!(
+    NAMESPACE,
+    NS_BASE,
+    "namespace",
+    "The namespace associated with a prefix in a prefix declaration."
+);
+
+/// All the terms of this vocabulary.
+#[allow(deprecated)]
+pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[
+    NODE_SHAPE,
+    PROPERTY_SHAPE,
+    TARGET_CLASS,
+    CLOSED,
+    PROPERTY,
+    PATH,
+    MAX_COUNT,
+    MIN_COUNT,
+    CLASS,
+    DATA_TYPE,
+    OR,
+    NODE,
+    PATTERN,
+    NODE_KIND,
+    TARGET_OBJECTS_OF,
+    TARGET_SUBJECTS_OF,
+    NAME,
+    DESCRIPTION,
+    DECLARE,
+    PREFIX,
+    NAMESPACE,
+];
+
+/// The deprecated terms of this vocabulary,
+/// each with the version it was deprecated in, a note
+/// and the term replacing it, if any.
+#[allow(deprecated)]
+pub static DEPRECATIONS: &[(
+    oxrdf::NamedNodeRef<'_>,
+    &str,
+    &str,
+    Option<oxrdf::NamedNodeRef<'_>>,
+)] = &[];
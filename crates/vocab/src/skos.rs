@@ -0,0 +1,629 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+// This file was generated by vocabgen v6225f9f from file 'skos.ttl'.
+// Do not edit it manually; re-generate it instead.
+
+//! [SKOS Vocabulary (SKOS)](
+//! http://www.w3.org/2004/02/skos/core#)
+//! vocabulary.
+
+pub const NS_BASE: &str = "http://www.w3.org/2004/02/skos/core#";
+pub const NS_PREFERRED_PREFIX: &str = "skos";
+/// The prefix declaration of this vocabulary, for use in SPARQL queries.
+pub const SPARQL_PREFIX: &str = "PREFIX skos: <http://www.w3.org/2004/02/skos/core#>";
+/// The prefix declaration of this vocabulary, for use in RDF/Turtle documents.
+pub const TURTLE_PREFIX: &str = "@prefix skos: <http://www.w3.org/2004/02/skos/core#> .";
+
+/// The classes of this vocabulary.
+pub mod classes {
+    use super::NS_BASE;
+    use crate::named_node;
+
+    named_node!(
+        COLLECTION,
+        NS_BASE,
+        "Collection",
+        r#"A meaningful collection of concepts.
+
+```turtle
+skos:Collection
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A meaningful collection of concepts."@en ;
+  rdfs:label "Collection"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CONCEPT,
+        NS_BASE,
+        "Concept",
+        r#"An idea or notion; a unit of thought.
+
+```turtle
+skos:Concept
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "An idea or notion; a unit of thought."@en ;
+  rdfs:label "Concept"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CONCEPT_SCHEME,
+        NS_BASE,
+        "ConceptScheme",
+        r#"A set of concepts, optionally including statements about semantic relationships between those concepts.
+
+```turtle
+skos:ConceptScheme
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A set of concepts, optionally including statements about semantic relationships between those concepts."@en ;
+  rdfs:label "Concept Scheme"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        ORDERED_COLLECTION,
+        NS_BASE,
+        "OrderedCollection",
+        r#"An ordered collection of concepts, where both the grouping and the ordering are meaningful.
+
+```turtle
+skos:OrderedCollection
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "An ordered collection of concepts, where both the grouping and the ordering are meaningful."@en ;
+  rdfs:label "Ordered Collection"@en ;
+  rdf:type rdfs:Class ;
+  .
+```
+"#
+    );
+}
+
+pub use classes::*;
+
+/// The properties of this vocabulary.
+pub mod properties {
+    use super::NS_BASE;
+    use crate::named_node;
+
+    named_node!(
+        ALT_LABEL,
+        NS_BASE,
+        "altLabel",
+        r#"An alternative lexical label for a resource.
+
+```turtle
+skos:altLabel
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "An alternative lexical label for a resource."@en ;
+  rdfs:label "alternative label"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        BROAD_MATCH,
+        NS_BASE,
+        "broadMatch",
+        r#"Used to state a hierarchical mapping link between two conceptual resources in different concept schemes.
+
+```turtle
+skos:broadMatch
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Used to state a hierarchical mapping link between two conceptual resources in different concept schemes."@en ;
+  rdfs:label "has broader match"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        BROADER,
+        NS_BASE,
+        "broader",
+        r#"Relates a concept to a concept that is more general in meaning.
+
+```turtle
+skos:broader
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Relates a concept to a concept that is more general in meaning."@en ;
+  rdfs:label "has broader"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        BROADER_TRANSITIVE,
+        NS_BASE,
+        "broaderTransitive",
+        r#"A transitive superproperty of skos:broader.
+
+```turtle
+skos:broaderTransitive
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A transitive superproperty of skos:broader."@en ;
+  rdfs:label "has broader transitive"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CHANGE_NOTE,
+        NS_BASE,
+        "changeNote",
+        r#"A note about a modification to a concept.
+
+```turtle
+skos:changeNote
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A note about a modification to a concept."@en ;
+  rdfs:label "change note"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        CLOSE_MATCH,
+        NS_BASE,
+        "closeMatch",
+        r#"Used to link two concepts that are sufficiently similar that they can be used interchangeably in some information retrieval applications. In order to avoid the possibility of "compound errors" when combining mappings across more than two concept schemes, it is not declared to be a transitive property.
+
+```turtle
+skos:closeMatch
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Used to link two concepts that are sufficiently similar that they can be used interchangeably in some information retrieval applications. In order to avoid the possibility of "compound errors" when combining mappings across more than two concept schemes, it is not declared to be a transitive property."@en ;
+  rdfs:label "has close match"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        DEFINITION,
+        NS_BASE,
+        "definition",
+        r#"A statement or formal explanation of the meaning of a concept.
+
+```turtle
+skos:definition
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A statement or formal explanation of the meaning of a concept."@en ;
+  rdfs:label "definition"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        EDITORIAL_NOTE,
+        NS_BASE,
+        "editorialNote",
+        r#"A note for an editor, translator or maintainer of the vocabulary.
+
+```turtle
+skos:editorialNote
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A note for an editor, translator or maintainer of the vocabulary."@en ;
+  rdfs:label "editorial note"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        EXACT_MATCH,
+        NS_BASE,
+        "exactMatch",
+        r#"Used to link two concepts, indicating a high degree of confidence that the concepts can be used interchangeably across a wide range of information retrieval applications. It is a transitive property, and is a sub-property of skos:closeMatch.
+
+```turtle
+skos:exactMatch
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Used to link two concepts, indicating a high degree of confidence that the concepts can be used interchangeably across a wide range of information retrieval applications. It is a transitive property, and is a sub-property of skos:closeMatch."@en ;
+  rdfs:label "has exact match"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        EXAMPLE,
+        NS_BASE,
+        "example",
+        r#"An example of the use of a concept.
+
+```turtle
+skos:example
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "An example of the use of a concept."@en ;
+  rdfs:label "example"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HAS_TOP_CONCEPT,
+        NS_BASE,
+        "hasTopConcept",
+        r#"Relates, by convention, a concept scheme to a concept which is topmost in the broader/narrower concept hierarchies for that scheme, providing an entry point to these hierarchies.
+
+```turtle
+skos:hasTopConcept
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Relates, by convention, a concept scheme to a concept which is topmost in the broader/narrower concept hierarchies for that scheme, providing an entry point to these hierarchies."@en ;
+  rdfs:label "has top concept"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HIDDEN_LABEL,
+        NS_BASE,
+        "hiddenLabel",
+        r#"A lexical label for a resource that should be hidden when generating visual displays of the resource, but should still be accessible to free text search operations.
+
+```turtle
+skos:hiddenLabel
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A lexical label for a resource that should be hidden when generating visual displays of the resource, but should still be accessible to free text search operations."@en ;
+  rdfs:label "hidden label"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        HISTORY_NOTE,
+        NS_BASE,
+        "historyNote",
+        r#"A note about the past state/use/meaning of a concept.
+
+```turtle
+skos:historyNote
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A note about the past state/use/meaning of a concept."@en ;
+  rdfs:label "history note"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        IN_SCHEME,
+        NS_BASE,
+        "inScheme",
+        r#"Relates a resource (for example a concept) to a concept scheme in which it is included.
+
+```turtle
+skos:inScheme
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Relates a resource (for example a concept) to a concept scheme in which it is included."@en ;
+  rdfs:label "is in scheme"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MAPPING_RELATION,
+        NS_BASE,
+        "mappingRelation",
+        r#"Relates two concepts coming, by convention, from different schemes, and that have comparable meanings.
+
+```turtle
+skos:mappingRelation
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Relates two concepts coming, by convention, from different schemes, and that have comparable meanings."@en ;
+  rdfs:label "is in mapping relation with"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MEMBER,
+        NS_BASE,
+        "member",
+        r#"Relates a collection to one of its members.
+
+```turtle
+skos:member
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Relates a collection to one of its members."@en ;
+  rdfs:label "has member"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        MEMBER_LIST,
+        NS_BASE,
+        "memberList",
+        r#"Relates an ordered collection to the RDF list containing its members.
+
+```turtle
+skos:memberList
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Relates an ordered collection to the RDF list containing its members."@en ;
+  rdfs:label "has member list"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        NARROW_MATCH,
+        NS_BASE,
+        "narrowMatch",
+        r#"Used to state a hierarchical mapping link between two conceptual resources in different concept schemes.
+
+```turtle
+skos:narrowMatch
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Used to state a hierarchical mapping link between two conceptual resources in different concept schemes."@en ;
+  rdfs:label "has narrower match"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        NARROWER,
+        NS_BASE,
+        "narrower",
+        r#"Relates a concept to a concept that is more specific in meaning.
+
+```turtle
+skos:narrower
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Relates a concept to a concept that is more specific in meaning."@en ;
+  rdfs:label "has narrower"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        NARROWER_TRANSITIVE,
+        NS_BASE,
+        "narrowerTransitive",
+        r#"A transitive superproperty of skos:narrower.
+
+```turtle
+skos:narrowerTransitive
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A transitive superproperty of skos:narrower."@en ;
+  rdfs:label "has narrower transitive"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        NOTATION,
+        NS_BASE,
+        "notation",
+        r#"A notation, also known as classification code, is a string of characters such as "T58.5" or "303.4833" used to uniquely identify a concept within the scope of a given concept scheme.
+
+```turtle
+skos:notation
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A notation, also known as classification code, is a string of characters such as "T58.5" or "303.4833" used to uniquely identify a concept within the scope of a given concept scheme."@en ;
+  rdfs:label "notation"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        NOTE,
+        NS_BASE,
+        "note",
+        r#"A general note, for any purpose.
+
+```turtle
+skos:note
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A general note, for any purpose."@en ;
+  rdfs:label "note"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        PREF_LABEL,
+        NS_BASE,
+        "prefLabel",
+        r#"The preferred and only permitted lexical label for a resource, in a given language.
+
+```turtle
+skos:prefLabel
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "The preferred and only permitted lexical label for a resource, in a given language."@en ;
+  rdfs:label "preferred label"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        RELATED,
+        NS_BASE,
+        "related",
+        r#"Relates a concept to a concept with which there is an associative semantic relationship.
+
+```turtle
+skos:related
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Relates a concept to a concept with which there is an associative semantic relationship."@en ;
+  rdfs:label "has related"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        RELATED_MATCH,
+        NS_BASE,
+        "relatedMatch",
+        r#"Used to state an associative mapping link between two conceptual resources in different concept schemes.
+
+```turtle
+skos:relatedMatch
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Used to state an associative mapping link between two conceptual resources in different concept schemes."@en ;
+  rdfs:label "has related match"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SCOPE_NOTE,
+        NS_BASE,
+        "scopeNote",
+        r#"A note that helps to clarify the meaning and/or the use of a concept.
+
+```turtle
+skos:scopeNote
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "A note that helps to clarify the meaning and/or the use of a concept."@en ;
+  rdfs:label "scope note"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        SEMANTIC_RELATION,
+        NS_BASE,
+        "semanticRelation",
+        r#"Links a concept to a concept related by meaning.
+
+```turtle
+skos:semanticRelation
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Links a concept to a concept related by meaning."@en ;
+  rdfs:label "is in semantic relation with"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+
+    named_node!(
+        TOP_CONCEPT_OF,
+        NS_BASE,
+        "topConceptOf",
+        r#"Relates a concept to the concept scheme that it is a top level concept of.
+
+```turtle
+skos:topConceptOf
+  rdfs:isDefinedBy <http://www.w3.org/2004/02/skos/core> ;
+  rdfs:comment "Relates a concept to the concept scheme that it is a top level concept of."@en ;
+  rdfs:label "is top concept in scheme"@en ;
+  rdf:type rdf:Property ;
+  .
+```
+"#
+    );
+}
+
+pub use properties::*;
+
+/// All the terms of this vocabulary.
+#[allow(deprecated)]
+pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[
+    COLLECTION,
+    CONCEPT,
+    CONCEPT_SCHEME,
+    ORDERED_COLLECTION,
+    ALT_LABEL,
+    BROAD_MATCH,
+    BROADER,
+    BROADER_TRANSITIVE,
+    CHANGE_NOTE,
+    CLOSE_MATCH,
+    DEFINITION,
+    EDITORIAL_NOTE,
+    EXACT_MATCH,
+    EXAMPLE,
+    HAS_TOP_CONCEPT,
+    HIDDEN_LABEL,
+    HISTORY_NOTE,
+    IN_SCHEME,
+    MAPPING_RELATION,
+    MEMBER,
+    MEMBER_LIST,
+    NARROW_MATCH,
+    NARROWER,
+    NARROWER_TRANSITIVE,
+    NOTATION,
+    NOTE,
+    PREF_LABEL,
+    RELATED,
+    RELATED_MATCH,
+    SCOPE_NOTE,
+    SEMANTIC_RELATION,
+    TOP_CONCEPT_OF,
+];
+
+/// The deprecated terms of this vocabulary,
+/// each with the version it was deprecated in, a note
+/// and the term replacing it, if any.
+#[allow(
+    deprecated,
+    clippy::needless_raw_string_hashes,
+    clippy::type_complexity
+)]
+pub static DEPRECATIONS: &[(
+    oxrdf::NamedNodeRef<'_>,
+    &str,
+    &str,
+    Option<oxrdf::NamedNodeRef<'_>>,
+)] = &[];
@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Makes the terms of the bundled vocabularies usable
+//! with the [`sophia_api`] stack.
+//!
+//! ```
+//! # #[cfg(feature = "owl")] {
+//! use rdfoothills_vocab::{owl, sophia::{iri, AsSophia}};
+//! use sophia_api::term::IriRef;
+//!
+//! const CLASS: IriRef<&str> = iri(owl::CLASS);
+//! assert_eq!(CLASS, owl::CLASS.as_sophia());
+//! # }
+//! ```
+
+use oxrdf::NamedNodeRef;
+use sophia_api::term::IriRef;
+
+/// Converts a term with a static IRI into a `sophia_api` IRI,
+/// usable in `const` context.
+#[must_use]
+pub const fn iri(term: NamedNodeRef<'static>) -> IriRef<&'static str> {
+    IriRef::new_unchecked_const(term.as_str())
+}
+
+/// Conversion of `oxrdf` terms into `sophia_api` terms.
+pub trait AsSophia<'a> {
+    /// Returns the `sophia_api` IRI of this term,
+    /// borrowing the IRI string.
+    fn as_sophia(&self) -> IriRef<&'a str>;
+}
+
+impl<'a> AsSophia<'a> for NamedNodeRef<'a> {
+    fn as_sophia(&self) -> IriRef<&'a str> {
+        IriRef::new_unchecked(self.as_str())
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! [Vocabulary for annotating vocabulary descriptions (VANN)](
+//! http://purl.org/vocab/vann/)
+//! vocabulary.
+
+use crate::named_node;
+
+pub const NS_BASE: &str = "http://purl.org/vocab/vann/";
+pub const NS_PREFERRED_PREFIX: &str = "vann";
+
+named_node!(
+    CHANGES,
+    NS_BASE,
+    "changes",
+    "A reference to a resource that describes changes between this version of a vocabulary and the previous."
+);
+named_node!(
+    EXAMPLE,
+    NS_BASE,
+    "example",
+    "A reference to a resource that provides an example of how this resource can be used."
+);
+named_node!(
+    PREFERRED_NAMESPACE_PREFIX,
+    NS_BASE,
+    "preferredNamespacePrefix",
+    "The preferred namespace prefix to use when using terms from this vocabulary in an XML document."
+);
+named_node!(
+    PREFERRED_NAMESPACE_URI,
+    NS_BASE,
+    "preferredNamespaceUri",
+    "The preferred namespace URI to use when using terms from this vocabulary in an XML document."
+);
+named_node!(
+    TERM_GROUP,
+    NS_BASE,
+    "termGroup",
+    "A group of related terms in a vocabulary."
+);
+named_node!(
+    USAGE_NOTE,
+    NS_BASE,
+    "usageNote",
+    "A reference to a resource that provides information on how this resource is to be used."
+);
+
+/// All the terms of this vocabulary.
+#[allow(deprecated)]
+pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[
+    CHANGES,
+    EXAMPLE,
+    PREFERRED_NAMESPACE_PREFIX,
+    PREFERRED_NAMESPACE_URI,
+    TERM_GROUP,
+    USAGE_NOTE,
+];
+
+/// The deprecated terms of this vocabulary,
+/// each with the version it was deprecated in, a note
+/// and the term replacing it, if any.
+#[allow(deprecated)]
+pub static DEPRECATIONS: &[(
+    oxrdf::NamedNodeRef<'_>,
+    &str,
+    &str,
+    Option<oxrdf::NamedNodeRef<'_>>,
+)] = &[];
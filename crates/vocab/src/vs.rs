@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! [Term-centric Semantic Web Vocabulary Annotations (VS)](
+//! http://www.w3.org/2003/06/sw-vocab-status/ns)
+//! vocabulary.
+
+use crate::named_node;
+
+pub const NS_BASE: &str = "http://www.w3.org/2003/06/sw-vocab-status/ns#";
+pub const NS_PREFERRED_PREFIX: &str = "vs";
+
+named_node!(
+    MOREINFO,
+    NS_BASE,
+    "moreinfo",
+    "More information about the status etc. of a term, typically human oriented."
+);
+named_node!(
+    TERM_STATUS,
+    NS_BASE,
+    "term_status",
+    "The status of a vocabulary term, expressed as a short symbolic string; known values include 'unstable', 'testing', 'stable' and 'archaic'."
+);
+named_node!(
+    USERDOCS,
+    NS_BASE,
+    "userdocs",
+    "Further information about the use of a term."
+);
+
+/// All the terms of this vocabulary.
+#[allow(deprecated)]
+pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[MOREINFO, TERM_STATUS, USERDOCS];
+
+/// The deprecated terms of this vocabulary,
+/// each with the version it was deprecated in, a note
+/// and the term replacing it, if any.
+#[allow(deprecated)]
+pub static DEPRECATIONS: &[(
+    oxrdf::NamedNodeRef<'_>,
+    &str,
+    &str,
+    Option<oxrdf::NamedNodeRef<'_>>,
+)] = &[];
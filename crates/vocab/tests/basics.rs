@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use oxrdf::{vocab::xsd, Literal, Term};
+use rdfoothills_vocab::basics::{
+    rdf_date, rdf_date_time, rdf_decimal, rdf_double, rdf_int, rdf_lang_string, rdf_long,
+};
+
+fn typed(value: &str, datatype: oxrdf::NamedNodeRef<'_>) -> Term {
+    Literal::new_typed_literal(value, datatype).into()
+}
+
+#[test]
+fn test_numbers() {
+    assert_eq!(rdf_int(-42), typed("-42", xsd::INT));
+    assert_eq!(rdf_long(1 << 40), typed("1099511627776", xsd::LONG));
+    assert_eq!(rdf_double(1.5), typed("1.5", xsd::DOUBLE));
+    assert_eq!(rdf_double(f64::INFINITY), typed("INF", xsd::DOUBLE));
+    assert_eq!(
+        rdf_decimal("-12.50").unwrap(),
+        typed("-12.50", xsd::DECIMAL)
+    );
+    assert!(rdf_decimal("12,5").is_err());
+}
+
+#[test]
+fn test_dates() {
+    assert_eq!(
+        rdf_date("2024-03-01").unwrap(),
+        typed("2024-03-01", xsd::DATE)
+    );
+    assert!(rdf_date("2024-13-01").is_err());
+    assert_eq!(
+        rdf_date_time("2024-03-01T12:30:00Z").unwrap(),
+        typed("2024-03-01T12:30:00Z", xsd::DATE_TIME)
+    );
+    assert!(rdf_date_time("2024-03-01").is_err());
+}
+
+#[test]
+fn test_lang_string() {
+    assert_eq!(
+        rdf_lang_string("Ding", "de").unwrap(),
+        Literal::new_language_tagged_literal_unchecked("Ding", "de").into()
+    );
+    assert!(rdf_lang_string("Ding", "not a tag").is_err());
+}
@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(feature = "owl", feature = "foaf"))]
+
+use oxrdf::vocab::rdfs;
+use rdfoothills_vocab::{foaf, owl};
+
+#[test]
+fn test_replacement_consts() {
+    assert_eq!(owl::DATA_RANGE_REPLACEMENT, rdfs::DATATYPE);
+    assert_eq!(foaf::GIVEN_NAME_ARCHAIC_REPLACEMENT, foaf::GIVEN_NAME);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecations_table() {
+    assert_eq!(
+        owl::DEPRECATIONS,
+        &[(
+            owl::DATA_RANGE,
+            "OWL 2",
+            "use `rdfs::DATATYPE` instead",
+            Some(rdfs::DATATYPE)
+        )]
+    );
+    for (term, _since, _note, replacement) in foaf::DEPRECATIONS {
+        assert!(foaf::ALL_TERMS.contains(term));
+        assert!(replacement.is_some());
+    }
+}
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(feature = "sh", feature = "dcterms"))]
+
+use rdfoothills_vocab::{prefixes, resolve_curie, sh, shorten_iri};
+
+#[test]
+fn test_prefixes() {
+    assert!(prefixes().contains(&("sh", "http://www.w3.org/ns/shacl#")));
+    assert!(prefixes().contains(&("dcterms", "http://purl.org/dc/terms/")));
+}
+
+#[test]
+fn test_resolve_curie() {
+    assert_eq!(
+        resolve_curie("sh:NodeShape").as_deref(),
+        Some(sh::NODE_SHAPE.as_str())
+    );
+    assert_eq!(resolve_curie("unknown:NodeShape"), None);
+    assert_eq!(resolve_curie("NodeShape"), None);
+}
+
+#[test]
+fn test_shorten_iri() {
+    assert_eq!(
+        shorten_iri(sh::NODE_SHAPE.as_str()).as_deref(),
+        Some("sh:NodeShape")
+    );
+    assert_eq!(
+        shorten_iri("http://www.w3.org/2004/02/skos/core#prefLabel").as_deref(),
+        Some("skos:prefLabel")
+    );
+    assert_eq!(shorten_iri("http://example.org/ont#Thing"), None);
+}
@@ -0,0 +1,21 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(feature = "sophia", feature = "skos"))]
+
+use rdfoothills_vocab::skos;
+use rdfoothills_vocab::sophia::{iri, AsSophia};
+use sophia_api::term::{IriRef, Term};
+
+const CONCEPT: IriRef<&str> = iri(skos::CONCEPT);
+
+#[test]
+fn test_as_sophia() {
+    assert_eq!(CONCEPT.as_str(), skos::CONCEPT.as_str());
+    assert_eq!(CONCEPT, skos::CONCEPT.as_sophia());
+    for term in skos::ALL_TERMS {
+        assert_eq!(term.as_sophia().iri().unwrap().as_str(), term.as_str());
+    }
+}
@@ -0,0 +1,18 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(all(feature = "dcterms", feature = "owl", feature = "sh"))]
+
+use rdfoothills_vocab::{dcterms, owl, sh};
+
+#[test]
+fn test_all_terms() {
+    assert!(sh::ALL_TERMS.contains(&sh::NODE_SHAPE));
+    assert!(owl::ALL_TERMS.contains(&owl::CLASS));
+    assert!(dcterms::ALL_TERMS.contains(&dcterms::TITLE));
+    assert!(owl::ALL_TERMS
+        .iter()
+        .all(|term| term.as_str().starts_with(owl::NS_BASE)));
+}
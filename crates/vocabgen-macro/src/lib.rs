@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::env;
+use std::path::PathBuf;
+
+use proc_macro::TokenStream;
+use rdfoothills_vocabgen::config::{Config, OntSource};
+use rdfoothills_vocabgen::generate_vocabs;
+use syn::{parse_macro_input, LitStr};
+
+// Only used by the integration tests.
+#[cfg(test)]
+use {const_format as _, oxrdf as _, rdfoothills_vocab as _};
+
+// This tests rust code in the README with doc-tests.
+// Though, It will not appear in the generated documentation.
+#[doc = include_str!("../README.md")]
+#[cfg(doctest)]
+struct ReadmeDoctests;
+
+/// Generates shortcuts for the RDF terms of a vocabulary at compile time.
+///
+/// The argument is the path to an ontology file
+/// in any format supported by `vocabgen`,
+/// relative to the root of the package using this macro.
+///
+/// This expands to a module named after the preferred namespace prefix
+/// of each ontology in the file,
+/// containing the same code `vocabgen` would generate.
+/// The `named_node!` and `named_node_deprecated!` macros
+/// from `rdfoothills-vocab` have to be available in the crate root.
+///
+/// Reading, parsing or generation failures
+/// are reported as compile errors.
+#[proc_macro]
+pub fn vocab_from_ttl(input: TokenStream) -> TokenStream {
+    let path_lit = parse_macro_input!(input as LitStr);
+    let mut ont_file = PathBuf::from(path_lit.value());
+    if ont_file.is_relative() {
+        if let Some(manifest_dir) = env::var_os("CARGO_MANIFEST_DIR") {
+            ont_file = PathBuf::from(manifest_dir).join(ont_file);
+        }
+    }
+
+    let generated = match generate_vocabs(&OntSource::File(ont_file.clone()), &Config::default()) {
+        Ok(generated) => generated,
+        Err(err) => {
+            return syn::Error::new(
+                path_lit.span(),
+                format!(
+                    "Failed to generate vocabulary from '{}': {err}",
+                    ont_file.display()
+                ),
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    // The `include_bytes!` makes the compiler track the ontology file,
+    // so the code gets re-generated when it changes.
+    let mut code = String::new();
+    code.extend(generated.into_iter().map(|vocab| {
+        format!(
+            "pub mod {} {{\n{}\nconst _: &[u8] = include_bytes!({:?});\n}}\n",
+            vocab.module,
+            vocab.source,
+            ont_file.display().to_string()
+        )
+    }));
+    code.parse().unwrap_or_else(|err| {
+        syn::Error::new(
+            path_lit.span(),
+            format!("Generated vocabulary code is not valid Rust: {err}"),
+        )
+        .to_compile_error()
+        .into()
+    })
+}
@@ -0,0 +1,17 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_vocab::named_node;
+use rdfoothills_vocabgen_macro::vocab_from_ttl;
+
+vocab_from_ttl!("tests/ont.ttl");
+
+#[test]
+fn test_vocab_from_ttl() {
+    assert_eq!(ex::NS_BASE, "http://example.org/ont#");
+    assert_eq!(ex::NS_PREFERRED_PREFIX, "ex");
+    assert_eq!(ex::THING.as_str(), "http://example.org/ont#Thing");
+}
@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::config::{Config, OntSource};
+use crate::generate;
+
+/// Generates Rust vocabularies from within a build script (`build.rs`).
+///
+/// The output is written to the `OUT_DIR` provided by cargo,
+/// which is also returned,
+/// so the generated code can be included like this:
+///
+/// ```ignore
+/// include!(concat!(env!("OUT_DIR"), "/mod.rs"));
+/// ```
+///
+/// For each local input ontology,
+/// `cargo:rerun-if-changed` is emitted,
+/// and generation is skipped if none of the inputs changed
+/// since the last run (see [`generate`]).
+///
+/// Each input is parsed like a CLI argument,
+/// so it may be either a local file path
+/// (relative to the package root)
+/// or an HTTP(S) URL.
+///
+/// # Errors
+///
+/// - the `OUT_DIR` environment variable is not set
+/// - one of the inputs is an invalid URL
+/// - generation failed (see [`generate`])
+// Printing to stdout is how build scripts communicate with cargo.
+#[allow(clippy::print_stdout)]
+pub fn build<I>(inputs: I) -> io::Result<PathBuf>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let out_dir = env::var_os("OUT_DIR").map(PathBuf::from).ok_or_else(|| {
+        io::Error::other(
+            "Environment variable 'OUT_DIR' is not set; is this called from within a build script?",
+        )
+    })?;
+    let ontologies = inputs
+        .into_iter()
+        .map(|ont| OntSource::from_str(ont.as_ref()).map_err(io::Error::other))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    for ont in &ontologies {
+        if let OntSource::File(file) = ont {
+            println!("cargo:rerun-if-changed={}", file.display());
+        }
+    }
+
+    let config = Config {
+        ontologies,
+        out_dir: out_dir.clone(),
+        force: true,
+        ..Config::default()
+    };
+    generate(&config)?;
+
+    Ok(out_dir)
+}
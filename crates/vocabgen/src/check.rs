@@ -0,0 +1,94 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Sanity checks of an ontology,
+//! run before generating code from it.
+
+use std::collections::BTreeMap;
+
+use oxrdf::NamedNode;
+use thiserror::Error;
+
+use crate::parse::VocabInfo;
+
+/// A problem with an ontology
+/// that would lead to broken or surprising generated code.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    #[error("No preferred namespace prefix (vann:preferredNamespacePrefix or sh:declare) found")]
+    MissingNamespacePrefix,
+    #[error("No preferred namespace URI (vann:preferredNamespaceUri or sh:declare) found")]
+    MissingNamespaceUri,
+    #[error("Term '{iri}' is outside of the ontologies namespace '{namespace}'")]
+    OutsideNamespace { iri: String, namespace: String },
+    #[error("Label '{label}' is used by multiple terms: {}", iris.join(", "))]
+    DuplicateLabel { label: String, iris: Vec<String> },
+    #[error("Malformed IRI '{iri}': {reason}")]
+    MalformedIri { iri: String, reason: String },
+    #[error("Term '{iri}' has non-ASCII characters in its local name '{postfix}'")]
+    NonAsciiPostfix { iri: String, postfix: String },
+}
+
+/// Checks the vocabulary for problems,
+/// in a deterministic order.
+#[must_use]
+pub fn check(vocab: &VocabInfo) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    if vocab.preferred_namespace_prefix.is_none() {
+        issues.push(Issue::MissingNamespacePrefix);
+    }
+    match &vocab.preferred_namespace_uri {
+        None => issues.push(Issue::MissingNamespaceUri),
+        Some(namespace) => {
+            check_iri(namespace, &mut issues);
+            issues.extend(
+                vocab
+                    .subjects
+                    .iter()
+                    .filter(|subj| !subj.iri().starts_with(namespace.as_str()))
+                    .map(|subj| Issue::OutsideNamespace {
+                        iri: subj.iri().to_owned(),
+                        namespace: namespace.clone(),
+                    }),
+            );
+        }
+    }
+
+    let mut iris_by_label: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for subj in &vocab.subjects {
+        check_iri(subj.iri(), &mut issues);
+        if !subj.postfix().is_ascii() {
+            issues.push(Issue::NonAsciiPostfix {
+                iri: subj.iri().to_owned(),
+                postfix: subj.postfix().to_owned(),
+            });
+        }
+        if let Some(label) = subj.label() {
+            iris_by_label
+                .entry(label)
+                .or_default()
+                .push(subj.iri().to_owned());
+        }
+    }
+    issues.extend(
+        iris_by_label
+            .into_iter()
+            .filter(|(_, iris)| iris.len() > 1)
+            .map(|(label, iris)| Issue::DuplicateLabel {
+                label: label.to_owned(),
+                iris,
+            }),
+    );
+
+    issues
+}
+
+fn check_iri(iri: &str, issues: &mut Vec<Issue>) {
+    if let Err(err) = NamedNode::new(iri) {
+        issues.push(Issue::MalformedIri {
+            iri: iri.to_owned(),
+            reason: err.to_string(),
+        });
+    }
+}
@@ -6,8 +6,10 @@ use std::path::PathBuf;
 
 use clap::{command, value_parser, Arg, ArgAction, Command, ValueHint};
 use const_format::formatcp;
+use regex::Regex;
 
-use crate::config::Config;
+use crate::config::{Backend, Config, Emit, Mirror, Mode, OntSource, SortBy};
+use crate::filter::TermFilter;
 
 pub const A_S_VERSION: char = 'V';
 pub const A_L_VERSION: &str = "version";
@@ -19,6 +21,39 @@ pub const A_S_FORCE: char = 'f';
 pub const A_L_FORCE: &str = "force";
 pub const A_S_HEADER: char = 'H';
 pub const A_L_HEADER: &str = "header";
+pub const A_S_RENAME: char = 'r';
+pub const A_L_RENAME: &str = "rename";
+pub const A_S_LANG: char = 'l';
+pub const A_L_LANG: &str = "lang";
+pub const A_S_IMPORTS_DEPTH: char = 'i';
+pub const A_L_IMPORTS_DEPTH: &str = "imports-depth";
+pub const A_S_CACHE_DIR: char = 'c';
+pub const A_L_CACHE_DIR: &str = "cache-dir";
+pub const A_L_MIRROR: &str = "mirror";
+pub const A_L_OFFLINE: &str = "offline";
+pub const A_L_WARC_DIR: &str = "warc-dir";
+pub const A_L_INCLUDE_IRI: &str = "include-iri";
+pub const A_L_EXCLUDE_IRI: &str = "exclude-iri";
+pub const A_L_INCLUDE_TYPE: &str = "include-type";
+pub const A_L_EXCLUDE_TYPE: &str = "exclude-type";
+pub const A_L_INCLUDE_STATUS: &str = "include-status";
+pub const A_L_EXCLUDE_STATUS: &str = "exclude-status";
+pub const A_L_DEFINED_BY_ONTOLOGY: &str = "defined-by-ontology";
+pub const A_S_SORT_BY: char = 's';
+pub const A_L_SORT_BY: &str = "sort-by";
+pub const A_S_ENUMS: char = 'e';
+pub const A_L_ENUMS: &str = "enum";
+pub const A_S_BACKEND: char = 'b';
+pub const A_L_BACKEND: &str = "backend";
+pub const A_S_EMIT: char = 'E';
+pub const A_L_EMIT: &str = "emit";
+pub const A_L_STRICT: &str = "strict";
+pub const A_L_ONTOLOGY_IRI: &str = "ontology-iri";
+pub const A_S_MODE: char = 'm';
+pub const A_L_MODE: &str = "mode";
+pub const A_S_WATCH: char = 'w';
+pub const A_L_WATCH: &str = "watch";
+pub const A_L_CHECK: &str = "check";
 pub const A_S_OUT_DIR: char = 'O';
 pub const A_L_OUT_DIR: &str = "output-directory";
 // pub const A_S_IN_FILE: char = 'I';
@@ -64,7 +99,7 @@ fn arg_force() -> Arg {
 
 fn arg_header() -> Arg {
     Arg::new(A_L_HEADER)
-        .help("The text to insert on top of all output files (generated Rust source code)")
+        .help("The text to insert on top of all output files (generated Rust source code), usually a license banner; this has to consist of Rust comments")
         .short(A_S_HEADER)
         .long(A_L_HEADER)
         .action(ArgAction::Set)
@@ -72,6 +107,202 @@ fn arg_header() -> Arg {
         .value_name("TEXT")
 }
 
+/// Parses a `TERM_IRI=IDENTIFIER` pair.
+fn parse_rename(arg: &str) -> Result<(String, String), String> {
+    arg.rsplit_once('=')
+        .filter(|(iri, ident)| !iri.is_empty() && !ident.is_empty())
+        .map(|(iri, ident)| (iri.to_owned(), ident.to_owned()))
+        .ok_or_else(|| format!("Expected 'TERM_IRI=IDENTIFIER', got '{arg}'"))
+}
+
+fn arg_rename() -> Arg {
+    Arg::new(A_L_RENAME)
+        .help("Use a specific Rust identifier for a term, instead of the one derived from it; may be given multiple times")
+        .short(A_S_RENAME)
+        .long(A_L_RENAME)
+        .action(ArgAction::Append)
+        .value_parser(parse_rename)
+        .value_hint(ValueHint::Other)
+        .value_name("TERM_IRI=IDENTIFIER")
+}
+
+fn arg_lang() -> Arg {
+    Arg::new(A_L_LANG)
+        .help("The preferred languages for labels and descriptions, most preferred first (e.g. 'en,de'); all other available languages are listed in the documentation too")
+        .short(A_S_LANG)
+        .long(A_L_LANG)
+        .action(ArgAction::Append)
+        .value_delimiter(',')
+        .value_hint(ValueHint::Other)
+        .value_name("LANG")
+}
+
+fn arg_imports_depth() -> Arg {
+    Arg::new(A_L_IMPORTS_DEPTH)
+        .help(
+            "How many levels of owl:imports to follow; each imported ontology gets its own module",
+        )
+        .short(A_S_IMPORTS_DEPTH)
+        .long(A_L_IMPORTS_DEPTH)
+        .action(ArgAction::Set)
+        .value_parser(value_parser!(usize))
+        .value_hint(ValueHint::Other)
+        .value_name("DEPTH")
+        .default_value("0")
+}
+
+fn arg_cache_dir() -> Arg {
+    Arg::new(A_L_CACHE_DIR)
+        .help("Where to store downloaded ontologies, so later runs can reuse them (also offline)")
+        .short(A_S_CACHE_DIR)
+        .long(A_L_CACHE_DIR)
+        .action(ArgAction::Set)
+        .value_parser(value_parser!(std::path::PathBuf))
+        .value_hint(ValueHint::DirPath)
+        .value_name("CACHE_DIR")
+}
+
+fn arg_mirror() -> Arg {
+    Arg::new(A_L_MIRROR)
+        .help("Try to download remote ontologies whose URL starts with NAMESPACE from MIRROR first (replacing that part of the URL); may be given multiple times, and is tried in the given order")
+        .long(A_L_MIRROR)
+        .action(ArgAction::Append)
+        .value_parser(value_parser!(Mirror))
+        .value_hint(ValueHint::Other)
+        .value_name("NAMESPACE=MIRROR")
+}
+
+fn arg_offline() -> Arg {
+    Arg::new(A_L_OFFLINE)
+        .help("Never download remote ontologies, but only use them from the cache directory, failing if they are not in there")
+        .long(A_L_OFFLINE)
+        .action(ArgAction::SetTrue)
+}
+
+fn arg_warc_dir() -> Arg {
+    Arg::new(A_L_WARC_DIR)
+        .help("Record all HTTP exchanges with upstream servers as web archive (WARC) files in this directory, one per download")
+        .long(A_L_WARC_DIR)
+        .action(ArgAction::Set)
+        .value_parser(value_parser!(std::path::PathBuf))
+        .value_hint(ValueHint::DirPath)
+        .value_name("WARC_DIR")
+}
+
+fn arg_filter_iri(name: &'static str, help: &'static str) -> Arg {
+    Arg::new(name)
+        .help(help)
+        .long(name)
+        .action(ArgAction::Append)
+        .value_parser(Regex::new)
+        .value_hint(ValueHint::Other)
+        .value_name("REGEX")
+}
+
+fn arg_filter(name: &'static str, help: &'static str, value_name: &'static str) -> Arg {
+    Arg::new(name)
+        .help(help)
+        .long(name)
+        .action(ArgAction::Append)
+        .value_hint(ValueHint::Other)
+        .value_name(value_name)
+}
+
+fn arg_defined_by_ontology() -> Arg {
+    Arg::new(A_L_DEFINED_BY_ONTOLOGY)
+        .help("Only generate terms that are rdfs:isDefinedBy the ontology itself")
+        .long(A_L_DEFINED_BY_ONTOLOGY)
+        .action(ArgAction::SetTrue)
+}
+
+fn arg_sort_by() -> Arg {
+    Arg::new(A_L_SORT_BY)
+        .help("In which order the terms appear in the generated code; one of: 'iri', 'label'")
+        .short(A_S_SORT_BY)
+        .long(A_L_SORT_BY)
+        .action(ArgAction::Set)
+        .value_parser(value_parser!(SortBy))
+        .value_hint(ValueHint::Other)
+        .value_name("ORDER")
+        .default_value("iri")
+}
+
+fn arg_enums() -> Arg {
+    Arg::new(A_L_ENUMS)
+        .help("Also generate a `Term` enum with a variant per vocabulary term")
+        .short(A_S_ENUMS)
+        .long(A_L_ENUMS)
+        .action(ArgAction::SetTrue)
+}
+
+fn arg_backend() -> Arg {
+    Arg::new(A_L_BACKEND)
+        .help("Which RDF library the generated code targets; one of: 'oxrdf', 'sophia', 'plain' (&'static str IRIs)")
+        .short(A_S_BACKEND)
+        .long(A_L_BACKEND)
+        .action(ArgAction::Set)
+        .value_parser(value_parser!(Backend))
+        .value_hint(ValueHint::Other)
+        .value_name("BACKEND")
+        .default_value("oxrdf")
+}
+
+fn arg_emit() -> Arg {
+    Arg::new(A_L_EMIT)
+        .help("Additional outputs to write for each vocabulary, next to the Rust code; any of: 'markdown' (a reference of all terms), 'json' (all the extracted information)")
+        .short(A_S_EMIT)
+        .long(A_L_EMIT)
+        .action(ArgAction::Append)
+        .value_delimiter(',')
+        .value_parser(value_parser!(Emit))
+        .value_hint(ValueHint::Other)
+        .value_name("KIND")
+}
+
+fn arg_strict() -> Arg {
+    Arg::new(A_L_STRICT)
+        .help("Fail if any issues are found in the ontologies (e.g. terms outside of the namespace, duplicate labels, malformed IRIs), instead of only warning about them")
+        .long(A_L_STRICT)
+        .action(ArgAction::SetTrue)
+}
+
+fn arg_ontology_iri() -> Arg {
+    Arg::new(A_L_ONTOLOGY_IRI)
+        .help("Only generate code for the ontology with this IRI, if an input declares multiple ones; by default, one module is generated per ontology")
+        .long(A_L_ONTOLOGY_IRI)
+        .action(ArgAction::Set)
+        .value_hint(ValueHint::Url)
+        .value_name("IRI")
+}
+
+fn arg_mode() -> Arg {
+    Arg::new(A_L_MODE)
+        .help("What kind of vocabularies the inputs are; one of: 'owl' (with an owl:Ontology as root), 'skos' (taxonomies with a skos:ConceptScheme as root; only the skos:Concepts are generated)")
+        .short(A_S_MODE)
+        .long(A_L_MODE)
+        .action(ArgAction::Set)
+        .value_parser(value_parser!(Mode))
+        .value_hint(ValueHint::Other)
+        .value_name("MODE")
+        .default_value("owl")
+}
+
+fn arg_watch() -> Arg {
+    Arg::new(A_L_WATCH)
+        .help("Keep running, and re-generate the output whenever one of the input files changes")
+        .short(A_S_WATCH)
+        .long(A_L_WATCH)
+        .action(ArgAction::SetTrue)
+}
+
+fn arg_check() -> Arg {
+    Arg::new(A_L_CHECK)
+        .help("Do not generate anything, but fail if the output is not up to date with the inputs; useful in CI")
+        .long(A_L_CHECK)
+        .action(ArgAction::SetTrue)
+        .conflicts_with(A_L_WATCH)
+}
+
 fn arg_out_dir() -> Arg {
     Arg::new(A_L_OUT_DIR)
         .help("The output directory, where Rust source files get written to")
@@ -86,12 +317,12 @@ fn arg_out_dir() -> Arg {
 
 fn arg_in_file() -> Arg {
     Arg::new(A_L_IN_FILE)
-        .help("The input OWL input file(s)")
+        .help("The input OWL file(s) or HTTP(S) URL(s), in any supported RDF format")
         // .short(A_S_IN_FILE)
         // .long(A_L_IN_FILE)
         .action(ArgAction::Set)
-        .value_parser(value_parser!(std::path::PathBuf))
-        .value_hint(ValueHint::FilePath)
+        .value_parser(value_parser!(OntSource))
+        .value_hint(ValueHint::AnyPath)
         .value_name("OWL_FILE")
         .required_unless_present(A_L_VERSION)
         .num_args(1..)
@@ -109,6 +340,51 @@ pub fn args_matcher() -> Command {
         .arg(arg_verbose())
         .arg(arg_force())
         .arg(arg_header())
+        .arg(arg_rename())
+        .arg(arg_lang())
+        .arg(arg_imports_depth())
+        .arg(arg_cache_dir())
+        .arg(arg_mirror())
+        .arg(arg_offline())
+        .arg(arg_warc_dir())
+        .arg(arg_filter_iri(
+            A_L_INCLUDE_IRI,
+            "Only generate terms whose IRI matches (any of) these regular expressions",
+        ))
+        .arg(arg_filter_iri(
+            A_L_EXCLUDE_IRI,
+            "Do not generate terms whose IRI matches any of these regular expressions",
+        ))
+        .arg(arg_filter(
+            A_L_INCLUDE_TYPE,
+            "Only generate terms with (any of) these rdf:types",
+            "TYPE_IRI",
+        ))
+        .arg(arg_filter(
+            A_L_EXCLUDE_TYPE,
+            "Do not generate terms with any of these rdf:types",
+            "TYPE_IRI",
+        ))
+        .arg(arg_filter(
+            A_L_INCLUDE_STATUS,
+            "Only generate terms with (any of) these vs:term_status (e.g. 'stable')",
+            "STATUS",
+        ))
+        .arg(arg_filter(
+            A_L_EXCLUDE_STATUS,
+            "Do not generate terms with any of these vs:term_status (e.g. 'unstable')",
+            "STATUS",
+        ))
+        .arg(arg_defined_by_ontology())
+        .arg(arg_sort_by())
+        .arg(arg_enums())
+        .arg(arg_backend())
+        .arg(arg_emit())
+        .arg(arg_strict())
+        .arg(arg_ontology_iri())
+        .arg(arg_mode())
+        .arg(arg_watch())
+        .arg(arg_check())
         .arg(arg_out_dir())
         .arg(arg_in_file())
 }
@@ -122,10 +398,23 @@ fn print_version_and_exit(quiet: bool) {
     std::process::exit(0);
 }
 
+/// What to do with the configuration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Action {
+    /// Generate the output once.
+    #[default]
+    Generate,
+    /// Generate the output, and re-generate it whenever an input changes.
+    Watch,
+    /// Only check whether the output is up to date with the inputs.
+    Check,
+}
+
 #[derive(Clone, Debug)]
 pub struct Args {
     pub quiet: bool,
     pub verbose: bool,
+    pub action: Action,
     pub config: Config,
 }
 
@@ -149,13 +438,53 @@ pub fn parse() -> Args {
     let verbose = args.get_flag(A_L_VERBOSE);
     let force = args.get_flag(A_L_FORCE);
     let header = args.get_one::<String>(A_L_HEADER).cloned();
+    let renames = args
+        .get_many::<(String, String)>(A_L_RENAME)
+        .map(|renames| renames.cloned().collect())
+        .unwrap_or_default();
+    let langs = args
+        .get_many::<String>(A_L_LANG)
+        .map(|langs| langs.cloned().collect())
+        .unwrap_or_default();
+    let imports_depth = args
+        .get_one::<usize>(A_L_IMPORTS_DEPTH)
+        .copied()
+        .unwrap_or_default();
+    let cache_dir = args.get_one::<PathBuf>(A_L_CACHE_DIR).cloned();
+    let mirrors = args
+        .get_many::<Mirror>(A_L_MIRROR)
+        .map(|mirrors| mirrors.cloned().collect())
+        .unwrap_or_default();
+    let many_cloned = |name: &str| -> Vec<String> {
+        args.get_many::<String>(name)
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default()
+    };
+    let many_regexes = |name: &str| -> Vec<Regex> {
+        args.get_many::<Regex>(name)
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default()
+    };
+    let filter = TermFilter {
+        include_iris: many_regexes(A_L_INCLUDE_IRI),
+        exclude_iris: many_regexes(A_L_EXCLUDE_IRI),
+        include_types: many_cloned(A_L_INCLUDE_TYPE),
+        exclude_types: many_cloned(A_L_EXCLUDE_TYPE),
+        include_status: many_cloned(A_L_INCLUDE_STATUS),
+        exclude_status: many_cloned(A_L_EXCLUDE_STATUS),
+        defined_by_ontology: args.get_flag(A_L_DEFINED_BY_ONTOLOGY),
+    };
+    let sort_by = args
+        .get_one::<SortBy>(A_L_SORT_BY)
+        .copied()
+        .unwrap_or_default();
     let out_dir = args
         .get_one::<PathBuf>(A_L_OUT_DIR)
         .cloned()
         .expect("The output directory is required");
-    let in_files: Vec<PathBuf> = args
+    let in_files: Vec<OntSource> = args
         .get_many(A_L_IN_FILE)
-        .expect("At least one OWL input file (in RDF/Turtle format) is required")
+        .expect("At least one OWL input file is required")
         .cloned()
         .collect();
 
@@ -164,11 +493,41 @@ pub fn parse() -> Args {
         out_dir,
         force,
         header,
+        renames,
+        langs,
+        imports_depth,
+        cache_dir,
+        mirrors,
+        offline: args.get_flag(A_L_OFFLINE),
+        warc_dir: args.get_one::<PathBuf>(A_L_WARC_DIR).cloned(),
+        filter,
+        sort_by,
+        enums: args.get_flag(A_L_ENUMS),
+        backend: args
+            .get_one::<Backend>(A_L_BACKEND)
+            .copied()
+            .unwrap_or_default(),
+        emit: args
+            .get_many::<Emit>(A_L_EMIT)
+            .map(|emits| emits.copied().collect())
+            .unwrap_or_default(),
+        strict: args.get_flag(A_L_STRICT),
+        ontology_iri: args.get_one::<String>(A_L_ONTOLOGY_IRI).cloned(),
+        mode: args.get_one::<Mode>(A_L_MODE).copied().unwrap_or_default(),
+    };
+
+    let action = if args.get_flag(A_L_WATCH) {
+        Action::Watch
+    } else if args.get_flag(A_L_CHECK) {
+        Action::Check
+    } else {
+        Action::Generate
     };
 
     Args {
         quiet,
         verbose,
+        action,
         config,
     }
 }
@@ -2,15 +2,295 @@
 //
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
-use std::path::PathBuf;
+use std::{collections::HashMap, fmt::Display, path::PathBuf, str::FromStr};
 
+use url::Url;
+
+use crate::filter::TermFilter;
+
+/// Where to read an ontology from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OntSource {
+    /// A locally stored file.
+    File(PathBuf),
+    /// An HTTP(S) URL to download the ontology from.
+    Url(Url),
+}
+
+impl FromStr for OntSource {
+    type Err = url::ParseError;
+
+    /// Parses `http://` and `https://` URLs as [`Self::Url`],
+    /// anything else as [`Self::File`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("http://") || s.starts_with("https://") {
+            Ok(Self::Url(Url::parse(s)?))
+        } else {
+            Ok(Self::File(PathBuf::from(s)))
+        }
+    }
+}
+
+impl From<PathBuf> for OntSource {
+    fn from(file: PathBuf) -> Self {
+        Self::File(file)
+    }
+}
+
+impl From<Url> for OntSource {
+    fn from(url: Url) -> Self {
+        Self::Url(url)
+    }
+}
+
+impl Display for OntSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(file) => file.display().fmt(f),
+            Self::Url(url) => url.fmt(f),
+        }
+    }
+}
+
+impl OntSource {
+    /// The file name without extension,
+    /// for URLs this is taken from the last path segment.
+    #[must_use]
+    pub fn file_stem(&self) -> Option<String> {
+        match self {
+            Self::File(file) => file
+                .file_stem()
+                .map(|stem_os_str| stem_os_str.to_string_lossy().to_string()),
+            Self::Url(url) => url
+                .path_segments()
+                .and_then(Iterator::last)
+                .filter(|file_name| !file_name.is_empty())
+                .map(|file_name| {
+                    file_name
+                        .split_once('.')
+                        .map_or(file_name, |(stem, _ext)| stem)
+                        .to_owned()
+                }),
+        }
+    }
+}
+
+/// An alternative location to download ontologies
+/// below a namespace from, e.g. a local mirror.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mirror {
+    /// The prefix of the original URLs this mirror serves.
+    pub namespace: String,
+    /// What to replace `namespace` with in these URLs.
+    pub mirror: String,
+}
+
+impl FromStr for Mirror {
+    type Err = String;
+
+    /// Parses a `NAMESPACE=MIRROR` pair.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_once('=')
+            .filter(|(namespace, mirror)| !namespace.is_empty() && !mirror.is_empty())
+            .map(|(namespace, mirror)| Self {
+                namespace: namespace.to_owned(),
+                mirror: mirror.to_owned(),
+            })
+            .ok_or_else(|| format!("Expected 'NAMESPACE=MIRROR', got '{s}'"))
+    }
+}
+
+impl Mirror {
+    /// The URL to download `url` from this mirror,
+    /// or `None` if it is not below the mirrored namespace.
+    #[must_use]
+    pub fn apply(&self, url: &Url) -> Option<Url> {
+        let rest = url.as_str().strip_prefix(&self.namespace)?;
+        Url::parse(&format!("{}{rest}", self.mirror)).ok()
+    }
+}
+
+/// In which order the terms appear in the generated code.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortBy {
+    /// By the full IRI of the term.
+    #[default]
+    Iri,
+    /// By the (language selected) label of the term,
+    /// falling back to the IRI.
+    Label,
+}
+
+impl FromStr for SortBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "iri" => Ok(Self::Iri),
+            "label" => Ok(Self::Label),
+            _ => Err(format!(
+                "Unknown sort order '{s}'; supported are: 'iri', 'label'"
+            )),
+        }
+    }
+}
+
+impl Display for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Iri => write!(f, "iri"),
+            Self::Label => write!(f, "label"),
+        }
+    }
+}
+
+/// Which RDF library the generated code targets.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Backend {
+    /// `oxrdf::NamedNodeRef` constants,
+    /// using the macros of `rdfoothills-vocab`.
+    #[default]
+    Oxrdf,
+    /// `sophia_api::term::IriRef<&'static str>` constants.
+    Sophia,
+    /// Plain `&'static str` IRI constants.
+    Plain,
+}
+
+impl Backend {
+    /// The type of the IRI of a term.
+    #[must_use]
+    pub const fn iri_type(self) -> &'static str {
+        match self {
+            Self::Oxrdf => "oxrdf::NamedNodeRef<'static>",
+            Self::Sophia => "sophia_api::term::IriRef<&'static str>",
+            Self::Plain => "&'static str",
+        }
+    }
+
+    /// The type of the generated constants,
+    /// if they are generated directly (vs through macros).
+    #[must_use]
+    pub const fn const_iri_type(self) -> Option<&'static str> {
+        match self {
+            Self::Oxrdf => None,
+            Self::Sophia => Some(self.iri_type()),
+            Self::Plain => Some("&str"),
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "oxrdf" => Ok(Self::Oxrdf),
+            "sophia" => Ok(Self::Sophia),
+            "plain" => Ok(Self::Plain),
+            _ => Err(format!(
+                "Unknown backend '{s}'; supported are: 'oxrdf', 'sophia', 'plain'"
+            )),
+        }
+    }
+}
+
+impl Display for Backend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Oxrdf => write!(f, "oxrdf"),
+            Self::Sophia => write!(f, "sophia"),
+            Self::Plain => write!(f, "plain"),
+        }
+    }
+}
+
+/// What kind of vocabularies the inputs are.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// OWL ontologies, with an `owl:Ontology` as root.
+    #[default]
+    Owl,
+    /// SKOS vocabularies (taxonomies), with a `skos:ConceptScheme` as root;
+    /// only the `skos:Concept`s are generated.
+    Skos,
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "owl" => Ok(Self::Owl),
+            "skos" => Ok(Self::Skos),
+            _ => Err(format!("Unknown mode '{s}'; supported are: 'owl', 'skos'")),
+        }
+    }
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Owl => write!(f, "owl"),
+            Self::Skos => write!(f, "skos"),
+        }
+    }
+}
+
+/// An additional kind of output, written next to the Rust code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Emit {
+    /// A Markdown reference of each vocabulary.
+    Markdown,
+    /// A machine-readable dump of the information extracted from each vocabulary.
+    Json,
+}
+
+impl Emit {
+    /// The file extension of the output files.
+    #[must_use]
+    pub const fn file_ext(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Json => "json",
+        }
+    }
+}
+
+impl FromStr for Emit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "Unknown output kind '{s}'; supported are: 'markdown', 'json'"
+            )),
+        }
+    }
+}
+
+impl Display for Emit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Markdown => write!(f, "markdown"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
+
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Default)]
 pub struct Config {
     /**
-     * Paths to locally stored ontology files in the RDF/Turtle format,
+     * Ontologies in any supported RDF format,
      * to be converted to Rust source files representing them.
+     * These may be locally stored files
+     * (format detected by file extension or content),
+     * or HTTP(S) URLs (format negotiated, preferring RDF/Turtle).
      */
-    pub ontologies: Vec<PathBuf>,
+    pub ontologies: Vec<OntSource>,
     /**
      * Where to write the output Rust source files to.
      */
@@ -24,4 +304,83 @@ pub struct Config {
      * Whether to overwrite potentially already existing output files.
      */
     pub force: bool,
+    /**
+     * Maps full term IRIs to the Rust identifiers to use for them,
+     * instead of the ones derived from the terms.
+     */
+    pub renames: HashMap<String, String>,
+    /**
+     * The preferred languages for labels and descriptions,
+     * most preferred first.
+     * If none of them is available,
+     * untagged literals are preferred.
+     */
+    pub langs: Vec<String>,
+    /**
+     * How many levels of `owl:imports` to follow;
+     * each imported ontology gets its own module.
+     * `0` means imports are ignored.
+     */
+    pub imports_depth: usize,
+    /**
+     * Where to store downloaded ontologies,
+     * so later runs can reuse them (also offline).
+     * If `None`, every run downloads again.
+     */
+    pub cache_dir: Option<PathBuf>,
+    /**
+     * Alternative locations to download remote ontologies from.
+     * All mirrors matching an ontology URL are tried in the given order,
+     * before the original URL.
+     */
+    pub mirrors: Vec<Mirror>,
+    /**
+     * Whether to never download remote ontologies,
+     * but only use them from the cache directory,
+     * failing if they are not in there.
+     */
+    pub offline: bool,
+    /**
+     * Where to record all HTTP exchanges with upstream servers
+     * as web archive (WARC) files, one per download.
+     * If `None`, nothing is recorded.
+     */
+    pub warc_dir: Option<PathBuf>,
+    /**
+     * Which terms of the vocabularies to generate.
+     */
+    pub filter: TermFilter,
+    /**
+     * In which order the terms appear in the generated code.
+     */
+    pub sort_by: SortBy,
+    /**
+     * Whether to also generate a `Term` enum
+     * with a variant per vocabulary term.
+     */
+    pub enums: bool,
+    /**
+     * Which RDF library the generated code targets.
+     */
+    pub backend: Backend,
+    /**
+     * Additional outputs to write for each vocabulary,
+     * next to the Rust code.
+     */
+    pub emit: Vec<Emit>,
+    /**
+     * Whether to fail on issues found in the ontologies,
+     * instead of only logging them as warnings.
+     */
+    pub strict: bool,
+    /**
+     * Only generate code for the ontology with this IRI,
+     * if an input declares multiple ones.
+     * By default, one module is generated per ontology.
+     */
+    pub ontology_iri: Option<String>,
+    /**
+     * What kind of vocabularies the inputs are.
+     */
+    pub mode: Mode,
 }
@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Selects which terms of a vocabulary get generated.
+
+use regex::Regex;
+
+use crate::parse::SubjectMeta;
+
+/// Include and exclude rules for the terms of a vocabulary.
+///
+/// A term is generated if it matches all the include rules
+/// (empty rules match everything)
+/// and none of the exclude rules.
+#[derive(Clone, Debug, Default)]
+pub struct TermFilter {
+    /// Only terms whose IRI matches at least one of these.
+    pub include_iris: Vec<Regex>,
+    /// No terms whose IRI matches any of these.
+    pub exclude_iris: Vec<Regex>,
+    /// Only terms with at least one of these `rdf:type`s (full IRIs).
+    pub include_types: Vec<String>,
+    /// No terms with any of these `rdf:type`s (full IRIs).
+    pub exclude_types: Vec<String>,
+    /// Only terms with one of these `vs:term_status`es (e.g. "stable").
+    pub include_status: Vec<String>,
+    /// No terms with any of these `vs:term_status`es (e.g. "unstable").
+    pub exclude_status: Vec<String>,
+    /// Only terms that are `rdfs:isDefinedBy` the ontology itself.
+    pub defined_by_ontology: bool,
+}
+
+/// Compares IRIs, ignoring a trailing `#` or `/`,
+/// so `http://example.org/ont#` and `http://example.org/ont` are the same.
+pub(crate) fn same_ontology_iri(iri_a: &str, iri_b: &str) -> bool {
+    iri_a.trim_end_matches(['#', '/']) == iri_b.trim_end_matches(['#', '/'])
+}
+
+impl TermFilter {
+    /// Whether this filter lets through all terms.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.include_iris.is_empty()
+            && self.exclude_iris.is_empty()
+            && self.include_types.is_empty()
+            && self.exclude_types.is_empty()
+            && self.include_status.is_empty()
+            && self.exclude_status.is_empty()
+            && !self.defined_by_ontology
+    }
+
+    /// Whether the given term should be generated.
+    ///
+    /// `ont_iris` are the IRIs identifying the ontology
+    /// (e.g. its own IRI and its namespace),
+    /// as used by [`Self::defined_by_ontology`].
+    #[must_use]
+    pub fn accepts(&self, subj: &SubjectMeta, ont_iris: &[&str]) -> bool {
+        let status = subj.status().unwrap_or_default();
+        let has_status = |statuses: &[String]| {
+            statuses
+                .iter()
+                .any(|other_status| other_status.eq_ignore_ascii_case(status))
+        };
+        let has_type = |types: &[String]| subj.types().iter().any(|typ| types.contains(typ));
+
+        (self.include_iris.is_empty() || self.include_iris.iter().any(|re| re.is_match(subj.iri())))
+            && !self.exclude_iris.iter().any(|re| re.is_match(subj.iri()))
+            && (self.include_types.is_empty() || has_type(&self.include_types))
+            && !has_type(&self.exclude_types)
+            && (self.include_status.is_empty() || has_status(&self.include_status))
+            && !has_status(&self.exclude_status)
+            && (!self.defined_by_ontology
+                || subj.defined_by().iter().any(|defined_by| {
+                    ont_iris
+                        .iter()
+                        .any(|ont_iri| same_ontology_iri(defined_by, ont_iri))
+                }))
+    }
+}
@@ -0,0 +1,157 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Creates valid and unique Rust identifiers from RDF terms.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+/// Rust keywords (strict and reserved, of all editions),
+/// which can only be used as raw identifiers (`r#type`).
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Keywords that can not even be used as raw identifiers.
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super", "_"];
+
+/// Why an identifier differs from the one naturally derived from a term.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RenameReason {
+    /// The user configured a specific identifier for the term.
+    Configured,
+    /// The natural identifier is not valid Rust.
+    Sanitized,
+    /// The identifier was already taken by an other term.
+    Collision,
+}
+
+/// An identifier that differs from the one naturally derived from a term.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Rename {
+    /// The full IRI of the term.
+    pub iri: String,
+    /// The identifier naturally derived from the term.
+    pub original: String,
+    /// The identifier actually used.
+    pub renamed: String,
+    pub reason: RenameReason,
+}
+
+impl Display for Rename {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self.reason {
+            RenameReason::Configured => "configured",
+            RenameReason::Sanitized => "invalid identifier",
+            RenameReason::Collision => "name collision",
+        };
+        write!(
+            f,
+            "<{}>: {} -> {} ({reason})",
+            self.iri, self.original, self.renamed
+        )
+    }
+}
+
+/// Turns an arbitrary string into a valid Rust identifier.
+///
+/// Invalid characters are replaced by `_`,
+/// a leading digit gets prefixed with `_`,
+/// keywords become raw identifiers (`r#type`),
+/// and keywords that can not be raw get suffixed with `_`.
+#[must_use]
+pub fn sanitize(raw: &str) -> String {
+    let mut ident = raw
+        .chars()
+        .map(|chr| {
+            if chr.is_ascii_alphanumeric() || chr == '_' {
+                chr
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    if ident.is_empty() || ident.starts_with(|chr: char| chr.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if NON_RAW_KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    } else if KEYWORDS.contains(&ident.as_str()) {
+        ident.insert_str(0, "r#");
+    }
+    ident
+}
+
+/// Hands out unique identifiers within one scope (e.g. a generated file),
+/// and records all the renames applied in the process.
+///
+/// To get deterministic results,
+/// the terms have to be allocated in a deterministic order.
+#[derive(Debug, Clone)]
+pub struct Idents<'a> {
+    configured: &'a HashMap<String, String>,
+    seen: HashSet<String>,
+    renames: Vec<Rename>,
+}
+
+impl<'a> Idents<'a> {
+    /// Creates a new, empty scope.
+    ///
+    /// `configured` maps full term IRIs to the identifiers to use for them.
+    #[must_use]
+    pub fn new(configured: &'a HashMap<String, String>) -> Self {
+        Self {
+            configured,
+            seen: HashSet::new(),
+            renames: Vec::new(),
+        }
+    }
+
+    /// Allocates an identifier for the term with the given IRI,
+    /// preferably the configured one, or else the sanitized natural one.
+    /// If that is already taken, it gets suffixed with `__2`, `__3`, ...
+    pub fn allocate(&mut self, iri: &str, natural: &str) -> String {
+        let (base, base_reason) = self.configured.get(iri).map_or_else(
+            || {
+                let sanitized = sanitize(natural);
+                let reason = (sanitized != natural).then_some(RenameReason::Sanitized);
+                (sanitized, reason)
+            },
+            |configured| (sanitize(configured), Some(RenameReason::Configured)),
+        );
+        let mut ident = base.clone();
+        let mut reason = base_reason;
+        let mut distinguishing_idx = 1;
+        while self.seen.contains(&ident) {
+            distinguishing_idx += 1;
+            ident = format!("{base}__{distinguishing_idx}");
+            reason = Some(RenameReason::Collision);
+        }
+        if let Some(rename_reason) = reason {
+            self.renames.push(Rename {
+                iri: iri.to_owned(),
+                original: natural.to_owned(),
+                renamed: ident.clone(),
+                reason: rename_reason,
+            });
+        }
+        self.seen.insert(ident.clone());
+        ident
+    }
+
+    /// All the renames applied so far.
+    #[must_use]
+    pub fn renames(&self) -> &[Rename] {
+        &self.renames
+    }
+
+    #[must_use]
+    pub fn into_renames(self) -> Vec<Rename> {
+        self.renames
+    }
+}
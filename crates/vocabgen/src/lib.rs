@@ -6,16 +6,35 @@
 
 use cli_utils as _;
 
+mod build_script;
+pub mod check;
 pub mod cli;
 pub mod config;
+pub mod filter;
+pub mod ident;
 pub mod parse;
+#[cfg(feature = "remote")]
+mod remote;
+pub mod state;
+#[cfg(feature = "watch")]
+pub mod watch;
 
+use std::collections::{HashSet, VecDeque};
 use std::fs;
-use std::io;
+use std::io::{self, BufReader};
+use std::path::Path;
 
-use config::Config;
+pub use build_script::build;
+
+use config::{Config, Emit, OntSource};
 use git_version::git_version;
 use oxrdfio::RdfFormat;
+use parse::VocabInfo;
+use rdfoothills_base::util;
+use rdfoothills_conversion::{self as conversion, OntFile};
+use rdfoothills_mime as mime;
+use state::State;
+use url::Url;
 
 // This tests rust code in the README with doc-tests.
 // Though, It will not appear in the generated documentation.
@@ -25,46 +44,369 @@ pub struct ReadmeDoctests;
 
 pub const VERSION: &str = git_version!(cargo_prefix = "", fallback = "unknown");
 
+/// Creates the text to insert on top of a generated file:
+/// the user supplied header (if any),
+/// followed by a note that the file is auto-generated.
+fn file_header(header: Option<&str>, source: Option<&OntSource>) -> String {
+    let mut content = String::new();
+    if let Some(header_text) = header {
+        content.push_str(header_text);
+        if !header_text.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push('\n');
+    }
+    content.push_str(&source.map_or_else(
+        || format!("// This file was generated by vocabgen v{VERSION}.\n"),
+        |src| format!("// This file was generated by vocabgen v{VERSION} from file '{src}'.\n"),
+    ));
+    content.push_str("// Do not edit it manually; re-generate it instead.\n");
+    content
+}
+
+/// The name of the generated file that declares all the vocabulary modules.
+pub const MOD_FILE_NAME: &str = "mod.rs";
+
+/// The name of the file within the output directory
+/// that is locked during generation,
+/// so concurrent runs (e.g. parallel build jobs)
+/// do not write the same files at the same time.
+pub const LOCK_FILE_NAME: &str = ".vocabgen.lock";
+
+/// Generates the content of a `mod.rs` file
+/// that declares all the given vocabulary modules,
+/// and maps their preferred prefixes to their namespaces.
+fn mod_file_content(mut modules: Vec<String>) -> String {
+    modules.sort();
+    modules.dedup();
+    let mut content = String::from("\n//! Generated RDF vocabularies.\n\n");
+    content.extend(modules.iter().map(|module| format!("pub mod {module};\n")));
+    content.push_str(
+        "\n/// The preferred prefixes of the vocabularies in this module,\n\
+         /// mapped to their namespace IRIs.\n\
+         pub const PREFIXES: &[(&str, &str)] = &[\n",
+    );
+    content.extend(
+        modules
+            .iter()
+            .map(|module| format!("    ({module}::NS_PREFERRED_PREFIX, {module}::NS_BASE),\n")),
+    );
+    content.push_str("];\n");
+    content
+}
+
+/// Reads and parses an ontology file of the given format.
+///
+/// Formats not natively supported by `oxrdfio`
+/// are first converted to RDF/Turtle with an external tool.
+///
+/// # Errors
+///
+/// - the file cannot be read
+/// - the file needs conversion, and that failed
+fn read_ontology_file(ont: &Path, mime_type: mime::Type) -> io::Result<parse::RdfContent> {
+    if let Some(rdf_format) = mime_type.to_rdf_format() {
+        let reader = BufReader::new(fs::File::open(ont)?);
+        return Ok(parse::rdf(reader, rdf_format));
+    }
+
+    tracing::info!(
+        "Converting '{}' from {mime_type} to {} before parsing ...",
+        ont.display(),
+        mime::Type::Turtle
+    );
+    let tmp_dir = tempfile::tempdir()?;
+    let from = OntFile {
+        file: ont.to_owned(),
+        mime_type,
+    };
+    let to = OntFile {
+        file: tmp_dir
+            .path()
+            .join(format!("ontology.{}", mime::Type::Turtle.file_ext())),
+        mime_type: mime::Type::Turtle,
+    };
+    conversion::convert(&from, &to).map_err(io::Error::other)?;
+    let reader = BufReader::new(fs::File::open(&to.file)?);
+    Ok(parse::rdf(reader, RdfFormat::Turtle))
+}
+
+/// Reads and parses an ontology,
+/// downloading it first if it is remote.
+///
+/// # Errors
+///
+/// - the format of the ontology could not be detected
+/// - the ontology cannot be read or downloaded
+/// - the ontology needs conversion, and that failed
+#[cfg_attr(not(feature = "remote"), allow(unused_variables))]
+fn read_ontology(ont: &OntSource, config: &Config) -> io::Result<parse::RdfContent> {
+    match ont {
+        OntSource::File(file) => {
+            let mime_type = mime::Type::from_path(file).map_err(io::Error::other)?;
+            read_ontology_file(file, mime_type)
+        }
+        #[cfg(feature = "remote")]
+        OntSource::Url(url) => {
+            if config.offline {
+                let cached = match &config.cache_dir {
+                    Some(cache_dir) => remote::lookup_cached(url, cache_dir)?,
+                    None => None,
+                };
+                let (file, mime_type) = cached.ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("Ontology '{url}' is not in the cache, and offline mode forbids downloading it"),
+                    )
+                })?;
+                return read_ontology_file(&file, mime_type);
+            }
+            if let Some(cache_dir) = &config.cache_dir {
+                let (file, mime_type) = remote::download_cached(url, config, cache_dir)?;
+                return read_ontology_file(&file, mime_type);
+            }
+            let tmp_dir = tempfile::tempdir()?;
+            let (file, mime_type, _source) =
+                remote::download_mirrored(url, config, tmp_dir.path())?;
+            read_ontology_file(&file, mime_type)
+        }
+        #[cfg(not(feature = "remote"))]
+        OntSource::Url(url) => Err(io::Error::other(format!(
+            "Remote ontologies are not supported (feature 'remote' is disabled): '{url}'"
+        ))),
+    }
+}
+
+/// The result of generating the Rust source of a single vocabulary.
+#[derive(Clone, Debug)]
+pub struct GeneratedVocab {
+    /// The name of the module (the preferred namespace prefix,
+    /// sanitized to be a valid Rust identifier).
+    pub module: String,
+    /// The generated Rust source code.
+    pub source: String,
+    /// The IRIs of the ontologies imported (`owl:imports`) by this one.
+    pub imports: Vec<String>,
+    /// The additional outputs requested with [`Config::emit`].
+    pub extra: Vec<(Emit, String)>,
+}
+
+/// Generates the Rust source of the vocabularies in a single input,
+/// without writing it to disk.
+///
+/// There is one result per ontology declared in the input,
+/// or only the one selected with [`Config::ontology_iri`].
+///
+/// The [`Config::filter`], [`Config::sort_by`], [`Config::renames`],
+/// [`Config::enums`], [`Config::backend`], [`Config::emit`] and [`Config::langs`] are applied,
+/// all identifiers that differ from the ones naturally derived
+/// from the terms are reported in the log,
+/// and so are the issues found by [`check::check`].
+///
+/// # Errors
+///
+/// - the format of the input could not be detected
+/// - the input cannot be read or downloaded
+/// - the input needs conversion to RDF/Turtle, and that failed
+/// - the input does not declare an ontology (with the configured IRI)
+/// - the input vocabulary does not have a preferred namespace prefix defined internally
+/// - the input vocabulary does not have a preferred namespace uri defined internally
+/// - [`Config::strict`] is set, and the input vocabulary has issues
+pub fn generate_vocabs(ont: &OntSource, config: &Config) -> io::Result<Vec<GeneratedVocab>> {
+    let rdf_cont = read_ontology(ont, config)?;
+
+    rdf_cont
+        .into_vocab_infos(config)
+        .map_err(io::Error::other)?
+        .into_iter()
+        .map(|vocab_info| generate_vocab(ont, vocab_info, config))
+        .collect()
+}
+
+/// Generates the Rust source of a single, already extracted vocabulary.
+fn generate_vocab(
+    ont: &OntSource,
+    mut vocab_info: VocabInfo,
+    config: &Config,
+) -> io::Result<GeneratedVocab> {
+    // Fall back to the input file name
+    if vocab_info.preferred_namespace_prefix.is_none() {
+        vocab_info.preferred_namespace_prefix = ont.file_stem();
+    }
+    vocab_info.filter_subjects(&config.filter);
+    vocab_info.sort_subjects(config.sort_by);
+    let issues = check::check(&vocab_info);
+    for issue in &issues {
+        if config.strict {
+            tracing::error!("In ontology '{ont}': {issue}");
+        } else {
+            tracing::warn!("In ontology '{ont}': {issue}");
+        }
+    }
+    if config.strict && !issues.is_empty() {
+        return Err(io::Error::other(format!(
+            "Found {} issue(s) in ontology '{ont}', and running in strict mode",
+            issues.len()
+        )));
+    }
+    let ont_namespace = vocab_info
+        .preferred_namespace_prefix
+        .clone()
+        .ok_or_else(|| io::Error::other(format!(
+            "For input ontology '{ont}', we were unable to find a preferred namespace prefix; we checked within the ontology data, and considered the input file-name.")))?;
+    let (rust_vocab_src, applied_renames) = vocab_info.to_rust(config).map_err(io::Error::other)?;
+    for rename in applied_renames {
+        tracing::info!("Renamed term in '{ont}': {rename}");
+    }
+    let extra = config
+        .emit
+        .iter()
+        .map(|emit| {
+            let content = match emit {
+                Emit::Markdown => vocab_info.to_markdown(),
+                #[cfg(feature = "json")]
+                Emit::Json => vocab_info.to_json().map_err(io::Error::other)?,
+                #[cfg(not(feature = "json"))]
+                Emit::Json => {
+                    return Err(io::Error::other(
+                        "JSON output is not supported (feature 'json' is disabled)",
+                    ))
+                }
+            };
+            Ok((*emit, content))
+        })
+        .collect::<io::Result<_>>()?;
+    Ok(GeneratedVocab {
+        module: ident::sanitize(&ont_namespace),
+        source: rust_vocab_src,
+        imports: vocab_info.imports,
+        extra,
+    })
+}
+
+/// Figures out where to read an imported ontology from.
+///
+/// Returns `None` for IRIs that are neither HTTP(S) nor `file:` URLs.
+fn import_source(import: &str) -> Option<OntSource> {
+    let url = Url::parse(import).ok()?;
+    match url.scheme() {
+        "http" | "https" => Some(OntSource::Url(url)),
+        "file" => url.to_file_path().ok().map(OntSource::File),
+        _ => None,
+    }
+}
+
 #[allow(clippy::doc_markdown)]
 /// Generates one of more Rust `vocab` files (for OxRDF)
-/// from one or more RDF/Turtle files.
+/// from one or more RDF files or URLs.
+///
+/// The format of each file is detected from its extension or content;
+/// the format of each URL is negotiated.
+/// Besides one file per vocabulary,
+/// this also writes a [`MOD_FILE_NAME`] file that declares all of them.
+/// Imported ontologies (`owl:imports`) get their own modules,
+/// up to [`Config::imports_depth`] levels deep.
+///
+/// All Rust output files start with the configured [`Config::header`],
+/// followed by a note that they were auto-generated.
+/// Additional outputs ([`Config::emit`]) are written next to them,
+/// with the same file stem.
+///
+/// Hashes of the configuration and of all inputs are recorded
+/// in a [`state::STATE_FILE_NAME`] file in the output directory.
+/// If none of them changed since the last run,
+/// generation is skipped altogether - even with [`Config::force`].
+///
+/// Concurrent runs targeting the same output directory
+/// wait for each other, by locking a [`LOCK_FILE_NAME`] file in it.
 ///
 /// # Errors
 ///
-/// - one of the input files cannot be read
+/// - the output directory cannot be locked
+/// - the format of one of the input files could not be detected
+/// - one of the input files cannot be read or downloaded
+/// - one of the input files needs conversion to RDF/Turtle, and that failed
 /// - one of the output files cannot be written
 /// - one of the input vocabularies does not have a preferred namespace prefix defined internally
 /// - one of the input vocabularies does not have a preferred namespace uri defined internally
 pub fn generate(config: &Config) -> io::Result<()> {
+    util::with_file_lock(config.out_dir.join(LOCK_FILE_NAME), || {
+        generate_locked(config)
+    })?
+}
+
+/// Does the work of [`generate`],
+/// while the output directory is locked.
+fn generate_locked(config: &Config) -> io::Result<()> {
+    if State::is_up_to_date(config)? {
+        tracing::info!("Inputs unchanged since the last generation; skipping.");
+        return Ok(());
+    }
+
+    let mut read_onts = Vec::new();
     let mut written_out_files = Vec::new();
-    for ont in &config.ontologies {
-        let turtle_content_str = fs::read_to_string(ont)?;
-        let turtle_content = turtle_content_str.as_bytes();
-
-        let rdf_cont = parse::rdf(turtle_content, RdfFormat::Turtle); // TODO Allow to parse other formats then Turtle
-
-        let vocab_info = rdf_cont.into_vocab_info().map_err(io::Error::other)?;
-        let ont_namespace = vocab_info
-            .preferred_namespace_prefix
-            .clone()
-            .or_else(|| {
-                ont.file_stem()
-                    .map(|stem_os_str| stem_os_str.to_string_lossy().to_string())
-            })
-            .ok_or_else(|| io::Error::other(format!(
-                "For input file '{ont}', we were unable to find a preferred namespace prefix; we checked within the ontology data, and considered the input file-name.",
-                ont = ont.display())))?;
-        let rust_vocab_src = vocab_info.to_str().map_err(io::Error::other)?;
-        let out_file = config.out_dir.join(format!("{ont_namespace}.rs"));
-        if config.force || !out_file.exists() {
-            if written_out_files.contains(&out_file) {
-                return Err(io::Error::other(format!(
-                    "Two (or more) input ontologies result in the same output file name: {out_file:?}; please change that.")));
+    let mut modules = Vec::new();
+    let mut queue = config
+        .ontologies
+        .iter()
+        .map(|ont| (ont.clone(), 0))
+        .collect::<VecDeque<_>>();
+    let mut seen = config
+        .ontologies
+        .iter()
+        .map(ToString::to_string)
+        .collect::<HashSet<_>>();
+    while let Some((ont, depth)) = queue.pop_front() {
+        read_onts.push(ont.clone());
+        for GeneratedVocab {
+            module: ont_namespace,
+            source: rust_vocab_src,
+            imports,
+            extra,
+        } in generate_vocabs(&ont, config)?
+        {
+            if depth < config.imports_depth {
+                for import in imports {
+                    if let Some(import_src) = import_source(&import) {
+                        if seen.insert(import_src.to_string()) {
+                            queue.push_back((import_src, depth + 1));
+                        }
+                    } else {
+                        tracing::warn!("Ignoring unsupported import '{import}' of '{ont}'");
+                    }
+                }
+            }
+            // NOTE: The module `r#type` is stored in the file `type.rs`.
+            let file_stem = ont_namespace.trim_start_matches("r#");
+            let out_file = config.out_dir.join(format!("{file_stem}.rs"));
+            if config.force || !out_file.exists() {
+                if written_out_files.contains(&out_file) {
+                    return Err(io::Error::other(format!(
+                        "Two (or more) input ontologies result in the same output file name: '{}'; please change that.",
+                        out_file.display())));
+                }
+                let header = file_header(config.header.as_deref(), Some(&ont));
+                util::write_atomic(&out_file, header + &rust_vocab_src)?;
+                written_out_files.push(out_file);
             }
-            fs::write(&out_file, rust_vocab_src)?;
-            written_out_files.push(out_file);
+            for (emit, content) in extra {
+                let extra_file = config
+                    .out_dir
+                    .join(format!("{file_stem}.{}", emit.file_ext()));
+                if config.force || !extra_file.exists() {
+                    util::write_atomic(&extra_file, content)?;
+                }
+            }
+            modules.push(ont_namespace);
         }
     }
 
+    let mod_file = config.out_dir.join(MOD_FILE_NAME);
+    if config.force || !mod_file.exists() {
+        let header = file_header(config.header.as_deref(), None);
+        util::write_atomic(&mod_file, header + &mod_file_content(modules))?;
+    }
+    State::new(config, read_onts)?.write(&config.out_dir)?;
+
     Ok(())
 }
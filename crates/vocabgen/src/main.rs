@@ -6,11 +6,14 @@
 
 mod cli;
 
+use cli::Action;
 use cli_utils::logging;
 use cli_utils::BoxResult;
 pub use rdfoothills_vocabgen as vocabgen;
 use tracing::metadata::LevelFilter;
 pub use vocabgen::config;
+pub use vocabgen::filter;
+use vocabgen::state::State;
 
 pub use vocabgen::VERSION;
 
@@ -28,7 +31,29 @@ fn main() -> BoxResult<()> {
     };
     logging::set_log_level_tracing(&log_reload_handle, log_level)?;
 
-    vocabgen::generate(&cli_args.config)?;
+    match cli_args.action {
+        Action::Generate => vocabgen::generate(&cli_args.config)?,
+        Action::Watch => watch(&cli_args.config)?,
+        Action::Check => {
+            if !State::is_up_to_date(&cli_args.config)? {
+                return Err(format!(
+                    "The output in '{}' is out of date; please re-generate it.",
+                    cli_args.config.out_dir.display()
+                )
+                .into());
+            }
+        }
+    }
 
     Ok(())
 }
+
+#[cfg(feature = "watch")]
+fn watch(config: &config::Config) -> BoxResult<()> {
+    Ok(vocabgen::watch::watch(config)?)
+}
+
+#[cfg(not(feature = "watch"))]
+fn watch(_config: &config::Config) -> BoxResult<()> {
+    Err("Watching is not supported (feature 'watch' is disabled)".into())
+}
@@ -2,6 +2,8 @@
 //
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
+#[cfg(feature = "json")]
+use std::collections::BTreeMap;
 use std::{
     collections::{HashMap, HashSet},
     fmt::Display,
@@ -11,24 +13,38 @@ use std::{
 
 use const_format::concatcp;
 use convert_case::{Case, Casing};
-use oxrdf::{NamedNode, Subject, Term};
+use oxrdf::{
+    vocab::{rdf, xsd},
+    NamedNode, Subject, Term,
+};
 use oxrdfio::{RdfFormat, RdfParser};
 use petgraph::graph::{DefaultIx, DiGraph, NodeIndex};
 use petgraph::visit::EdgeRef;
+use rdfoothills_iri::{split_term, well_known_prefix, PrefixMap};
+use rdfoothills_vocab::{dcterms, owl, rdfs, schema, sh, skos, vann, vs};
+#[cfg(feature = "json")]
+use serde::Serialize;
 use thiserror::Error;
 use tracing;
 
+use crate::config::{Backend, Config, Mode, SortBy};
+use crate::filter::{same_ontology_iri, TermFilter};
+use crate::ident::{Idents, Rename};
+
 const PF_CC: &str = "http://creativecommons.org/ns#";
-// const PF_DCAT: &str = "http://www.w3.org/ns/dcat#";
-const PF_DCTERMS: &str = "http://purl.org/dc/terms/";
-const PF_OWL: &str = "http://www.w3.org/2002/07/owl#";
-const PF_RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
-const PF_RDFS: &str = "http://www.w3.org/2000/01/rdf-schema#";
-const PF_SCHEMA: &str = "http://schema.org/";
-const PF_SH: &str = "http://www.w3.org/ns/shacl#";
-const PF_VANN: &str = "http://purl.org/vocab/vann/";
-const PF_VS: &str = "http://www.w3.org/2003/06/sw-vocab-status/ns#";
-// const PF_XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// The predicates whose values are used as the label of a subject.
+const TITLE_PREDICATES: [&str; 3] = [
+    dcterms::TITLE.as_str(),
+    rdfs::LABEL.as_str(),
+    skos::PREF_LABEL.as_str(),
+];
+/// The predicates whose values are used as the description of a subject.
+const DESCRIPTION_PREDICATES: [&str; 3] = [
+    dcterms::DESCRIPTION.as_str(),
+    rdfs::COMMENT.as_str(),
+    skos::DEFINITION.as_str(),
+];
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct PrefixedIri {
@@ -48,6 +64,10 @@ pub enum ParsedNamedNode {
 pub enum VocabExtractError {
     #[error("No owl:Ontology subject found!")]
     MissingOntology,
+    #[error("No skos:ConceptScheme subject found!")]
+    MissingConceptScheme,
+    #[error("No owl:Ontology subject with IRI '{0}' found; available are: {1:?}")]
+    UnknownOntology(String, Vec<String>),
 }
 
 #[derive(Error, Debug)]
@@ -62,8 +82,10 @@ impl Display for ParsedNamedNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Prefixed(node) => write!(f, "{}:{}", node.prefix_name, node.postfix),
-            Self::BaseRelative(node) => write!(f, "<{}>", node.postfix),
-            Self::Full(node) => write!(f, "{}", node.as_str()),
+            // NOTE: The base is not declared where this gets displayed,
+            //       so we use the full IRI.
+            Self::BaseRelative(node) => write!(f, "<{}{}>", node.prefix_value, node.postfix),
+            Self::Full(node) => node.fmt(f),
         }
     }
 }
@@ -79,11 +101,89 @@ impl ParsedNamedNode {
     }
 }
 
+/// An RDF literal, as stored in the parse graph.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct LiteralNode {
+    pub value: String,
+    pub language: Option<String>,
+    /// The datatype, if it is neither `xsd:string` nor `rdf:langString`,
+    /// which are implied by the lack of a datatype and the language respectively.
+    pub datatype: Option<ParsedNamedNode>,
+}
+
+impl LiteralNode {
+    /// Whether the datatype of this literal is the given one.
+    #[must_use]
+    pub fn has_datatype(&self, datatype_iri: &str) -> bool {
+        self.datatype
+            .as_ref()
+            .is_some_and(|datatype| datatype.raw() == datatype_iri)
+    }
+
+    /// Interprets this literal as an `xsd:boolean`,
+    /// which is also attempted if it has no datatype.
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        if self.datatype.is_some() && !self.has_datatype(xsd::BOOLEAN.as_str()) {
+            return None;
+        }
+        match self.value.trim().to_lowercase().as_str() {
+            "true" | "1" => Some(true),
+            "false" | "0" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Interprets this literal as a date,
+    /// dropping the time part of an `xsd:dateTime`.
+    #[must_use]
+    pub fn as_date(&self) -> &str {
+        if self.has_datatype(xsd::DATE_TIME.as_str()) {
+            self.value
+                .split_once('T')
+                .map_or(self.value.as_str(), |(date, _time)| date)
+        } else {
+            self.value.as_str()
+        }
+    }
+
+    /// Whether this literal is tagged with the given language,
+    /// or a sub-tag of it (`en` also matches `en-US`).
+    #[must_use]
+    pub fn matches_lang(&self, lang: &str) -> bool {
+        self.language.as_deref().is_some_and(|own_lang| {
+            own_lang.eq_ignore_ascii_case(lang)
+                || own_lang
+                    .get(..=lang.len())
+                    .is_some_and(|own_prefix| own_prefix.eq_ignore_ascii_case(&format!("{lang}-")))
+        })
+    }
+}
+
+/// Chooses one literal out of alternatives in different languages.
+///
+/// The first language in `langs` that is available wins.
+/// If none of them is, an untagged literal is preferred,
+/// and as a last resort, the first one in (language, value) order is used.
+#[must_use]
+pub fn select_lang<'a>(literals: &'a [LiteralNode], langs: &[String]) -> Option<&'a LiteralNode> {
+    langs
+        .iter()
+        .find_map(|lang| literals.iter().filter(|lit| lit.matches_lang(lang)).min())
+        .or_else(|| literals.iter().filter(|lit| lit.language.is_none()).min())
+        .or_else(|| {
+            literals
+                .iter()
+                .min_by_key(|lit| (&lit.language, &lit.value))
+        })
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Node {
     Iri(ParsedNamedNode),
-    BlankNode,
-    Literal(String),
+    /// A blank node, identified by its document local ID.
+    BlankNode(String),
+    Literal(LiteralNode),
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
@@ -91,32 +191,194 @@ pub struct Deprecation {
     enabled: bool,
     since: String,
     message: String,
+    /// The IRI of the term replacing this one
+    /// (`schema:supersededBy` or `dcterms:isReplacedBy`).
+    replaced_by: Option<String>,
+}
+
+/// The kind of term a subject represents,
+/// as derived from its `rdf:type`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "json", derive(Serialize), serde(rename_all = "lowercase"))]
+pub enum TermKind {
+    Class,
+    Property,
+    Individual,
+    Concept,
+    #[default]
+    Other,
+}
+
+impl TermKind {
+    /// All kinds of terms, in the order they appear in generated code.
+    pub const ALL: [Self; 5] = [
+        Self::Other,
+        Self::Class,
+        Self::Property,
+        Self::Individual,
+        Self::Concept,
+    ];
+
+    /// Maps an `rdf:type` IRI to the kind of term it denotes.
+    #[must_use]
+    pub fn from_type_iri(type_iri: &str) -> Option<Self> {
+        if [owl::CLASS.as_str(), rdfs::CLASS.as_str()].contains(&type_iri) {
+            Some(Self::Class)
+        } else if [
+            owl::OBJECT_PROPERTY.as_str(),
+            owl::DATATYPE_PROPERTY.as_str(),
+            owl::ANNOTATION_PROPERTY.as_str(),
+            rdf::PROPERTY.as_str(),
+        ]
+        .contains(&type_iri)
+        {
+            Some(Self::Property)
+        } else if type_iri == owl::NAMED_INDIVIDUAL.as_str() {
+            Some(Self::Individual)
+        } else if type_iri == skos::CONCEPT.as_str() {
+            Some(Self::Concept)
+        } else {
+            None
+        }
+    }
+
+    /// The name of the generated sub-module holding the terms of this kind,
+    /// or `None` if they go directly into the vocabulary module.
+    #[must_use]
+    pub const fn module_name(self) -> Option<&'static str> {
+        match self {
+            Self::Class => Some("classes"),
+            Self::Property => Some("properties"),
+            Self::Individual => Some("individuals"),
+            Self::Concept => Some("concepts"),
+            Self::Other => None,
+        }
+    }
+
+    /// The heading of the section listing the terms of this kind
+    /// in generated documentation.
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Class => "Classes",
+            Self::Property => "Properties",
+            Self::Individual => "Individuals",
+            Self::Concept => "Concepts",
+            Self::Other => "Other Terms",
+        }
+    }
+
+    #[must_use]
+    const fn module_doc(self) -> &'static str {
+        match self {
+            Self::Class => "The classes of this vocabulary.",
+            Self::Property => "The properties of this vocabulary.",
+            Self::Individual => "The named individuals of this vocabulary.",
+            Self::Concept => "The concepts of this vocabulary.",
+            Self::Other => "",
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct SubjectMeta {
+    iri: String,
     postfix: String,
     title: String,
     description: String,
+    /// The label in the preferred language, as-is.
+    label: Option<String>,
+    /// The description in the preferred language, as-is.
+    comment: Option<String>,
     deprecation: Deprecation,
+    kind: TermKind,
+    types: Vec<String>,
+    status: Option<String>,
+    defined_by: Vec<String>,
+}
+
+impl SubjectMeta {
+    /// The full IRI of the term.
+    #[must_use]
+    pub fn iri(&self) -> &str {
+        &self.iri
+    }
+
+    /// The part of the IRI after the namespace.
+    #[must_use]
+    pub fn postfix(&self) -> &str {
+        &self.postfix
+    }
+
+    /// The IRIs of all the `rdf:type`s of the term.
+    #[must_use]
+    pub fn types(&self) -> &[String] {
+        &self.types
+    }
+
+    /// The `vs:term_status` of the term (e.g. "stable" or "testing").
+    #[must_use]
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// The label of the term in the preferred language.
+    #[must_use]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// The description of the term in the preferred language.
+    #[must_use]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// The IRIs the term is `rdfs:isDefinedBy`.
+    #[must_use]
+    pub fn defined_by(&self) -> &[String] {
+        &self.defined_by
+    }
 }
 
 impl Display for Node {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Iri(node) => node.fmt(f),
-            Self::BlankNode => write!(f, "[]"),
-            Self::Literal(lit_str) => {
-                if lit_str.contains('\n') {
-                    write!(f, r#""""{lit_str}""""#)
-                } else {
-                    write!(f, r#""{lit_str}""#)
-                }
-            }
+            Self::BlankNode(id) => write!(f, "_:{id}"),
+            Self::Literal(lit) => lit.fmt(f),
         }
     }
 }
 
+impl Node {
+    /// The IRI itself - without any prefix or angle brackets -
+    /// for IRIs, the Turtle representation for everything else.
+    fn raw(&self) -> String {
+        match self {
+            Self::Iri(iri) => iri.raw(),
+            Self::BlankNode(_) | Self::Literal(_) => self.to_string(),
+        }
+    }
+}
+
+impl Display for LiteralNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lit_str = &self.value;
+        if lit_str.contains('\n') {
+            write!(f, r#""""{lit_str}""""#)?;
+        } else {
+            write!(f, r#""{lit_str}""#)?;
+        }
+        if let Some(lang) = &self.language {
+            write!(f, "@{lang}")?;
+        } else if let Some(datatype) = &self.datatype {
+            write!(f, "^^{datatype}")?;
+        }
+        Ok(())
+    }
+}
+
 type NodeIdx = NodeIndex<DefaultIx>;
 pub type Edge = Node;
 
@@ -127,18 +389,65 @@ pub struct RdfContent {
     pub graph: Rc<RdfGraph>,
     pub subjects: HashSet<NodeIndex<u32>>,
     pub base: Option<String>,
-    pub prefixes: Vec<(String, String)>,
+    pub prefixes: PrefixMap,
 }
 
 pub struct VocabInfo {
     pub content: RdfContent,
+    /// The IRI of the `owl:Ontology` subject.
+    pub iri: String,
     pub title: Option<String>,
     pub description: Option<String>,
     pub preferred_namespace_prefix: Option<String>,
     pub preferred_namespace_uri: Option<String>,
+    /// The prefixes declared on the ontology with `sh:declare`.
+    pub declared_prefixes: PrefixMap,
+    /// The IRIs of the ontologies imported with `owl:imports`.
+    pub imports: Vec<String>,
     pub subjects: Vec<SubjectMeta>,
 }
 
+/// The machine-readable form of a vocabulary,
+/// as exported by [`VocabInfo::to_json`].
+#[cfg(feature = "json")]
+#[derive(Serialize)]
+struct JsonVocab<'a> {
+    iri: &'a str,
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    preferred_namespace_prefix: Option<&'a str>,
+    preferred_namespace_uri: Option<&'a str>,
+    /// All known prefixes, mapped to their namespaces.
+    prefixes: BTreeMap<&'a str, &'a str>,
+    imports: &'a [String],
+    terms: Vec<JsonTerm<'a>>,
+}
+
+/// The machine-readable form of a single term.
+#[cfg(feature = "json")]
+#[derive(Serialize)]
+struct JsonTerm<'a> {
+    iri: &'a str,
+    /// The part of the IRI after the namespace.
+    name: &'a str,
+    kind: TermKind,
+    label: Option<&'a str>,
+    description: Option<&'a str>,
+    types: &'a [String],
+    status: Option<&'a str>,
+    defined_by: &'a [String],
+    /// `None` if the term is not deprecated.
+    deprecation: Option<JsonDeprecation<'a>>,
+}
+
+#[cfg(feature = "json")]
+#[derive(Serialize)]
+struct JsonDeprecation<'a> {
+    since: Option<&'a str>,
+    message: Option<&'a str>,
+    replaced_by: Option<&'a str>,
+}
+
 impl RdfContent {
     /// Serializes the RDF content to RDF/Turtle (*.ttl).
     ///
@@ -156,22 +465,53 @@ impl RdfContent {
             turtle.push('\n');
             turtle.push_str(subj.to_string().as_str());
             turtle.push('\n');
-            for pred_ref in self.graph.edges(*subj_idx) {
-                let pred = pred_ref.weight();
-                let obj_idx = pred_ref.target();
-                let obj = self.graph.node_weight(obj_idx).unwrap();
-                turtle.push_str("  ");
-                turtle.push_str(pred.to_string().as_str());
-                turtle.push(' ');
-                turtle.push_str(obj.to_string().as_str());
-                turtle.push_str(" ;\n");
-            }
+            let mut visited = HashSet::from([*subj_idx]);
+            self.push_turtle_predicates(&mut turtle, *subj_idx, 1, &mut visited);
             turtle.push_str("  .\n");
         }
 
         turtle
     }
 
+    /// Serializes all subjects to RDF/Turtle (see [`Self::to_turtle`]),
+    /// wrapped in a Markdown code block,
+    /// so it renders verbatim in documentation.
+    #[must_use]
+    pub fn to_turtle_code_block(&self) -> String {
+        format!("```turtle{}```\n", self.to_turtle())
+    }
+
+    /// Serializes all predicates and objects of a node to RDF/Turtle,
+    /// with blank node objects inlined (`[ ... ]`).
+    fn push_turtle_predicates(
+        &self,
+        turtle: &mut String,
+        subj_idx: NodeIdx,
+        depth: usize,
+        visited: &mut HashSet<NodeIdx>,
+    ) {
+        let indent = "  ".repeat(depth);
+        for pred_ref in self.graph.edges(subj_idx) {
+            let pred = pred_ref.weight();
+            let obj_idx = pred_ref.target();
+            let obj = self.graph.node_weight(obj_idx).unwrap();
+            turtle.push_str(&indent);
+            turtle.push_str(pred.to_string().as_str());
+            turtle.push(' ');
+            // NOTE: The visited check prevents endless recursion
+            //       on cyclic blank node structures.
+            if matches!(obj, Node::BlankNode(_)) && visited.insert(obj_idx) {
+                turtle.push_str("[\n");
+                self.push_turtle_predicates(turtle, obj_idx, depth + 1, visited);
+                turtle.push_str(&indent);
+                turtle.push(']');
+            } else {
+                turtle.push_str(obj.to_string().as_str());
+            }
+            turtle.push_str(" ;\n");
+        }
+    }
+
     #[must_use]
     pub fn extract_for_subject(&self, subj_idx: NodeIndex<DefaultIx>) -> Self {
         let mut copy = self.clone();
@@ -182,112 +522,318 @@ impl RdfContent {
 
     /// Extract the literal string of the pointed to node.
     ///
-    /// # Panics
+    /// Returns `None` if the given node-ID points to a non-literal node.
+    #[must_use]
+    pub fn extract_literal_string(&self, node_idx: NodeIndex<DefaultIx>) -> Option<String> {
+        self.extract_literal(node_idx).map(|lit| lit.value)
+    }
+
+    /// Extract the literal of the pointed to node,
+    /// including its language tag.
     ///
-    /// If the given node-ID points to as non-literal node.
+    /// Returns `None` if the given node-ID points to a non-literal node.
     #[must_use]
-    pub fn extract_literal_string(&self, node_idx: NodeIndex<DefaultIx>) -> String {
-        let obj = self.graph.node_weight(node_idx).unwrap();
-        if let Node::Literal(lit) = obj {
-            lit.clone()
-        } else {
-            panic!("Expected literal, got {obj}");
+    pub fn extract_literal(&self, node_idx: NodeIndex<DefaultIx>) -> Option<LiteralNode> {
+        match self.graph.node_weight(node_idx)? {
+            Node::Literal(lit) => Some(lit.clone()),
+            Node::Iri(_) | Node::BlankNode(_) => None,
         }
     }
 
+    /// Extracts the literal object of a statement,
+    /// like [`Self::extract_literal`],
+    /// but warns about the statement being ignored
+    /// if the object is not a literal (e.g. a blank node or an IRI).
     #[must_use]
-    fn find_ontology(&self) -> Option<NodeIdx> {
-        let mut ont_subj_idx_opt = None;
-        'subj_loop: for subj_idx in &self.subjects {
-            for pred_ref in self.graph.edges(*subj_idx) {
-                let pred = pred_ref.weight();
-                if let Node::Iri(pred_node) = pred {
-                    if pred_node.raw() == concatcp!(PF_RDF, "type") {
-                        let obj_idx = pred_ref.target();
-                        let obj = self.graph.node_weight(obj_idx).unwrap();
-                        if let Node::Iri(obj_node) = obj {
-                            if [concatcp!(PF_OWL, "Ontology")].contains(&obj_node.raw().as_str()) {
-                                // This is the ontology subject!
-                                ont_subj_idx_opt = Some(*subj_idx);
-                                break 'subj_loop;
-                            }
-                        }
-                    }
+    fn literal_object(
+        &self,
+        subj_idx: NodeIdx,
+        pred: &Node,
+        obj_idx: NodeIdx,
+    ) -> Option<LiteralNode> {
+        let lit = self.extract_literal(obj_idx);
+        if lit.is_none() {
+            if let (Some(subj), Some(obj)) = (
+                self.graph.node_weight(subj_idx),
+                self.graph.node_weight(obj_idx),
+            ) {
+                tracing::warn!("Ignoring non-literal object of {pred} for {subj}: {obj}");
+            }
+        }
+        lit
+    }
+
+    /// Extracts a SHACL prefix declaration
+    /// (`[ sh:prefix "ex" ; sh:namespace "http://example.org/ont#" ]`).
+    ///
+    /// Returns `None` if the node is not a blank node,
+    /// or one of the two properties is missing.
+    #[must_use]
+    fn extract_declaration(&self, decl_idx: NodeIdx) -> Option<(String, String)> {
+        if !matches!(self.graph.node_weight(decl_idx), Some(Node::BlankNode(_))) {
+            return None;
+        }
+        let mut prefix = None;
+        let mut namespace = None;
+        for pred_ref in self.graph.edges(decl_idx) {
+            let obj = self.graph.node_weight(pred_ref.target());
+            if let (Node::Iri(pred_node), Some(Node::Literal(lit))) = (pred_ref.weight(), obj) {
+                if pred_node.raw() == sh::PREFIX.as_str() {
+                    prefix = Some(lit.value.clone());
+                } else if pred_node.raw() == sh::NAMESPACE.as_str() {
+                    namespace = Some(lit.value.clone());
                 }
             }
         }
-        ont_subj_idx_opt
+        prefix.zip(namespace)
     }
 
+    /// Extracts the IRIs of all the objects of the given predicate.
     #[must_use]
-    fn extract_subj_metas(&self, ont_subj_idx: NodeIdx) -> Vec<SubjectMeta> {
+    fn extract_iri_objects(&self, subj_idx: NodeIdx, pred_iri: &str) -> Vec<String> {
+        let mut objects = self
+            .graph
+            .edges(subj_idx)
+            .filter(|pred_ref| {
+                matches!(pred_ref.weight(), Node::Iri(pred_node) if pred_node.raw() == pred_iri)
+            })
+            .filter_map(|pred_ref| match self.graph.node_weight(pred_ref.target()) {
+                Some(Node::Iri(obj_node)) => Some(obj_node.raw()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        objects.sort();
+        objects
+    }
+
+    /// Lists the broader concepts (`skos:broader`) of a subject,
+    /// to be added to the generated documentation.
+    fn broader_doc(&self, subj_idx: NodeIdx) -> String {
+        let broader = self.extract_iri_objects(subj_idx, skos::BROADER.as_str());
+        if broader.is_empty() {
+            return String::new();
+        }
+        let mut doc = String::from("Broader concepts:\n\n");
+        for broader_iri in broader {
+            doc.push_str("- <");
+            doc.push_str(&broader_iri);
+            doc.push_str(">\n");
+        }
+        doc.push('\n');
+        doc
+    }
+
+    /// Extracts the `vs:term_status` of a subject, if any.
+    fn extract_status(&self, subj_idx: NodeIdx) -> Option<String> {
+        self.graph
+            .edges(subj_idx)
+            .filter(|pred_ref| {
+                matches!(pred_ref.weight(), Node::Iri(pred_node) if pred_node.raw() == vs::TERM_STATUS.as_str())
+            })
+            .find_map(|pred_ref| match self.graph.node_weight(pred_ref.target()) {
+                Some(Node::Literal(lit)) => Some(lit.value.clone()),
+                _ => None,
+            })
+    }
+
+    /// Finds all the subjects of the given type
+    /// (usually `owl:Ontology`),
+    /// together with their IRIs, sorted by IRI.
+    #[must_use]
+    fn find_ontologies(&self, root_type: &str) -> Vec<(NodeIdx, String)> {
+        let mut ontologies = self
+            .subjects
+            .iter()
+            .filter(|subj_idx| {
+                self.graph.edges(**subj_idx).any(|pred_ref| {
+                    matches!(pred_ref.weight(), Node::Iri(pred_node) if pred_node.raw() == rdf::TYPE.as_str())
+                        && matches!(
+                            self.graph.node_weight(pred_ref.target()),
+                            Some(Node::Iri(obj_node)) if obj_node.raw() == root_type
+                        )
+                })
+            })
+            .map(|subj_idx| {
+                let iri = self
+                    .graph
+                    .node_weight(*subj_idx)
+                    .map(|ont_subj| match ont_subj {
+                        Node::Iri(ont_iri) => ont_iri.raw(),
+                        Node::BlankNode(_) | Node::Literal(_) => ont_subj.to_string(),
+                    })
+                    .unwrap_or_default();
+                (*subj_idx, iri)
+            })
+            .collect::<Vec<_>>();
+        ontologies.sort_by(|(_, iri_a), (_, iri_b)| iri_a.cmp(iri_b));
+        ontologies
+    }
+
+    /// The namespace most of the subjects are in,
+    /// as (prefix, namespace).
+    /// For subjects written neither with a prefix
+    /// nor relative to the base IRI (`@base`),
+    /// the namespace is guessed from their IRI.
+    /// The prefix is empty for the base IRI and for guessed namespaces.
+    fn common_namespace(&self, ont_subj_idxs: &[NodeIdx]) -> Option<(String, String)> {
+        let mut counts: HashMap<(&str, &str), usize> = HashMap::new();
+        for subj_idx in &self.subjects {
+            if ont_subj_idxs.contains(subj_idx) {
+                continue;
+            }
+            let namespace = match self.graph.node_weight(*subj_idx) {
+                Some(Node::Iri(
+                    ParsedNamedNode::Prefixed(prefixed) | ParsedNamedNode::BaseRelative(prefixed),
+                )) => (
+                    prefixed.prefix_name.as_str(),
+                    prefixed.prefix_value.as_str(),
+                ),
+                // NOTE: Without a declared prefix, we guess the namespace.
+                Some(Node::Iri(ParsedNamedNode::Full(iri))) => ("", split_term(iri.as_str()).0),
+                _ => continue,
+            };
+            *counts.entry(namespace).or_default() += 1;
+        }
+        counts
+            .into_iter()
+            .max_by(|(ns_a, count_a), (ns_b, count_b)| count_a.cmp(count_b).then(ns_b.cmp(ns_a)))
+            .map(|((prefix, namespace), _)| (prefix.to_owned(), namespace.to_owned()))
+    }
+
+    /// The IRIs of all the ontologies (`owl:Ontology` subjects),
+    /// or concept schemes (`skos:ConceptScheme` subjects) in [`Mode::Skos`],
+    /// in this content, sorted.
+    #[must_use]
+    pub fn ontology_iris(&self, mode: Mode) -> Vec<String> {
+        self.find_ontologies(root_type(mode))
+            .into_iter()
+            .map(|(_, iri)| iri)
+            .collect()
+    }
+
+    /// Extracts the part of the subjects IRI that follows the namespace.
+    ///
+    /// # Panics
+    ///
+    /// If the given node-ID points to a non-IRI node.
+    fn extract_postfix(&self, subj_idx: NodeIdx, namespace_uri: Option<&str>) -> Option<String> {
+        let subj = self.graph.node_weight(subj_idx).unwrap();
+        match subj {
+            Node::Iri(ParsedNamedNode::Prefixed(prefxd)) => Some(prefxd.postfix.clone()),
+            Node::Iri(iri @ ParsedNamedNode::BaseRelative(rel)) => namespace_uri.map_or_else(
+                || Some(rel.postfix.clone()),
+                |ns_uri| iri.raw().strip_prefix(ns_uri).map(ToOwned::to_owned),
+            ),
+            // NOTE: Formats like N-Triples do not support prefixes,
+            //       so we fall back to the namespace of the ontology.
+            Node::Iri(iri) => namespace_uri
+                .and_then(|ns_uri| iri.raw().strip_prefix(ns_uri).map(ToOwned::to_owned)),
+            Node::BlankNode(_) | Node::Literal(_) => panic!("Expected IRI node, got {subj}"),
+        }
+    }
+
+    #[must_use]
+    fn extract_subj_metas(
+        &self,
+        ont_subj_idxs: &[NodeIdx],
+        namespace_uri: Option<&str>,
+        langs: &[String],
+    ) -> Vec<SubjectMeta> {
         let mut subjects = Vec::new();
         for subj_idx in &self.subjects {
-            if *subj_idx == ont_subj_idx {
+            if ont_subj_idxs.contains(subj_idx) {
                 continue;
             }
-            let postfix;
-            let mut title = None;
-            let mut description = None;
+            let mut titles = Vec::new();
+            let mut descriptions = Vec::new();
             let mut deprecation_enabled = None;
             let mut deprecation_since = None;
             let mut deprecation_message = None;
+            let mut replaced_by = None;
             let subj = self.graph.node_weight(*subj_idx).unwrap();
-            if let Node::Iri(ParsedNamedNode::Prefixed(ref prefxd)) = subj {
-                postfix = prefxd.postfix.clone();
-            } else {
-                panic!("Expected prefixed node, got {subj}");
-            }
+            let Some(postfix) = self.extract_postfix(*subj_idx, namespace_uri) else {
+                tracing::warn!("Ignoring subject outside of the ontologies namespace: {subj}");
+                continue;
+            };
             for pred_ref in self.graph.edges(*subj_idx) {
                 let pred = pred_ref.weight();
                 if let Node::Iri(pred_node) = pred {
-                    if [concatcp!(PF_DCTERMS, "title"), concatcp!(PF_RDFS, "label")]
-                        .contains(&pred_node.raw().as_str())
-                    {
-                        title = Some(self.extract_literal_string(pred_ref.target()));
+                    let literal = || self.literal_object(*subj_idx, pred, pred_ref.target());
+                    if TITLE_PREDICATES.contains(&pred_node.raw().as_str()) {
+                        titles.extend(literal());
+                    } else if DESCRIPTION_PREDICATES.contains(&pred_node.raw().as_str()) {
+                        descriptions.extend(literal());
+                    } else if pred_node.raw().as_str() == vs::TERM_STATUS.as_str() {
+                        if let Some(status) = literal() {
+                            deprecation_enabled = Some(status.value.to_lowercase() == "deprecated");
+                        }
+                    } else if pred_node.raw().as_str() == owl::DEPRECATED.as_str() {
+                        if let Some(deprecated) = literal() {
+                            deprecation_enabled = Some(deprecated.as_bool() == Some(true));
+                        }
+                    } else if pred_node.raw().as_str() == concatcp!(PF_CC, "deprecatedOn") {
+                        if let Some(since) = literal() {
+                            deprecation_since = Some(since.as_date().to_owned());
+                        }
                     } else if [
-                        concatcp!(PF_DCTERMS, "description"),
-                        concatcp!(PF_RDFS, "comment"),
+                        schema::SUPERSEDED_BY.as_str(),
+                        dcterms::IS_REPLACED_BY.as_str(),
                     ]
                     .contains(&pred_node.raw().as_str())
                     {
-                        description = Some(self.extract_literal_string(pred_ref.target()));
-                    } else if pred_node.raw().as_str() == concatcp!(PF_VS, "term_status") {
-                        deprecation_enabled = Some(
-                            self.extract_literal_string(pred_ref.target())
-                                .to_lowercase()
-                                == "deprecated",
-                        );
-                    } else if pred_node.raw().as_str() == concatcp!(PF_OWL, "deprecated") {
-                        deprecation_enabled = Some(
-                            self.extract_literal_string(pred_ref.target())
-                                .to_lowercase()
-                                == "true",
-                        );
-                    } else if pred_node.raw().as_str() == concatcp!(PF_CC, "deprecatedOn") {
-                        deprecation_since = Some(self.extract_literal_string(pred_ref.target()));
-                    } else if pred_node.raw().as_str() == concatcp!(PF_SCHEMA, "supersededBy") {
                         let obj = self.graph.node_weight(pred_ref.target()).unwrap();
-                        deprecation_message = Some(format!("Use this instead: {obj}"));
+                        deprecation_message = Some(format!("Use this instead: {}", obj.raw()));
+                        if let Node::Iri(obj_iri) = obj {
+                            replaced_by = Some(obj_iri.raw());
+                        }
                     }
                 }
             }
-            #[allow(clippy::shadow_reuse)]
-            let title = title.unwrap_or_else(|| format!("No title found for {subj}"));
-            #[allow(clippy::shadow_reuse)]
+            let title = select_lang(&titles, langs);
+            let description_lit = select_lang(&descriptions, langs);
             let mut description =
-                description.map_or_else(String::new, |desc| format!("{desc}\n\n"));
+                description_lit.map_or_else(String::new, |desc| format!("{}\n\n", desc.value));
+            description.push_str(&self.broader_doc(*subj_idx));
+            description.push_str(&other_langs_doc(
+                &titles,
+                title,
+                &descriptions,
+                description_lit,
+            ));
+            let label = title.map(|title_lit| title_lit.value.clone());
+            let comment = description_lit.map(|desc| desc.value.clone());
+            #[allow(clippy::shadow_reuse)]
+            let title = title.map_or_else(
+                || format!("No title found for {subj}"),
+                |title_lit| title_lit.value.clone(),
+            );
             let rdf_content = self.extract_for_subject(*subj_idx);
-            description.push_str(&rdf_content.to_turtle());
+            description.push_str(&rdf_content.to_turtle_code_block());
+            let types = self.extract_iri_objects(*subj_idx, rdf::TYPE.as_str());
+            // If there are multiple types, the first kind in order wins
+            let kind = types
+                .iter()
+                .filter_map(|typ| TermKind::from_type_iri(typ))
+                .min()
+                .unwrap_or_default();
+            let status = self.extract_status(*subj_idx);
             subjects.push(SubjectMeta {
+                iri: subj.raw(),
                 postfix,
                 title,
                 description,
+                label,
+                comment,
                 deprecation: Deprecation {
                     enabled: deprecation_enabled.unwrap_or(false),
                     since: deprecation_since.unwrap_or_else(String::new),
                     message: deprecation_message.unwrap_or_else(String::new),
+                    replaced_by,
                 },
+                kind,
+                types,
+                status,
+                defined_by: self.extract_iri_objects(*subj_idx, rdfs::IS_DEFINED_BY.as_str()),
             });
         }
 
@@ -296,52 +842,181 @@ impl RdfContent {
 
     /// Extract vocabulary/ontology meta-data.
     ///
+    /// If multiple ontologies are declared,
+    /// this returns the first one (by IRI);
+    /// use [`Self::into_vocab_infos`] to get all of them.
+    ///
     /// # Errors
     ///
     /// If no `owl:Ontology` subject was found.
-    pub fn into_vocab_info(self) -> Result<VocabInfo, VocabExtractError> {
-        if let Some(ont_subj_idx) = self.find_ontology() {
-            let mut preferred_namespace_prefix = None;
-            let mut preferred_namespace_uri = None;
-            let mut title = None;
-            let mut description = None;
-            for pred_ref in self.graph.edges(ont_subj_idx) {
-                let pred = pred_ref.weight();
-                if let Node::Iri(pred_node) = pred {
-                    if pred_node.raw() == concatcp!(PF_VANN, "preferredNamespacePrefix") {
-                        preferred_namespace_prefix =
-                            Some(self.extract_literal_string(pred_ref.target()));
-                    } else if pred_node.raw() == concatcp!(PF_VANN, "preferredNamespaceUri") {
-                        preferred_namespace_uri =
-                            Some(self.extract_literal_string(pred_ref.target()));
-                    } else if [concatcp!(PF_DCTERMS, "title"), concatcp!(PF_RDFS, "label")]
-                        .contains(&pred_node.raw().as_str())
-                    {
-                        title = Some(self.extract_literal_string(pred_ref.target()));
-                    } else if [
-                        concatcp!(PF_DCTERMS, "description"),
-                        concatcp!(PF_RDFS, "comment"),
-                    ]
-                    .contains(&pred_node.raw().as_str())
-                    {
-                        description = Some(self.extract_literal_string(pred_ref.target()));
+    pub fn into_vocab_info(self, langs: &[String]) -> Result<VocabInfo, VocabExtractError> {
+        let config = Config {
+            langs: langs.to_vec(),
+            ..Config::default()
+        };
+        self.into_vocab_infos(&config)?
+            .into_iter()
+            .next()
+            .ok_or(VocabExtractError::MissingOntology)
+    }
+
+    /// Extract the meta-data of all the vocabularies/ontologies declared,
+    /// or only of the one with the [`Config::ontology_iri`].
+    ///
+    /// If multiple ontologies are declared,
+    /// each term is attributed to the ontology it `rdfs:isDefinedBy`,
+    /// or else to the one whose namespace its IRI starts with.
+    ///
+    /// In [`Mode::Skos`], `skos:ConceptScheme`s take the place of ontologies,
+    /// and only `skos:Concept`s are extracted as terms.
+    ///
+    /// Of the configuration, also the [`Config::langs`] are used.
+    ///
+    /// # Errors
+    ///
+    /// - If no `owl:Ontology` (or `skos:ConceptScheme`) subject was found.
+    /// - If [`Config::ontology_iri`] is set, but no ontology with that IRI was found.
+    pub fn into_vocab_infos(self, config: &Config) -> Result<Vec<VocabInfo>, VocabExtractError> {
+        let langs = &config.langs;
+        let ontology_iri = config.ontology_iri.as_deref();
+        let ontologies = self.find_ontologies(root_type(config.mode));
+        if ontologies.is_empty() {
+            return Err(match config.mode {
+                Mode::Owl => VocabExtractError::MissingOntology,
+                Mode::Skos => VocabExtractError::MissingConceptScheme,
+            });
+        }
+        let ont_subj_idxs = ontologies
+            .iter()
+            .map(|(ont_subj_idx, _)| *ont_subj_idx)
+            .collect::<Vec<_>>();
+        let ont_iris = ontologies
+            .iter()
+            .map(|(_, ont_iri)| ont_iri.clone())
+            .collect::<Vec<_>>();
+        let selected = ontologies
+            .into_iter()
+            .filter(|(_, ont_iri)| {
+                ontology_iri.is_none_or(|sel_iri| same_ontology_iri(ont_iri, sel_iri))
+            })
+            .collect::<Vec<_>>();
+        if let (Some(sel_iri), true) = (ontology_iri, selected.is_empty()) {
+            return Err(VocabExtractError::UnknownOntology(
+                sel_iri.to_owned(),
+                ont_iris,
+            ));
+        }
+
+        Ok(selected
+            .into_iter()
+            .map(|(ont_subj_idx, iri)| {
+                let mut vocab_info =
+                    self.extract_vocab_info(ont_subj_idx, iri, &ont_subj_idxs, langs);
+                if ont_iris.len() > 1 {
+                    vocab_info.retain_own_subjects(&ont_iris);
+                }
+                if config.mode == Mode::Skos {
+                    vocab_info
+                        .subjects
+                        .retain(|subj| subj.kind == TermKind::Concept);
+                }
+                vocab_info
+            })
+            .collect())
+    }
+
+    /// Extracts the meta-data of a single vocabulary/ontology.
+    fn extract_vocab_info(
+        &self,
+        ont_subj_idx: NodeIdx,
+        iri: String,
+        ont_subj_idxs: &[NodeIdx],
+        langs: &[String],
+    ) -> VocabInfo {
+        let mut preferred_namespace_prefix = None;
+        let mut preferred_namespace_uri = None;
+        let mut declared_prefixes = PrefixMap::new();
+        let mut imports = Vec::new();
+        let mut titles = Vec::new();
+        let mut descriptions = Vec::new();
+        for pred_ref in self.graph.edges(ont_subj_idx) {
+            let pred = pred_ref.weight();
+            if let Node::Iri(pred_node) = pred {
+                let literal = || self.literal_object(ont_subj_idx, pred, pred_ref.target());
+                if pred_node.raw() == vann::PREFERRED_NAMESPACE_PREFIX.as_str() {
+                    if let Some(prefix) = literal() {
+                        preferred_namespace_prefix = Some(prefix.value);
+                    }
+                } else if pred_node.raw() == vann::PREFERRED_NAMESPACE_URI.as_str() {
+                    if let Some(uri) = literal() {
+                        preferred_namespace_uri = Some(uri.value);
+                    }
+                } else if pred_node.raw() == owl::IMPORTS.as_str() {
+                    if let Some(Node::Iri(import)) = self.graph.node_weight(pred_ref.target()) {
+                        imports.push(import.raw());
                     }
+                } else if pred_node.raw() == sh::DECLARE.as_str() {
+                    if let Some((prefix, namespace)) = self.extract_declaration(pred_ref.target()) {
+                        declared_prefixes.insert(prefix, namespace);
+                    }
+                } else if TITLE_PREDICATES.contains(&pred_node.raw().as_str()) {
+                    titles.extend(literal());
+                } else if DESCRIPTION_PREDICATES.contains(&pred_node.raw().as_str()) {
+                    descriptions.extend(literal());
                 }
             }
+        }
 
-            let subjects = self.extract_subj_metas(ont_subj_idx);
+        imports.sort();
+        // Fall back to the prefix declarations,
+        // preferring the one for the namespace of the ontology (if known)
+        if preferred_namespace_prefix.is_none() || preferred_namespace_uri.is_none() {
+            let declaration = declared_prefixes
+                .iter()
+                .find(|(prefix, namespace)| {
+                    preferred_namespace_prefix.as_deref() == Some(*prefix)
+                        || preferred_namespace_uri.as_deref() == Some(*namespace)
+                })
+                .or_else(|| declared_prefixes.iter().next());
+            if let Some((prefix, namespace)) = declaration {
+                preferred_namespace_prefix.get_or_insert_with(|| prefix.to_owned());
+                preferred_namespace_uri.get_or_insert_with(|| namespace.to_owned());
+            }
+        }
+        // Fall back to the namespace most terms are in
+        if preferred_namespace_uri.is_none() {
+            if let Some((prefix, namespace)) = self.common_namespace(ont_subj_idxs) {
+                if !prefix.is_empty() {
+                    preferred_namespace_prefix.get_or_insert(prefix);
+                }
+                preferred_namespace_uri = Some(namespace);
+            }
+        }
 
-            return Ok(VocabInfo {
-                content: self,
-                title,
-                description,
-                preferred_namespace_prefix,
-                preferred_namespace_uri,
-                subjects,
-            });
+        // Fall back to the commonly used prefix for the namespace
+        if preferred_namespace_prefix.is_none() {
+            preferred_namespace_prefix = preferred_namespace_uri
+                .as_deref()
+                .and_then(well_known_prefix)
+                .map(ToOwned::to_owned);
         }
 
-        Err(VocabExtractError::MissingOntology)
+        let subjects =
+            self.extract_subj_metas(ont_subj_idxs, preferred_namespace_uri.as_deref(), langs);
+        let title = select_lang(&titles, langs).map(|lit| lit.value.clone());
+        let description = select_lang(&descriptions, langs).map(|lit| lit.value.clone());
+
+        VocabInfo {
+            iri,
+            content: self.clone(),
+            title,
+            description,
+            preferred_namespace_prefix,
+            preferred_namespace_uri,
+            declared_prefixes,
+            imports,
+            subjects,
+        }
     }
 }
 
@@ -358,6 +1033,169 @@ impl RdfContent {
 // dcat:keyword "meta", "comments", "notes" ;
 
 impl VocabInfo {
+    /// Sorts the subjects,
+    /// so the generated code does not depend on the parsing order.
+    pub fn sort_subjects(&mut self, sort_by: SortBy) {
+        match sort_by {
+            SortBy::Iri => self
+                .subjects
+                .sort_by(|subj_a, subj_b| subj_a.iri.cmp(&subj_b.iri)),
+            SortBy::Label => self.subjects.sort_by(|subj_a, subj_b| {
+                subj_a
+                    .title
+                    .to_lowercase()
+                    .cmp(&subj_b.title.to_lowercase())
+                    .then_with(|| subj_a.iri.cmp(&subj_b.iri))
+            }),
+        }
+    }
+
+    /// Removes the subjects that belong to another one of the given ontologies,
+    /// which are declared in the same content as this one.
+    fn retain_own_subjects(&mut self, ont_iris: &[String]) {
+        let own_iri = &self.iri;
+        let namespace_uri = self.preferred_namespace_uri.as_deref();
+        self.subjects.retain(|subj| {
+            subj.defined_by
+                .iter()
+                .find(|definer| {
+                    ont_iris
+                        .iter()
+                        .any(|ont_iri| same_ontology_iri(ont_iri, definer))
+                })
+                .map_or_else(
+                    || namespace_uri.is_some_and(|ns_uri| subj.iri.starts_with(ns_uri)),
+                    |definer| same_ontology_iri(definer, own_iri),
+                )
+        });
+    }
+
+    /// Removes all the subjects the filter does not accept.
+    pub fn filter_subjects(&mut self, filter: &TermFilter) {
+        let ont_iris = [
+            Some(self.iri.as_str()),
+            self.preferred_namespace_uri.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        self.subjects.retain(|subj| filter.accepts(subj, &ont_iris));
+    }
+
+    /// Chooses valid and unique constant names for all subjects,
+    /// in the same order as [`Self::subjects`].
+    ///
+    /// The names are allocated in a deterministic order,
+    /// so disambiguation does not depend on the parsing order.
+    fn const_names<'a>(
+        &self,
+        namespace_uri: &str,
+        renames: &'a HashMap<String, String>,
+    ) -> (Vec<String>, Idents<'a>) {
+        let mut alloc_order = (0..self.subjects.len()).collect::<Vec<_>>();
+        alloc_order.sort_by_key(|subj_idx| {
+            self.subjects
+                .get(*subj_idx)
+                .map(|subj| (&subj.postfix, &subj.title, &subj.description))
+        });
+        let mut idents = Idents::new(renames);
+        let mut consts = vec![String::new(); self.subjects.len()];
+        for subj_idx in alloc_order {
+            if let (Some(subj), Some(subj_const)) =
+                (self.subjects.get(subj_idx), consts.get_mut(subj_idx))
+            {
+                let natural = format!(
+                    "{}{}",
+                    if subj.deprecation.enabled {
+                        "DEPRECATED_"
+                    } else {
+                        ""
+                    },
+                    subj.postfix.to_case(Case::ScreamingSnake)
+                );
+                *subj_const =
+                    idents.allocate(&format!("{namespace_uri}{}", subj.postfix), &natural);
+            }
+        }
+        (consts, idents)
+    }
+
+    /// Generates a `Term` enum with one variant per subject,
+    /// which refer to the given constants (in the same order as [`Self::subjects`]).
+    fn term_enum(&self, consts: &[String], backend: Backend) -> String {
+        let no_renames = HashMap::new();
+        let mut variant_idents = Idents::new(&no_renames);
+        let variants = self
+            .subjects
+            .iter()
+            .zip(consts)
+            .map(|(subj, subj_const)| {
+                let natural = subj_const
+                    .trim_start_matches("r#")
+                    .to_lowercase()
+                    .to_case(Case::UpperCamel);
+                (
+                    subj,
+                    variant_idents.allocate(&subj.iri, &natural),
+                    subj_const,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut code = String::from(
+            "\n/// All the terms of this vocabulary.\n\
+             #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n\
+             #[non_exhaustive]\n\
+             pub enum Term {\n",
+        );
+        for (subj, variant, _) in &variants {
+            code.push_str("    /// <");
+            code.push_str(&subj.iri);
+            code.push_str(">\n    ");
+            code.push_str(variant);
+            code.push_str(",\n");
+        }
+        code.push_str(
+            "}\n\n\
+             #[allow(deprecated)]\n\
+             impl Term {\n    \
+             /// All the terms of this vocabulary.\n    \
+             pub const ALL: &'static [Self] = &[\n",
+        );
+        for (_, variant, _) in &variants {
+            code.push_str("        Self::");
+            code.push_str(variant);
+            code.push_str(",\n");
+        }
+        code.push_str(
+            "    ];\n\n    \
+             /// The IRI of this term.\n    \
+             #[must_use]\n    \
+             pub const fn iri(self) -> ",
+        );
+        code.push_str(backend.iri_type());
+        code.push_str(" {\n        match self {\n");
+        for (_, variant, subj_const) in &variants {
+            code.push_str("            Self::");
+            code.push_str(variant);
+            code.push_str(" => ");
+            code.push_str(subj_const);
+            code.push_str(",\n");
+        }
+        code.push_str(
+            "        }\n    }\n\n    \
+             /// Looks up the term with the given IRI.\n    \
+             #[must_use]\n    \
+             pub fn from_iri(iri: &str) -> Option<Self> {\n        \
+             Self::ALL.iter().copied().find(|term| term.iri()",
+        );
+        if backend != Backend::Plain {
+            code.push_str(".as_str()");
+        }
+        code.push_str(" == iri)\n    }\n}\n");
+        code
+    }
+
     /// Convert to Rust vocab code.
     ///
     /// # Errors
@@ -365,6 +1203,22 @@ impl VocabInfo {
     /// - The `preferred_namespace_prefix` property is set to `None`.
     /// - The `preferred_namespace_uri` property is set to `None`.
     pub fn to_str(&self) -> Result<String, RustVocabGenError> {
+        self.to_rust(&Config::default())
+            .map(|(vocab, _renames)| vocab)
+    }
+
+    /// Convert to Rust vocab code,
+    /// returning it together with all the identifier renames applied.
+    ///
+    /// Of the configuration, the [`Config::renames`] are used,
+    /// which override the identifiers derived from the terms,
+    /// and [`Config::enums`] decides whether a `Term` enum gets generated.
+    ///
+    /// # Errors
+    ///
+    /// - The `preferred_namespace_prefix` property is set to `None`.
+    /// - The `preferred_namespace_uri` property is set to `None`.
+    pub fn to_rust(&self, config: &Config) -> Result<(String, Vec<Rename>), RustVocabGenError> {
         let namespace_prefix = self
             .preferred_namespace_prefix
             .as_ref()
@@ -380,50 +1234,384 @@ impl VocabInfo {
 //! {namespace_uri})
 //! vocabulary.
 
-use crate::{{named_node, named_node_deprecated}};
-
 pub const NS_BASE: &str = "{namespace_uri}";
 pub const NS_PREFERRED_PREFIX: &str = "{namespace_prefix}";
-
+/// The prefix declaration of this vocabulary, for use in SPARQL queries.
+pub const SPARQL_PREFIX: &str = "PREFIX {namespace_prefix}: <{namespace_uri}>";
+/// The prefix declaration of this vocabulary, for use in RDF/Turtle documents.
+pub const TURTLE_PREFIX: &str = "@prefix {namespace_prefix}: <{namespace_uri}> .";
 "#,
             namespace_prefix.to_ascii_uppercase(),
         );
 
-        let mut seen_consts = HashSet::new();
-        for subj in &self.subjects {
-            let subj_postfix_const_base = format!(
-                "{}{}",
-                if subj.deprecation.enabled {
-                    "DEPRECATED_"
-                } else {
-                    ""
-                },
-                subj.postfix.to_case(Case::ScreamingSnake)
-            );
-            let mut subj_postfix_const = subj_postfix_const_base.clone();
-            // Ensure that the chosen constant name is unique within the file
-            let mut distingushing_idx = 1;
-            while seen_consts.contains(&subj_postfix_const) {
-                distingushing_idx += 1;
-                subj_postfix_const.clear();
-                subj_postfix_const.push_str(&subj_postfix_const_base);
-                subj_postfix_const.push_str("__");
-                subj_postfix_const.push_str(distingushing_idx.to_string().as_str());
+        // The generated code for each subject, grouped by kind,
+        // together with whether it is deprecated
+        let mut groups: HashMap<TermKind, Vec<(bool, String)>> = HashMap::new();
+        let mut deprecated = Vec::new();
+        let (consts, idents) = self.const_names(namespace_uri, &config.renames);
+        let const_by_iri = self
+            .subjects
+            .iter()
+            .map(|subj| (subj.iri.as_str(), subj.kind))
+            .zip(consts.iter().map(String::as_str))
+            .map(|((iri, kind), subj_const)| (iri, (subj_const, kind)))
+            .collect::<HashMap<_, _>>();
+        for (subj, subj_postfix_const) in self.subjects.iter().zip(&consts) {
+            // The constant and kind of the term replacing this deprecated one,
+            // if it is part of this vocabulary
+            let replacement = subj
+                .deprecation
+                .replaced_by
+                .as_deref()
+                .filter(|_| subj.deprecation.enabled)
+                .and_then(|replaced_by| const_by_iri.get(replaced_by).copied())
+                .filter(|(repl_const, _)| repl_const != subj_postfix_const);
+            let subj_str = subject_code(subj, subj_postfix_const, replacement, config.backend);
+            if subj.deprecation.enabled {
+                deprecated.push(deprecation_entry(subj, subj_postfix_const, replacement));
+            }
+            groups
+                .entry(subj.kind)
+                .or_default()
+                .push((subj.deprecation.enabled, subj_str));
+        }
+
+        for kind in TermKind::ALL {
+            let Some(items) = groups.get(&kind) else {
+                continue;
+            };
+            let imports = if config.backend == Backend::Oxrdf {
+                macro_imports(items)
+            } else {
+                String::new()
+            };
+            let items_str = items
+                .iter()
+                .map(|(_, item)| item.as_str())
+                .collect::<String>();
+            if let Some(module) = kind.module_name() {
+                vocab.push_str("\n/// ");
+                vocab.push_str(kind.module_doc());
+                vocab.push_str("\npub mod ");
+                vocab.push_str(module);
+                vocab.push_str(" {\nuse super::NS_BASE;\n");
+                vocab.push_str(&imports);
+                vocab.push_str(&items_str);
+                vocab.push_str("}\n\npub use ");
+                vocab.push_str(module);
+                vocab.push_str("::*;\n");
+            } else {
+                vocab.push('\n');
+                vocab.push_str(&imports);
+                vocab.push_str(&items_str);
+            }
+        }
+
+        vocab.push_str(&all_terms(&consts, config.backend));
+        vocab.push_str(&deprecations(&deprecated, config.backend));
+        if config.enums {
+            vocab.push_str(&self.term_enum(&consts, config.backend));
+        }
+
+        Ok((vocab, idents.into_renames()))
+    }
+
+    /// Generates a Markdown reference of this vocabulary,
+    /// listing the IRI, label, description and deprecation status of each term,
+    /// grouped by kind.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let title = self.title.as_deref().unwrap_or(&self.iri);
+        let mut doc = format!("# {title}\n\n");
+        if let Some(description) = &self.description {
+            doc.push_str(description.trim());
+            doc.push_str("\n\n");
+        }
+        doc.push_str("- IRI: <");
+        doc.push_str(&self.iri);
+        doc.push_str(">\n");
+        if let Some(namespace_uri) = &self.preferred_namespace_uri {
+            doc.push_str("- Namespace: <");
+            doc.push_str(namespace_uri);
+            doc.push_str(">\n");
+        }
+        if let Some(namespace_prefix) = &self.preferred_namespace_prefix {
+            doc.push_str("- Preferred prefix: `");
+            doc.push_str(namespace_prefix);
+            doc.push_str("`\n");
+        }
+
+        for kind in TermKind::ALL {
+            let subjects = self
+                .subjects
+                .iter()
+                .filter(|subj| subj.kind == kind)
+                .collect::<Vec<_>>();
+            if subjects.is_empty() {
+                continue;
             }
-            let deprecation_args = if subj.deprecation.enabled {
+            doc.push_str("\n## ");
+            doc.push_str(kind.title());
+            doc.push_str(
+                "\n\n\
+                 | Term | IRI | Label | Description | Deprecated |\n\
+                 | --- | --- | --- | --- | --- |\n",
+            );
+            doc.extend(subjects.into_iter().map(|subj| {
                 format!(
-                    ",
-    r#\"{}\"#,
-    r#\"{}\"#",
-                    subj.deprecation.since, subj.deprecation.message
+                    "| `{}` | <{}> | {} | {} | {} |\n",
+                    subj.postfix,
+                    subj.iri,
+                    md_table_cell(subj.label.as_deref().unwrap_or_default()),
+                    md_table_cell(subj.comment.as_deref().unwrap_or_default()),
+                    md_table_cell(&subj.deprecation.to_markdown()),
                 )
+            }));
+        }
+
+        doc
+    }
+}
+
+#[cfg(feature = "json")]
+impl VocabInfo {
+    /// Exports the extracted information about this vocabulary
+    /// (prefixes, terms, labels, descriptions and deprecations)
+    /// as pretty-printed JSON,
+    /// for consumption by other tools.
+    ///
+    /// # Errors
+    ///
+    /// If serialization fails, which should never happen.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        let prefixes = self
+            .content
+            .prefixes
+            .iter()
+            .chain(self.declared_prefixes.iter())
+            .chain(
+                self.preferred_namespace_prefix
+                    .as_deref()
+                    .zip(self.preferred_namespace_uri.as_deref()),
+            )
+            .collect();
+        let terms = self
+            .subjects
+            .iter()
+            .map(|subj| JsonTerm {
+                iri: &subj.iri,
+                name: &subj.postfix,
+                kind: subj.kind,
+                label: subj.label.as_deref(),
+                description: subj.comment.as_deref(),
+                types: &subj.types,
+                status: subj.status.as_deref(),
+                defined_by: &subj.defined_by,
+                deprecation: subj.deprecation.enabled.then(|| JsonDeprecation {
+                    since: non_empty(&subj.deprecation.since),
+                    message: non_empty(&subj.deprecation.message),
+                    replaced_by: subj.deprecation.replaced_by.as_deref(),
+                }),
+            })
+            .collect();
+        let json = JsonVocab {
+            iri: &self.iri,
+            title: self.title.as_deref(),
+            description: self.description.as_deref(),
+            preferred_namespace_prefix: self.preferred_namespace_prefix.as_deref(),
+            preferred_namespace_uri: self.preferred_namespace_uri.as_deref(),
+            prefixes,
+            imports: &self.imports,
+            terms,
+        };
+        serde_json::to_string_pretty(&json).map(|json_str| json_str + "\n")
+    }
+}
+
+#[cfg(feature = "json")]
+fn non_empty(text: &str) -> Option<&str> {
+    (!text.is_empty()).then_some(text)
+}
+
+impl Deprecation {
+    /// Describes the deprecation status for use in documentation;
+    /// empty if the term is not deprecated.
+    fn to_markdown(&self) -> String {
+        if !self.enabled {
+            return String::new();
+        }
+        let mut status = "yes".to_owned();
+        if !self.since.is_empty() {
+            status.push_str(" (since ");
+            status.push_str(&self.since);
+            status.push(')');
+        }
+        if !self.message.is_empty() {
+            status.push_str(": ");
+            status.push_str(&self.message);
+        }
+        status
+    }
+}
+
+/// Makes text safe for use within a cell of a Markdown table.
+fn md_table_cell(text: &str) -> String {
+    text.trim()
+        .replace('|', "\\|")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Generates the code for a single term constant.
+// NOTE: This prevents triggering a false positive
+#[allow(clippy::needless_raw_string_hashes)]
+fn subject_code(
+    subj: &SubjectMeta,
+    subj_postfix_const: &str,
+    replacement: Option<(&str, TermKind)>,
+    backend: Backend,
+) -> String {
+    let (description, deprecation_note) = doc_and_deprecation_note(subj, replacement);
+    let replacement_expr = replacement_expr(subj, replacement, backend);
+    let Some(iri_type) = backend.const_iri_type() else {
+        return oxrdf_subject_code(
+            subj,
+            subj_postfix_const,
+            &description,
+            &deprecation_note,
+            replacement_expr.as_deref(),
+        );
+    };
+    let deprecation_attrs = if subj.deprecation.enabled {
+        format!(
+            "#[allow(clippy::deprecated_semver)]\n#[deprecated(since = r#\"{}\"#, note = r#\"{}\"#)]\n",
+            subj.deprecation.since, deprecation_note
+        )
+    } else {
+        String::new()
+    };
+    let iri_expr = format!("const_format::concatcp!(NS_BASE, \"{}\")", subj.postfix);
+    let value_expr = if backend == Backend::Sophia {
+        format!("sophia_api::term::IriRef::new_unchecked_const({iri_expr})")
+    } else {
+        iri_expr
+    };
+    let replacement_const = replacement_expr.map_or_else(String::new, |expr| {
+        format!(
+            "#[doc = \"The term replacing the deprecated [`{subj_postfix_const}`].\"]\n\
+             pub const {subj_postfix_const}_REPLACEMENT: {iri_type} = {expr};\n"
+        )
+    });
+    format!(
+        r###"
+#[doc = r#"{description}"#]
+{deprecation_attrs}pub const {subj_postfix_const}: {iri_type} = {value_expr};
+{replacement_const}"###
+    )
+}
+
+/// Whether code in the module of the term `subj_kind`
+/// has to refer to a term of kind `other_kind` through `super::`.
+///
+/// NOTE: All the constants are re-exported by the vocabulary module,
+///       which is the parent of the sub-module a term is in (if any).
+fn needs_super(subj_kind: TermKind, other_kind: TermKind) -> bool {
+    other_kind != subj_kind && subj_kind.module_name().is_some()
+}
+
+/// Creates the expression for the term replacing a deprecated one,
+/// if it is known.
+///
+/// This is the constant of the replacement
+/// if it is part of the same vocabulary,
+/// or its IRI otherwise.
+fn replacement_expr(
+    subj: &SubjectMeta,
+    replacement: Option<(&str, TermKind)>,
+    backend: Backend,
+) -> Option<String> {
+    if !subj.deprecation.enabled {
+        return None;
+    }
+    if let Some((repl_const, repl_kind)) = replacement {
+        return Some(if needs_super(subj.kind, repl_kind) {
+            format!("super::{repl_const}")
+        } else {
+            repl_const.to_owned()
+        });
+    }
+    let replaced_by = subj
+        .deprecation
+        .replaced_by
+        .as_deref()
+        .filter(|replaced_by| *replaced_by != subj.iri)?;
+    Some(match backend {
+        Backend::Oxrdf => format!("oxrdf::NamedNodeRef::new_unchecked(\"{replaced_by}\")"),
+        Backend::Sophia => {
+            format!("sophia_api::term::IriRef::new_unchecked_const(\"{replaced_by}\")")
+        }
+        Backend::Plain => format!("\"{replaced_by}\""),
+    })
+}
+
+/// Creates the documentation and the deprecation note of a term.
+///
+/// If the term is deprecated in favor of another one of the same vocabulary,
+/// both refer to the constant of the replacement,
+/// instead of its IRI.
+fn doc_and_deprecation_note(
+    subj: &SubjectMeta,
+    replacement: Option<(&str, TermKind)>,
+) -> (String, String) {
+    replacement.map_or_else(
+        || (subj.description.clone(), subj.deprecation.message.clone()),
+        |(repl_const, repl_kind)| {
+            let repl_link = if needs_super(subj.kind, repl_kind) {
+                format!("[`{repl_const}`](super::{repl_const})")
             } else {
-                String::new()
+                format!("[`{repl_const}`]")
             };
-            // NOTE: This prevents triggering a false positive
-            #[allow(clippy::needless_raw_string_hashes)]
-            let subj_str = format!(
-                r###"
+            (
+                format!(
+                    "Deprecated; use {repl_link} instead.\n\n{}",
+                    subj.description
+                ),
+                format!("use `{repl_const}` instead"),
+            )
+        },
+    )
+}
+
+/// Generates the code for a single term constant,
+/// using the `named_node` macros for `oxrdf`.
+// NOTE: This prevents triggering a false positive
+#[allow(clippy::needless_raw_string_hashes)]
+fn oxrdf_subject_code(
+    subj: &SubjectMeta,
+    subj_postfix_const: &str,
+    description: &str,
+    deprecation_note: &str,
+    replacement_expr: Option<&str>,
+) -> String {
+    let mut deprecation_args = if subj.deprecation.enabled {
+        format!(
+            ",
+    r#\"{}\"#,
+    r#\"{}\"#",
+            subj.deprecation.since, deprecation_note
+        )
+    } else {
+        String::new()
+    };
+    if let Some(expr) = replacement_expr {
+        deprecation_args.push_str(",\n    ");
+        deprecation_args.push_str(subj_postfix_const);
+        deprecation_args.push_str("_REPLACEMENT = ");
+        deprecation_args.push_str(expr);
+    }
+    format!(
+        r###"
 named_node{}!(
     {subj_postfix_const},
     NS_BASE,
@@ -431,45 +1619,169 @@ named_node{}!(
     r#"{}"#{}
 );
 "###,
-                if subj.deprecation.enabled {
-                    "_deprecated"
-                } else {
-                    ""
-                },
-                subj.postfix,
-                subj.description,
-                deprecation_args,
-            );
-            seen_consts.insert(subj_postfix_const);
-            vocab.push_str(&subj_str);
-        }
+        if subj.deprecation.enabled {
+            "_deprecated"
+        } else {
+            ""
+        },
+        subj.postfix,
+        description,
+        deprecation_args,
+    )
+}
 
-        Ok(vocab)
+/// The type of the subjects that are the roots of vocabularies.
+const fn root_type(mode: Mode) -> &'static str {
+    match mode {
+        Mode::Owl => owl::ONTOLOGY.as_str(),
+        Mode::Skos => skos::CONCEPT_SCHEME.as_str(),
     }
 }
 
-fn parse_iri(
-    subj: &NamedNode,
-    base: Option<&str>,
-    prefixes: &Vec<(&str, &str)>,
-) -> ParsedNamedNode {
-    for prefix in prefixes {
-        if subj.as_str().starts_with(prefix.1) {
-            return ParsedNamedNode::Prefixed(PrefixedIri {
-                prefix_name: prefix.0.to_string(),
-                prefix_value: prefix.1.to_string(),
-                // postfix: subj.as_str()[prefix.1.len()..].to_string(),
-                postfix: subj.as_str().strip_prefix(prefix.1).unwrap().to_string(),
-            });
-        }
+/// Lists the labels and descriptions in all the languages
+/// besides the ones chosen as the main ones,
+/// to be added to the generated documentation.
+fn other_langs_doc(
+    titles: &[LiteralNode],
+    title: Option<&LiteralNode>,
+    descriptions: &[LiteralNode],
+    description: Option<&LiteralNode>,
+) -> String {
+    let mut others = titles
+        .iter()
+        .filter(|lit| Some(*lit) != title)
+        .map(|lit| (lit, "Label"))
+        .chain(
+            descriptions
+                .iter()
+                .filter(|lit| Some(*lit) != description)
+                .map(|lit| (lit, "Description")),
+        )
+        .filter_map(|(lit, what)| lit.language.as_ref().map(|lang| (lang, what, &lit.value)))
+        .collect::<Vec<_>>();
+    if others.is_empty() {
+        return String::new();
+    }
+    others.sort();
+    let mut doc = String::from("Other languages:\n\n");
+    for (lang, what, value) in others {
+        doc.push_str("- ");
+        doc.push_str(what);
+        doc.push_str(" (");
+        doc.push_str(lang);
+        doc.push_str("): ");
+        doc.push_str(value);
+        doc.push('\n');
+    }
+    doc.push('\n');
+    doc
+}
+
+/// Generates a slice of all the term constants of a vocabulary,
+/// so they can be iterated over.
+fn all_terms(consts: &[String], backend: Backend) -> String {
+    let mut code = String::from(
+        "\n/// All the terms of this vocabulary.\n\
+         #[allow(deprecated)]\n\
+         pub static ALL_TERMS: &[",
+    );
+    code.push_str(
+        backend
+            .const_iri_type()
+            .unwrap_or("oxrdf::NamedNodeRef<'_>"),
+    );
+    code.push_str("] = &[\n");
+    for subj_const in consts {
+        code.push_str("    ");
+        code.push_str(subj_const);
+        code.push_str(",\n");
+    }
+    code.push_str("];\n");
+    code
+}
+
+/// Creates the entry of a deprecated term
+/// in the table generated by [`deprecations`].
+fn deprecation_entry(
+    subj: &SubjectMeta,
+    subj_postfix_const: &str,
+    replacement: Option<(&str, TermKind)>,
+) -> String {
+    let (_, deprecation_note) = doc_and_deprecation_note(subj, replacement);
+    let has_replacement = replacement.is_some()
+        || subj
+            .deprecation
+            .replaced_by
+            .as_deref()
+            .is_some_and(|replaced_by| replaced_by != subj.iri);
+    let replacement_value = if has_replacement {
+        format!("Some({subj_postfix_const}_REPLACEMENT)")
+    } else {
+        "None".to_owned()
+    };
+    format!(
+        "    ({subj_postfix_const}, r#\"{}\"#, r#\"{deprecation_note}\"#, {replacement_value}),\n",
+        subj.deprecation.since
+    )
+}
+
+/// Generates a table of the deprecated terms of a vocabulary,
+/// so tooling can find them and their replacements.
+fn deprecations(entries: &[String], backend: Backend) -> String {
+    let iri_type = backend
+        .const_iri_type()
+        .unwrap_or("oxrdf::NamedNodeRef<'_>");
+    let mut code = String::from(
+        "\n/// The deprecated terms of this vocabulary,\n\
+         /// each with the version it was deprecated in, a note\n\
+         /// and the term replacing it, if any.\n\
+         #[allow(deprecated, clippy::needless_raw_string_hashes, clippy::type_complexity)]\n\
+         pub static DEPRECATIONS: &[(",
+    );
+    code.push_str(iri_type);
+    code.push_str(", &str, &str, Option<");
+    code.push_str(iri_type);
+    code.push_str(">)] = &[\n");
+    for entry in entries {
+        code.push_str(entry);
+    }
+    code.push_str("];\n");
+    code
+}
+
+/// Creates the `use` statement for the macros
+/// required by the given generated items.
+fn macro_imports(items: &[(bool, String)]) -> String {
+    let has_deprecated = items.iter().any(|(deprecated, _)| *deprecated);
+    let has_non_deprecated = items.iter().any(|(deprecated, _)| !*deprecated);
+    match (has_non_deprecated, has_deprecated) {
+        (true, true) => "use crate::{named_node, named_node_deprecated};\n".to_owned(),
+        (true, false) => "use crate::named_node;\n".to_owned(),
+        (false, true) => "use crate::named_node_deprecated;\n".to_owned(),
+        (false, false) => String::new(),
+    }
+}
+
+fn parse_iri(subj: &NamedNode, base: Option<&str>, prefixes: &PrefixMap) -> ParsedNamedNode {
+    if let Some((prefix_name, prefix_value)) = prefixes.longest_match(subj.as_str()) {
+        return ParsedNamedNode::Prefixed(PrefixedIri {
+            prefix_name: prefix_name.to_owned(),
+            prefix_value: prefix_value.to_owned(),
+            postfix: subj.as_str().strip_prefix(prefix_value).unwrap().to_owned(),
+        });
     }
     if let Some(base_iri) = base {
-        if subj.as_str().starts_with(base_iri) {
+        if let Some(rel_iri) = subj.as_str().strip_prefix(base_iri) {
+            // NOTE: `<#Thing>` relative to `<http://example.org/ont>`
+            //       is in the namespace `http://example.org/ont#`.
+            let (prefix_value, postfix) = match rel_iri.strip_prefix('#') {
+                Some(fragment) if !base_iri.ends_with('#') => (format!("{base_iri}#"), fragment),
+                _ => (base_iri.to_owned(), rel_iri),
+            };
             return ParsedNamedNode::BaseRelative(PrefixedIri {
                 prefix_name: String::new(),
-                prefix_value: base_iri.to_owned(),
-                // postfix: subj.as_str().[base_iri.len()..].to_string(),
-                postfix: subj.as_str().strip_prefix(base_iri).unwrap().to_string(),
+                prefix_value,
+                postfix: postfix.to_owned(),
             });
         }
     }
@@ -484,50 +1796,56 @@ where
     let mut subjects = HashSet::new();
 
     let mut parser = RdfParser::from_format(format).for_reader(input);
-    let mut iri_to_graph_idx = HashMap::new();
+    let mut node_to_graph_idx = HashMap::new();
     while let Some(Ok(quad)) = parser.next() {
-        if let Subject::NamedNode(subj) = &quad.subject {
-            let prefixes = parser.prefixes().collect::<Vec<_>>();
-            let base = parser.base_iri();
-
-            let subj_inner = parse_iri(subj, base, &prefixes);
-            // let subj_grp = subj_inner.group();
-            let subj_iri = Node::Iri(subj_inner);
-            let pred_iri = Node::Iri(parse_iri(&quad.predicate, base, &prefixes));
-
-            let obj_node = match quad.object {
-                Term::NamedNode(nn) => Node::Iri(parse_iri(&nn, base, &prefixes)),
-                Term::BlankNode(bn) => {
-                    tracing::warn!("BlankNode objects are not supported -> ignored! {:?}", bn);
-                    continue;
-                }
-                Term::Literal(lit) => Node::Literal(lit.value().to_string()),
-                Term::Triple(tr) => {
-                    tracing::warn!("Triple objects are not supported -> ignored! {:?}", tr);
-                    continue;
-                }
-            };
+        let prefixes = parser.prefixes().collect::<PrefixMap>();
+        let base = parser.base_iri();
+
+        let (subj_node, is_named) = match &quad.subject {
+            Subject::NamedNode(subj) => (Node::Iri(parse_iri(subj, base, &prefixes)), true),
+            Subject::BlankNode(bn) => (Node::BlankNode(bn.as_str().to_owned()), false),
+            Subject::Triple(_) => {
+                tracing::warn!("Ignoring triple with subject: {quad:?}");
+                continue;
+            }
+        };
+        let pred_iri = Node::Iri(parse_iri(&quad.predicate, base, &prefixes));
 
-            let subj_idx = *iri_to_graph_idx
-                .entry(subj_iri.clone())
-                .or_insert_with(|| graph.add_node(subj_iri));
-            let obj_idx = *iri_to_graph_idx
-                .entry(obj_node.clone())
-                .or_insert_with(|| graph.add_node(obj_node));
+        let obj_node = match quad.object {
+            Term::NamedNode(nn) => Node::Iri(parse_iri(&nn, base, &prefixes)),
+            Term::BlankNode(bn) => Node::BlankNode(bn.as_str().to_owned()),
+            Term::Literal(lit) => {
+                let datatype = (lit.language().is_none() && lit.datatype() != xsd::STRING)
+                    .then(|| parse_iri(&lit.datatype().into_owned(), base, &prefixes));
+                Node::Literal(LiteralNode {
+                    value: lit.value().to_owned(),
+                    language: lit.language().map(ToOwned::to_owned),
+                    datatype,
+                })
+            }
+            Term::Triple(tr) => {
+                tracing::warn!("Triple objects are not supported -> ignored! {:?}", tr);
+                continue;
+            }
+        };
+
+        let subj_idx = *node_to_graph_idx
+            .entry(subj_node.clone())
+            .or_insert_with(|| graph.add_node(subj_node));
+        let obj_idx = *node_to_graph_idx
+            .entry(obj_node.clone())
+            .or_insert_with(|| graph.add_node(obj_node));
+        // NOTE: Blank nodes are only reachable through the named subjects
+        if is_named {
             subjects.insert(subj_idx);
-            graph.add_edge(subj_idx, obj_idx, pred_iri);
-        } else {
-            tracing::warn!("Ignoring triple with subject: {quad:?}");
         }
+        graph.add_edge(subj_idx, obj_idx, pred_iri);
     }
 
     RdfContent {
         graph: Rc::new(graph),
         subjects,
         base: parser.base_iri().map(std::borrow::ToOwned::to_owned),
-        prefixes: parser
-            .prefixes()
-            .map(|p| (p.0.to_owned(), p.1.to_owned()))
-            .collect(),
+        prefixes: parser.prefixes().collect(),
     }
 }
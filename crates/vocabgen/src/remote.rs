@@ -0,0 +1,292 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::LazyLock,
+    time::SystemTime,
+};
+
+use rdfoothills_base::{util, warc};
+use rdfoothills_mime as mime;
+use url::Url;
+
+use crate::config::Config;
+use crate::VERSION;
+
+/// The name of the file within a cache entry
+/// that records the URL the ontology was actually downloaded from,
+/// which differs from the original one if it came from a mirror.
+pub const SOURCE_FILE_NAME: &str = ".source";
+
+/// The formats we ask for when downloading,
+/// in order of preference.
+const PREFERRED_TYPES: &[mime::Type] = &[
+    mime::Type::Turtle,
+    mime::Type::RdfXml,
+    mime::Type::NTriples,
+    mime::Type::OwlXml,
+    mime::Type::N3,
+    mime::Type::JsonLd,
+];
+
+/// The value of the HTTP `Accept` header we send,
+/// e.g. `text/turtle, application/rdf+xml;q=0.9, ...`.
+static ACCEPT: LazyLock<String> = LazyLock::new(|| {
+    let mut accept = Vec::new();
+    for (idx, typ) in (0_u16..).zip(PREFERRED_TYPES) {
+        if idx == 0 {
+            accept.push(typ.mime_type().to_owned());
+        } else {
+            accept.push(format!("{};q=0.{}", typ.mime_type(), 9 - idx.min(8)));
+        }
+    }
+    accept.push("*/*;q=0.1".to_owned());
+    accept.join(", ")
+});
+
+/// The HTTP request we send for `url`, as recorded in WARC files.
+/// This only contains the headers we set ourselves,
+/// not those added by the HTTP client.
+fn request_message(url: &Url) -> String {
+    let query = url
+        .query()
+        .map(|query| format!("?{query}"))
+        .unwrap_or_default();
+    format!(
+        "GET {}{query} HTTP/1.1\r\nHost: {}\r\nAccept: {}\r\n\r\n",
+        url.path(),
+        url.host_str().unwrap_or_default(),
+        *ACCEPT
+    )
+}
+
+/// The status-line and headers of the HTTP response received,
+/// as recorded in WARC files.
+/// The HTTP client already decoded the body,
+/// so the headers about its transfer encoding are left out;
+/// [`record_exchange`] adds a `Content-Length` matching the decoded body.
+fn response_head(response: &ureq::Response) -> String {
+    let mut head = format!(
+        "{} {} {}\r\n",
+        response.http_version(),
+        response.status(),
+        response.status_text()
+    );
+    for name in response.headers_names() {
+        if ["content-encoding", "content-length", "transfer-encoding"]
+            .iter()
+            .any(|skipped| name.eq_ignore_ascii_case(skipped))
+        {
+            continue;
+        }
+        for value in response.all(&name) {
+            let _ = write!(head, "{name}: {value}\r\n");
+        }
+    }
+    head
+}
+
+/// Records an HTTP exchange as a new WARC file in `warc_dir`.
+///
+/// # Errors
+///
+/// If the file cannot be written.
+fn record_exchange(
+    warc_dir: &Path,
+    url: &Url,
+    date: SystemTime,
+    response_head: &str,
+    body: &[u8],
+) -> io::Result<()> {
+    let mut response =
+        format!("{response_head}Content-Length: {}\r\n\r\n", body.len()).into_bytes();
+    response.extend_from_slice(body);
+    let mut content = Vec::new();
+    warc::write_warcinfo(&mut content, &format!("vocabgen {VERSION}"), date)?;
+    warc::write_exchange(
+        &mut content,
+        url.as_str(),
+        date,
+        request_message(url).as_bytes(),
+        &response,
+    )?;
+    fs::create_dir_all(warc_dir)?;
+    let file_name = format!(
+        "{}-{}.{}",
+        util::format_utc(date).replace(':', ""),
+        util::url2fname(url),
+        warc::FILE_EXT
+    );
+    util::write_atomic(warc_dir.join(file_name), content)
+}
+
+/// Downloads an ontology into the given directory,
+/// using content negotiation (preferring RDF/Turtle).
+///
+/// The format is detected from the `Content-Type` of the response,
+/// falling back to the URL.
+/// If `warc_dir` is given, the HTTP exchange is recorded in a WARC file in there.
+///
+/// # Errors
+///
+/// - the download fails
+/// - the format could not be detected
+/// - the file cannot be written
+pub fn download(
+    url: &Url,
+    warc_dir: Option<&Path>,
+    target_dir: &Path,
+) -> io::Result<(PathBuf, mime::Type)> {
+    tracing::info!("Downloading ontology '{url}' ...");
+    let date = SystemTime::now();
+    let response = ureq::get(url.as_str())
+        .set("Accept", &ACCEPT)
+        .call()
+        .map_err(io::Error::other)?;
+    let mime_type = mime::Type::from_str(response.content_type())
+        .ok()
+        .filter(|typ| typ.is_machine_readable())
+        .map_or_else(|| mime::Type::from_url(url), Ok)
+        .map_err(io::Error::other)?;
+    let file = target_dir.join(format!("ontology.{}", mime_type.file_ext()));
+    if let Some(dir) = warc_dir {
+        let head = response_head(&response);
+        let mut body = Vec::new();
+        response.into_reader().read_to_end(&mut body)?;
+        record_exchange(dir, url, date, &head, &body)?;
+        fs::write(&file, body)?;
+    } else {
+        let mut writer = fs::File::create(&file)?;
+        io::copy(&mut response.into_reader(), &mut writer)?;
+    }
+    Ok((file, mime_type))
+}
+
+/// The URLs to try downloading `url` from, in order:
+/// the matching mirrors first, the original URL last.
+fn candidate_urls(url: &Url, config: &Config) -> Vec<Url> {
+    let mut candidates = config
+        .mirrors
+        .iter()
+        .filter_map(|mirror| mirror.apply(url))
+        .collect::<Vec<_>>();
+    candidates.push(url.clone());
+    candidates
+}
+
+/// Like [`download`], but tries the mirrors (see [`Config::mirrors`])
+/// matching `url` first,
+/// falling back to the next one - and finally the original URL -
+/// on failure.
+///
+/// Also returns the URL the ontology was actually downloaded from.
+///
+/// # Errors
+///
+/// The error of the last try, if all of them failed
+/// (see [`download`]).
+pub fn download_mirrored(
+    url: &Url,
+    config: &Config,
+    target_dir: &Path,
+) -> io::Result<(PathBuf, mime::Type, Url)> {
+    let mut last_err = None;
+    for candidate in candidate_urls(url, config) {
+        match download(&candidate, config.warc_dir.as_deref(), target_dir) {
+            Ok((file, mime_type)) => return Ok((file, mime_type, candidate)),
+            Err(err) => {
+                tracing::warn!("Failed to download ontology from '{candidate}': {err}");
+                last_err = Some(err);
+            }
+        }
+    }
+    Err(last_err.unwrap_or_else(|| io::Error::other(format!("No URL to download '{url}' from"))))
+}
+
+/// Looks for a previously downloaded ontology in the given directory.
+///
+/// # Errors
+///
+/// If the directory exists, but cannot be read.
+fn find_cached(url_cache_dir: &Path) -> io::Result<Option<(PathBuf, mime::Type)>> {
+    if !url_cache_dir.is_dir() {
+        return Ok(None);
+    }
+    for entry in fs::read_dir(url_cache_dir)? {
+        let file = entry?.path();
+        if file.file_stem().is_some_and(|stem| stem == "ontology") {
+            if let Ok(mime_type) = mime::Type::from_path(&file) {
+                return Ok(Some((file, mime_type)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Looks up a previous download of `url` in the cache directory,
+/// without downloading it if there is none.
+///
+/// # Errors
+///
+/// If the cache directory exists, but cannot be read.
+pub fn lookup_cached(url: &Url, cache_dir: &Path) -> io::Result<Option<(PathBuf, mime::Type)>> {
+    find_cached(&cache_dir.join(util::url2fname_reversible(url)))
+}
+
+/// Like [`download_mirrored`], but reuses a previous download of the same URL
+/// from the cache directory, if available.
+/// The URL it was actually downloaded from is recorded
+/// in the cache entry (see [`SOURCE_FILE_NAME`]).
+///
+/// Concurrent calls for the same URL and cache directory
+/// wait for each other, so the ontology is only downloaded once.
+///
+/// # Errors
+///
+/// - the cache directory cannot be read, written or locked
+/// - the download fails (see [`download_mirrored`])
+pub fn download_cached(
+    url: &Url,
+    config: &Config,
+    cache_dir: &Path,
+) -> io::Result<(PathBuf, mime::Type)> {
+    let url_fname = util::url2fname_reversible(url);
+    let url_cache_dir = cache_dir.join(&url_fname);
+    if let Some(cached) = find_cached(&url_cache_dir)? {
+        tracing::info!("Using cached copy of ontology '{url}' ...");
+        return Ok(cached);
+    }
+    fs::create_dir_all(cache_dir)?;
+    util::with_file_lock(cache_dir.join(format!(".{url_fname}.lock")), || {
+        download_into_cache(url, config, cache_dir, &url_cache_dir)
+    })?
+}
+
+/// Does the work of [`download_cached`],
+/// while the cache entry is locked.
+fn download_into_cache(
+    url: &Url,
+    config: &Config,
+    cache_dir: &Path,
+    url_cache_dir: &Path,
+) -> io::Result<(PathBuf, mime::Type)> {
+    // Another process might have downloaded it while we waited for the lock.
+    if let Some(cached) = find_cached(url_cache_dir)? {
+        tracing::info!("Using cached copy of ontology '{url}' ...");
+        return Ok(cached);
+    }
+    // Download into a temporary directory first,
+    // so a failed download does not leave a broken cache entry behind.
+    let tmp_dir = tempfile::tempdir_in(cache_dir)?;
+    let (_file, _mime_type, source) = download_mirrored(url, config, tmp_dir.path())?;
+    fs::write(tmp_dir.path().join(SOURCE_FILE_NAME), format!("{source}\n"))?;
+    fs::rename(tmp_dir.path(), url_cache_dir)?;
+    find_cached(url_cache_dir)?
+        .ok_or_else(|| io::Error::other(format!("Failed to cache ontology '{url}'")))
+}
@@ -0,0 +1,140 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Records what the output was generated from,
+//! so generation can be skipped if nothing changed since.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use rdfoothills_base::{hasher, util};
+
+use crate::config::{Config, OntSource};
+use crate::{MOD_FILE_NAME, VERSION};
+
+/// The name of the file within the output directory
+/// that records the state of the inputs of the last generation.
+pub const STATE_FILE_NAME: &str = ".vocabgen-state";
+
+/// Hashes of everything that influences the generated output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct State {
+    /// Hash of the version of this tool and of the configuration,
+    /// which includes the input ontologies.
+    config: u64,
+    /// All ontologies read during generation - including imports -
+    /// together with the hashes of their content.
+    inputs: Vec<(OntSource, u64)>,
+}
+
+/// Hashes the version of this tool and all of the configuration
+/// that influences the generated output.
+fn config_hash(config: &Config) -> u64 {
+    let mut renames = config.renames.iter().collect::<Vec<_>>();
+    renames.sort();
+    let rest = Config {
+        force: false,
+        renames: HashMap::new(),
+        ..config.clone()
+    };
+    hasher::hash_num((VERSION, format!("{rest:?}"), renames))
+}
+
+/// Hashes the content of a local file,
+/// or just the URL itself for remote sources,
+/// which are assumed to never change.
+///
+/// # Errors
+///
+/// If a local file cannot be read.
+fn input_hash(ont: &OntSource) -> io::Result<u64> {
+    match ont {
+        OntSource::File(file) => Ok(hasher::hash_num(fs::read(file)?)),
+        OntSource::Url(url) => Ok(hasher::hash_num(url.as_str())),
+    }
+}
+
+impl State {
+    /// Records the state of the given inputs, as they are now.
+    ///
+    /// # Errors
+    ///
+    /// If one of the local input files cannot be read.
+    pub fn new(config: &Config, inputs: Vec<OntSource>) -> io::Result<Self> {
+        Ok(Self {
+            config: config_hash(config),
+            inputs: inputs
+                .into_iter()
+                .map(|ont| input_hash(&ont).map(|hash| (ont, hash)))
+                .collect::<io::Result<_>>()?,
+        })
+    }
+
+    /// Reads the state of the last generation from the output directory.
+    /// Returns `None` if there is none, or it is not readable.
+    #[must_use]
+    pub fn read(out_dir: &Path) -> Option<Self> {
+        let content = fs::read_to_string(out_dir.join(STATE_FILE_NAME)).ok()?;
+        let mut lines = content.lines().filter(|line| !line.starts_with('#'));
+        let config = lines.next()?.strip_prefix("config ")?.parse().ok()?;
+        let inputs = lines
+            .map(|line| {
+                let (hash, ont) = line.strip_prefix("input ")?.split_once(' ')?;
+                Some((OntSource::from_str(ont).ok()?, hash.parse().ok()?))
+            })
+            .collect::<Option<_>>()?;
+        Some(Self { config, inputs })
+    }
+
+    /// Writes this state into the output directory.
+    ///
+    /// # Errors
+    ///
+    /// If the state file could not be written.
+    pub fn write(&self, out_dir: &Path) -> io::Result<()> {
+        let mut content = String::from(
+            "# This file was generated by vocabgen;\n\
+             # it records what the vocabularies in this directory were generated from.\n",
+        );
+        content.push_str("config ");
+        content.push_str(&self.config.to_string());
+        content.push('\n');
+        content.extend(
+            self.inputs
+                .iter()
+                .map(|(ont, hash)| format!("input {hash} {ont}\n")),
+        );
+        util::write_atomic(out_dir.join(STATE_FILE_NAME), content)
+    }
+
+    /// Whether the output in `config.out_dir` was generated
+    /// with the same configuration from the same inputs
+    /// as it would be now.
+    ///
+    /// # Errors
+    ///
+    /// If one of the local input files cannot be read.
+    pub fn is_up_to_date(config: &Config) -> io::Result<bool> {
+        let Some(previous) = Self::read(&config.out_dir) else {
+            return Ok(false);
+        };
+        if previous.config != config_hash(config) || !config.out_dir.join(MOD_FILE_NAME).exists() {
+            return Ok(false);
+        }
+        for (ont, hash) in &previous.inputs {
+            if let OntSource::File(file) = ont {
+                if !file.exists() {
+                    return Ok(false);
+                }
+            }
+            if input_hash(ont)? != *hash {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
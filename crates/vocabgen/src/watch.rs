@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Re-generates the output whenever an input file changes.
+
+use std::collections::HashSet;
+use std::io;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{Event, RecursiveMode, Watcher};
+
+use crate::config::{Config, OntSource};
+
+/// How long to wait for further changes after one was detected,
+/// before re-generating;
+/// editors often save a file in multiple steps.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Generates the output, and then re-generates it
+/// whenever one of the input files changes.
+///
+/// Only local input files are watched;
+/// remote inputs are read anew on each re-generation.
+/// Failures to generate are logged, and watching continues,
+/// so a temporarily broken ontology does not end the session.
+/// Re-generation always overwrites the output files,
+/// regardless of [`Config::force`].
+///
+/// This only returns if the file-system watcher stops.
+///
+/// # Errors
+///
+/// - none of the inputs are local files
+/// - the file-system watcher could not be set up
+pub fn watch(config: &Config) -> io::Result<()> {
+    let in_files = config
+        .ontologies
+        .iter()
+        .filter_map(|ont| match ont {
+            OntSource::File(file) => Some(file.canonicalize()),
+            OntSource::Url(_) => None,
+        })
+        .collect::<io::Result<HashSet<_>>>()?;
+    if in_files.is_empty() {
+        return Err(io::Error::other(
+            "None of the input ontologies is a local file; there is nothing to watch",
+        ));
+    }
+
+    if let Err(err) = crate::generate(config) {
+        tracing::error!("Failed to generate: {err}");
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(io::Error::other)?;
+    // NOTE: We watch the directories instead of the files themselves,
+    //       because many editors replace a file when saving it.
+    let in_dirs = in_files
+        .iter()
+        .filter_map(|in_file| in_file.parent())
+        .collect::<HashSet<_>>();
+    for in_dir in in_dirs {
+        watcher
+            .watch(in_dir, RecursiveMode::NonRecursive)
+            .map_err(io::Error::other)?;
+    }
+    tracing::info!("Watching {} input file(s) for changes ...", in_files.len());
+
+    let regen_config = Config {
+        force: true,
+        ..config.clone()
+    };
+    while let Ok(event) = receiver.recv() {
+        let mut changed = changed_inputs(event, &in_files);
+        while let Ok(further_event) = receiver.recv_timeout(DEBOUNCE) {
+            changed.extend(changed_inputs(further_event, &in_files));
+        }
+        if changed.is_empty() {
+            continue;
+        }
+        for in_file in &changed {
+            tracing::info!("Input changed: '{}'", in_file.display());
+        }
+        match crate::generate(&regen_config) {
+            Ok(()) => tracing::info!("Re-generated the output"),
+            Err(err) => tracing::error!("Failed to re-generate: {err}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Extracts the input files affected by a file-system event.
+fn changed_inputs(event: notify::Result<Event>, in_files: &HashSet<PathBuf>) -> HashSet<PathBuf> {
+    match event {
+        Ok(fs_event) if fs_event.kind.is_create() || fs_event.kind.is_modify() => fs_event
+            .paths
+            .into_iter()
+            .filter(|path| in_files.contains(path))
+            .collect(),
+        Ok(_) => HashSet::new(),
+        Err(err) => {
+            tracing::warn!("Error while watching the input files: {err}");
+            HashSet::new()
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use std::env;
+use std::fs;
+
+use rdfoothills_vocabgen::build;
+
+const ONT_NT: &str = r#"
+<http://example.org/ont#> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Ontology> .
+<http://example.org/ont#> <http://purl.org/vocab/vann/preferredNamespacePrefix> "ex" .
+<http://example.org/ont#> <http://purl.org/vocab/vann/preferredNamespaceUri> "http://example.org/ont#" .
+<http://example.org/ont#Thing> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Class> .
+"#;
+
+#[test]
+fn test_build_skips_unchanged() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let out_dir = tmp_dir.path().join("out");
+    fs::create_dir(&out_dir).unwrap();
+    env::set_var("OUT_DIR", &out_dir);
+    let ont_file = tmp_dir.path().join("ont.nt");
+    fs::write(&ont_file, ONT_NT).unwrap();
+    let ont_file_str = ont_file.to_str().unwrap();
+    let generated_file = out_dir.join("ex.rs");
+
+    assert_eq!(build([ont_file_str]).unwrap(), out_dir);
+    assert!(generated_file.exists());
+
+    fs::write(&generated_file, "// unchanged").unwrap();
+    build([ont_file_str]).unwrap();
+    assert_eq!(fs::read_to_string(&generated_file).unwrap(), "// unchanged");
+
+    fs::write(&ont_file, format!("{ONT_NT}\n")).unwrap();
+    build([ont_file_str]).unwrap();
+    assert_ne!(fs::read_to_string(&generated_file).unwrap(), "// unchanged");
+}
@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use oxrdfio::RdfFormat;
+use rdfoothills_vocabgen::check::{check, Issue};
+use rdfoothills_vocabgen::config::SortBy;
+use rdfoothills_vocabgen::parse::rdf;
+
+const ONT_TTL: &str = r#"
+@prefix ex: <http://example.org/ont#> .
+@prefix other: <http://example.org/other#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix vann: <http://purl.org/vocab/vann/> .
+
+ex: a owl:Ontology ;
+    vann:preferredNamespacePrefix "ex" ;
+    vann:preferredNamespaceUri "http://example.org/ont#" .
+
+ex:Thing a owl:Class ;
+    rdfs:label "Thing" .
+
+ex:Größe a owl:DatatypeProperty ;
+    rdfs:label "Size" .
+
+other:Thing a owl:Class ;
+    rdfs:label "Thing" .
+"#;
+
+#[test]
+fn test_check() {
+    let mut vocab = rdf(ONT_TTL.as_bytes(), RdfFormat::Turtle)
+        .into_vocab_info(&[])
+        .unwrap();
+    vocab.sort_subjects(SortBy::Iri);
+    let issues = check(&vocab);
+
+    assert_eq!(
+        issues,
+        vec![
+            Issue::OutsideNamespace {
+                iri: "http://example.org/other#Thing".to_owned(),
+                namespace: "http://example.org/ont#".to_owned(),
+            },
+            Issue::NonAsciiPostfix {
+                iri: "http://example.org/ont#Größe".to_owned(),
+                postfix: "Größe".to_owned(),
+            },
+            Issue::DuplicateLabel {
+                label: "Thing".to_owned(),
+                iris: vec![
+                    "http://example.org/ont#Thing".to_owned(),
+                    "http://example.org/other#Thing".to_owned(),
+                ],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_check_missing_namespace() {
+    let vocab = rdf(
+        "<http://example.org/ont#> a <http://www.w3.org/2002/07/owl#Ontology> .".as_bytes(),
+        RdfFormat::Turtle,
+    )
+    .into_vocab_info(&[])
+    .unwrap();
+
+    assert_eq!(
+        check(&vocab),
+        vec![Issue::MissingNamespacePrefix, Issue::MissingNamespaceUri]
+    );
+}
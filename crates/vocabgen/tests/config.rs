@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_vocabgen::config::Mirror;
+use url::Url;
+
+#[test]
+fn test_mirror_parse() {
+    let mirror: Mirror = "http://purl.org/=http://localhost:8080/purl/"
+        .parse()
+        .unwrap();
+    assert_eq!(mirror.namespace, "http://purl.org/");
+    assert_eq!(mirror.mirror, "http://localhost:8080/purl/");
+    assert!("http://purl.org/".parse::<Mirror>().is_err());
+    assert!("=http://localhost/".parse::<Mirror>().is_err());
+}
+
+#[test]
+fn test_mirror_apply() {
+    let mirror: Mirror = "http://purl.org/=http://localhost:8080/purl/"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        mirror.apply(&Url::parse("http://purl.org/dc/terms/").unwrap()),
+        Some(Url::parse("http://localhost:8080/purl/dc/terms/").unwrap())
+    );
+    assert_eq!(
+        mirror.apply(&Url::parse("http://www.w3.org/ns/shacl").unwrap()),
+        None
+    );
+}
@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+#![cfg(unix)]
+
+//! Tests generating from a format that is not parsed natively,
+//! and thus gets converted with a CLI tool first,
+//! which is faked by a stub `rdfx` on the `PATH`.
+
+use std::env;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+
+use rdfoothills_vocabgen::config::Config;
+use rdfoothills_vocabgen::generate;
+
+/// Mimics `rdfx` converting, by writing a fixed ontology
+/// into the file given with `--output`.
+const STUB_RDFX: &str = r#"#!/bin/sh
+if [ "$1" = "convert" ]; then
+    while [ $# -gt 0 ]; do
+        if [ "$1" = "--output" ]; then
+            out="$2"
+        fi
+        shift
+    done
+    cat > "$out" << 'EOF'
+<http://example.org/ont#> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Ontology> .
+<http://example.org/ont#> <http://purl.org/vocab/vann/preferredNamespacePrefix> "ex" .
+<http://example.org/ont#> <http://purl.org/vocab/vann/preferredNamespaceUri> "http://example.org/ont#" .
+<http://example.org/ont#Thing> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Class> .
+EOF
+fi
+"#;
+
+#[test]
+fn test_generate_from_converted() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let stub = tmp_dir.path().join("rdfx");
+    fs::write(&stub, STUB_RDFX).unwrap();
+    fs::set_permissions(&stub, fs::Permissions::from_mode(0o755)).unwrap();
+    let path = env::var_os("PATH").unwrap_or_default();
+    let paths = std::iter::once(tmp_dir.path().to_owned()).chain(env::split_paths(&path));
+    env::set_var("PATH", env::join_paths(paths).unwrap());
+
+    // Not parsed natively; the content is ignored by the stub.
+    let ont_file = tmp_dir.path().join("ont.jsonld");
+    fs::write(&ont_file, "{}").unwrap();
+    let config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+
+    let generated = fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap();
+    assert!(generated.contains("pub const NS_BASE: &str = \"http://example.org/ont#\";"));
+    assert!(generated.contains("THING"));
+}
@@ -0,0 +1,631 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use std::fs;
+
+use rdfoothills_vocabgen::config::{Backend, Config, Emit, Mode, SortBy};
+use rdfoothills_vocabgen::state::{State, STATE_FILE_NAME};
+use rdfoothills_vocabgen::{filter::TermFilter, generate};
+
+const ONT_NT: &str = r#"
+<http://example.org/ont#> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Ontology> .
+<http://example.org/ont#> <http://purl.org/vocab/vann/preferredNamespacePrefix> "ex" .
+<http://example.org/ont#> <http://purl.org/vocab/vann/preferredNamespaceUri> "http://example.org/ont#" .
+<http://example.org/ont#Thing> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Class> .
+<http://example.org/ont#Thing> <http://www.w3.org/2000/01/rdf-schema#label> "Thing" .
+<http://example.org/ont#Thing> <http://www.w3.org/2000/01/rdf-schema#comment> "A thing"@en .
+<http://example.org/ont#Thing> <http://www.w3.org/2000/01/rdf-schema#comment> "Ein Ding"@de .
+<http://example.org/ont#Old> <http://www.w3.org/2002/07/owl#deprecated> "1"^^<http://www.w3.org/2001/XMLSchema#boolean> .
+<http://example.org/ont#Old> <http://creativecommons.org/ns#deprecatedOn> "2024-03-01T12:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> .
+"#;
+
+#[test]
+fn test_generate_from_n_triples() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.nt");
+    fs::write(&ont_file, ONT_NT).unwrap();
+    let config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        header: Some("// SPDX-License-Identifier: CC0-1.0".to_owned()),
+        force: true,
+        langs: vec!["de".to_owned()],
+        enums: true,
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+
+    let generated = fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap();
+    assert!(generated.starts_with("// SPDX-License-Identifier: CC0-1.0\n"));
+    assert!(generated.contains("// This file was generated by vocabgen v"));
+    assert!(generated.contains("pub const NS_BASE: &str = \"http://example.org/ont#\";"));
+    assert!(generated.contains("    THING,\n"));
+    assert!(generated.contains("pub mod classes {\n"));
+    assert!(generated.contains("pub enum Term {\n"));
+    assert!(generated.contains("            Self::Thing => THING,\n"));
+    assert!(generated.contains("    DEPRECATED_OLD,\n"));
+    assert!(generated.contains("    r#\"2024-03-01\"#,\n"));
+    assert!(
+        generated.contains("    r#\"Ein Ding\n\nOther languages:\n\n- Description (en): A thing\n")
+    );
+    assert!(generated.contains("pub use classes::*;\n"));
+    assert!(generated.contains("pub static ALL_TERMS: &[oxrdf::NamedNodeRef<'_>] = &[\n"));
+
+    let mod_file = fs::read_to_string(tmp_dir.path().join("mod.rs")).unwrap();
+    assert!(mod_file.contains("pub mod ex;\n"));
+    assert!(mod_file.contains("(ex::NS_PREFERRED_PREFIX, ex::NS_BASE),"));
+}
+
+const ONT_TTL_SH_DECLARE: &str = r#"
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix sh: <http://www.w3.org/ns/shacl#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix ex: <http://example.org/ont#> .
+
+ex:
+    a owl:Ontology ;
+    sh:declare [
+        sh:prefix "ex" ;
+        sh:namespace "http://example.org/ont#"^^xsd:anyURI ;
+    ] ;
+    .
+
+ex:Thing
+    a owl:Class ;
+    rdfs:subClassOf [
+        a owl:Restriction ;
+        owl:onProperty ex:part ;
+    ] ;
+    .
+"#;
+
+#[test]
+fn test_generate_with_blank_nodes() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.ttl");
+    fs::write(&ont_file, ONT_TTL_SH_DECLARE).unwrap();
+    let config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+
+    let generated = fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap();
+    assert!(generated.contains("pub const NS_PREFERRED_PREFIX: &str = \"ex\";"));
+    assert!(generated
+        .contains("pub const SPARQL_PREFIX: &str = \"PREFIX ex: <http://example.org/ont#>\";"));
+    assert!(generated
+        .contains("pub const TURTLE_PREFIX: &str = \"@prefix ex: <http://example.org/ont#> .\";"));
+    assert!(generated.contains("  rdfs:subClassOf [\n"));
+    assert!(generated.contains("    owl:onProperty ex:part ;\n"));
+}
+
+const ONT_TTL_NON_LITERAL_LABELS: &str = r#"
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix vann: <http://purl.org/vocab/vann/> .
+@prefix ex: <http://example.org/ont#> .
+
+ex:
+    a owl:Ontology ;
+    rdfs:label [ rdfs:label "x" ] ;
+    vann:preferredNamespacePrefix "ex" ;
+    vann:preferredNamespaceUri "http://example.org/ont#" ;
+    .
+
+ex:A
+    a owl:Class ;
+    rdfs:label [ rdfs:label "x" ] ;
+    rdfs:label "A"@en ;
+    rdfs:comment <http://example.org/doc> ;
+    owl:deprecated [ rdfs:label "x" ] ;
+    .
+"#;
+
+#[test]
+fn test_generate_with_non_literal_labels() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.ttl");
+    fs::write(&ont_file, ONT_TTL_NON_LITERAL_LABELS).unwrap();
+    let config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+
+    let generated = fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap();
+    assert!(generated.contains("    A,\n"), "{generated}");
+    assert!(generated.contains("  rdfs:label [\n"), "{generated}");
+    assert!(!generated.contains("DEPRECATED_A"), "{generated}");
+}
+
+#[test]
+fn test_generate_with_imports() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let imported_file = tmp_dir.path().join("imported.nt");
+    fs::write(
+        &imported_file,
+        ONT_NT
+            .replace("http://example.org/ont#", "http://example.org/imported#")
+            .replace("\"ex\"", "\"imp\""),
+    )
+    .unwrap();
+    let imported_url = url::Url::from_file_path(&imported_file).unwrap();
+    let ont_file = tmp_dir.path().join("ont.nt");
+    fs::write(
+        &ont_file,
+        format!("{ONT_NT}<http://example.org/ont#> <http://www.w3.org/2002/07/owl#imports> <{imported_url}> .\n"),
+    )
+    .unwrap();
+    let mut config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().join("out"),
+        force: true,
+        ..Config::default()
+    };
+    fs::create_dir(&config.out_dir).unwrap();
+
+    generate(&config).unwrap();
+    assert!(config.out_dir.join("ex.rs").exists());
+    assert!(!config.out_dir.join("imp.rs").exists());
+
+    config.imports_depth = 1;
+    generate(&config).unwrap();
+    assert!(config.out_dir.join("imp.rs").exists());
+    let mod_file = fs::read_to_string(config.out_dir.join("mod.rs")).unwrap();
+    assert!(mod_file.contains("pub mod imp;\n"));
+}
+
+#[test]
+fn test_generate_filtered() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.nt");
+    fs::write(&ont_file, ONT_NT).unwrap();
+    let config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        filter: TermFilter {
+            exclude_iris: vec![regex::Regex::new("#O[a-z]+$").unwrap()],
+            ..TermFilter::default()
+        },
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+
+    let generated = fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap();
+    assert!(generated.contains("    THING,\n"));
+    assert!(!generated.contains("DEPRECATED_OLD"));
+}
+
+#[test]
+fn test_generate_sorted() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.nt");
+    let ont = [("Zeta", "Alpha"), ("Beta", "Gamma"), ("Alpha", "Zeta")]
+        .iter()
+        .fold(ONT_NT.to_owned(), |ont, (name, label)| {
+            ont + &format!(
+                "<http://example.org/ont#{name}> <http://www.w3.org/2000/01/rdf-schema#label> \"{label}\" .\n"
+            )
+        });
+    fs::write(&ont_file, ont).unwrap();
+    let mut config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        ..Config::default()
+    };
+    let positions = |generated: &str| {
+        ["    ALPHA,", "    BETA,", "    ZETA,"].map(|name| generated.find(name).unwrap())
+    };
+
+    generate(&config).unwrap();
+    let generated = fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap();
+    let [alpha, beta, zeta] = positions(&generated);
+    assert!(alpha < beta && beta < zeta);
+    generate(&config).unwrap();
+    assert_eq!(
+        fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap(),
+        generated
+    );
+
+    config.sort_by = SortBy::Label;
+    generate(&config).unwrap();
+    let generated_by_label = fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap();
+    let [alpha_by_label, beta_by_label, zeta_by_label] = positions(&generated_by_label);
+    assert!(zeta_by_label < beta_by_label && beta_by_label < alpha_by_label);
+}
+
+#[test]
+fn test_generate_backends() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.nt");
+    fs::write(&ont_file, ONT_NT).unwrap();
+    let mut config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        enums: true,
+        backend: Backend::Plain,
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+    let generated = fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap();
+    assert!(
+        generated.contains("pub const THING: &str = const_format::concatcp!(NS_BASE, \"Thing\");")
+    );
+    assert!(generated.contains("pub const fn iri(self) -> &'static str {"));
+    assert!(!generated.contains("named_node"));
+
+    config.backend = Backend::Sophia;
+    generate(&config).unwrap();
+    let generated = fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap();
+    assert!(generated.contains(
+        "pub const THING: sophia_api::term::IriRef<&'static str> = sophia_api::term::IriRef::new_unchecked_const(const_format::concatcp!(NS_BASE, \"Thing\"));"
+    ));
+    assert!(!generated.contains("named_node"));
+}
+
+#[test]
+fn test_generate_markdown() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.nt");
+    fs::write(&ont_file, ONT_NT).unwrap();
+    let config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        langs: vec!["en".to_owned()],
+        emit: vec![Emit::Markdown],
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+    assert!(tmp_dir.path().join("ex.rs").exists());
+    let generated = fs::read_to_string(tmp_dir.path().join("ex.md")).unwrap();
+    assert!(generated.contains("- Preferred prefix: `ex`\n"));
+    assert!(generated.contains("\n## Classes\n"));
+    assert!(
+        generated.contains("| `Thing` | <http://example.org/ont#Thing> | Thing | A thing |  |\n")
+    );
+    assert!(generated.contains("| `Old` | <http://example.org/ont#Old> |"));
+    assert!(generated.contains("| yes (since 2024-03-01"));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_generate_json() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.nt");
+    fs::write(&ont_file, ONT_NT).unwrap();
+    let config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        langs: vec!["en".to_owned()],
+        emit: vec![Emit::Json],
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+    let generated: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(tmp_dir.path().join("ex.json")).unwrap()).unwrap();
+    let at = |pointer: &str| generated.pointer(pointer).cloned().unwrap_or_default();
+    assert_eq!(at("/preferred_namespace_prefix"), "ex");
+    assert_eq!(at("/prefixes/ex"), "http://example.org/ont#");
+    assert_eq!(at("/terms").as_array().map(Vec::len), Some(2));
+    assert_eq!(at("/terms/0/name"), "Old");
+    assert_eq!(at("/terms/0/deprecation/since"), "2024-03-01");
+    assert_eq!(at("/terms/1/name"), "Thing");
+    assert_eq!(at("/terms/1/kind"), "class");
+    assert_eq!(at("/terms/1/label"), "Thing");
+    assert_eq!(at("/terms/1/description"), "A thing");
+    assert!(at("/terms/1/deprecation").is_null());
+}
+
+const ONT_TTL_MULTIPLE: &str = r#"
+@prefix a: <http://example.org/a#> .
+@prefix b: <http://example.org/b#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix vann: <http://purl.org/vocab/vann/> .
+
+<http://example.org/b> a owl:Ontology ;
+    vann:preferredNamespacePrefix "b" ;
+    vann:preferredNamespaceUri "http://example.org/b#" .
+
+<http://example.org/a> a owl:Ontology ;
+    vann:preferredNamespacePrefix "a" ;
+    vann:preferredNamespaceUri "http://example.org/a#" .
+
+a:Apple a owl:Class .
+
+b:Banana a owl:Class .
+
+b:Cherry a owl:Class ;
+    rdfs:isDefinedBy <http://example.org/a> .
+"#;
+
+#[test]
+fn test_generate_multiple_ontologies_per_file() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("onts.ttl");
+    fs::write(&ont_file, ONT_TTL_MULTIPLE).unwrap();
+    let mut config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+    let generated_a = fs::read_to_string(tmp_dir.path().join("a.rs")).unwrap();
+    let generated_b = fs::read_to_string(tmp_dir.path().join("b.rs")).unwrap();
+    assert!(generated_a.contains("    APPLE,"));
+    assert!(generated_a.contains("    CHERRY,"));
+    assert!(!generated_a.contains("BANANA"));
+    assert!(generated_b.contains("    BANANA,"));
+    assert!(!generated_b.contains("APPLE"));
+    assert!(!generated_b.contains("CHERRY"));
+    let mod_file = fs::read_to_string(tmp_dir.path().join("mod.rs")).unwrap();
+    assert!(mod_file.contains("pub mod a;\n"));
+    assert!(mod_file.contains("pub mod b;\n"));
+
+    let out_dir = tempfile::tempdir().unwrap();
+    config.out_dir = out_dir.path().to_owned();
+    config.ontology_iri = Some("http://example.org/b#".to_owned());
+    generate(&config).unwrap();
+    assert!(!out_dir.path().join("a.rs").exists());
+    assert!(out_dir.path().join("b.rs").exists());
+
+    config.ontology_iri = Some("http://example.org/c".to_owned());
+    assert!(generate(&config).is_err());
+}
+
+const ONT_TTL_BASE_RELATIVE: &str = r#"
+@base <http://example.org/ont> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+<> a owl:Ontology .
+
+<#Thing> a owl:Class ;
+    rdfs:label "Thing" .
+
+<#knows> a owl:ObjectProperty ;
+    rdfs:domain <#Thing> .
+"#;
+
+#[test]
+fn test_generate_base_relative() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("rel.ttl");
+    fs::write(&ont_file, ONT_TTL_BASE_RELATIVE).unwrap();
+    let config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        strict: true,
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+    let generated = fs::read_to_string(tmp_dir.path().join("rel.rs")).unwrap();
+    assert!(generated.contains("pub const NS_BASE: &str = \"http://example.org/ont#\";\n"));
+    assert!(generated.contains("pub const NS_PREFERRED_PREFIX: &str = \"rel\";\n"));
+    assert!(generated.contains("    THING,\n    NS_BASE,\n    \"Thing\","));
+    assert!(generated.contains("    KNOWS,\n    NS_BASE,\n    \"knows\","));
+    assert!(generated.contains("<http://example.org/ont#Thing>"));
+}
+
+const SKOS_TTL: &str = r#"
+@prefix fruit: <http://example.org/fruit/> .
+@prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+
+<http://example.org/fruit> a skos:ConceptScheme ;
+    skos:prefLabel "Fruit"@en .
+
+fruit:Fruit a skos:Concept ;
+    skos:inScheme <http://example.org/fruit> ;
+    skos:prefLabel "Fruit"@en ;
+    skos:definition "Something sweet that grows on plants."@en .
+
+fruit:Apple a skos:Concept ;
+    skos:inScheme <http://example.org/fruit> ;
+    skos:prefLabel "Apple"@en ;
+    skos:prefLabel "Apfel"@de ;
+    skos:definition "A round fruit."@en ;
+    skos:broader fruit:Fruit .
+
+fruit:Collection a skos:Collection ;
+    skos:member fruit:Apple .
+"#;
+
+#[test]
+fn test_generate_skos() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("fruit.ttl");
+    fs::write(&ont_file, SKOS_TTL).unwrap();
+    let mut config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        langs: vec!["en".to_owned()],
+        ..Config::default()
+    };
+
+    assert!(generate(&config).is_err());
+
+    config.mode = Mode::Skos;
+    generate(&config).unwrap();
+    let generated = fs::read_to_string(tmp_dir.path().join("fruit.rs")).unwrap();
+    assert!(generated.contains("//! [Fruit (FRUIT)](\n//! http://example.org/fruit/)\n"));
+    assert!(generated.contains("pub mod concepts {\n"));
+    assert!(generated.contains(
+        "    APPLE,\n    NS_BASE,\n    \"Apple\",\n    r#\"A round fruit.\n\nBroader concepts:\n\n- <http://example.org/fruit/Fruit>\n\nOther languages:\n\n- Label (de): Apfel\n"
+    ));
+    assert!(generated.contains("    FRUIT,\n"));
+    assert!(!generated.contains("COLLECTION"));
+}
+
+const ONT_TTL_REPLACED: &str = r#"
+@prefix ex: <http://example.org/ont#> .
+@prefix dcterms: <http://purl.org/dc/terms/> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix schema: <http://schema.org/> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+ex: a owl:Ontology .
+
+ex:Thing a owl:Class .
+
+ex:OldThing a owl:Class ;
+    owl:deprecated "true"^^xsd:boolean ;
+    schema:supersededBy ex:Thing .
+
+ex:thingRef a owl:ObjectProperty ;
+    owl:deprecated "true"^^xsd:boolean ;
+    dcterms:isReplacedBy ex:Thing .
+
+ex:oldName a owl:DatatypeProperty ;
+    owl:deprecated "true"^^xsd:boolean ;
+    dcterms:isReplacedBy <http://example.org/other#name> .
+"#;
+
+const ONT_NT_WELL_KNOWN: &str = "
+<http://usefulinc.com/ns/doap#> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Ontology> .
+<http://usefulinc.com/ns/doap#Project> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Class> .
+<http://usefulinc.com/ns/doap#Repository> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Class> .
+";
+
+#[test]
+fn test_generate_well_known_prefix() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.nt");
+    fs::write(&ont_file, ONT_NT_WELL_KNOWN).unwrap();
+    let config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        backend: Backend::Plain,
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+    let generated = fs::read_to_string(tmp_dir.path().join("doap.rs")).unwrap();
+    assert!(generated.contains("pub const NS_BASE: &str = \"http://usefulinc.com/ns/doap#\";"));
+    assert!(generated.contains("pub const NS_PREFERRED_PREFIX: &str = \"doap\";"));
+    assert!(generated.contains("pub const REPOSITORY: &str = "));
+}
+
+#[test]
+fn test_generate_replacements() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.ttl");
+    fs::write(&ont_file, ONT_TTL_REPLACED).unwrap();
+    let config = Config {
+        ontologies: vec![ont_file.into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        backend: Backend::Plain,
+        ..Config::default()
+    };
+
+    generate(&config).unwrap();
+    let generated = fs::read_to_string(tmp_dir.path().join("ex.rs")).unwrap();
+    assert!(generated.contains("#[doc = r#\"Deprecated; use [`THING`] instead.\n"));
+    assert!(generated.contains("#[doc = r#\"Deprecated; use [`THING`](super::THING) instead.\n"));
+    assert!(generated.contains(
+        "#[deprecated(since = r#\"\"#, note = r#\"use `THING` instead\"#)]\npub const DEPRECATED_OLD_THING: &str"
+    ));
+    assert!(generated.contains(
+        "#[deprecated(since = r#\"\"#, note = r#\"Use this instead: http://example.org/other#name\"#)]\npub const DEPRECATED_OLD_NAME: &str"
+    ));
+    assert!(generated.contains("pub const DEPRECATED_OLD_THING_REPLACEMENT: &str = THING;\n"));
+    assert!(generated.contains("_REPLACEMENT: &str = super::THING;\n"));
+    assert!(generated.contains(
+        "pub const DEPRECATED_OLD_NAME_REPLACEMENT: &str = \"http://example.org/other#name\";\n"
+    ));
+    assert!(
+        generated.contains("pub static DEPRECATIONS: &[(&str, &str, &str, Option<&str>)] = &[\n")
+    );
+    assert!(generated.contains(
+        "    (DEPRECATED_OLD_THING, r#\"\"#, r#\"use `THING` instead\"#, Some(DEPRECATED_OLD_THING_REPLACEMENT)),\n"
+    ));
+}
+
+#[test]
+fn test_generate_skips_unchanged() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ont_file = tmp_dir.path().join("ont.nt");
+    fs::write(&ont_file, ONT_NT).unwrap();
+    let mut config = Config {
+        ontologies: vec![ont_file.clone().into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        ..Config::default()
+    };
+    let generated_file = tmp_dir.path().join("ex.rs");
+
+    assert!(!State::is_up_to_date(&config).unwrap());
+    generate(&config).unwrap();
+    assert!(tmp_dir.path().join(STATE_FILE_NAME).exists());
+    assert!(State::is_up_to_date(&config).unwrap());
+
+    fs::write(&generated_file, "// unchanged").unwrap();
+    generate(&config).unwrap();
+    assert_eq!(fs::read_to_string(&generated_file).unwrap(), "// unchanged");
+
+    config.enums = true;
+    assert!(!State::is_up_to_date(&config).unwrap());
+    generate(&config).unwrap();
+    assert_ne!(fs::read_to_string(&generated_file).unwrap(), "// unchanged");
+
+    fs::write(&ont_file, format!("{ONT_NT}\n")).unwrap();
+    assert!(!State::is_up_to_date(&config).unwrap());
+    generate(&config).unwrap();
+    assert!(State::is_up_to_date(&config).unwrap());
+}
+
+#[cfg(feature = "remote")]
+#[test]
+fn test_generate_offline() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let cache_dir = tmp_dir.path().join("cache");
+    let url = url::Url::parse("http://example.org/ont").unwrap();
+    let mut config = Config {
+        ontologies: vec![url.clone().into()],
+        out_dir: tmp_dir.path().to_owned(),
+        force: true,
+        cache_dir: Some(cache_dir.clone()),
+        offline: true,
+        ..Config::default()
+    };
+
+    let err = generate(&config).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    assert!(err.to_string().contains("offline"));
+
+    let url_cache_dir = cache_dir.join(rdfoothills_base::util::url2fname_reversible(&url));
+    fs::create_dir_all(&url_cache_dir).unwrap();
+    fs::write(url_cache_dir.join("ontology.nt"), ONT_NT).unwrap();
+    generate(&config).unwrap();
+    assert!(tmp_dir.path().join("ex.rs").exists());
+
+    config.cache_dir = None;
+    assert!(generate(&config).is_err());
+}
@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use std::collections::HashMap;
+
+use rdfoothills_vocabgen::ident::{sanitize, Idents, RenameReason};
+
+#[test]
+fn test_sanitize() {
+    assert_eq!(sanitize("THING"), "THING");
+    assert_eq!(sanitize("type"), "r#type");
+    assert_eq!(sanitize("self"), "self_");
+    assert_eq!(sanitize("1ST"), "_1ST");
+    assert_eq!(sanitize("A.B-C"), "A_B_C");
+    assert_eq!(sanitize(""), "__");
+}
+
+#[test]
+fn test_idents() {
+    let configured = HashMap::from([(
+        "http://example.org/ont#Other".to_owned(),
+        "ANOTHER".to_owned(),
+    )]);
+    let mut idents = Idents::new(&configured);
+    assert_eq!(
+        idents.allocate("http://example.org/ont#Thing", "THING"),
+        "THING"
+    );
+    assert_eq!(
+        idents.allocate("http://example.org/ont#thing", "THING"),
+        "THING__2"
+    );
+    assert_eq!(
+        idents.allocate("http://example.org/ont#Other", "OTHER"),
+        "ANOTHER"
+    );
+    assert_eq!(idents.allocate("http://example.org/ont#1st", "1ST"), "_1ST");
+
+    let reasons = idents
+        .renames()
+        .iter()
+        .map(|rename| rename.reason)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        reasons,
+        [
+            RenameReason::Collision,
+            RenameReason::Configured,
+            RenameReason::Sanitized
+        ]
+    );
+}
@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2024 Robin Vobruba <hoijui.quaero@gmail.com>
+//
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+#![allow(unused_crate_dependencies)]
+
+use rdfoothills_vocabgen::parse::{select_lang, LiteralNode};
+
+fn lit(value: &str, language: Option<&str>) -> LiteralNode {
+    LiteralNode {
+        value: value.to_owned(),
+        language: language.map(ToOwned::to_owned),
+        datatype: None,
+    }
+}
+
+#[test]
+fn test_select_lang() {
+    let literals = [
+        lit("Ding", Some("de")),
+        lit("Thing", Some("en-US")),
+        lit("Chose", Some("fr")),
+        lit("thing", None),
+    ];
+    let langs = |langs: &[&str]| {
+        langs
+            .iter()
+            .map(|&lang| lang.to_owned())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        select_lang(&literals, &langs(&["de", "en"])),
+        literals.first()
+    );
+    assert_eq!(
+        select_lang(&literals, &langs(&["en", "de"])),
+        literals.get(1)
+    );
+    assert_eq!(select_lang(&literals, &langs(&["it"])), literals.get(3));
+    assert_eq!(
+        select_lang(literals.get(..3).unwrap(), &[]),
+        literals.first()
+    );
+    assert_eq!(select_lang(&[], &langs(&["en"])), None);
+}